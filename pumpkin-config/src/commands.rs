@@ -16,6 +16,8 @@ pub struct CommandsConfig {
     pub log_console: bool, // TODO: commands...
     /// The `op` permission level of everyone that is not in the `ops` file.
     pub default_op_level: PermissionLvl,
+    /// Recording of executed commands for accountability.
+    pub audit: CommandAuditConfig,
 }
 
 impl Default for CommandsConfig {
@@ -25,6 +27,44 @@ impl Default for CommandsConfig {
             log_console: true,
             use_tty: true,
             default_op_level: PermissionLvl::Zero,
+            audit: CommandAuditConfig::default(),
+        }
+    }
+}
+
+/// Configuration for the command audit log.
+///
+/// The log is rotated once it grows past `max_file_size`: the current file becomes
+/// `<path>.1`, the previous `<path>.1` becomes `<path>.2`, and so on.
+#[derive(Deserialize, Serialize)]
+#[serde(default)]
+pub struct CommandAuditConfig {
+    /// Whether executed commands are written to the audit log.
+    pub enabled: bool,
+    /// Only commands run by senders with at least this permission level are recorded.
+    pub min_permission_level: PermissionLvl,
+    /// Whether commands run by command blocks are recorded too.
+    pub include_command_blocks: bool,
+    /// The audit log file, relative to the server directory.
+    pub path: String,
+    /// The size in bytes after which the log is rotated.
+    pub max_file_size: u64,
+    /// How many rotated log files are kept.
+    pub max_files: u32,
+    /// Whether other operators are told about recorded commands in chat.
+    pub notify_ops: bool,
+}
+
+impl Default for CommandAuditConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            min_permission_level: PermissionLvl::Two,
+            include_command_blocks: false,
+            path: "logs/commands.log".to_string(),
+            max_file_size: 10 * 1024 * 1024,
+            max_files: 5,
+            notify_ops: false,
         }
     }
 }
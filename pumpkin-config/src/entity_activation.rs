@@ -0,0 +1,30 @@
+use serde::{Deserialize, Serialize};
+
+/// Configuration for freezing entities far away from every player.
+///
+/// Entities outside the activation range of their category skip their AI, pathfinding and
+/// movement, but keep counting down timers such as item despawning. Ranges are in blocks and
+/// measured horizontally. Players, projectiles and vehicles are always active.
+#[derive(Deserialize, Serialize)]
+#[serde(default)]
+pub struct EntityActivationConfig {
+    /// Whether distant entities are frozen. Vanilla ticks every entity in simulation distance.
+    pub enabled: bool,
+    /// Range for hostile mobs.
+    pub monsters: u32,
+    /// Range for passive mobs, such as animals and fish.
+    pub animals: u32,
+    /// Range for dropped items and experience orbs.
+    pub misc: u32,
+}
+
+impl Default for EntityActivationConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            monsters: 32,
+            animals: 32,
+            misc: 16,
+        }
+    }
+}
@@ -0,0 +1,39 @@
+use serde::{Deserialize, Serialize};
+
+/// Configuration for which entities are sent to which players.
+///
+/// Ranges are in blocks and are capped by each player's view distance. The defaults match the
+/// vanilla tracking ranges.
+#[derive(Deserialize, Serialize)]
+#[serde(default)]
+pub struct EntityTrackingConfig {
+    /// Whether entities are only sent to players within their tracking range. Otherwise every
+    /// entity in a player's view distance is sent to them.
+    pub enabled: bool,
+    /// Ticks between updates of which entities each player tracks.
+    pub update_interval: u32,
+    /// Range for hostile mobs.
+    pub monsters: u32,
+    /// Range for passive mobs, such as animals, villagers and fish.
+    pub animals: u32,
+    /// Range for dropped items and experience orbs.
+    pub misc: u32,
+    /// Range for projectiles, such as arrows, snowballs and fireballs.
+    pub projectiles: u32,
+    /// Range for every other entity, such as boats, minecarts and armor stands.
+    pub other: u32,
+}
+
+impl Default for EntityTrackingConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            update_interval: 1,
+            monsters: 128,
+            animals: 160,
+            misc: 96,
+            projectiles: 64,
+            other: 160,
+        }
+    }
+}
@@ -18,7 +18,10 @@ pub mod recipe;
 pub mod resource_pack;
 
 pub use chat::ChatConfig;
-pub use commands::CommandsConfig;
+pub use commands::{CommandAuditConfig, CommandsConfig};
+pub use entity_activation::EntityActivationConfig;
+pub use entity_tracking::EntityTrackingConfig;
+pub use movement::MovementConfig;
 pub use networking::auth::AuthenticationConfig;
 pub use networking::compression::CompressionConfig;
 pub use networking::lan_broadcast::LANBroadcastConfig;
@@ -28,12 +31,15 @@ pub use pvp::PVPConfig;
 pub use server_links::ServerLinksConfig;
 
 mod commands;
+mod entity_activation;
+mod entity_tracking;
 
 mod chat;
 pub mod chunk;
 pub mod lighting;
 pub mod op;
 
+mod movement;
 mod player_data;
 mod pvp;
 mod server_links;
@@ -88,6 +94,12 @@ pub struct AdvancedConfiguration {
     pub chat: ChatConfig,
     /// Player-vs-player rules and mechanics.
     pub pvp: PVPConfig,
+    /// Server-side validation of player movement.
+    pub movement: MovementConfig,
+    /// Ranges within which entities are sent to players.
+    pub entity_tracking: EntityTrackingConfig,
+    /// Ranges outside of which entities stop running their AI.
+    pub entity_activation: EntityActivationConfig,
     /// Server links configuration exposed to clients.
     pub server_links: ServerLinksConfig,
     /// Persistent player data handling and storage behaviour.
@@ -120,7 +132,8 @@ pub struct BasicConfiguration {
     pub max_players: u32,
     /// The maximum view distance for players.
     pub view_distance: NonZeroU8,
-    /// The maximum simulated view distance.
+    /// The maximum number of chunks around each player in which entities and blocks are ticked.
+    /// Chunks are still sent up to `view_distance`.
     pub simulation_distance: NonZeroU8,
     /// The default game difficulty.
     pub default_difficulty: Difficulty,
@@ -132,11 +145,15 @@ pub struct BasicConfiguration {
     pub allow_end: bool,
     /// Whether the server is in hardcore mode.
     pub hardcore: bool,
+    /// Whether to hide coordinates and other details from every player's F3 debug screen, on top
+    /// of the `reduced_debug_info` game rule.
+    pub reduced_debug_info: bool,
     /// Whether online mode is enabled. Requires valid Minecraft accounts.
     pub online_mode: bool,
     /// Whether packet encryption is enabled. Required when online mode is enabled.
     pub encryption: bool,
     /// Message of the Day; the server's description displayed on the status screen.
+    /// `%online%` and `%max%` are replaced with the current and maximum player counts.
     pub motd: String,
     /// The server's ticks per second.
     pub tps: f32,
@@ -148,7 +165,7 @@ pub struct BasicConfiguration {
     pub scrub_ips: bool,
     /// Whether to use a server favicon.
     pub use_favicon: bool,
-    /// Path to optional server favicon.
+    /// Path to optional server favicon. Without one, `server-icon.png` is used if present.
     pub favicon_path: Option<String>,
     /// The default level name
     pub default_level_name: String,
@@ -158,6 +175,10 @@ pub struct BasicConfiguration {
     pub white_list: bool,
     /// Whether to enforce the whitelist.
     pub enforce_whitelist: bool,
+    /// The message shown to players who aren't whitelisted. Leave empty for the vanilla message.
+    pub whitelist_message: String,
+    /// Minutes a player may go without input before being kicked. Specifying `0` disables this.
+    pub player_idle_timeout: u32,
 }
 
 impl Default for BasicConfiguration {
@@ -176,6 +197,7 @@ impl Default for BasicConfiguration {
             allow_nether: true,
             allow_end: true,
             hardcore: false,
+            reduced_debug_info: false,
             online_mode: true,
             encryption: true,
             motd: "A blazingly fast Pumpkin server!".to_string(),
@@ -189,6 +211,8 @@ impl Default for BasicConfiguration {
             allow_chat_reports: false,
             white_list: false,
             enforce_whitelist: false,
+            whitelist_message: String::new(),
+            player_idle_timeout: 0,
         }
     }
 }
@@ -0,0 +1,38 @@
+use serde::{Deserialize, Serialize};
+
+/// Configuration for server-side validation of player movement.
+///
+/// Moves exceeding a limit are rejected and the player is teleported back to their last valid
+/// position.
+#[derive(Deserialize, Serialize)]
+#[serde(default)]
+pub struct MovementConfig {
+    /// Whether player movement is validated.
+    pub enabled: bool,
+    /// The maximum distance in blocks a player may move per tick, walking or flying.
+    pub max_speed: f64,
+    /// The maximum distance in blocks a player may move per tick while gliding with an elytra.
+    pub max_fall_flying_speed: f64,
+    /// Multiplier applied to every limit, raise it to avoid false positives on laggy connections.
+    pub tolerance: f64,
+    /// Ticks a player who may not fly can hover in mid-air before being kicked. `0` disables the
+    /// check.
+    pub max_floating_ticks: u32,
+    /// Moves in a row that may be rejected before the player is kicked. `0` never kicks.
+    pub max_violations: u32,
+}
+
+impl Default for MovementConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            // Vanilla rejects moves of more than 10 blocks, or ~17 blocks with an elytra.
+            max_speed: 10.0,
+            max_fall_flying_speed: 17.3,
+            tolerance: 1.0,
+            // Vanilla kicks players floating for 4 seconds if flight is disabled.
+            max_floating_ticks: 80,
+            max_violations: 0,
+        }
+    }
+}
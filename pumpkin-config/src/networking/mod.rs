@@ -1,6 +1,7 @@
 use auth::AuthenticationConfig;
 use proxy::ProxyConfig;
 use query::QueryConfig;
+use rate_limit::PacketRateLimitConfig;
 use rcon::RCONConfig;
 use serde::{Deserialize, Serialize};
 
@@ -11,13 +12,14 @@ pub mod compression;
 pub mod lan_broadcast;
 pub mod proxy;
 pub mod query;
+pub mod rate_limit;
 pub mod rcon;
 
 /// Configuration for server networking features.
 ///
 /// Covers authentication, query, RCON, proxying, packet compression,
-/// and LAN broadcast behaviour.
-#[derive(Deserialize, Serialize, Default)]
+/// LAN broadcast and inbound packet rate limiting behaviour.
+#[derive(Deserialize, Serialize)]
 pub struct NetworkingConfig {
     /// Authentication settings for client connections.
     pub authentication: AuthenticationConfig,
@@ -33,4 +35,49 @@ pub struct NetworkingConfig {
     pub bedrock_compression: CompressionConfig,
     /// LAN broadcast settings.
     pub lan_broadcast: LANBroadcastConfig,
+    /// Per-connection rate limits for inbound play packets.
+    #[serde(default)]
+    pub packet_rate_limit: PacketRateLimitConfig,
+    /// Number of ticks a client has to report that it finished loading the world
+    /// before it is kicked.
+    #[serde(default = "default_client_load_timeout")]
+    pub client_load_timeout: u32,
+    /// Maximum length, in UTF-16 characters, of strings read from clients without a
+    /// packet-specific limit.
+    #[serde(default = "default_max_string_length")]
+    pub max_string_length: usize,
+    /// Maximum number of elements a list read from clients may declare.
+    #[serde(default = "default_max_list_length")]
+    pub max_list_length: usize,
+}
+
+const fn default_client_load_timeout() -> u32 {
+    // 15 seconds at 20 TPS. Vanilla's 3 second window is too short for slow clients
+    300
+}
+
+const fn default_max_string_length() -> usize {
+    32767 // Vanilla's default string limit
+}
+
+const fn default_max_list_length() -> usize {
+    65536
+}
+
+impl Default for NetworkingConfig {
+    fn default() -> Self {
+        Self {
+            authentication: AuthenticationConfig::default(),
+            query: QueryConfig::default(),
+            rcon: RCONConfig::default(),
+            proxy: ProxyConfig::default(),
+            java_compression: CompressionConfig::default(),
+            bedrock_compression: CompressionConfig::default(),
+            lan_broadcast: LANBroadcastConfig::default(),
+            packet_rate_limit: PacketRateLimitConfig::default(),
+            client_load_timeout: default_client_load_timeout(),
+            max_string_length: default_max_string_length(),
+            max_list_length: default_max_list_length(),
+        }
+    }
 }
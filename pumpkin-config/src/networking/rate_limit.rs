@@ -0,0 +1,72 @@
+use serde::{Deserialize, Serialize};
+
+/// Configuration for per-connection rate limiting of inbound play packets.
+///
+/// Each packet category has its own token bucket: it holds up to `burst` packets and refills
+/// at `per_second` packets per second.
+#[derive(Deserialize, Serialize)]
+#[serde(default)]
+pub struct PacketRateLimitConfig {
+    /// Whether inbound packets are rate limited.
+    pub enabled: bool,
+    /// What happens to a client that exceeds a limit.
+    pub action: RateLimitAction,
+    /// Limit for chat messages and chat commands.
+    pub chat: RateLimit,
+    /// Limit for command suggestion requests, which are sent while typing a command.
+    ///
+    /// Requests over this limit are always dropped, regardless of `action`.
+    pub command_suggestion: RateLimit,
+    /// Limit for inventory interactions such as slot clicks.
+    pub inventory: RateLimit,
+    /// Limit for player movement and rotation.
+    pub movement: RateLimit,
+    /// Limit for block and entity interactions, such as using items or digging.
+    pub interaction: RateLimit,
+    /// Limit for every other play packet.
+    pub other: RateLimit,
+}
+
+impl Default for PacketRateLimitConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            action: RateLimitAction::Kick,
+            // Vanilla kicks for spam after ~10 quick messages, allowing one per second after that.
+            chat: RateLimit::new(1.0, 10),
+            // The client asks for suggestions on every keystroke of a command.
+            command_suggestion: RateLimit::new(20.0, 60),
+            inventory: RateLimit::new(60.0, 120),
+            // Clients send up to 20 movement packets per second, plus vehicle and teleport bursts.
+            movement: RateLimit::new(60.0, 200),
+            interaction: RateLimit::new(60.0, 120),
+            other: RateLimit::new(200.0, 500),
+        }
+    }
+}
+
+/// A token bucket configuration.
+#[derive(Deserialize, Serialize, Clone, Copy)]
+pub struct RateLimit {
+    /// The number of packets refilled per second.
+    pub per_second: f64,
+    /// The maximum number of packets that can be received in a burst.
+    pub burst: u32,
+}
+
+impl RateLimit {
+    #[must_use]
+    pub const fn new(per_second: f64, burst: u32) -> Self {
+        Self { per_second, burst }
+    }
+}
+
+/// The action taken when a client exceeds a packet rate limit.
+#[derive(Deserialize, Serialize, Clone, Copy, PartialEq, Eq, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum RateLimitAction {
+    /// Silently ignore packets over the limit.
+    Drop,
+    /// Disconnect the client.
+    Kick,
+}
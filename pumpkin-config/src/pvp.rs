@@ -16,6 +16,9 @@ pub struct PVPConfig {
     pub knockback: bool,
     /// Whether players swing their hand when attacking.
     pub swing: bool,
+    /// Ticks a player has to wait after eating an enchanted golden apple before eating another,
+    /// so they can't be spammed in fights. `0` disables it, like in vanilla.
+    pub enchanted_golden_apple_cooldown: u32,
 }
 
 impl Default for PVPConfig {
@@ -26,6 +29,7 @@ impl Default for PVPConfig {
             protect_creative: true,
             knockback: true,
             swing: true,
+            enchanted_golden_apple_cooldown: 20,
         }
     }
 }
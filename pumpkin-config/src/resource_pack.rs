@@ -8,20 +8,66 @@ pub struct ResourcePackConfig {
     pub bedrock: BedrockResourcePackConfig,
 }
 
-/// Java-specific resource pack configuration (Single URL/Hash)
+/// Java-specific resource pack configuration (Supports a stack of remote packs)
 #[derive(Deserialize, Serialize, Default)]
-#[serde(default)]
+#[serde(from = "JavaResourcePackFile")]
 pub struct JavaResourcePackConfig {
     /// Whether the resource pack system is enabled.
     pub enabled: bool,
+    /// List of packs to be sent to the client, applied in order.
+    pub packs: Vec<JavaPack>,
+}
+
+/// The stored Java configuration, which also accepts the single `url`/`sha1` pack of older
+/// configs.
+#[derive(Deserialize, Default)]
+#[serde(default)]
+struct JavaResourcePackFile {
+    enabled: bool,
+    packs: Vec<JavaPack>,
+    url: String,
+    sha1: String,
+    prompt_message: String,
+    force: bool,
+}
+
+impl From<JavaResourcePackFile> for JavaResourcePackConfig {
+    fn from(file: JavaResourcePackFile) -> Self {
+        let mut packs = file.packs;
+        if !file.url.is_empty() {
+            packs.insert(
+                0,
+                JavaPack {
+                    // The UUID the single pack was sent with
+                    uuid: Uuid::new_v3(&Uuid::NAMESPACE_DNS, file.url.as_bytes()),
+                    url: file.url,
+                    sha1: file.sha1,
+                    force: file.force,
+                    prompt_message: file.prompt_message,
+                },
+            );
+        }
+        Self {
+            enabled: file.enabled,
+            packs,
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct JavaPack {
+    /// Identifies the pack on the client so it can be removed individually.
+    pub uuid: Uuid,
     /// The URL to the resource pack.
     pub url: String,
     /// The SHA1 hash (40 characters) of the resource pack.
     pub sha1: String,
-    /// Custom prompt text component shown to players; leave blank for none.
-    pub prompt_message: String,
     /// Whether players are forced to accept the resource pack.
+    #[serde(default)]
     pub force: bool,
+    /// Custom prompt text component shown to players; leave blank for none.
+    #[serde(default)]
+    pub prompt_message: String,
 }
 
 /// Bedrock-specific configuration (Supports multiple local/remote packs)
@@ -54,3 +100,51 @@ pub struct BedrockPack {
     #[serde(default)]
     pub rtx_enabled: bool,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn single_pack_configs_become_a_pack_list() {
+        let config: JavaResourcePackConfig = toml::from_str(
+            r#"
+            enabled = true
+            url = "https://example.com/pack.zip"
+            sha1 = "0123456789abcdef0123456789abcdef01234567"
+            force = true
+            "#,
+        )
+        .expect("valid config");
+
+        assert!(config.enabled);
+        assert_eq!(config.packs.len(), 1);
+        let pack = &config.packs[0];
+        assert_eq!(pack.url, "https://example.com/pack.zip");
+        assert_eq!(pack.sha1, "0123456789abcdef0123456789abcdef01234567");
+        assert!(pack.force);
+        assert_eq!(
+            pack.uuid,
+            Uuid::new_v3(&Uuid::NAMESPACE_DNS, b"https://example.com/pack.zip")
+        );
+    }
+
+    #[test]
+    fn pack_lists_round_trip() {
+        let config = JavaResourcePackConfig {
+            enabled: true,
+            packs: vec![JavaPack {
+                uuid: Uuid::nil(),
+                url: "https://example.com/pack.zip".to_string(),
+                sha1: String::new(),
+                force: false,
+                prompt_message: String::new(),
+            }],
+        };
+        let parsed: JavaResourcePackConfig =
+            toml::from_str(&toml::to_string(&config).expect("serializable")).expect("valid config");
+
+        assert_eq!(parsed.packs.len(), 1);
+        assert_eq!(parsed.packs[0].url, "https://example.com/pack.zip");
+    }
+}
@@ -11,9 +11,14 @@ pub struct LevelConfig {
     pub chunk: ChunkConfig,
     #[serde(default)]
     pub lighting: LightingEngineConfig,
-    /// Number of ticks between autosave checks. If 0, autosave is disabled.
+    /// Number of ticks between autosaves of modified chunks, online players and `level.dat`.
+    /// If 0, autosave is disabled.
     #[serde(default = "default_autosave_ticks")]
     pub autosave_ticks: u64,
+    /// Maximum number of chunks read from disk per tick, spreading large loads over several
+    /// ticks. If 0, chunk loading is not rate limited.
+    #[serde(default)]
+    pub max_chunk_loads_per_tick: u32,
     // TODO: More options
 }
 
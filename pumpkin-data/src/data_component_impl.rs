@@ -3,10 +3,11 @@
 use crate::attributes::Attributes;
 use crate::data_component::DataComponent;
 use crate::data_component::DataComponent::{
-    AttributeModifiers, BlocksAttacks, ChargedProjectiles, Consumable, CustomData, CustomName,
-    Damage, DamageResistant, DeathProtection, Enchantable, Enchantments, Equippable,
-    FireworkExplosion, Fireworks, Food, ItemModel, ItemName, JukeboxPlayable, MapId, MaxDamage,
-    MaxStackSize, PotionContents, StoredEnchantments, Tool, Unbreakable, UseCooldown, Weapon,
+    AttributeModifiers, BlocksAttacks, CanBreak, CanPlaceOn, ChargedProjectiles, Consumable,
+    CustomData, CustomName, Damage, DamageResistant, DeathProtection, Enchantable, Enchantments,
+    Equippable, FireworkExplosion, Fireworks, Food, ItemModel, ItemName, JukeboxPlayable, MapId,
+    MaxDamage, MaxStackSize, PotionContents, StoredEnchantments, Tool, Unbreakable, UseCooldown,
+    Weapon, WrittenBookContent,
 };
 use crate::effect::{self, StatusEffect};
 use crate::entity_type::EntityType;
@@ -70,6 +71,8 @@ pub fn read_data(id: DataComponent, data: &NbtTag) -> Option<Box<dyn DataCompone
         DataComponent::ChargedProjectiles => {
             Some(ChargedProjectilesImpl::read_data(data)?.to_dyn())
         }
+        CanPlaceOn => Some(CanPlaceOnImpl::read_data(data)?.to_dyn()),
+        CanBreak => Some(CanBreakImpl::read_data(data)?.to_dyn()),
         _ => None,
     }
 }
@@ -371,6 +374,26 @@ fn hash() {
     assert_eq!(MapIdImpl { id: 10 }.get_hash(), -919192125i32);
 }
 
+#[test]
+fn can_place_on_matches_listed_blocks() {
+    let mut stone = NbtCompound::new();
+    stone.put_string("blocks", "minecraft:stone".to_string());
+    let mut logs = NbtCompound::new();
+    logs.put_string("blocks", "#minecraft:logs".to_string());
+
+    let single = CanPlaceOnImpl::read_data(&NbtTag::Compound(stone.clone())).unwrap();
+    assert!(single.matches(&Block::STONE));
+    assert!(!single.matches(&Block::DIRT));
+
+    let list = CanPlaceOnImpl::read_data(&NbtTag::List(vec![
+        NbtTag::Compound(stone),
+        NbtTag::Compound(logs),
+    ]))
+    .unwrap();
+    assert!(list.matches(&Block::OAK_LOG));
+    assert!(!list.matches(&Block::DIRT));
+}
+
 impl DataComponentImpl for EnchantmentsImpl {
     fn write_data(&self) -> NbtTag {
         let mut data = NbtCompound::new();
@@ -391,10 +414,133 @@ impl DataComponentImpl for EnchantmentsImpl {
     }
     default_impl!(Enchantments);
 }
-#[derive(Clone, Debug, Hash, PartialEq, Eq)]
-pub struct CanPlaceOnImpl;
-#[derive(Clone, Debug, Hash, PartialEq, Eq)]
-pub struct CanBreakImpl;
+/// A block predicate of the adventure mode `can_place_on` and `can_break` components.
+/// TODO: block state and NBT conditions
+#[derive(Clone, Debug, Hash, PartialEq)]
+pub struct BlockPredicate {
+    /// The blocks that match, or `None` to match any block.
+    pub blocks: Option<IDSet<Block>>,
+}
+impl BlockPredicate {
+    fn read(data: &NbtTag) -> Option<Self> {
+        let compound = data.extract_compound()?;
+        let blocks = match compound.get("blocks") {
+            Some(blocks) => Some(IDSet::read(blocks)?),
+            None => None,
+        };
+        Some(Self { blocks })
+    }
+
+    fn write(&self) -> NbtTag {
+        let mut compound = NbtCompound::new();
+        if let Some(blocks) = &self.blocks {
+            blocks.write(&mut compound, "blocks");
+        }
+        NbtTag::Compound(compound)
+    }
+
+    fn get_hash(&self) -> u32 {
+        let mut digest = Digest::new(Crc32Iscsi);
+        digest.update(&[2u8]);
+        if let Some(blocks) = &self.blocks {
+            digest.update(&get_str_hash("blocks").to_le_bytes());
+            digest.update(&get_idset_hash(blocks).to_le_bytes());
+        }
+        digest.update(&[3u8]);
+        digest.finalize() as u32
+    }
+
+    #[must_use]
+    pub fn test(&self, block: &'static Block) -> bool {
+        match &self.blocks {
+            None => true,
+            Some(IDSet::Tag(tag)) => block.is_tagged_with(tag).unwrap_or(false),
+            Some(IDSet::IDs(blocks)) => blocks.contains(&block),
+        }
+    }
+}
+
+/// Reads an adventure mode predicate, which is either a single block predicate or a list of them.
+fn read_block_predicates(data: &NbtTag) -> Option<Vec<BlockPredicate>> {
+    match data {
+        NbtTag::List(list) => list.iter().map(BlockPredicate::read).collect(),
+        data => Some(vec![BlockPredicate::read(data)?]),
+    }
+}
+
+fn write_block_predicates(predicates: &[BlockPredicate]) -> NbtTag {
+    match predicates {
+        [predicate] => predicate.write(),
+        predicates => NbtTag::List(predicates.iter().map(BlockPredicate::write).collect()),
+    }
+}
+
+fn get_block_predicates_hash(predicates: &[BlockPredicate]) -> i32 {
+    if let [predicate] = predicates {
+        return predicate.get_hash() as i32;
+    }
+    let mut digest = Digest::new(Crc32Iscsi);
+    digest.update(&[4u8]);
+    for predicate in predicates {
+        digest.update(&predicate.get_hash().to_le_bytes());
+    }
+    digest.update(&[5u8]);
+    digest.finalize() as i32
+}
+
+/// The blocks an adventure mode player may place this item on.
+#[derive(Clone, Debug, Hash, PartialEq)]
+pub struct CanPlaceOnImpl {
+    pub predicates: Vec<BlockPredicate>,
+}
+impl CanPlaceOnImpl {
+    fn read_data(data: &NbtTag) -> Option<Self> {
+        read_block_predicates(data).map(|predicates| Self { predicates })
+    }
+
+    #[must_use]
+    pub fn matches(&self, block: &'static Block) -> bool {
+        self.predicates
+            .iter()
+            .any(|predicate| predicate.test(block))
+    }
+}
+impl DataComponentImpl for CanPlaceOnImpl {
+    fn write_data(&self) -> NbtTag {
+        write_block_predicates(&self.predicates)
+    }
+    fn get_hash(&self) -> i32 {
+        get_block_predicates_hash(&self.predicates)
+    }
+    default_impl!(CanPlaceOn);
+}
+
+/// The blocks an adventure mode player may break with this item.
+#[derive(Clone, Debug, Hash, PartialEq)]
+pub struct CanBreakImpl {
+    pub predicates: Vec<BlockPredicate>,
+}
+impl CanBreakImpl {
+    fn read_data(data: &NbtTag) -> Option<Self> {
+        read_block_predicates(data).map(|predicates| Self { predicates })
+    }
+
+    #[must_use]
+    pub fn matches(&self, block: &'static Block) -> bool {
+        self.predicates
+            .iter()
+            .any(|predicate| predicate.test(block))
+    }
+}
+impl DataComponentImpl for CanBreakImpl {
+    fn write_data(&self) -> NbtTag {
+        write_block_predicates(&self.predicates)
+    }
+    fn get_hash(&self) -> i32 {
+        get_block_predicates_hash(&self.predicates)
+    }
+    default_impl!(CanBreak);
+}
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum Operation {
@@ -1900,7 +2046,30 @@ pub struct SuspiciousStewEffectsImpl;
 #[derive(Clone, Debug, Hash, PartialEq, Eq)]
 pub struct WritableBookContentImpl;
 #[derive(Clone, Debug, Hash, PartialEq, Eq)]
-pub struct WrittenBookContentImpl;
+pub struct WrittenBookContentImpl {
+    pub title: String,
+    pub author: String,
+    /// 0 for the original, up to 3 for a copy of a copy of a copy.
+    pub generation: i32,
+    pub pages: Vec<TextComponent>,
+    /// Whether selectors and scores in the pages have already been resolved.
+    pub resolved: bool,
+}
+impl DataComponentImpl for WrittenBookContentImpl {
+    fn get_hash(&self) -> i32 {
+        let mut digest = Digest::new(Crc32Iscsi);
+        digest.update(&get_str_hash(&self.title).to_le_bytes());
+        digest.update(&get_str_hash(&self.author).to_le_bytes());
+        digest.update(&get_i32_hash(self.generation).to_le_bytes());
+        for page in &self.pages {
+            digest.update(&get_str_hash(&page.clone().get_text()).to_le_bytes());
+        }
+        digest.update(&[u8::from(self.resolved)]);
+        digest.finalize() as i32
+    }
+
+    default_impl!(WrittenBookContent);
+}
 #[derive(Clone, Debug, Hash, PartialEq, Eq)]
 pub struct TrimImpl;
 #[derive(Clone, Debug, Hash, PartialEq, Eq)]
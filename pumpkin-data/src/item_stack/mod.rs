@@ -19,6 +19,11 @@ use std::sync::atomic::{AtomicU32, Ordering};
 
 mod categories;
 
+static ONE_SECOND_USE_COOLDOWN: UseCooldownImpl = UseCooldownImpl {
+    seconds: 1.0,
+    cooldown_group: None,
+};
+
 /// The outcome of a [`ItemStack::damage_item`] call.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum DamageResult {
@@ -231,9 +236,22 @@ impl ItemStack {
             .map(|value| value.max_damage)
     }
 
+    /// Returns the stack's `use_cooldown` component.
+    ///
+    /// The generated item data lacks this component, so ender pearls and chorus fruit fall back
+    /// to their vanilla one second cooldown unless the stack removes it.
     #[must_use]
     pub fn get_use_cooldown(&self) -> Option<&UseCooldownImpl> {
-        self.get_data_component::<UseCooldownImpl>()
+        if let Some(cooldown) = self.get_data_component::<UseCooldownImpl>() {
+            return Some(cooldown);
+        }
+        let removed = self
+            .patch
+            .iter()
+            .any(|(id, _)| *id == UseCooldownImpl::get_enum());
+        let has_vanilla_cooldown =
+            self.item.id == Item::ENDER_PEARL.id || self.item.id == Item::CHORUS_FRUIT.id;
+        (has_vanilla_cooldown && !removed).then_some(&ONE_SECOND_USE_COOLDOWN)
     }
 
     #[must_use]
@@ -315,9 +333,9 @@ impl ItemStack {
 
     /// Apply durability damage to this item and return the outcome.
     /// Callers must check the return value to handle break broadcasts and item stack updates.
-    /// TODO: Restore `#[must_use]` once all callsites (esp. tool/mob block-hit/damage sites)
-    /// implement proper `DamageResult::Broken` handling instead of suppressing with `let _ =`.
-    /// Without this enforcement, the fix is incomplete vs vanilla break behavior.
+    ///
+    /// Item and entity interactions that damage the held stack they were handed may ignore it:
+    /// the packet handler syncs the slot and plays the break effect if the use wore it out.
     #[must_use]
     pub fn damage_item(&mut self, amount: i32) -> DamageResult {
         if amount <= 0 || !self.is_damageable() || self.is_unbreakable() {
@@ -1075,4 +1093,24 @@ mod tests {
             );
         }
     }
+
+    // ── use cooldown ─────────────────────────────────────────────────
+
+    #[test]
+    fn ender_pearl_and_chorus_fruit_have_a_one_second_cooldown() {
+        for item in [&Item::ENDER_PEARL, &Item::CHORUS_FRUIT] {
+            let stack = ItemStack::new(1, item);
+            let cooldown = stack.get_use_cooldown().expect("missing use cooldown");
+            assert!((cooldown.seconds - 1.0).abs() < f32::EPSILON);
+            assert_eq!(cooldown.cooldown_group, None);
+        }
+        assert!(ItemStack::new(1, &Item::APPLE).get_use_cooldown().is_none());
+    }
+
+    #[test]
+    fn removed_use_cooldown_has_no_fallback() {
+        let mut stack = ItemStack::new(1, &Item::ENDER_PEARL);
+        stack.patch.push((DataComponent::UseCooldown, None));
+        assert!(stack.get_use_cooldown().is_none());
+    }
 }
@@ -22,7 +22,7 @@ use std::collections::HashMap;
 use std::pin::Pin;
 use std::sync::Arc;
 use std::sync::atomic::{AtomicU8, Ordering};
-use tokio::sync::Mutex;
+use tokio::sync::{Mutex, MutexGuard};
 use tracing::warn;
 
 /// The player's inventory.
@@ -110,9 +110,10 @@ impl PlayerInventory {
     /// The new main hand item and new off-hand item.
     pub async fn swap_item(&self) -> (ItemStack, ItemStack) {
         let slot = self.equipment_slots.get(&Self::OFF_HAND_SLOT).unwrap();
-        let mut equipment = self.entity_equipment.lock().await;
+        // Main slots are locked before the equipment, like in `lock_main_inventory`
         let binding = self.held_item();
         let mut main_hand_item = binding.lock().await;
+        let mut equipment = self.entity_equipment.lock().await;
         let off_hand_item = main_hand_item.clone();
         *main_hand_item = equipment.put(slot, off_hand_item.clone()).await;
         (main_hand_item.clone(), off_hand_item)
@@ -397,15 +398,31 @@ impl PlayerInventory {
 impl Clearable for PlayerInventory {
     fn clear(&self) -> Pin<Box<dyn Future<Output = ()> + Send + '_>> {
         Box::pin(async move {
-            for item in &self.main_inventory {
-                *item.lock().await = ItemStack::EMPTY.clone();
+            let mut main_inventory = self.lock_main_inventory().await;
+            let mut entity_equipment = self.entity_equipment.lock().await;
+            for stack in &mut main_inventory {
+                **stack = ItemStack::EMPTY.clone();
             }
-
-            self.entity_equipment.lock().await.clear();
+            entity_equipment.clear();
         })
     }
 }
 
+/// A copy of every slot of a [`PlayerInventory`], taken with [`PlayerInventory::snapshot`].
+#[derive(Clone)]
+pub struct InventorySnapshot {
+    /// The stacks by inventory slot index: the main inventory followed by the equipment.
+    stacks: Vec<ItemStack>,
+}
+
+impl InventorySnapshot {
+    /// Returns the stack that was in `slot` when the snapshot was taken.
+    #[must_use]
+    pub fn get_stack(&self, slot: usize) -> Option<&ItemStack> {
+        self.stacks.get(slot)
+    }
+}
+
 impl Inventory for PlayerInventory {
     fn size(&self) -> usize {
         self.main_inventory.len() + self.equipment_slots.len()
@@ -505,6 +522,58 @@ impl Inventory for PlayerInventory {
 }
 
 impl PlayerInventory {
+    /// Locks all main inventory slots in order, so no other slot operation can interleave
+    /// with a whole-inventory operation.
+    ///
+    /// The equipment must only be locked after this, to keep the lock order consistent.
+    async fn lock_main_inventory(&self) -> Vec<MutexGuard<'_, ItemStack>> {
+        let mut guards = Vec::with_capacity(Self::MAIN_SIZE);
+        for stack in &self.main_inventory {
+            guards.push(stack.lock().await);
+        }
+        guards
+    }
+
+    /// Copies every slot, including the armor and off-hand, at a single point in time.
+    pub async fn snapshot(&self) -> InventorySnapshot {
+        let main_inventory = self.lock_main_inventory().await;
+        let entity_equipment = self.entity_equipment.lock().await;
+
+        let mut stacks: Vec<ItemStack> = main_inventory
+            .iter()
+            .map(|stack| (**stack).clone())
+            .collect();
+        stacks.resize(self.size(), ItemStack::EMPTY.clone());
+        for (&index, slot) in &*self.equipment_slots {
+            if let Some(stack) = stacks.get_mut(index) {
+                *stack = entity_equipment.get(slot).lock().await.clone();
+            }
+        }
+        InventorySnapshot { stacks }
+    }
+
+    /// Replaces every slot with the stacks of `snapshot` at once.
+    ///
+    /// This only changes the inventory; the client has to be synced afterwards.
+    pub async fn restore(&self, snapshot: InventorySnapshot) {
+        let mut main_inventory = self.lock_main_inventory().await;
+        let mut entity_equipment = self.entity_equipment.lock().await;
+
+        let mut stacks = snapshot.stacks.into_iter();
+        for (stack, restored) in main_inventory.iter_mut().zip(stacks.by_ref()) {
+            **stack = restored;
+        }
+        let equipment: Vec<ItemStack> = stacks.collect();
+        for (&index, slot) in &*self.equipment_slots {
+            let restored = index
+                .checked_sub(Self::MAIN_SIZE)
+                .and_then(|equipment_index| equipment.get(equipment_index))
+                .cloned()
+                .unwrap_or_else(|| ItemStack::EMPTY.clone());
+            entity_equipment.put(slot, restored).await;
+        }
+    }
+
     /// Sets the selected hotbar slot.
     ///
     /// # Panics
@@ -522,3 +591,48 @@ impl PlayerInventory {
         self.selected_slot.load(Ordering::Relaxed)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    use pumpkin_data::item::Item;
+    use pumpkin_data::item_stack::ItemStack;
+    use tokio::sync::Mutex;
+
+    use super::PlayerInventory;
+    use crate::build_equipment_slots;
+    use crate::entity_equipment::EntityEquipment;
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn swap_does_not_deadlock_with_restore() {
+        let inventory = Arc::new(PlayerInventory::new(
+            Arc::new(Mutex::new(EntityEquipment::new())),
+            Arc::new(build_equipment_slots()),
+        ));
+        *inventory.held_item().lock().await = ItemStack::new(1, &Item::STONE);
+        let snapshot = inventory.snapshot().await;
+
+        let swapping = {
+            let inventory = inventory.clone();
+            tokio::spawn(async move {
+                for _ in 0..1000 {
+                    inventory.swap_item().await;
+                }
+            })
+        };
+        let restoring = tokio::spawn(async move {
+            for _ in 0..1000 {
+                inventory.restore(snapshot.clone()).await;
+            }
+        });
+
+        tokio::time::timeout(Duration::from_secs(10), async {
+            swapping.await.unwrap();
+            restoring.await.unwrap();
+        })
+        .await
+        .expect("swapping and restoring deadlocked");
+    }
+}
@@ -645,6 +645,36 @@ pub trait ScreenHandler: Send + Sync {
         })
     }
 
+    /// Checks whether the client's view of `slot` and the cursor still matches the server's
+    /// stacks, i.e. whether a click on `slot` was made based on the current contents.
+    fn is_client_view_in_sync(&mut self, slot: i32) -> ScreenHandlerFuture<'_, bool> {
+        Box::pin(async move {
+            if let Ok(slot) = usize::try_from(slot)
+                && slot < self.get_behaviour().slots.len()
+            {
+                let stack = self.get_behaviour().slots[slot].get_cloned_stack().await;
+                if !self.get_behaviour_mut().previous_tracked_stacks[slot].is_in_sync(&stack) {
+                    return false;
+                }
+            }
+
+            let behaviour = self.get_behaviour_mut();
+            let cursor_stack = behaviour.cursor_stack.lock().await;
+            behaviour.previous_cursor_stack.is_in_sync(&cursor_stack)
+        })
+    }
+
+    /// Checks whether a click on `slot` that the client made at state `revision` can be applied:
+    /// the client must have seen every update sent so far, and its view of `slot` and the cursor
+    /// must still match the server's stacks.
+    fn is_click_in_sync(&mut self, slot: i32, revision: i32) -> ScreenHandlerFuture<'_, bool> {
+        Box::pin(async move {
+            let current_revision = self.get_behaviour().current_revision();
+            u32::try_from(revision).is_ok_and(|revision| revision == current_revision)
+                && self.is_client_view_in_sync(slot).await
+        })
+    }
+
     /// Checks if a slot index is valid.
     fn is_slot_valid(&self, slot: i32) -> ScreenHandlerFuture<'_, bool> {
         Box::pin(async move {
@@ -1327,4 +1357,50 @@ impl ScreenHandlerBehaviour {
         self.revision.fetch_add(1, Ordering::Relaxed);
         self.revision.fetch_and(32767, Ordering::Relaxed) & 32767
     }
+
+    /// The revision of the last update sent to the client.
+    pub fn current_revision(&self) -> u32 {
+        self.revision.load(Ordering::Relaxed) & 32767
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use pumpkin_data::item::Item;
+    use pumpkin_data::item_stack::ItemStack;
+    use pumpkin_world::inventory::{Inventory, SimpleInventory};
+    use tokio::sync::Mutex;
+
+    use super::ScreenHandler;
+    use crate::build_equipment_slots;
+    use crate::entity_equipment::EntityEquipment;
+    use crate::generic_container_screen_handler::create_generic_9x3;
+    use crate::player::player_inventory::PlayerInventory;
+
+    #[tokio::test]
+    async fn clicks_on_a_stale_revision_are_rejected_until_resynced() {
+        let player_inventory = Arc::new(PlayerInventory::new(
+            Arc::new(Mutex::new(EntityEquipment::new())),
+            Arc::new(build_equipment_slots()),
+        ));
+        let chest: Arc<dyn Inventory> = Arc::new(SimpleInventory::new(27));
+        let mut handler = create_generic_9x3(1, &player_inventory, chest.clone()).await;
+        handler.sync_state().await;
+        let seen_revision = handler.get_behaviour().current_revision() as i32;
+        assert!(handler.is_click_in_sync(0, seen_revision).await);
+
+        // A hopper fills the slot and the server sends the change
+        chest.set_stack(0, ItemStack::new(1, &Item::STONE)).await;
+        handler.send_content_updates().await;
+
+        // The click was made before the client saw the update
+        assert!(!handler.is_click_in_sync(0, seen_revision).await);
+
+        handler.update_to_client().await;
+        let resynced_revision = handler.get_behaviour().current_revision() as i32;
+        assert_ne!(resynced_revision, seen_revision);
+        assert!(handler.is_click_in_sync(0, resynced_revision).await);
+    }
 }
@@ -0,0 +1,101 @@
+use std::hint::black_box;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicBool, AtomicI64};
+
+use criterion::{Criterion, Throughput, criterion_group, criterion_main};
+use pumpkin_data::{Block, chunk::ChunkStatus};
+use pumpkin_protocol::{
+    ClientPacket, CompressionLevel, CompressionThreshold, VarInt,
+    java::{client::play::CChunkData, packet_encoder::TCPNetworkEncoder},
+    ser::NetworkWriteExt,
+};
+use pumpkin_util::version::JavaMinecraftVersion;
+use pumpkin_world::chunk::{ChunkData, ChunkLight, ChunkSections};
+use tokio::runtime::Builder;
+
+const COMPRESSION: (CompressionThreshold, CompressionLevel) = (256, 4);
+
+/// A plains-like chunk: stone with scattered ores, a few layers of dirt, grass on top.
+fn typical_chunk() -> ChunkData {
+    let chunk = ChunkData {
+        section: ChunkSections::new(24, -64),
+        heightmap: Mutex::default(),
+        x: 0,
+        z: 0,
+        block_ticks: Default::default(),
+        fluid_ticks: Default::default(),
+        pending_block_entities: Mutex::default(),
+        light_engine: Mutex::new(ChunkLight::default()),
+        light_populated: AtomicBool::new(false),
+        inhabited_time: AtomicI64::new(0),
+        status: ChunkStatus::Full,
+        blending_data: None,
+        dirty: AtomicBool::new(false),
+    };
+    for x in 0..16 {
+        for z in 0..16 {
+            for y in -64..=64 {
+                let block = match y {
+                    64 => &Block::GRASS_BLOCK,
+                    60..64 => &Block::DIRT,
+                    _ if (x as i32 * 7 + y * 13 + z as i32 * 3) % 53 == 0 => &Block::COAL_ORE,
+                    _ => &Block::STONE,
+                };
+                chunk.set_block_absolute_y(x, y, z, block.default_state.id);
+            }
+        }
+    }
+    chunk
+}
+
+fn chunk_packet(chunk: &ChunkData) -> Vec<u8> {
+    let version = JavaMinecraftVersion::V_1_21_11;
+    let mut buf = Vec::new();
+    buf.write_var_int(&VarInt(CChunkData::to_id(version)))
+        .unwrap();
+    CChunkData(chunk)
+        .write_packet_data(&mut buf, &version)
+        .unwrap();
+    buf
+}
+
+async fn encode(
+    packet: &[u8],
+    compression: Option<(CompressionThreshold, CompressionLevel)>,
+) -> Vec<u8> {
+    let mut out = Vec::new();
+    let mut encoder = TCPNetworkEncoder::new(&mut out);
+    if let Some(compression) = compression {
+        encoder.set_compression(compression);
+    }
+    encoder.write_packet(packet.to_vec().into()).await.unwrap();
+    out
+}
+
+fn bench_chunk_compression(c: &mut Criterion) {
+    let runtime = Builder::new_current_thread().build().unwrap();
+    let packet = chunk_packet(&typical_chunk());
+
+    let uncompressed = runtime.block_on(encode(&packet, None));
+    let compressed = runtime.block_on(encode(&packet, Some(COMPRESSION)));
+    assert!(
+        compressed.len() * 2 < uncompressed.len(),
+        "a typical chunk packet should at least halve in size when compressed, got {} -> {} bytes",
+        uncompressed.len(),
+        compressed.len()
+    );
+
+    // Criterion reports the throughput over the uncompressed packet size
+    let mut group = c.benchmark_group("encode chunk packet");
+    group.throughput(Throughput::Bytes(uncompressed.len() as u64));
+    group.bench_function("uncompressed", |b| {
+        b.iter(|| runtime.block_on(encode(black_box(&packet), None)));
+    });
+    group.bench_function("compressed", |b| {
+        b.iter(|| runtime.block_on(encode(black_box(&packet), Some(COMPRESSION))));
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_chunk_compression);
+criterion_main!(benches);
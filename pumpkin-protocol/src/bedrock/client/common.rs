@@ -19,3 +19,24 @@ pub struct EntityLink {
     pub rider_initiated: bool,
     pub vehicle_angular_velocity: f32,
 }
+
+impl EntityLink {
+    /// The rider leaves the vehicle.
+    pub const TYPE_REMOVE: u8 = 0;
+    /// The rider controls the vehicle.
+    pub const TYPE_RIDER: u8 = 1;
+    /// The rider only sits in the vehicle.
+    pub const TYPE_PASSENGER: u8 = 2;
+
+    #[must_use]
+    pub const fn new(ridden_entity_id: i32, rider_entity_id: i32, link_type: u8) -> Self {
+        Self {
+            ridden_unique_id: VarLong(ridden_entity_id as i64),
+            rider_unique_id: VarLong(rider_entity_id as i64),
+            link_type,
+            immediate: false,
+            rider_initiated: false,
+            vehicle_angular_velocity: 0.0,
+        }
+    }
+}
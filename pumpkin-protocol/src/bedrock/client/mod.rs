@@ -30,6 +30,7 @@ pub mod resource_pack_stack;
 pub mod resource_packs_info;
 pub mod scoreboard;
 pub mod set_actor_data;
+pub mod set_actor_link;
 pub mod set_actor_motion;
 pub mod set_health;
 pub mod set_player_gamemode;
@@ -73,6 +74,7 @@ pub use resource_pack_stack::*;
 pub use resource_packs_info::*;
 pub use scoreboard::*;
 pub use set_actor_data::*;
+pub use set_actor_link::*;
 pub use set_actor_motion::*;
 pub use set_health::*;
 pub use set_player_gamemode::*;
@@ -0,0 +1,20 @@
+use crate::serial::PacketWrite;
+use pumpkin_macros::packet;
+
+use super::common::EntityLink;
+
+/// Mounts an entity onto a vehicle or dismounts it, like Java's set passengers packet but for a
+/// single passenger.
+#[derive(PacketWrite)]
+#[packet(41)]
+pub struct CSetActorLink {
+    // https://github.com/Sandertv/gophertunnel/blob/master/minecraft/protocol/packet/set_actor_link.go
+    pub link: EntityLink,
+}
+
+impl CSetActorLink {
+    #[must_use]
+    pub const fn new(link: EntityLink) -> Self {
+        Self { link }
+    }
+}
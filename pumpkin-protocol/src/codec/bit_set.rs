@@ -25,9 +25,9 @@ impl BitSet {
 
     pub fn decode(read: &mut impl Read) -> Result<Self, ReadingError> {
         // Read length
-        let length = read.get_var_int()?;
-        let mut array: Vec<i64> = Vec::with_capacity(length.0 as usize);
-        for _ in 0..length.0 {
+        let length = read.get_list_len()?;
+        let mut array: Vec<i64> = Vec::with_capacity(length);
+        for _ in 0..length {
             let long = read.get_i64_be()?;
             array.push(long);
         }
@@ -1,18 +1,21 @@
 use std::borrow::Cow;
 
 use crate::codec::var_int::VarInt;
+use crate::ser::max_list_length;
 use pumpkin_data::Enchantment;
 use pumpkin_data::data_component::DataComponent;
 use pumpkin_data::data_component_impl::{
-    ConsumableImpl, ConsumeAnimation, ConsumeEffect, CustomNameImpl, DamageImpl, DataComponentImpl,
-    EnchantmentsImpl, EquipmentSlot, EquippableImpl, FireworkExplosionImpl, FireworkExplosionShape,
-    FireworksImpl, IDSet, IDSetContent, IdOr, ItemModelImpl, MapIdImpl, MaxStackSizeImpl,
-    PotionContentsImpl, SoundEvent, StatusEffectInstance, StoredEnchantmentsImpl, UnbreakableImpl,
-    UseCooldownImpl, get,
+    BlockPredicate, CanBreakImpl, CanPlaceOnImpl, ConsumableImpl, ConsumeAnimation, ConsumeEffect,
+    CustomNameImpl, DamageImpl, DataComponentImpl, EnchantmentsImpl, EquipmentSlot, EquippableImpl,
+    FireworkExplosionImpl, FireworkExplosionShape, FireworksImpl, IDSet, IDSetContent, IdOr,
+    ItemModelImpl, MapIdImpl, MaxStackSizeImpl, PotionContentsImpl, SoundEvent,
+    StatusEffectInstance, StoredEnchantmentsImpl, UnbreakableImpl, UseCooldownImpl,
+    WrittenBookContentImpl, get,
 };
 use pumpkin_data::effect::StatusEffect;
 use pumpkin_data::entity::EntityType;
 use pumpkin_data::sound::Sound;
+use pumpkin_util::text::TextComponent;
 use serde::de;
 use serde::de::SeqAccess;
 use serde::ser::SerializeStruct;
@@ -60,8 +63,13 @@ fn deserialize_idset<'a, A: SeqAccess<'a>, T: IDSetContent>(
             Ok(IDSet::Tag(Cow::Owned(tag)))
         }
         std::cmp::Ordering::Greater => {
-            let len = id_type - 1;
-            let mut content_vec = Vec::with_capacity(len as usize);
+            let len = (id_type - 1) as usize;
+            if len > max_list_length() {
+                return Err(de::Error::custom(format!(
+                    "IDSet length {len} exceeds the limit"
+                )));
+            }
+            let mut content_vec = Vec::with_capacity(len);
 
             for _ in 0..len {
                 let varint_id = seq
@@ -250,6 +258,98 @@ fn serialize_consume_effect<T: SerializeStruct>(
     Ok(())
 }
 
+fn serialize_block_predicates<T: SerializeStruct>(
+    predicates: &[BlockPredicate],
+    seq: &mut T,
+) -> Result<(), T::Error> {
+    seq.serialize_field::<VarInt>("", &VarInt::from(predicates.len() as i32))?;
+    for predicate in predicates {
+        seq.serialize_field::<bool>("", &predicate.blocks.is_some())?;
+        if let Some(blocks) = &predicate.blocks {
+            serialize_idset(blocks, seq)?;
+        }
+        // No block state or NBT conditions
+        seq.serialize_field::<bool>("", &false)?;
+        seq.serialize_field::<bool>("", &false)?;
+        // No exact or partial component matchers
+        seq.serialize_field::<VarInt>("", &VarInt(0))?;
+        seq.serialize_field::<VarInt>("", &VarInt(0))?;
+    }
+    Ok(())
+}
+
+fn serialize_written_book_content<T: SerializeStruct>(
+    content: &WrittenBookContentImpl,
+    seq: &mut T,
+) -> Result<(), T::Error> {
+    // The title and pages are filterable; we never send a filtered variant
+    seq.serialize_field::<str>("", &content.title)?;
+    seq.serialize_field::<bool>("", &false)?;
+    seq.serialize_field::<str>("", &content.author)?;
+    seq.serialize_field::<VarInt>("", &VarInt(content.generation))?;
+    seq.serialize_field::<VarInt>("", &VarInt::from(content.pages.len() as i32))?;
+    for page in &content.pages {
+        seq.serialize_field::<TextComponent>("", page)?;
+        seq.serialize_field::<bool>("", &false)?;
+    }
+    seq.serialize_field::<bool>("", &content.resolved)
+}
+
+fn deserialize_block_predicates<'a, A: SeqAccess<'a>>(
+    seq: &mut A,
+) -> Result<Vec<BlockPredicate>, A::Error> {
+    // Vanilla doesn't have any limits
+    const MAX_PREDICATES: usize = 256;
+
+    let len = seq
+        .next_element::<VarInt>()?
+        .ok_or(de::Error::custom("No BlockPredicate list length VarInt!"))?
+        .0 as usize;
+    if len > MAX_PREDICATES {
+        return Err(de::Error::custom(format!(
+            "BlockPredicate list length {len} exceeds maximum of {MAX_PREDICATES}"
+        )));
+    }
+
+    let mut predicates = Vec::with_capacity(len);
+    for _ in 0..len {
+        let has_blocks = seq
+            .next_element::<bool>()?
+            .ok_or(de::Error::custom("No BlockPredicate blocks bool!"))?;
+        let blocks = if has_blocks {
+            Some(deserialize_idset(seq)?)
+        } else {
+            None
+        };
+        let has_state = seq
+            .next_element::<bool>()?
+            .ok_or(de::Error::custom("No BlockPredicate state bool!"))?;
+        let has_nbt = seq
+            .next_element::<bool>()?
+            .ok_or(de::Error::custom("No BlockPredicate nbt bool!"))?;
+        if has_state || has_nbt {
+            return Err(de::Error::custom(
+                "BlockPredicate state and nbt conditions (TODO)",
+            ));
+        }
+        for _ in 0..2 {
+            let matchers = seq
+                .next_element::<VarInt>()?
+                .ok_or(de::Error::custom(
+                    "No BlockPredicate component matchers VarInt!",
+                ))?
+                .0;
+            if matchers != 0 {
+                return Err(de::Error::custom(
+                    "BlockPredicate component matchers (TODO)",
+                ));
+            }
+        }
+        predicates.push(BlockPredicate { blocks });
+    }
+    Ok(predicates)
+}
+
 trait DataComponentCodec<Impl: DataComponentImpl> {
     fn serialize<T: SerializeStruct>(&self, seq: &mut T) -> Result<(), T::Error>;
     fn deserialize<'a, A: SeqAccess<'a>>(seq: &mut A) -> Result<Impl, A::Error>;
@@ -405,7 +505,11 @@ impl DataComponentCodec<Self> for ConsumableImpl {
             .next_element::<VarInt>()?
             .ok_or(de::Error::custom("No array_len VarInt in ConsumableImpl"))?
             .0;
-        let mut effects_vec = Vec::with_capacity(effects_len as usize);
+        let effects_len = usize::try_from(effects_len)
+            .ok()
+            .filter(|len| *len <= max_list_length())
+            .ok_or(de::Error::custom("Invalid ConsumableImpl effects length"))?;
+        let mut effects_vec = Vec::with_capacity(effects_len);
 
         for _ in 0..effects_len {
             effects_vec.push(deserialize_consume_effect(seq)?);
@@ -845,6 +949,14 @@ pub fn deserialize<'a, A: SeqAccess<'a>>(
         DataComponent::StoredEnchantments => Ok(StoredEnchantmentsImpl::deserialize(seq)?.to_dyn()),
         DataComponent::UseCooldown => Ok(UseCooldownImpl::deserialize(seq)?.to_dyn()),
         DataComponent::MapId => Ok(MapIdImpl::deserialize(seq)?.to_dyn()),
+        DataComponent::CanPlaceOn => Ok(CanPlaceOnImpl {
+            predicates: deserialize_block_predicates(seq)?,
+        }
+        .to_dyn()),
+        DataComponent::CanBreak => Ok(CanBreakImpl {
+            predicates: deserialize_block_predicates(seq)?,
+        }
+        .to_dyn()),
         _ => Err(serde::de::Error::custom(format!("{id:?} (TODO)"))),
     }
 }
@@ -868,6 +980,15 @@ pub fn serialize<T: SerializeStruct>(
         DataComponent::StoredEnchantments => get::<StoredEnchantmentsImpl>(value).serialize(seq),
         DataComponent::UseCooldown => get::<UseCooldownImpl>(value).serialize(seq),
         DataComponent::MapId => get::<MapIdImpl>(value).serialize(seq),
+        DataComponent::CanPlaceOn => {
+            serialize_block_predicates(&get::<CanPlaceOnImpl>(value).predicates, seq)
+        }
+        DataComponent::CanBreak => {
+            serialize_block_predicates(&get::<CanBreakImpl>(value).predicates, seq)
+        }
+        DataComponent::WrittenBookContent => {
+            serialize_written_book_content(get::<WrittenBookContentImpl>(value), seq)
+        }
         _ => Err(serde::ser::Error::custom(format!(
             "{} not yet implemented",
             id.to_name()
@@ -0,0 +1,43 @@
+use pumpkin_data::packet::clientbound::PLAY_RESOURCE_PACK_PUSH;
+use pumpkin_macros::java_packet;
+use pumpkin_util::text::TextComponent;
+use serde::Serialize;
+
+/// Pushes a resource pack onto the client's stack of server resource packs.
+///
+/// Unlike the configuration variant, this can be sent at any time during play.
+/// Packs are identified by their UUID, so pushing an already applied UUID replaces that pack.
+#[derive(Serialize)]
+#[java_packet(PLAY_RESOURCE_PACK_PUSH)]
+pub struct CAddResourcePack<'a> {
+    /// The unique identifier of the resource pack.
+    #[serde(with = "uuid::serde::compact")]
+    pub uuid: &'a uuid::Uuid,
+    /// The URL the client downloads the resource pack from.
+    pub url: &'a str,
+    /// The SHA1 hash (max 40 characters) of the resource pack.
+    pub hash: &'a str,
+    /// Whether the client is disconnected when declining the resource pack.
+    pub forced: bool,
+    /// An optional message shown in the resource pack prompt.
+    pub prompt_message: Option<TextComponent>,
+}
+
+impl<'a> CAddResourcePack<'a> {
+    #[must_use]
+    pub const fn new(
+        uuid: &'a uuid::Uuid,
+        url: &'a str,
+        hash: &'a str,
+        forced: bool,
+        prompt_message: Option<TextComponent>,
+    ) -> Self {
+        Self {
+            uuid,
+            url,
+            hash,
+            forced,
+            prompt_message,
+        }
+    }
+}
@@ -30,7 +30,9 @@ pub struct CLogin {
     pub simulated_distance: VarInt,
     /// If true, hides coordinates and other info from the F3 screen.
     pub reduced_debug_info: bool,
+    /// If false, the player respawns right away instead of seeing the death screen.
     pub enabled_respawn_screen: bool,
+    /// If true, the player can only craft recipes they have unlocked.
     pub limited_crafting: bool,
     // Spawn info
     /// The Dimension for the current dimension's properties (lighting, sky color).
@@ -2,6 +2,7 @@ mod award_stats;
 pub use award_stats::*;
 mod acknowledge_block;
 mod actionbar;
+mod add_resource_pack;
 mod block_destroy_stage;
 mod block_entity_data;
 mod block_event;
@@ -44,6 +45,7 @@ mod login;
 mod map_item_data;
 mod merchant_offers;
 mod multi_block_update;
+mod open_book;
 mod open_screen;
 mod open_sign_editor;
 mod particle;
@@ -60,6 +62,7 @@ mod recipe_book_add;
 mod recipe_book_settings;
 mod remove_entities;
 mod remove_mob_effect;
+mod remove_resource_pack;
 mod reset_score;
 mod respawn;
 mod server_links;
@@ -68,6 +71,7 @@ mod set_border_lerp_size;
 mod set_border_size;
 mod set_border_warning_delay;
 mod set_border_warning_distance;
+mod set_camera;
 mod set_container_content;
 mod set_container_property;
 mod set_container_slot;
@@ -79,6 +83,7 @@ mod set_held_item;
 mod set_passengers;
 mod set_player_inventory;
 mod set_player_team;
+mod set_simulation_distance;
 mod set_time;
 mod set_title;
 mod set_title_animation;
@@ -107,6 +112,7 @@ mod worldevent;
 
 pub use acknowledge_block::*;
 pub use actionbar::*;
+pub use add_resource_pack::*;
 pub use block_destroy_stage::*;
 pub use block_entity_data::*;
 pub use block_event::*;
@@ -149,6 +155,7 @@ pub use login::*;
 pub use map_item_data::*;
 pub use merchant_offers::*;
 pub use multi_block_update::*;
+pub use open_book::*;
 pub use open_screen::*;
 pub use open_sign_editor::*;
 pub use particle::*;
@@ -165,6 +172,7 @@ pub use recipe_book_add::*;
 pub use recipe_book_settings::*;
 pub use remove_entities::*;
 pub use remove_mob_effect::*;
+pub use remove_resource_pack::*;
 pub use reset_score::*;
 pub use respawn::*;
 pub use server_links::*;
@@ -173,6 +181,7 @@ pub use set_border_lerp_size::*;
 pub use set_border_size::*;
 pub use set_border_warning_delay::*;
 pub use set_border_warning_distance::*;
+pub use set_camera::*;
 pub use set_container_content::*;
 pub use set_container_property::*;
 pub use set_container_slot::*;
@@ -184,6 +193,7 @@ pub use set_held_item::*;
 pub use set_passengers::*;
 pub use set_player_inventory::*;
 pub use set_player_team::*;
+pub use set_simulation_distance::*;
 pub use set_time::*;
 pub use set_title::*;
 pub use set_title_animation::*;
@@ -0,0 +1,22 @@
+use pumpkin_data::packet::clientbound::PLAY_OPEN_BOOK;
+use pumpkin_macros::java_packet;
+use serde::Serialize;
+
+use crate::VarInt;
+
+/// Opens the written book held in the given hand.
+///
+/// The client only opens the book GUI if the item in that hand actually is a written book.
+#[derive(Serialize)]
+#[java_packet(PLAY_OPEN_BOOK)]
+pub struct COpenBook {
+    /// The hand holding the book: 0 for the main hand, 1 for the off hand.
+    pub hand: VarInt,
+}
+
+impl COpenBook {
+    #[must_use]
+    pub const fn new(hand: VarInt) -> Self {
+        Self { hand }
+    }
+}
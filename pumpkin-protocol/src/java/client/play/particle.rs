@@ -17,11 +17,12 @@ use crate::{
 /// block/item breaking (textures).
 #[java_packet(PLAY_LEVEL_PARTICLES)]
 pub struct CParticle<'a> {
+    /// Also known as "long distance": if true, the distance at which particles
+    /// are visible is significantly increased (from 256 to 65536 blocks).
+    /// Often used for massive events.
+    pub force_spawn: bool,
     /// If true, the particle renders even if the client's "Particles"
     /// setting is set to "Minimal".
-    pub force_spawn: bool,
-    /// If true, the distance at which particles are visible is significantly
-    /// increased (from 256 to 65536 blocks). Often used for massive events.
     pub important: bool,
     /// The absolute center position of the particle cluster.
     pub position: Vector3<f64>,
@@ -2,6 +2,8 @@ use pumpkin_util::text::TextComponent;
 
 use crate::{Property, VarInt};
 
+use super::PlayerInfoFlags;
+
 pub enum PlayerAction<'a> {
     AddPlayer {
         name: &'a str,
@@ -15,6 +17,23 @@ pub enum PlayerAction<'a> {
     UpdateListOrder(VarInt),
 }
 
+impl PlayerAction<'_> {
+    /// The bit of this action in [`PlayerInfoFlags`]. The client reads actions in the order of
+    /// their bits.
+    #[must_use]
+    pub const fn flag(&self) -> PlayerInfoFlags {
+        match self {
+            Self::AddPlayer { .. } => PlayerInfoFlags::ADD_PLAYER,
+            Self::InitializeChat(_) => PlayerInfoFlags::INITIALIZE_CHAT,
+            Self::UpdateGameMode(_) => PlayerInfoFlags::UPDATE_GAME_MODE,
+            Self::UpdateListed(_) => PlayerInfoFlags::UPDATE_LISTED,
+            Self::UpdateLatency(_) => PlayerInfoFlags::UPDATE_LATENCY,
+            Self::UpdateDisplayName(_) => PlayerInfoFlags::UPDATE_DISPLAY_NAME,
+            Self::UpdateListOrder(_) => PlayerInfoFlags::UPDATE_LIST_PRIORITY,
+        }
+    }
+}
+
 pub struct InitChat {
     pub session_id: uuid::Uuid,
     pub expires_at: i64,
@@ -73,7 +73,10 @@ impl ClientPacket for CPlayerInfoUpdate<'_> {
         write.write_u8(self.actions)?;
         write.write_list::<Player>(self.players, |p, v| {
             p.write_uuid(&v.uuid)?;
-            for action in v.actions {
+            // The client reads the actions in the order of their flags
+            let mut actions: Vec<_> = v.actions.iter().collect();
+            actions.sort_by_key(|action| action.flag().bits());
+            for action in actions {
                 match action {
                     PlayerAction::AddPlayer { name, properties } => {
                         p.write_string(name)?;
@@ -117,3 +120,66 @@ impl ClientPacket for CPlayerInfoUpdate<'_> {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use pumpkin_util::text::TextComponent;
+    use pumpkin_util::version::JavaMinecraftVersion;
+
+    use crate::{ClientPacket, VarInt, WritingError};
+
+    use super::{CPlayerInfoUpdate, Player, PlayerAction, PlayerInfoFlags};
+
+    fn write(actions: &[PlayerAction]) -> Result<Vec<u8>, WritingError> {
+        let flags = actions
+            .iter()
+            .fold(PlayerInfoFlags::empty(), |flags, action| {
+                flags | action.flag()
+            });
+        let players = [Player {
+            uuid: uuid::Uuid::nil(),
+            actions,
+        }];
+        let mut bytes = Vec::new();
+        CPlayerInfoUpdate::new(flags.bits(), &players)
+            .write_packet_data(&mut bytes, &JavaMinecraftVersion::V_1_21_11)?;
+        Ok(bytes)
+    }
+
+    #[test]
+    fn display_name_is_written_before_list_order() {
+        let name = TextComponent::text("Steve");
+        let bytes = write(&[
+            PlayerAction::UpdateLatency(VarInt(7)),
+            PlayerAction::UpdateDisplayName(Some(&name)),
+            PlayerAction::UpdateListOrder(VarInt(3)),
+        ])
+        .unwrap();
+
+        // Flags, one player and its UUID come first, then the actions in bit order
+        assert_eq!(bytes[0], 0x10 | 0x20 | 0x40);
+        assert_eq!(bytes[1], 1);
+        let actions = &bytes[18..];
+        assert_eq!(actions[0], 7);
+        assert_eq!(actions[1], 1, "the display name should follow the latency");
+        assert_eq!(*actions.last().unwrap(), 3);
+    }
+
+    #[test]
+    fn actions_are_written_in_bit_order() {
+        let name = TextComponent::text("Steve");
+        let in_order = write(&[
+            PlayerAction::UpdateDisplayName(Some(&name)),
+            PlayerAction::UpdateListOrder(VarInt(3)),
+        ])
+        .unwrap();
+        let out_of_order = write(&[
+            PlayerAction::UpdateListOrder(VarInt(3)),
+            PlayerAction::UpdateDisplayName(Some(&name)),
+        ])
+        .unwrap();
+
+        assert_eq!(*out_of_order.last().unwrap(), 3);
+        assert_eq!(in_order, out_of_order);
+    }
+}
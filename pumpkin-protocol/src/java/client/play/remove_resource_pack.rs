@@ -0,0 +1,37 @@
+use std::io::Write;
+
+use pumpkin_data::packet::clientbound::PLAY_RESOURCE_PACK_POP;
+use pumpkin_macros::java_packet;
+use pumpkin_util::version::JavaMinecraftVersion;
+
+use crate::{
+    ClientPacket,
+    ser::{NetworkWriteExt, WritingError},
+};
+
+/// Removes a resource pack from the client's stack of server resource packs.
+///
+/// Without a UUID, every server resource pack is removed.
+#[java_packet(PLAY_RESOURCE_PACK_POP)]
+pub struct CRemoveResourcePack {
+    /// The unique identifier of the resource pack to remove, or `None` for all of them.
+    pub uuid: Option<uuid::Uuid>,
+}
+
+impl CRemoveResourcePack {
+    #[must_use]
+    pub const fn new(uuid: Option<uuid::Uuid>) -> Self {
+        Self { uuid }
+    }
+}
+
+impl ClientPacket for CRemoveResourcePack {
+    fn write_packet_data(
+        &self,
+        write: impl Write,
+        _version: &JavaMinecraftVersion,
+    ) -> Result<(), WritingError> {
+        let mut write = write;
+        write.write_option(&self.uuid, |p, uuid| p.write_uuid(uuid))
+    }
+}
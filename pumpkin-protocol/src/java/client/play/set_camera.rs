@@ -0,0 +1,22 @@
+use pumpkin_data::packet::clientbound::PLAY_SET_CAMERA;
+use pumpkin_macros::java_packet;
+use serde::Serialize;
+
+use crate::VarInt;
+
+/// Makes the client view the world from another entity's perspective.
+///
+/// Sending the player's own entity ID resets the camera back to the player.
+#[derive(Serialize)]
+#[java_packet(PLAY_SET_CAMERA)]
+pub struct CSetCamera {
+    /// The ID of the entity to use as the camera.
+    pub camera_id: VarInt,
+}
+
+impl CSetCamera {
+    #[must_use]
+    pub const fn new(camera_id: VarInt) -> Self {
+        Self { camera_id }
+    }
+}
@@ -0,0 +1,22 @@
+use pumpkin_data::packet::clientbound::PLAY_SET_SIMULATION_DISTANCE;
+use pumpkin_macros::java_packet;
+use serde::Serialize;
+
+use crate::VarInt;
+
+/// Tells the client how many chunks around it the server is actively ticking.
+#[derive(Serialize)]
+#[java_packet(PLAY_SET_SIMULATION_DISTANCE)]
+pub struct CSetSimulationDistance {
+    /// The simulation distance, in chunks.
+    pub simulation_distance: VarInt,
+}
+
+impl CSetSimulationDistance {
+    #[must_use]
+    pub const fn new(simulation_distance: VarInt) -> Self {
+        Self {
+            simulation_distance,
+        }
+    }
+}
@@ -88,6 +88,8 @@ pub struct TCPNetworkEncoder<W: AsyncWrite + Unpin> {
     compressor: Option<(CompressionLevel, Compress)>,
     // Reused compression buffer to avoid allocating a new Vec for each packet.
     compression_scratch: Vec<u8>,
+    // Compressed size of the last written packet, if it was compressed.
+    last_compressed_len: Option<usize>,
 }
 
 impl<W: AsyncWrite + Unpin> TCPNetworkEncoder<W> {
@@ -97,9 +99,17 @@ impl<W: AsyncWrite + Unpin> TCPNetworkEncoder<W> {
             compression: None,
             compressor: None,
             compression_scratch: Vec::new(),
+            last_compressed_len: None,
         }
     }
 
+    /// Returns how many bytes the last packet passed to [`Self::write_packet`] took after
+    /// compression, or `None` if it was sent uncompressed.
+    #[must_use]
+    pub const fn last_compressed_len(&self) -> Option<usize> {
+        self.last_compressed_len
+    }
+
     pub const fn set_compression(
         &mut self,
         compression_info: (CompressionThreshold, CompressionLevel),
@@ -218,6 +228,7 @@ impl<W: AsyncWrite + Unpin> TCPNetworkEncoder<W> {
             ))
         })?;
 
+        self.last_compressed_len = None;
         if let Some((compression_threshold, compression_level)) = self.compression {
             if data_len >= compression_threshold {
                 // Pushed before data:
@@ -228,6 +239,7 @@ impl<W: AsyncWrite + Unpin> TCPNetworkEncoder<W> {
                 // buf here :( Is there a magic way to find a compressed length?
                 self.compress_packet_data(packet_data.as_ref(), compression_level)?;
                 debug_assert!(!self.compression_scratch.is_empty());
+                self.last_compressed_len = Some(self.compression_scratch.len());
 
                 let full_packet_len_var_int: VarInt = (data_len_var_int.written_size()
                     + self.compression_scratch.len())
@@ -35,6 +35,7 @@ mod player_session;
 mod recipe_book_change_settings;
 mod recipe_book_seen_recipe;
 mod rename_item;
+mod resource_pack_response;
 mod select_trade;
 mod set_beacon;
 mod set_command_block;
@@ -83,6 +84,7 @@ pub use player_session::*;
 pub use recipe_book_change_settings::*;
 pub use recipe_book_seen_recipe::*;
 pub use rename_item::*;
+pub use resource_pack_response::*;
 pub use select_trade::*;
 pub use set_beacon::*;
 pub use set_command_block::*;
@@ -0,0 +1,24 @@
+use pumpkin_data::packet::serverbound::PLAY_RESOURCE_PACK;
+use pumpkin_macros::java_packet;
+use serde::{Deserialize, Serialize};
+
+use crate::VarInt;
+use crate::java::server::config::ResourcePackResponseResult;
+
+/// Sent by the client to inform the server of the status of a resource pack pushed during play.
+#[derive(Deserialize, Serialize)]
+#[java_packet(PLAY_RESOURCE_PACK)]
+pub struct SPlayResourcePack {
+    /// The unique identifier of the resource pack this response refers to.
+    #[serde(with = "uuid::serde::compact")]
+    pub uuid: uuid::Uuid,
+    /// The status code of the operation, mapped to [`ResourcePackResponseResult`].
+    result: VarInt,
+}
+
+impl SPlayResourcePack {
+    #[must_use]
+    pub const fn response_result(&self) -> ResourcePackResponseResult {
+        ResourcePackResponseResult::from_id(self.result.0)
+    }
+}
@@ -234,7 +234,7 @@ impl<'de, R: Read> de::Deserializer<'de> for &mut Deserializer<R> {
                 seed.deserialize(&mut *self.deserializer)
             }
         }
-        let len = self.inner.get_var_int()?.0 as usize;
+        let len = self.inner.get_list_len()?;
 
         visitor.visit_map(Access {
             deserializer: self,
@@ -1,6 +1,7 @@
 use core::str;
 use serde::Serialize;
 use std::io::{Read, Write};
+use std::sync::atomic::{AtomicUsize, Ordering};
 
 use crate::{
     FixedBitSet,
@@ -15,6 +16,27 @@ use pumpkin_util::math::position::BlockPos;
 use thiserror::Error;
 pub mod serializer;
 
+/// Vanilla's maximum length of a string without an explicit bound, in UTF-16 characters.
+pub const DEFAULT_MAX_STRING_LENGTH: usize = 32767;
+/// The default maximum number of elements of a length-prefixed list.
+pub const DEFAULT_MAX_LIST_LENGTH: usize = 65536;
+
+static MAX_STRING_LENGTH: AtomicUsize = AtomicUsize::new(DEFAULT_MAX_STRING_LENGTH);
+static MAX_LIST_LENGTH: AtomicUsize = AtomicUsize::new(DEFAULT_MAX_LIST_LENGTH);
+
+/// Sets the limits enforced by [`NetworkReadExt::get_str`] and [`NetworkReadExt::get_list_len`]
+/// when reading client data, so a client can't make the server allocate huge buffers.
+pub fn set_read_limits(max_string_length: usize, max_list_length: usize) {
+    MAX_STRING_LENGTH.store(max_string_length, Ordering::Relaxed);
+    MAX_LIST_LENGTH.store(max_list_length, Ordering::Relaxed);
+}
+
+/// The maximum number of elements a length-prefixed list may declare.
+#[must_use]
+pub fn max_list_length() -> usize {
+    MAX_LIST_LENGTH.load(Ordering::Relaxed)
+}
+
 #[derive(Debug, Error)]
 pub enum ReadingError {
     #[error("EOF, Tried to read {0} but No bytes left to consume")]
@@ -61,6 +83,7 @@ pub trait NetworkReadExt {
     fn get_var_long(&mut self) -> Result<VarLong, ReadingError>;
     fn get_var_ulong(&mut self) -> Result<VarULong, ReadingError>;
     fn get_str_bounded(&mut self, bound: usize) -> Result<Box<str>, ReadingError>;
+    /// Reads a string of at most the configured maximum string length.
     fn get_str(&mut self) -> Result<Box<str>, ReadingError>;
     fn get_uuid(&mut self) -> Result<uuid::Uuid, ReadingError>;
     fn get_fixed_bitset(&mut self, bits: usize) -> Result<FixedBitSet, ReadingError>;
@@ -70,6 +93,9 @@ pub trait NetworkReadExt {
         parse: impl FnOnce(&mut Self) -> Result<G, ReadingError>,
     ) -> Result<Option<G>, ReadingError>;
 
+    /// Reads a list length, rejecting negative ones and ones above the configured maximum.
+    fn get_list_len(&mut self) -> Result<usize, ReadingError>;
+
     fn get_list<G>(
         &mut self,
         parse: impl Fn(&mut Self) -> Result<G, ReadingError>,
@@ -183,7 +209,7 @@ impl<R: Read> NetworkReadExt for R {
     }
 
     fn get_str(&mut self) -> Result<Box<str>, ReadingError> {
-        self.get_str_bounded(i32::MAX as usize)
+        self.get_str_bounded(MAX_STRING_LENGTH.load(Ordering::Relaxed))
     }
 
     fn get_uuid(&mut self) -> Result<uuid::Uuid, ReadingError> {
@@ -209,18 +235,25 @@ impl<R: Read> NetworkReadExt for R {
         }
     }
 
+    fn get_list_len(&mut self) -> Result<usize, ReadingError> {
+        let len = self.get_var_int()?.0;
+        let Ok(len) = usize::try_from(len) else {
+            return Err(ReadingError::Message(format!("negative list length {len}")));
+        };
+        let max_len = max_list_length();
+        if len > max_len {
+            return Err(ReadingError::TooLarge(format!(
+                "list length {len} exceeds limit {max_len}"
+            )));
+        }
+        Ok(len)
+    }
+
     fn get_list<G>(
         &mut self,
         parse: impl Fn(&mut Self) -> Result<G, ReadingError>,
     ) -> Result<Vec<G>, ReadingError> {
-        const MAX_LIST_SIZE: usize = 65536;
-
-        let len = self.get_var_int()?.0 as usize;
-        if len > MAX_LIST_SIZE {
-            return Err(ReadingError::TooLarge(format!(
-                "List length {len} exceeds limit"
-            )));
-        }
+        let len = self.get_list_len()?;
         let mut list = Vec::with_capacity(len);
         for _ in 0..len {
             list.push(parse(self)?);
@@ -743,4 +776,29 @@ mod test {
         assert_eq!(empty_map_original, empty_map_deserialized);
         Ok(())
     }
+
+    #[test]
+    fn oversized_lengths_are_rejected() -> Result<(), Box<dyn std::error::Error>> {
+        use super::{DEFAULT_MAX_LIST_LENGTH, NetworkReadExt, NetworkWriteExt, ReadingError};
+
+        let mut bytes = Vec::new();
+        bytes.write_var_int(&VarInt(-1))?;
+        assert!(Cursor::new(bytes).get_list_len().is_err());
+
+        let mut bytes = Vec::new();
+        bytes.write_var_int(&VarInt(DEFAULT_MAX_LIST_LENGTH as i32 + 1))?;
+        assert!(matches!(
+            Cursor::new(bytes).get_list_len(),
+            Err(ReadingError::TooLarge(_))
+        ));
+
+        // A string declaring more bytes than any allowed string can have
+        let mut bytes = Vec::new();
+        bytes.write_var_int(&VarInt(i32::MAX))?;
+        assert!(matches!(
+            Cursor::new(bytes).get_str(),
+            Err(ReadingError::TooLarge(_))
+        ));
+        Ok(())
+    }
 }
@@ -260,6 +260,22 @@ impl Hand {
     pub const fn all() -> [Self; 2] {
         [Self::Right, Self::Left]
     }
+
+    /// Converts the hand of an interaction, as sent in use, swing and interact packets.
+    ///
+    /// # Parameters
+    /// - `0`: the main hand, `Right`
+    /// - `1`: the off-hand, `Left`
+    ///
+    /// # Errors
+    /// Returns `InvalidHand` if the value is not 0 or 1.
+    pub const fn from_interaction_hand(value: i32) -> Result<Self, InvalidHand> {
+        match value {
+            0 => Ok(Self::Right),
+            1 => Ok(Self::Left),
+            _ => Err(InvalidHand),
+        }
+    }
 }
 
 /// Error type for invalid hand conversion.
@@ -268,7 +284,8 @@ pub struct InvalidHand;
 impl TryFrom<i32> for Hand {
     type Error = InvalidHand;
 
-    /// Converts an integer into a `Hand`.
+    /// Converts an integer into a `Hand`, as the player's dominant arm is sent in the client
+    /// information. Use [`Hand::from_interaction_hand`] for the hand of an interaction.
     ///
     /// # Parameters
     /// - `0`: `Left`
@@ -290,6 +290,41 @@ impl BoundingBox {
         Some(collision_time)
     }
 
+    /// Returns the point where the segment from `from` to `to` first enters the bounding box, or
+    /// `from` itself if it starts inside.
+    ///
+    /// # Returns
+    /// None if the segment misses the box.
+    #[must_use]
+    pub fn raycast(&self, from: Vector3<f64>, to: Vector3<f64>) -> Option<Vector3<f64>> {
+        let delta = to - from;
+        let mut t_min: f64 = 0.0;
+        let mut t_max: f64 = 1.0;
+
+        for axis in [Axis::X, Axis::Y, Axis::Z] {
+            let start = from.get_axis(axis);
+            let direction = delta.get_axis(axis);
+            let min = self.min.get_axis(axis);
+            let max = self.max.get_axis(axis);
+
+            if direction.abs() < 1.0e-8 {
+                if start < min || start > max {
+                    return None;
+                }
+            } else {
+                let t_near = (min - start) / direction;
+                let t_far = (max - start) / direction;
+                t_min = t_min.max(t_near.min(t_far));
+                t_max = t_max.min(t_near.max(t_far));
+                if t_max < t_min {
+                    return None;
+                }
+            }
+        }
+
+        Some(from + delta * t_min)
+    }
+
     /// Returns the average side length of the bounding box.
     #[must_use]
     pub fn get_average_side_length(&self) -> f64 {
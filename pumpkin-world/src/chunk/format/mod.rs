@@ -3,12 +3,12 @@ use std::{
     pin::Pin,
     sync::{
         RwLock,
-        atomic::{AtomicBool, Ordering},
+        atomic::{AtomicBool, AtomicI64, Ordering},
     },
 };
 
 use bytes::Bytes;
-use pumpkin_data::{Block, chunk::ChunkStatus, fluid::Fluid};
+use pumpkin_data::{Block, biome::Biome, chunk::ChunkStatus, fluid::Fluid};
 use pumpkin_nbt::{compound::NbtCompound, nbt_long_array};
 use rustc_hash::FxHashMap;
 use tokio::sync::Mutex;
@@ -37,6 +37,10 @@ pub mod anvil;
 pub mod linear;
 pub mod pump;
 
+/// The data version of 20w45a, the first version to store entities in their own region files
+/// rather than in the terrain chunk.
+const ENTITY_CHUNKS_DATA_VERSION: i32 = 2681;
+
 impl SingleChunkDataSerializer for ChunkData {
     #[inline]
     fn from_bytes(bytes: &Bytes, pos: Vector2<i32>) -> Result<Self, ChunkReadingError> {
@@ -81,8 +85,16 @@ impl ChunkData {
         position: Vector2<i32>,
     ) -> Result<Self, ChunkParsingError> {
         let chunk_data =
-            pumpkin_nbt::from_bytes_unnamed::<ChunkNbt>(std::io::Cursor::new(chunk_data))
-                .map_err(|e| ChunkParsingError::ErrorDeserializingChunk(e.to_string()))?;
+            match pumpkin_nbt::from_bytes_unnamed::<ChunkNbt>(std::io::Cursor::new(chunk_data)) {
+                Ok(chunk_data) => chunk_data,
+                Err(err) => {
+                    return Err(
+                        Self::legacy_chunk_error(chunk_data, position).unwrap_or_else(|| {
+                            ChunkParsingError::ErrorDeserializingChunk(err.to_string())
+                        }),
+                    );
+                }
+            };
 
         if chunk_data.x_pos != position.x || chunk_data.z_pos != position.y {
             return Err(ChunkParsingError::ErrorDeserializingChunk(format!(
@@ -125,7 +137,15 @@ impl ChunkData {
                 .unwrap_or_default();
             biome_palettes[index] = section
                 .biomes
-                .map(BiomePalette::from_disk_nbt)
+                .map(|mut biomes| {
+                    // Unknown biomes would disconnect clients, so replace them with plains
+                    for id in biomes.palette.iter_mut() {
+                        if Biome::from_id(*id).is_none() {
+                            *id = Biome::PLAINS.id;
+                        }
+                    }
+                    BiomePalette::from_disk_nbt(biomes)
+                })
                 .unwrap_or_default();
         }
 
@@ -170,11 +190,27 @@ impl ChunkData {
             },
             light_engine: std::sync::Mutex::new(light_engine),
             light_populated: AtomicBool::new(chunk_data.light_correct),
+            inhabited_time: AtomicI64::new(chunk_data.inhabited_time),
             status: chunk_data.status,
             blending_data: None,
         })
     }
 
+    /// Recognizes chunks saved before entities got their own region files. Their terrain uses the
+    /// pre-1.18 `Level` layout which can't be read, but their inline `Entities` can be kept.
+    fn legacy_chunk_error(chunk_data: &[u8], position: Vector2<i32>) -> Option<ChunkParsingError> {
+        let legacy =
+            pumpkin_nbt::from_bytes_unnamed::<LegacyChunkNbt>(std::io::Cursor::new(chunk_data))
+                .ok()?;
+        if legacy.data_version >= ENTITY_CHUNKS_DATA_VERSION
+            || legacy.level.x_pos != position.x
+            || legacy.level.z_pos != position.y
+        {
+            return None;
+        }
+        Some(ChunkParsingError::LegacyLayout(legacy.level.entities))
+    }
+
     async fn internal_to_bytes(&self) -> Result<Bytes, ChunkSerializingError> {
         let is_light_correct = self
             .light_populated
@@ -221,6 +257,7 @@ impl ChunkData {
             fluid_ticks: &self.fluid_ticks.to_vec(),
             block_entities: &block_entities_nbt,
             light_correct: is_light_correct,
+            inhabited_time: self.inhabited_time.load(Ordering::Relaxed),
         };
 
         let mut result = Vec::new();
@@ -289,44 +326,27 @@ impl ChunkEntityData {
                 chunk_entity_data.position[1],
             )));
         }
-        let mut map = FxHashMap::default();
-        for entity_nbt in chunk_entity_data.entities {
-            let uuid = if let Some(uuid) = entity_nbt.get_int_array("UUID") {
-                if uuid.len() != 4 {
-                    debug!(
-                        "Entity in chunk {},{} has invalid UUID array length {}: {:?}",
-                        position.x,
-                        position.y,
-                        uuid.len(),
-                        entity_nbt
-                    );
-                    continue;
-                }
-                Uuid::from_u128(
-                    (uuid[0] as u128) << 96
-                        | (uuid[1] as u128) << 64
-                        | (uuid[2] as u128) << 32
-                        | (uuid[3] as u128),
-                )
-            } else {
-                debug!(
-                    "Entity in chunk {},{} is missing UUID: {:?}",
-                    position.x, position.y, entity_nbt
-                );
-                continue;
-            };
-
-            map.insert(uuid, entity_nbt);
-        }
-
         Ok(Self {
             x: position.x,
             z: position.y,
-            data: Mutex::new(map),
+            data: Mutex::new(entities_by_uuid(chunk_entity_data.entities, position)),
             dirty: AtomicBool::new(false),
         })
     }
 
+    /// Creates the entity chunk for a chunk whose entities were stored inline in the terrain
+    /// chunk. It is marked dirty so the entities get saved to the entities region files.
+    #[must_use]
+    pub fn from_legacy_entities(position: Vector2<i32>, entities: Vec<NbtCompound>) -> Self {
+        let dirty = !entities.is_empty();
+        Self {
+            x: position.x,
+            z: position.y,
+            data: Mutex::new(entities_by_uuid(entities, position)),
+            dirty: AtomicBool::new(dirty),
+        }
+    }
+
     async fn internal_to_bytes(&self) -> Result<Bytes, ChunkSerializingError> {
         let nbt = EntityNbt {
             data_version: WORLD_DATA_VERSION,
@@ -341,6 +361,43 @@ impl ChunkEntityData {
     }
 }
 
+fn entities_by_uuid(
+    entities: Vec<NbtCompound>,
+    position: Vector2<i32>,
+) -> FxHashMap<Uuid, NbtCompound> {
+    let mut map = FxHashMap::default();
+    for entity_nbt in entities {
+        let Some(uuid) = entity_uuid(&entity_nbt) else {
+            debug!(
+                "Entity in chunk {},{} has a missing or invalid UUID: {:?}",
+                position.x, position.y, entity_nbt
+            );
+            continue;
+        };
+        map.insert(uuid, entity_nbt);
+    }
+    map
+}
+
+/// Reads an entity's UUID, stored as an int array since 1.16 and as two longs before that.
+fn entity_uuid(nbt: &NbtCompound) -> Option<Uuid> {
+    if let Some(uuid) = nbt.get_int_array("UUID") {
+        let [a, b, c, d] = uuid else {
+            return None;
+        };
+        return Some(Uuid::from_u128(
+            (*a as u32 as u128) << 96
+                | (*b as u32 as u128) << 64
+                | (*c as u32 as u128) << 32
+                | (*d as u32 as u128),
+        ));
+    }
+
+    let most = nbt.get_long("UUIDMost")?;
+    let least = nbt.get_long("UUIDLeast")?;
+    Some(Uuid::from_u64_pair(most as u64, least as u64))
+}
+
 #[derive(Serialize, Deserialize)]
 struct ChunkSectionNBT {
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -492,6 +549,30 @@ struct ChunkNbt {
     block_entities: Vec<NbtCompound>,
     #[serde(rename = "isLightOn", default)]
     light_correct: bool,
+    #[serde(default)]
+    inhabited_time: i64,
+}
+
+/// The parts of a chunk in the pre-1.18 layout, where everything is nested under `Level`, that
+/// are kept when it is loaded.
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct LegacyChunkNbt {
+    /// Missing in chunks saved before 1.9.
+    #[serde(default)]
+    data_version: i32,
+    level: LegacyLevelNbt,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct LegacyLevelNbt {
+    #[serde(rename = "xPos")]
+    x_pos: i32,
+    #[serde(rename = "zPos")]
+    z_pos: i32,
+    #[serde(default)]
+    entities: Vec<NbtCompound>,
 }
 
 #[derive(Serialize)]
@@ -516,6 +597,7 @@ struct ChunkNbtRef<'a> {
     block_entities: &'a [NbtCompound],
     #[serde(rename = "isLightOn", default)]
     light_correct: bool,
+    inhabited_time: i64,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -525,3 +607,107 @@ struct EntityNbt {
     position: [i32; 2],
     entities: Vec<NbtCompound>,
 }
+
+#[cfg(test)]
+mod tests {
+    use pumpkin_nbt::{compound::NbtCompound, tag::NbtTag};
+    use pumpkin_util::math::vector2::Vector2;
+    use uuid::Uuid;
+
+    use super::{ChunkData, ChunkEntityData, ENTITY_CHUNKS_DATA_VERSION, entity_uuid};
+    use crate::chunk::ChunkParsingError;
+
+    /// A terrain chunk as saved by 1.15.2, with everything nested under `Level`.
+    fn legacy_chunk_bytes(data_version: i32, entities: Vec<NbtTag>) -> Vec<u8> {
+        let mut level = NbtCompound::new();
+        level.put_int("xPos", 3);
+        level.put_int("zPos", -2);
+        level.put_long("LastUpdate", 1_234);
+        level.put_long("InhabitedTime", 0);
+        level.put_string("Status", "full".to_string());
+        level.put_list("Sections", Vec::new());
+        level.put_list("TileEntities", Vec::new());
+        level.put_compound("Heightmaps", NbtCompound::new());
+        level.put_list("Entities", entities);
+
+        let mut nbt = NbtCompound::new();
+        nbt.put_int("DataVersion", data_version);
+        nbt.put_compound("Level", level);
+
+        let mut bytes = Vec::new();
+        pumpkin_nbt::to_bytes_unnamed(&nbt, &mut bytes).unwrap();
+        bytes
+    }
+
+    fn legacy_cow() -> NbtCompound {
+        let mut cow = NbtCompound::new();
+        cow.put_string("id", "minecraft:cow".to_string());
+        cow.put_long("UUIDMost", 0x0123_4567_89ab_cdef);
+        cow.put_long("UUIDLeast", -1);
+        cow
+    }
+
+    #[test]
+    fn legacy_chunk_keeps_inline_entities() {
+        let bytes = legacy_chunk_bytes(2230, vec![NbtTag::Compound(legacy_cow())]);
+        let Err(ChunkParsingError::LegacyLayout(entities)) =
+            ChunkData::internal_from_bytes(&bytes, Vector2::new(3, -2))
+        else {
+            panic!("legacy chunk was not recognized");
+        };
+
+        assert_eq!(entities.len(), 1);
+        assert_eq!(
+            entity_uuid(&entities[0]),
+            Some(Uuid::from_u64_pair(0x0123_4567_89ab_cdef, u64::MAX))
+        );
+    }
+
+    #[test]
+    fn chunk_with_entity_regions_is_not_legacy() {
+        let bytes = legacy_chunk_bytes(
+            ENTITY_CHUNKS_DATA_VERSION,
+            vec![NbtTag::Compound(legacy_cow())],
+        );
+
+        assert!(matches!(
+            ChunkData::internal_from_bytes(&bytes, Vector2::new(3, -2)),
+            Err(ChunkParsingError::ErrorDeserializingChunk(_))
+        ));
+    }
+
+    #[test]
+    fn legacy_chunk_for_another_position_is_rejected() {
+        let bytes = legacy_chunk_bytes(2230, vec![NbtTag::Compound(legacy_cow())]);
+
+        assert!(matches!(
+            ChunkData::internal_from_bytes(&bytes, Vector2::new(0, 0)),
+            Err(ChunkParsingError::ErrorDeserializingChunk(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn legacy_entities_are_saved_to_the_entity_chunk() {
+        let chunk = ChunkEntityData::from_legacy_entities(Vector2::new(3, -2), vec![legacy_cow()]);
+
+        assert!(chunk.dirty.load(std::sync::atomic::Ordering::Relaxed));
+        assert!(
+            chunk
+                .data
+                .lock()
+                .await
+                .contains_key(&Uuid::from_u64_pair(0x0123_4567_89ab_cdef, u64::MAX))
+        );
+    }
+
+    #[test]
+    fn int_array_uuid_is_not_sign_extended() {
+        let mut nbt = NbtCompound::new();
+        nbt.put("UUID", NbtTag::IntArray(vec![-1, 0, -1, 0]));
+
+        assert_eq!(
+            entity_uuid(&nbt),
+            Some(Uuid::from_u128(0xffff_ffff_0000_0000_ffff_ffff_0000_0000))
+        );
+    }
+}
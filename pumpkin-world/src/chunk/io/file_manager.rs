@@ -29,7 +29,9 @@ use super::{ChunkSerializer, FileIO, LoadedData};
 ///
 /// * `file_locks` — one `Arc<RwLock<S>>` per on-disk file, created lazily.
 ///   All readers/writers for the same region file share this lock, so there
-///   are never two concurrent writers for the same file.
+///   are never two concurrent writers for the same file. Both applying chunk
+///   updates and flushing the file to disk take the write-lock, so readers
+///   never observe a half-updated location table.
 /// * `watchers` — a ref-count per path.  While a path has active watchers the
 ///   serializer is **not** evicted from the cache and the file is **not**
 ///   flushed to disk (the caller owns the flush lifecycle).
@@ -360,7 +362,8 @@ where
                                 writer.update_chunk(&**chunk, &self.chunk_config).await?;
                             }
                         }
-                        // Write-lock released here — flush can proceed under a read-lock.
+                        // Write-lock released here so the watcher check below does not
+                        // hold it.
                     }
 
                     trace!("Chunk data updated for {}", path.display());
@@ -373,16 +376,17 @@ where
                     };
 
                     if !is_watched {
-                        // A read-lock suffices for `write()` since we have already
-                        // applied all mutations above.
+                        // Flushing needs the write-lock too: concurrent saves of the
+                        // same region would otherwise write the same file (or the same
+                        // temporary file) at once and leave it torn.
                         {
-                            let serializer = chunk_serializer.read().await;
+                            let serializer = chunk_serializer.write().await;
                             debug!("Flushing {} to disk", path.display());
                             serializer
                                 .write(&path)
                                 .await
                                 .map_err(ChunkWritingError::IoError)?;
-                            // Read-lock released here.
+                            // Write-lock released here.
                         };
 
                         // Drop our handle so `can_remove` may succeed.
@@ -430,3 +434,199 @@ where
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        collections::BTreeMap,
+        path::PathBuf,
+        sync::{
+            Arc,
+            atomic::{AtomicBool, Ordering},
+        },
+    };
+
+    use bytes::{Buf, BufMut, Bytes};
+    use futures::future::join_all;
+    use pumpkin_util::math::vector2::Vector2;
+    use temp_dir::TempDir;
+    use tokio::{io::AsyncWriteExt, sync::mpsc};
+
+    use super::{ChunkFileManager, PathFromLevelFolder};
+    use crate::{
+        chunk::{
+            ChunkReadingError, ChunkWritingError,
+            io::{ChunkSerializer, Dirtiable, FileIO, LoadedData},
+        },
+        level::LevelFolder,
+    };
+
+    /// Region files hold 4x4 chunks so many writers contend for the same file.
+    const REGION_SHIFT: i32 = 2;
+
+    struct TestChunk {
+        position: Vector2<i32>,
+        value: u64,
+        dirty: AtomicBool,
+    }
+
+    impl Dirtiable for TestChunk {
+        fn is_dirty(&self) -> bool {
+            self.dirty.load(Ordering::Relaxed)
+        }
+
+        fn mark_dirty(&self, flag: bool) {
+            self.dirty.store(flag, Ordering::Relaxed);
+        }
+    }
+
+    impl PathFromLevelFolder for TestChunk {
+        fn file_path(folder: &LevelFolder, file_name: &str) -> PathBuf {
+            folder.region_folder.join(file_name)
+        }
+    }
+
+    /// A minimal region format: a chunk count followed by `(x, z, value)` entries.
+    #[derive(Default)]
+    struct TestRegion {
+        chunks: BTreeMap<(i32, i32), u64>,
+    }
+
+    impl ChunkSerializer for TestRegion {
+        type Data = TestChunk;
+        type WriteBackend = PathBuf;
+        type ChunkConfig = ();
+
+        fn get_chunk_key(chunk: &Vector2<i32>) -> String {
+            format!(
+                "r.{}.{}.test",
+                chunk.x >> REGION_SHIFT,
+                chunk.y >> REGION_SHIFT
+            )
+        }
+
+        fn should_write(&self, _is_watched: bool) -> bool {
+            true
+        }
+
+        async fn write(&self, path: &PathBuf) -> Result<(), std::io::Error> {
+            // Same temp-file-then-rename scheme as the real formats, written entry by
+            // entry so overlapping flushes would interleave.
+            let temp_path = path.with_extension("tmp");
+            let mut file = tokio::fs::File::create(&temp_path).await?;
+            file.write_u32(self.chunks.len() as u32).await?;
+            for (&(x, z), &value) in &self.chunks {
+                let mut entry = Vec::with_capacity(16);
+                entry.put_i32(x);
+                entry.put_i32(z);
+                entry.put_u64(value);
+                file.write_all(&entry).await?;
+            }
+            file.flush().await?;
+            drop(file);
+            tokio::fs::rename(temp_path, path).await
+        }
+
+        fn read(mut r: Bytes) -> Result<Self, ChunkReadingError> {
+            if r.remaining() < 4 {
+                return Err(ChunkReadingError::InvalidHeader);
+            }
+            let count = r.get_u32() as usize;
+            if r.remaining() != count * 16 {
+                return Err(ChunkReadingError::RegionIsInvalid);
+            }
+            let chunks = (0..count)
+                .map(|_| ((r.get_i32(), r.get_i32()), r.get_u64()))
+                .collect();
+            Ok(Self { chunks })
+        }
+
+        async fn update_chunk(
+            &mut self,
+            chunk_data: &TestChunk,
+            _chunk_config: &(),
+        ) -> Result<(), ChunkWritingError> {
+            self.chunks.insert(
+                (chunk_data.position.x, chunk_data.position.y),
+                chunk_data.value,
+            );
+            Ok(())
+        }
+
+        async fn get_chunks(
+            &self,
+            chunks: Vec<Vector2<i32>>,
+            stream: mpsc::Sender<LoadedData<TestChunk, ChunkReadingError>>,
+        ) {
+            for position in chunks {
+                let data = match self.chunks.get(&(position.x, position.y)) {
+                    Some(&value) => LoadedData::Loaded(TestChunk {
+                        position,
+                        value,
+                        dirty: AtomicBool::new(false),
+                    }),
+                    None => LoadedData::Missing(position),
+                };
+                if stream.send(data).await.is_err() {
+                    return;
+                }
+            }
+        }
+    }
+
+    const fn chunk_value(position: Vector2<i32>) -> u64 {
+        ((position.x as u32 as u64) << 32) | position.y as u32 as u64
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn concurrent_saves_to_one_region_read_back_intact() {
+        let temp_dir = TempDir::new().unwrap();
+        let folder = LevelFolder {
+            root_folder: temp_dir.path().to_path_buf(),
+            region_folder: temp_dir.path().join("region"),
+            entities_folder: temp_dir.path().join("entities"),
+        };
+        std::fs::create_dir(&folder.region_folder).unwrap();
+
+        let side = 1 << REGION_SHIFT;
+        let positions: Vec<_> = (0..side)
+            .flat_map(|x| (0..side).map(move |z| Vector2::new(x, z)))
+            .collect();
+
+        let manager = ChunkFileManager::<TestRegion>::new(());
+        for _ in 0..8 {
+            // Every chunk is saved by its own concurrent call, all targeting one file.
+            let saves = positions.iter().map(|&position| {
+                let chunk = Arc::new(TestChunk {
+                    position,
+                    value: chunk_value(position),
+                    dirty: AtomicBool::new(true),
+                });
+                manager.save_chunks(&folder, vec![(position, chunk)])
+            });
+            for result in join_all(saves).await {
+                result.expect("failed to save chunk");
+            }
+        }
+
+        // A fresh manager guarantees everything is read back from disk.
+        let manager = ChunkFileManager::<TestRegion>::new(());
+        let (send, mut recv) = mpsc::channel(positions.len());
+        manager.fetch_chunks(&folder, &positions, send).await;
+
+        let mut read = 0;
+        while let Some(data) = recv.recv().await {
+            match data {
+                LoadedData::Loaded(chunk) => {
+                    assert_eq!(chunk.value, chunk_value(chunk.position));
+                    read += 1;
+                }
+                LoadedData::Missing(position) => panic!("chunk {position:?} was lost"),
+                LoadedData::Error((position, err)) => {
+                    panic!("failed to read chunk {position:?}: {err}")
+                }
+            }
+        }
+        assert_eq!(read, positions.len());
+    }
+}
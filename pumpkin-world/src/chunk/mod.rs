@@ -1,7 +1,9 @@
 use crate::BlockStateId;
 use crate::chunk::format::LightContainer;
+use crate::tick::ScheduledTick;
 use crate::tick::scheduler::ChunkTickScheduler;
 use palette::{BiomePalette, BlockPalette, has_random_ticking_fluid};
+use pumpkin_data::biome::Biome;
 use pumpkin_data::block_properties::{blocks_movement, has_random_ticks, is_air};
 use pumpkin_data::chunk::ChunkStatus;
 use pumpkin_data::fluid::Fluid;
@@ -13,7 +15,7 @@ use pumpkin_util::math::position::BlockPos;
 use rustc_hash::FxHashMap;
 use serde::{Deserialize, Serialize};
 use std::sync::RwLock;
-use std::sync::atomic::AtomicBool;
+use std::sync::atomic::{AtomicBool, AtomicI64};
 use thiserror::Error;
 use tokio::sync::Mutex;
 
@@ -78,7 +80,10 @@ pub struct ChunkData {
     pub fluid_ticks: ChunkTickScheduler<&'static Fluid>,
     pub pending_block_entities: std::sync::Mutex<FxHashMap<BlockPos, NbtCompound>>,
     pub light_engine: std::sync::Mutex<ChunkLight>,
+    /// Mirrors the `isLightOn` flag: whether the stored light data is complete.
     pub light_populated: AtomicBool,
+    /// Total number of ticks players have spent near this chunk, used for local difficulty.
+    pub inhabited_time: AtomicI64,
     pub status: ChunkStatus,
     pub blending_data: Option<crate::generation::blender::blending_data::BlendingData>,
     pub dirty: AtomicBool,
@@ -575,6 +580,29 @@ impl ChunkSections {
 }
 
 impl ChunkData {
+    /// Returns the block and fluid ticks still pending in this chunk, with their remaining
+    /// delay and priority. These are the ticks loaded from (and saved to) the `block_ticks`
+    /// and `fluid_ticks` lists.
+    #[must_use]
+    pub fn scheduled_ticks(
+        &self,
+    ) -> (
+        Vec<ScheduledTick<&'static Block>>,
+        Vec<ScheduledTick<&'static Fluid>>,
+    ) {
+        (self.block_ticks.to_vec(), self.fluid_ticks.to_vec())
+    }
+
+    /// Returns the biome at the given chunk-relative x/z and absolute y, which is stored per
+    /// 4x4x4 cell. Heights outside the chunk fall back to plains.
+    #[must_use]
+    pub fn biome_at(&self, relative_x: usize, y: i32, relative_z: usize) -> &'static Biome {
+        self.section
+            .get_rough_biome_absolute_y(relative_x, y, relative_z)
+            .and_then(Biome::from_id)
+            .unwrap_or(&Biome::PLAINS)
+    }
+
     /// Returns the replaced block state ID
     pub fn set_block_absolute_y(
         &self,
@@ -588,8 +616,21 @@ impl ChunkData {
         if y_rel < 0 {
             return Block::AIR.default_state.id;
         }
-        let relative_y = y_rel as usize;
+        self.set_block(relative_x, y_rel as usize, relative_z, block_state_id)
+    }
 
+    /// Sets the given block in the chunk and updates the heightmaps of its column, returning the
+    /// replaced block state ID.
+    ///
+    /// Placing a block above a heightmap raises it to that block, while removing the block a
+    /// heightmap points at scans down the column for the next block it counts.
+    pub fn set_block(
+        &self,
+        relative_x: usize,
+        relative_y: usize,
+        relative_z: usize,
+        block_state_id: BlockStateId,
+    ) -> BlockStateId {
         let old = self.section.set_block_no_heightmap_update(
             relative_x,
             relative_y,
@@ -644,7 +685,7 @@ impl ChunkData {
             .get_relative_block(relative_x, relative_y, relative_z)
     }
 
-    /// Sets the given block in the chunk
+    /// Sets the given block in the chunk, see [`Self::set_block`]
     #[inline]
     pub fn set_relative_block(
         &mut self,
@@ -653,10 +694,7 @@ impl ChunkData {
         relative_z: usize,
         block_state_id: BlockStateId,
     ) {
-        let state = BlockState::from_id(block_state_id);
-        self.update_heightmap(relative_x, relative_y, relative_z, state);
-        self.section
-            .set_relative_block(relative_x, relative_y, relative_z, block_state_id);
+        self.set_block(relative_x, relative_y, relative_z, block_state_id);
     }
 
     /// Sets the given block in the chunk, returning the old block
@@ -762,6 +800,10 @@ pub enum ChunkParsingError {
     ChunkNotGenerated,
     #[error("Error deserializing chunk: {0}")]
     ErrorDeserializingChunk(String),
+    /// The chunk was saved before 1.17 and its terrain can't be read. It holds the entities
+    /// stored inline in it, which newer versions keep in the entities region files.
+    #[error("The chunk uses the legacy pre-1.17 layout")]
+    LegacyLayout(Vec<NbtCompound>),
 }
 
 #[derive(Error, Debug)]
@@ -772,9 +814,12 @@ pub enum ChunkSerializingError {
 
 #[cfg(test)]
 mod tests {
-    use super::ChunkSections;
+    use super::{ChunkData, ChunkHeightmapType, ChunkHeightmaps, ChunkSections};
     use crate::chunk::palette::BlockPalette;
+    use crate::tick::scheduler::ChunkTickScheduler;
+    use pumpkin_data::chunk::ChunkStatus;
     use pumpkin_data::{Block, block_properties::has_random_ticks};
+    use std::sync::atomic::{AtomicBool, AtomicI64};
 
     #[test]
     fn random_tick_cache_initializes_from_palette_contents() {
@@ -836,6 +881,70 @@ mod tests {
         }
     }
 
+    fn empty_chunk(min_y: i32) -> ChunkData {
+        ChunkData {
+            section: ChunkSections::new(24, min_y),
+            heightmap: std::sync::Mutex::new(ChunkHeightmaps::default()),
+            x: 0,
+            z: 0,
+            block_ticks: ChunkTickScheduler::default(),
+            fluid_ticks: ChunkTickScheduler::default(),
+            pending_block_entities: std::sync::Mutex::default(),
+            light_engine: std::sync::Mutex::default(),
+            light_populated: AtomicBool::new(false),
+            inhabited_time: AtomicI64::new(0),
+            status: ChunkStatus::Full,
+            blending_data: None,
+            dirty: AtomicBool::new(false),
+        }
+    }
+
+    fn heights(chunk: &ChunkData, x: i32, z: i32) -> (i32, i32, i32) {
+        let heightmap = chunk.heightmap.lock().unwrap();
+        let min_y = chunk.section.min_y;
+        (
+            heightmap.get(ChunkHeightmapType::WorldSurface, x, z, min_y),
+            heightmap.get(ChunkHeightmapType::MotionBlocking, x, z, min_y),
+            heightmap.get(ChunkHeightmapType::MotionBlockingNoLeaves, x, z, min_y),
+        )
+    }
+
+    #[test]
+    fn placing_on_top_of_a_column_raises_the_heightmaps() {
+        let chunk = empty_chunk(-64);
+        chunk.set_block_absolute_y(3, 10, 5, Block::STONE.default_state.id);
+        assert_eq!(heights(&chunk, 3, 5), (10, 10, 10));
+
+        chunk.set_block_absolute_y(3, 11, 5, Block::OAK_LEAVES.default_state.id);
+        assert_eq!(heights(&chunk, 3, 5), (11, 11, 10));
+
+        // Blocks which don't block motion only raise the world surface
+        chunk.set_block_absolute_y(3, 12, 5, Block::SHORT_GRASS.default_state.id);
+        assert_eq!(heights(&chunk, 3, 5), (12, 11, 10));
+
+        // Other columns are left alone
+        assert_eq!(heights(&chunk, 4, 5), (-65, -65, -65));
+    }
+
+    #[test]
+    fn breaking_the_top_block_of_a_column_lowers_the_heightmaps() {
+        let chunk = empty_chunk(-64);
+        let air = Block::AIR.default_state.id;
+        chunk.set_block_absolute_y(7, -20, 7, Block::STONE.default_state.id);
+        chunk.set_block_absolute_y(7, 0, 7, Block::OAK_LEAVES.default_state.id);
+        chunk.set_block_absolute_y(7, 1, 7, Block::STONE.default_state.id);
+        assert_eq!(heights(&chunk, 7, 7), (1, 1, 1));
+
+        chunk.set_block_absolute_y(7, 1, 7, air);
+        assert_eq!(heights(&chunk, 7, 7), (0, 0, -20));
+
+        chunk.set_block_absolute_y(7, 0, 7, air);
+        assert_eq!(heights(&chunk, 7, 7), (-20, -20, -20));
+
+        chunk.set_block_absolute_y(7, -20, 7, air);
+        assert_eq!(heights(&chunk, 7, 7), (-65, -65, -65));
+    }
+
     #[test]
     fn test_heightmap_is_opaque() {
         use crate::chunk::ChunkHeightmapType;
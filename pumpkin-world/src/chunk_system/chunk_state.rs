@@ -4,7 +4,7 @@ use pumpkin_config::lighting::LightingEngineConfig;
 use pumpkin_data::dimension::Dimension;
 use rustc_hash::FxHashMap;
 use std::sync::Arc;
-use std::sync::atomic::AtomicBool;
+use std::sync::atomic::{AtomicBool, AtomicI64};
 
 use crate::ProtoChunk;
 use crate::level::SyncChunk;
@@ -237,6 +237,7 @@ impl Chunk {
                 pending_block_entities: Default::default(),
                 light_engine: Mutex::new(ChunkLight::default()),
                 light_populated: AtomicBool::new(false),
+                inhabited_time: AtomicI64::new(0),
                 status: ChunkStatus::Empty,
                 blending_data: None,
                 dirty: AtomicBool::new(false),
@@ -321,6 +322,7 @@ impl Chunk {
         let mut chunk = ChunkData {
             light_engine: Mutex::new(light_data),
             light_populated: AtomicBool::new(is_lit),
+            inhabited_time: AtomicI64::new(0),
             section: sections,
             heightmap: Default::default(),
             x: proto_chunk.x,
@@ -0,0 +1,138 @@
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+
+/// The length of one game tick at the default tick rate.
+const TICK: Duration = Duration::from_millis(50);
+
+/// Spreads chunk reads over game ticks and tracks how many reads are pending.
+///
+/// IO workers take from a budget of `max_per_tick` loads that refills every tick, and wait
+/// for the next tick once it is used up. The budget is time based rather than driven by the
+/// tick loop so chunks can still load before the server starts ticking or while it shuts
+/// down. A limit of 0 disables rate limiting.
+pub struct ChunkLoadLimiter {
+    max_per_tick: usize,
+    window: Mutex<LoadWindow>,
+    queued: AtomicUsize,
+}
+
+struct LoadWindow {
+    started: Instant,
+    used: usize,
+}
+
+impl ChunkLoadLimiter {
+    #[must_use]
+    pub fn new(max_per_tick: usize) -> Self {
+        Self {
+            max_per_tick,
+            window: Mutex::new(LoadWindow {
+                started: Instant::now(),
+                used: 0,
+            }),
+            queued: AtomicUsize::new(0),
+        }
+    }
+
+    /// Takes up to `wanted` loads from this tick's budget, waiting for the next tick if it is
+    /// used up. Returns how many loads were granted, which is at least one.
+    pub async fn acquire(&self, wanted: usize) -> usize {
+        if self.max_per_tick == 0 {
+            return wanted;
+        }
+        loop {
+            match self.try_acquire_at(wanted, Instant::now()) {
+                Ok(granted) => return granted,
+                Err(wait) => tokio::time::sleep(wait).await,
+            }
+        }
+    }
+
+    /// Grants up to `wanted` loads at `now`, or returns how long to wait for the next tick.
+    fn try_acquire_at(&self, wanted: usize, now: Instant) -> Result<usize, Duration> {
+        let mut window = self.window.lock().unwrap();
+        let elapsed = now.saturating_duration_since(window.started);
+        if elapsed >= TICK {
+            window.started = now;
+            window.used = 0;
+        }
+
+        let available = self.max_per_tick - window.used;
+        if available == 0 {
+            return Err(TICK.saturating_sub(elapsed));
+        }
+        let granted = available.min(wanted);
+        window.used += granted;
+        Ok(granted)
+    }
+
+    /// Records `count` chunk reads handed to the IO workers.
+    pub fn enqueue(&self, count: usize) {
+        self.queued.fetch_add(count, Ordering::Relaxed);
+    }
+
+    /// Records `count` chunk reads as finished.
+    pub fn finish(&self, count: usize) {
+        let _ = self
+            .queued
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |queued| {
+                Some(queued.saturating_sub(count))
+            });
+    }
+
+    /// The number of chunk reads queued or in progress.
+    pub fn queue_depth(&self) -> usize {
+        self.queued.load(Ordering::Relaxed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant};
+
+    use super::{ChunkLoadLimiter, TICK};
+
+    #[tokio::test]
+    async fn unlimited_grants_everything() {
+        let limiter = ChunkLoadLimiter::new(0);
+        assert_eq!(limiter.acquire(100).await, 100);
+        assert_eq!(limiter.acquire(100).await, 100);
+    }
+
+    #[test]
+    fn budget_is_split_and_refilled_next_tick() {
+        let limiter = ChunkLoadLimiter::new(10);
+        let start = limiter.window.lock().unwrap().started;
+
+        assert_eq!(limiter.try_acquire_at(6, start), Ok(6));
+        assert_eq!(limiter.try_acquire_at(6, start), Ok(4));
+
+        let later = start + Duration::from_millis(20);
+        assert_eq!(
+            limiter.try_acquire_at(1, later),
+            Err(TICK - Duration::from_millis(20))
+        );
+
+        assert_eq!(limiter.try_acquire_at(3, start + TICK), Ok(3));
+    }
+
+    #[tokio::test]
+    async fn exhausted_budget_waits_for_next_tick() {
+        let limiter = ChunkLoadLimiter::new(1);
+        let start = Instant::now();
+        assert_eq!(limiter.acquire(1).await, 1);
+        assert_eq!(limiter.acquire(1).await, 1);
+        assert!(start.elapsed() >= TICK / 2);
+    }
+
+    #[test]
+    fn queue_depth_tracks_enqueued_reads() {
+        let limiter = ChunkLoadLimiter::new(0);
+        limiter.enqueue(16);
+        limiter.finish(10);
+        assert_eq!(limiter.queue_depth(), 6);
+        limiter.finish(10);
+        assert_eq!(limiter.queue_depth(), 0);
+    }
+}
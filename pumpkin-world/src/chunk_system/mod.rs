@@ -23,6 +23,7 @@ pub mod chunk_state;
 pub mod dag;
 pub mod generation;
 pub mod generation_cache;
+pub mod load_limiter;
 pub mod schedule;
 pub mod worker_logic;
 
@@ -37,4 +38,5 @@ pub use chunk_state::{Chunk, StagedChunkEnum};
 pub use dag::DAG;
 pub use generation::generate_single_chunk;
 pub use generation_cache::Cache;
+pub use load_limiter::ChunkLoadLimiter;
 pub use schedule::GenerationSchedule;
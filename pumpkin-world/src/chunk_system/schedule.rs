@@ -5,8 +5,8 @@ use super::dag::{DAG, EdgeKey, Node, NodeKey};
 use super::generation_cache::Cache;
 use super::worker_logic::{RecvChunk, generation_work, io_read_work, io_write_work};
 use super::{
-    ChunkLevel, ChunkListener, ChunkLoading, ChunkPos, HashMapType, HashSetType, IOLock,
-    LevelChannel,
+    ChunkLevel, ChunkListener, ChunkLoadLimiter, ChunkLoading, ChunkPos, HashMapType, HashSetType,
+    IOLock, LevelChannel,
 };
 use crate::chunk::io::Dirtiable;
 use crate::level::{Level, SyncChunk};
@@ -71,6 +71,7 @@ pub struct GenerationSchedule {
     queue_dirty: bool,
     recv_chunk: crossfire::compat::MRx<(ChunkPos, RecvChunk)>,
     io_read: crossfire::compat::MTx<Vec<ChunkPos>>,
+    load_limiter: Arc<ChunkLoadLimiter>,
     io_write: crossfire::compat::Tx<Vec<(ChunkPos, Chunk)>>,
     generate: crossfire::compat::MTx<(ChunkPos, Cache, StagedChunkEnum)>,
     send_chunk: crossfire::compat::MTx<(ChunkPos, RecvChunk)>,
@@ -162,6 +163,7 @@ impl GenerationSchedule {
                     queue_dirty: false,
                     recv_chunk,
                     io_read: send_read_io,
+                    load_limiter: level_sched.chunk_load_limiter.clone(),
                     io_write: send_write_io,
                     generate: send_gen,
                     send_chunk,
@@ -984,6 +986,18 @@ impl GenerationSchedule {
         self.running_task_count -= 1;
     }
 
+    /// Hands `batch` to the IO read workers, returning `false` if they have shut down.
+    fn send_io_read(&self, batch: &mut Vec<ChunkPos>) -> bool {
+        // Count before sending so a fast worker cannot finish the batch first.
+        let count = batch.len();
+        self.load_limiter.enqueue(count);
+        let sent = self.io_read.send(std::mem::take(batch)).is_ok();
+        if !sent {
+            self.load_limiter.finish(count);
+        }
+        sent
+    }
+
     fn work(mut self, level: Arc<Level>) {
         debug!(
             "schedule thread start id: {:?} name: {}",
@@ -1073,18 +1087,14 @@ impl GenerationSchedule {
                         holder.occupied = occupy;
 
                         io_batch.push(node.pos);
-                        if io_batch.len() >= 16
-                            && self.io_read.send(std::mem::take(&mut io_batch)).is_err()
-                        {
+                        if io_batch.len() >= 16 && !self.send_io_read(&mut io_batch) {
                             info!("IO read thread closed, saving remaining chunks...");
                             self.save_all_chunk(true);
                             break 'out2;
                         }
                     } else {
                         // Send any pending IO batch before starting generation
-                        if !io_batch.is_empty()
-                            && self.io_read.send(std::mem::take(&mut io_batch)).is_err()
-                        {
+                        if !io_batch.is_empty() && !self.send_io_read(&mut io_batch) {
                             info!("IO read thread closed, saving remaining chunks...");
                             self.save_all_chunk(true);
                             break 'out2;
@@ -1216,7 +1226,7 @@ impl GenerationSchedule {
             }
 
             // Flush any remaining IO batch
-            if !io_batch.is_empty() && self.io_read.send(std::mem::take(&mut io_batch)).is_err() {
+            if !io_batch.is_empty() && !self.send_io_read(&mut io_batch) {
                 info!("IO read thread closed, saving remaining chunks...");
                 self.save_all_chunk(true);
             }
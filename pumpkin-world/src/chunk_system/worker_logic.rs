@@ -5,6 +5,7 @@ use crate::ProtoChunk;
 use crate::chunk::format::LightContainer;
 use crate::chunk::io::LoadedData;
 use crate::chunk::io::LoadedData::Loaded;
+use crate::chunk::{ChunkParsingError, ChunkReadingError};
 use crate::level::Level;
 use crossfire::compat::AsyncRx;
 use pumpkin_config::lighting::LightingEngineConfig;
@@ -74,6 +75,11 @@ pub async fn io_read_work(
             }
         }
 
+        let mut remaining = batch.len();
+        while remaining > 0 {
+            remaining -= level.chunk_load_limiter.acquire(remaining).await;
+        }
+
         let (t_send, mut t_recv) = tokio::sync::mpsc::channel(1000);
 
         let level_clone = level.clone();
@@ -145,6 +151,13 @@ pub async fn io_read_work(
                     }
                 }
                 LoadedData::Missing(pos) | LoadedData::Error((pos, _)) => {
+                    if let LoadedData::Error((
+                        _,
+                        ChunkReadingError::ParsingError(ChunkParsingError::LegacyLayout(entities)),
+                    )) = data
+                    {
+                        level.store_legacy_entities(pos, entities);
+                    }
                     if send
                         .send((
                             pos,
@@ -162,6 +175,7 @@ pub async fn io_read_work(
             }
         }
         let _ = fetch_task.await;
+        level.chunk_load_limiter.finish(batch.len());
     }
     debug!("io read thread stop");
 }
@@ -18,6 +18,11 @@ pub fn into_level(
         base_directory.push("DIM-1");
     } else if dimension.minecraft_name == Dimension::THE_END.minecraft_name {
         base_directory.push("DIM1");
+    } else if let Some((namespace, path)) = dimension.minecraft_name.split_once(':') {
+        // Data pack dimensions, like vanilla stores them
+        base_directory.push("dimensions");
+        base_directory.push(namespace);
+        base_directory.push(path);
     }
     Level::from_root_folder(level_config, base_directory, seed, dimension, gen_pool)
 }
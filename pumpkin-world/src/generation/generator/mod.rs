@@ -1,5 +1,5 @@
 use pumpkin_data::BlockState;
-use pumpkin_data::chunk_gen_settings::GenerationSettings;
+use pumpkin_data::chunk_gen_settings::{GenerationSettings, GenerationShapeConfig};
 use pumpkin_data::dimension::Dimension;
 use pumpkin_data::noise_router::{
     END_BASE_NOISE_ROUTER, NETHER_BASE_NOISE_ROUTER, OVERWORLD_BASE_NOISE_ROUTER,
@@ -23,6 +23,8 @@ pub struct VanillaGenerator {
     pub base_router: ProtoNoiseRouters,
     pub dimension: Dimension,
     pub settings: &'static GenerationSettings,
+    /// The noise shape of `settings` clipped to the dimension's height
+    pub shape: GenerationShapeConfig,
     pub biome_mixer_seed: i64,
 
     pub terrain_cache: TerrainCache,
@@ -36,19 +38,24 @@ pub struct VanillaGenerator {
 
 impl GeneratorInit for VanillaGenerator {
     fn new(seed: Seed, dimension: Dimension) -> Self {
-        let settings = GenerationSettings::from_dimension(&dimension);
+        // Data pack dimensions are generated like the overworld
+        let generation_dimension =
+            if dimension == Dimension::THE_NETHER || dimension == Dimension::THE_END {
+                &dimension
+            } else {
+                &Dimension::OVERWORLD
+            };
+        let settings = GenerationSettings::from_dimension(generation_dimension);
+        let shape = clip_shape(&settings.shape, &dimension);
         let random_config = GlobalRandomConfig::new(seed.0, settings.legacy_random_source);
 
         // TODO: The generation settings contains (part of?) the noise routers too; do we keep the separate or
         // use only the generation settings?
-        let base = if dimension == Dimension::OVERWORLD {
-            OVERWORLD_BASE_NOISE_ROUTER
-        } else if dimension == Dimension::THE_NETHER {
+        let base = if dimension == Dimension::THE_NETHER {
             NETHER_BASE_NOISE_ROUTER
         } else if dimension == Dimension::THE_END {
             END_BASE_NOISE_ROUTER
         } else {
-            tracing::error!("Unsupported dimension for noise router: {:?}", dimension);
             OVERWORLD_BASE_NOISE_ROUTER
         };
         let terrain_cache = TerrainCache::from_random(&random_config);
@@ -70,6 +77,7 @@ impl GeneratorInit for VanillaGenerator {
             base_router,
             dimension,
             settings,
+            shape,
             biome_mixer_seed,
             terrain_cache,
             default_block,
@@ -80,3 +88,66 @@ impl GeneratorInit for VanillaGenerator {
         }
     }
 }
+
+/// Clips `shape` to the logical height of `dimension`, like vanilla does for data pack
+/// dimensions whose height differs from their noise settings.
+#[must_use]
+pub fn clip_shape(shape: &GenerationShapeConfig, dimension: &Dimension) -> GenerationShapeConfig {
+    let min_y = i32::from(shape.min_y).max(dimension.min_y);
+    let top_y = (i32::from(shape.min_y) + i32::from(shape.height))
+        .min(dimension.min_y + dimension.logical_height);
+    GenerationShapeConfig {
+        min_y: min_y as i8,
+        height: (top_y - min_y).max(0) as u16,
+        size_horizontal: shape.size_horizontal,
+        size_vertical: shape.size_vertical,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pumpkin_data::chunk_gen_settings::GenerationSettings;
+    use pumpkin_data::dimension::Dimension;
+
+    use super::clip_shape;
+
+    #[test]
+    fn vanilla_shapes_are_unchanged() {
+        let shape = &GenerationSettings::OVERWORLD.shape;
+        let clipped = clip_shape(shape, &Dimension::OVERWORLD);
+        assert_eq!(clipped.min_y, shape.min_y);
+        assert_eq!(clipped.height, shape.height);
+    }
+
+    #[test]
+    fn shorter_dimensions_clip_the_shape() {
+        let dimension = Dimension {
+            min_y: 0,
+            height: 256,
+            logical_height: 256,
+            ..Dimension::OVERWORLD
+        };
+        let clipped = clip_shape(&GenerationSettings::OVERWORLD.shape, &dimension);
+        assert_eq!(clipped.min_y, 0);
+        assert_eq!(clipped.height, 256);
+        assert_eq!(
+            clipped.vertical_cell_block_count(),
+            GenerationSettings::OVERWORLD
+                .shape
+                .vertical_cell_block_count()
+        );
+    }
+
+    #[test]
+    fn taller_dimensions_keep_the_noise_range() {
+        let dimension = Dimension {
+            min_y: -128,
+            height: 512,
+            logical_height: 512,
+            ..Dimension::OVERWORLD
+        };
+        let clipped = clip_shape(&GenerationSettings::OVERWORLD.shape, &dimension);
+        assert_eq!(clipped.min_y, -64);
+        assert_eq!(clipped.height, 384);
+    }
+}
@@ -503,7 +503,7 @@ impl ProtoChunk {
         debug_assert_eq!(self.stage, StagedChunkEnum::StructureReferences);
 
         let settings = generator.settings;
-        let generation_shape = &settings.shape;
+        let generation_shape = &generator.shape;
         let horizontal_cell_count = CHUNK_DIM / generation_shape.horizontal_cell_block_count();
         let start_x = start_block_x(self.x);
         let start_z = start_block_z(self.z);
@@ -684,7 +684,7 @@ impl ProtoChunk {
         debug_assert_eq!(self.stage, StagedChunkEnum::Noise);
         let start_x = start_block_x(self.x);
         let start_z = start_block_z(self.z);
-        let generation_shape = &generator.settings.shape;
+        let generation_shape = &generator.shape;
         let horizontal_cell_count = CHUNK_DIM / generation_shape.horizontal_cell_block_count();
 
         let horizontal_biome_end = biome_coords::from_block(
@@ -1195,9 +1195,9 @@ impl ProtoChunk {
                 crate::generation::biome_coords::from_block(crate::generation::positions::chunk_pos::start_block_x(self.x)),
                 crate::generation::biome_coords::from_block(crate::generation::positions::chunk_pos::start_block_z(self.z)),
                 4,
-                settings.shape.min_y as i32,
-                settings.shape.height as i32,
-                (settings.shape.height / settings.shape.vertical_cell_block_count() as u16) as usize,
+                generator.shape.min_y as i32,
+                generator.shape.height as i32,
+                (generator.shape.height / generator.shape.vertical_cell_block_count() as u16) as usize,
             ),
         );
 
@@ -1330,9 +1330,9 @@ impl ProtoChunk {
                 crate::generation::biome_coords::from_block(start_x),
                 crate::generation::biome_coords::from_block(start_z),
                 4,
-                settings.shape.min_y as i32,
-                settings.shape.height as i32,
-                (settings.shape.height / settings.shape.vertical_cell_block_count() as u16) as usize,
+                generator.shape.min_y as i32,
+                generator.shape.height as i32,
+                (generator.shape.height / generator.shape.vertical_cell_block_count() as u16) as usize,
             ),
         );
 
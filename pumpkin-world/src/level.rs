@@ -1,6 +1,8 @@
 use crate::chunk::format::linear::LinearV2File;
 use crate::chunk::format::pump::PumpFile;
-use crate::chunk_system::{ChunkListener, ChunkLoading, GenerationSchedule, LevelChannel};
+use crate::chunk_system::{
+    ChunkListener, ChunkLoadLimiter, ChunkLoading, GenerationSchedule, LevelChannel,
+};
 use crate::generation::generator::VanillaGenerator;
 use crate::lighting::DynamicLightEngine;
 use crate::{
@@ -18,16 +20,19 @@ use crate::{
 };
 use arc_swap::ArcSwap;
 use dashmap::{DashMap, Entry};
+use futures::{StreamExt, stream};
 use pumpkin_config::{chunk::ChunkConfig, lighting::LightingEngineConfig, world::LevelConfig};
 use pumpkin_data::biome::Biome;
 use pumpkin_data::dimension::Dimension;
 use pumpkin_data::{Block, block_properties::has_random_ticks, fluid::Fluid};
+use pumpkin_nbt::compound::NbtCompound;
 use pumpkin_util::math::{position::BlockPos, vector2::Vector2};
 use pumpkin_util::world_seed::Seed;
 use rustc_hash::{FxHashMap, FxHashSet};
 use std::sync::{Arc, Mutex, Weak};
 use std::time::Duration;
 use std::{
+    num::NonZeroUsize,
     path::PathBuf,
     sync::atomic::{AtomicBool, AtomicU64, Ordering},
     thread,
@@ -97,8 +102,12 @@ pub struct Level {
     pub autosave_ticks: u64,
 
     pending_entity_generations: Arc<DashMap<Vector2<i32>, Vec<oneshot::Sender<SyncEntityChunk>>>>,
+    /// Entities read from pre-1.17 terrain chunks, waiting to be moved into their entity chunk
+    legacy_entities: DashMap<Vector2<i32>, Vec<NbtCompound>>,
 
     pub level_channel: Arc<LevelChannel>,
+    /// Rate limits chunk reads and tracks the read queue depth
+    pub chunk_load_limiter: Arc<ChunkLoadLimiter>,
     pub thread_tracker: Mutex<Vec<thread::JoinHandle<()>>>,
     pub chunk_listener: Arc<ChunkListener>,
     pub gen_pool: Option<Arc<rayon::ThreadPool>>,
@@ -192,7 +201,11 @@ impl Level {
             should_unload: AtomicBool::new(false),
             autosave_ticks: level_config.autosave_ticks,
             pending_entity_generations,
+            legacy_entities: DashMap::new(),
             level_channel: level_channel.clone(),
+            chunk_load_limiter: Arc::new(ChunkLoadLimiter::new(
+                level_config.max_chunk_loads_per_tick as usize,
+            )),
             thread_tracker,
             chunk_listener: listener.clone(),
             gen_pool: gen_pool.clone(),
@@ -222,12 +235,10 @@ impl Level {
         let level = self.clone();
         if let Some(pool) = &self.gen_pool {
             pool.spawn(move || {
-                let arc_chunk = Arc::new(ChunkEntityData {
-                    x: pos.x,
-                    z: pos.y,
-                    data: tokio::sync::Mutex::new(FxHashMap::default()),
-                    dirty: AtomicBool::new(false),
-                });
+                let arc_chunk = Arc::new(ChunkEntityData::from_legacy_entities(
+                    pos,
+                    level.take_legacy_entities(pos),
+                ));
 
                 level.loaded_entity_chunks.insert(pos, arc_chunk.clone());
 
@@ -243,12 +254,10 @@ impl Level {
             thread::Builder::new()
                 .name(format!("Entity Gen {pos:?}"))
                 .spawn(move || {
-                    let arc_chunk = Arc::new(ChunkEntityData {
-                        x: pos.x,
-                        z: pos.y,
-                        data: tokio::sync::Mutex::new(FxHashMap::default()),
-                        dirty: AtomicBool::new(false),
-                    });
+                    let arc_chunk = Arc::new(ChunkEntityData::from_legacy_entities(
+                        pos,
+                        level_clone.take_legacy_entities(pos),
+                    ));
 
                     level_clone
                         .loaded_entity_chunks
@@ -265,6 +274,21 @@ impl Level {
         }
     }
 
+    /// Keeps the entities found inline in a pre-1.17 terrain chunk until the entity chunk at `pos`
+    /// is created, as such worlds have no entities region files to load them from.
+    pub fn store_legacy_entities(&self, pos: Vector2<i32>, entities: Vec<NbtCompound>) {
+        if !entities.is_empty() {
+            self.legacy_entities.insert(pos, entities);
+        }
+    }
+
+    fn take_legacy_entities(&self, pos: Vector2<i32>) -> Vec<NbtCompound> {
+        self.legacy_entities
+            .remove(&pos)
+            .map(|(_, entities)| entities)
+            .unwrap_or_default()
+    }
+
     /// Spawns a task associated with this world. All tasks spawned with this method are awaited
     /// when the client. This means tasks should complete in a reasonable (no looping) amount of time.
     pub fn spawn_task<F>(&self, task: F) -> JoinHandle<F::Output>
@@ -339,6 +363,11 @@ impl Level {
         self.write_entity_chunks(chunks_to_write).await;
     }
 
+    /// The number of chunk reads queued or in progress.
+    pub fn chunk_load_queue_depth(&self) -> usize {
+        self.chunk_load_limiter.queue_depth()
+    }
+
     pub fn loaded_chunk_count(&self) -> usize {
         self.loaded_chunks.len()
     }
@@ -572,6 +601,88 @@ impl Level {
         chunk
     }
 
+    /// Reads many chunks at once, returning one result per position in the same order.
+    ///
+    /// Chunks already in memory are returned as they are. The others are grouped by region file
+    /// so every file is opened once, reading at most `max_concurrent_regions` files at the same
+    /// time (unbounded when `None`). Nothing is generated, chunks missing on disk are reported
+    /// as [`ChunkReadingError::ChunkNotExist`].
+    ///
+    /// Chunks read from disk are not added to `loaded_chunks`, which only the chunk scheduler
+    /// fills so it can save and unload them. If the scheduler loaded a chunk while it was being
+    /// read, its copy is returned instead, so changes made to the result are never lost.
+    pub async fn read_chunks(
+        &self,
+        positions: &[Vector2<i32>],
+        max_concurrent_regions: Option<NonZeroUsize>,
+    ) -> Vec<Result<SyncChunk, ChunkReadingError>> {
+        let mut results: FxHashMap<Vector2<i32>, Result<SyncChunk, ChunkReadingError>> =
+            FxHashMap::default();
+        let mut regions: FxHashMap<Vector2<i32>, Vec<Vector2<i32>>> = FxHashMap::default();
+        for pos in positions {
+            if let Some(chunk) = self.loaded_chunks.get(pos) {
+                results.insert(*pos, Ok(chunk.clone()));
+                continue;
+            }
+            let region = regions
+                .entry(Vector2::new(pos.x >> 5, pos.y >> 5))
+                .or_default();
+            if !region.contains(pos) {
+                region.push(*pos);
+            }
+        }
+
+        let limit = max_concurrent_regions.map_or(usize::MAX, NonZeroUsize::get);
+        let mut reads = stream::iter(regions.into_values())
+            .map(|chunks| async move {
+                let (tx, mut rx) = mpsc::channel(chunks.len());
+                self.chunk_saver
+                    .fetch_chunks(&self.level_folder, &chunks, tx)
+                    .await;
+
+                let mut loaded = Vec::with_capacity(chunks.len());
+                while let Some(data) = rx.recv().await {
+                    loaded.push(data);
+                }
+                loaded
+            })
+            .buffer_unordered(limit);
+
+        while let Some(loaded) = reads.next().await {
+            for data in loaded {
+                match data {
+                    LoadedData::Loaded(chunk) => {
+                        results.insert(Vector2::new(chunk.x, chunk.z), Ok(chunk));
+                    }
+                    LoadedData::Missing(pos) => {
+                        results.insert(pos, Err(ChunkReadingError::ChunkNotExist));
+                    }
+                    LoadedData::Error((pos, err)) => {
+                        results.insert(pos, Err(err));
+                    }
+                }
+            }
+        }
+
+        positions
+            .iter()
+            .map(|pos| {
+                if let Some(loaded) = self.loaded_chunks.get(pos) {
+                    return Ok(loaded.clone());
+                }
+                match results.remove(pos) {
+                    Some(Ok(chunk)) => {
+                        // Keep it around in case the position was requested more than once
+                        results.insert(*pos, Ok(chunk.clone()));
+                        Ok(chunk)
+                    }
+                    Some(Err(err)) => Err(err),
+                    None => Err(ChunkReadingError::ChunkNotExist),
+                }
+            })
+            .collect()
+    }
+
     async fn load_single_entity_chunk(
         &self,
         pos: Vector2<i32>,
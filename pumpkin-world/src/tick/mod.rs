@@ -58,6 +58,14 @@ impl TryFrom<i32> for TickPriority {
     }
 }
 
+/// Converts a saved tick delay into one the scheduler can hold. Overdue ticks (negative delays)
+/// run on the next tick and delays past the scheduler's window run at its far end, rather than
+/// wrapping around to an unrelated delay.
+#[must_use]
+pub fn delay_from_nbt(delay: i32) -> u8 {
+    delay.clamp(0, MAX_TICK_DELAY as i32 - 1) as u8
+}
+
 #[derive(Clone)]
 pub struct ScheduledTick<T> {
     pub delay: u8,
@@ -147,7 +155,7 @@ where
         let y = get_int("y")?;
         let z = get_int("z")?;
 
-        let delay = get_int("t")? as u8;
+        let delay = delay_from_nbt(get_int("t")?);
 
         let priority = TickPriority::try_from(get_int("p")?)
             .map_err(|_| D::Error::custom("Invalid tick priority"))?;
@@ -168,3 +176,15 @@ where
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::delay_from_nbt;
+
+    #[test]
+    fn saved_delays_are_clamped_to_the_scheduler_window() {
+        assert_eq!(delay_from_nbt(20), 20);
+        assert_eq!(delay_from_nbt(-3), 0);
+        assert_eq!(delay_from_nbt(300), 255);
+    }
+}
@@ -137,8 +137,9 @@ impl<'a, T: std::hash::Hash + Eq + 'static> FromIterator<ScheduledTick<&'a T>>
             inner.queued_ticks.reserve(lower);
         }
 
-        for tick in iter {
-            scheduler.schedule_tick(&tick, 0);
+        // Keep the saved order for ticks that share a delay and priority
+        for (sub_tick_order, tick) in iter.enumerate() {
+            scheduler.schedule_tick(&tick, sub_tick_order as u64);
         }
         scheduler
     }
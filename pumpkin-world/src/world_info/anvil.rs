@@ -234,6 +234,7 @@ mod test {
             spawn_z: 160,
             spawn_yaw: 0.0,
             spawn_pitch: 0.0,
+            spawn_dimension: "minecraft:overworld".to_string(),
             level_version: 19133,
             world_version: WorldVersion {
                 name: "1.21.4".to_string(),
@@ -242,6 +243,10 @@ mod test {
                 series: "main".to_string(),
             },
             map_id: 0,
+            raining: false,
+            rain_time: 80_942,
+            thundering: false,
+            thunder_time: 74_299,
         },
     });
 
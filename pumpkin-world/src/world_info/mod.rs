@@ -76,12 +76,22 @@ pub struct LevelData {
     pub spawn_yaw: f32,
     #[serde(default)]
     pub spawn_pitch: f32,
+    #[serde(default = "default_spawn_dimension")]
+    pub spawn_dimension: String,
     #[serde(rename = "Version", default)]
     pub world_version: WorldVersion,
     #[serde(rename = "version", default = "default_level_version")]
     pub level_version: i32,
     #[serde(rename = "map_id", default)]
     pub map_id: i32,
+    #[serde(rename = "raining", default)]
+    pub raining: bool,
+    #[serde(rename = "rainTime", default)]
+    pub rain_time: i32,
+    #[serde(rename = "thundering", default)]
+    pub thundering: bool,
+    #[serde(rename = "thunderTime", default)]
+    pub thunder_time: i32,
 }
 
 const DEFAULT_BORDER_DAMAGE_PER_BLOCK: f64 = 0.2;
@@ -92,6 +102,7 @@ const DEFAULT_BORDER_WARNING_TIME: f64 = 15.0;
 const DEFAULT_DIFFICULTY: Difficulty = Difficulty::Normal;
 const DEFAULT_LEVEL_NAME: &str = "world";
 const DEFAULT_SPAWN_Y: i32 = 200;
+const DEFAULT_SPAWN_DIMENSION: &str = "minecraft:overworld";
 
 const fn default_border_damage_per_block() -> f64 {
     DEFAULT_BORDER_DAMAGE_PER_BLOCK
@@ -123,6 +134,9 @@ fn default_level_name() -> String {
 const fn default_spawn_y() -> i32 {
     DEFAULT_SPAWN_Y
 }
+fn default_spawn_dimension() -> String {
+    DEFAULT_SPAWN_DIMENSION.to_string()
+}
 const fn default_level_version() -> i32 {
     MAXIMUM_SUPPORTED_LEVEL_VERSION
 }
@@ -325,9 +339,14 @@ impl LevelData {
             spawn_z: 0,
             spawn_yaw: 0.0,
             spawn_pitch: 0.0,
+            spawn_dimension: DEFAULT_SPAWN_DIMENSION.to_string(),
             world_version: WorldVersion::default(),
             level_version: MAXIMUM_SUPPORTED_LEVEL_VERSION,
             map_id: 0,
+            raining: false,
+            rain_time: 0,
+            thundering: false,
+            thunder_time: 0,
         }
     }
 
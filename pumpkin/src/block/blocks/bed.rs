@@ -25,6 +25,7 @@ use crate::block::{
 };
 use crate::entity::{Entity, EntityBase};
 use crate::world::World;
+use crate::world::explosion::ExplosionBlockInteraction;
 
 type BedProperties = pumpkin_data::block_properties::WhiteBedLikeProperties;
 
@@ -235,7 +236,12 @@ impl BlockBehaviour for BedBlock {
                     .await;
 
                 args.world
-                    .explode(bed_head_pos.to_centered_f64(), 5.0)
+                    .create_explosion(
+                        bed_head_pos.to_centered_f64(),
+                        5.0,
+                        true,
+                        ExplosionBlockInteraction::DestroyWithDecay,
+                    )
                     .await;
 
                 return BlockActionResult::SuccessServer;
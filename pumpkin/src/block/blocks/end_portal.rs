@@ -23,6 +23,15 @@ impl BlockBehaviour for EndPortalBlock {
             if Arc::ptr_eq(&target_world, args.world) {
                 return;
             }
+            // Players leaving the End for the first time win the game and respawn once the
+            // credits are over, everyone else goes through the portal
+            if args.world.dimension.minecraft_name == Dimension::THE_END.minecraft_name
+                && let Some(player) = args.entity.get_player()
+                && player.should_show_credits()
+            {
+                player.win_game().await;
+                return;
+            }
             tracing::info!(
                 "End portal collision at {:?}, targeting world {:?}",
                 args.position,
@@ -194,8 +194,8 @@ impl BlockBehaviour for FireBlock {
             if (dimension == &Dimension::OVERWORLD || dimension == &Dimension::THE_NETHER)
                 && let Some(portal) =
                     NetherPortal::get_new_portal(args.world, args.position, HorizontalAxis::X)
+                && portal.light(args.world).await
             {
-                portal.create(args.world).await;
                 return;
             }
 
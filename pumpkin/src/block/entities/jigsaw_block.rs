@@ -78,8 +78,8 @@ impl JigsawBlockEntity {
                 chunk_x: self.position.chunk_position().x,
                 chunk_z: self.position.chunk_position().y,
                 random: RandomGenerator::Xoroshiro(Xoroshiro::from_seed(rand::rng().next_u64())),
-                sea_level: 63,
-                min_y: -64,
+                sea_level: world.sea_level,
+                min_y: world.get_bottom_y(),
                 height_sampler: None,
                 structure_key: None,
             };
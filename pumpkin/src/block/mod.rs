@@ -428,6 +428,28 @@ pub struct BlockEvent {
     pub data: u8,
 }
 
+/// Rolls the items `block` drops when `state` is broken with `tool`, honoring shears,
+/// Silk Touch and Fortune.
+#[must_use]
+pub fn get_block_drops(
+    block: &Block,
+    state: &'static BlockState,
+    tool: &ItemStack,
+) -> Vec<ItemStack> {
+    block
+        .loot_table
+        .as_ref()
+        .map_or_else(Vec::new, |loot_table| {
+            loot_table.get_loot(
+                LootContextParameters {
+                    block_state: Some(state),
+                    ..Default::default()
+                }
+                .with_tool(tool),
+            )
+        })
+}
+
 pub async fn drop_loot(
     world: &Arc<World>,
     block: &Block,
@@ -441,10 +463,12 @@ pub async fn drop_loot(
         }
     }
 
-    if experience && let Some(experience) = &block.experience {
+    if experience
+        && !params.silk_touch
+        && let Some(experience) = &block.experience
+    {
         let mut random = RandomGenerator::Xoroshiro(Xoroshiro::from_seed(get_seed()));
         let amount = experience.experience.get(&mut random);
-        // TODO: Silk touch gives no exp
         if amount > 0 {
             ExperienceOrbEntity::spawn(world, pos.to_f64(), amount as u32).await;
         }
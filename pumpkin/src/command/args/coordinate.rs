@@ -26,6 +26,10 @@ impl<const IS_Y: bool> TryFrom<&str> for MaybeRelativeCoordinate<IS_Y> {
 }
 
 impl<const IS_Y: bool> MaybeRelativeCoordinate<IS_Y> {
+    pub const fn is_relative(&self) -> bool {
+        matches!(self, Self::Relative(_))
+    }
+
     pub fn into_absolute(self, origin: Option<f64>) -> Option<f64> {
         match self {
             Self::Absolute(v) => Some(v),
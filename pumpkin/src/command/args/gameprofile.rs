@@ -14,8 +14,7 @@ use crate::{
         dispatcher::CommandError,
         tree::{RawArg, RawArgs},
     },
-    net::authentication::lookup_profile_by_name,
-    net::{GameProfile, offline_uuid},
+    net::GameProfile,
     server::Server,
 };
 
@@ -228,50 +227,16 @@ async fn resolve_profiles_from_token(
         return Ok(vec![player.gameprofile.clone()]);
     }
 
-    let cached_entry = server
-        .data
-        .user_cache
-        .write()
-        .await
-        .get_by_name(raw_arg.value);
-    if let Some(entry) = cached_entry {
-        return Ok(vec![profile_from_uuid_name(entry.uuid, entry.name)]);
-    }
-
     if let Some(profile) = resolve_known_profile_by_name(server, raw_arg.value).await {
         return Ok(vec![profile]);
     }
 
-    if server.basic_config.online_mode {
-        match lookup_profile_by_name(
-            raw_arg.value,
-            &server.advanced_config.networking.authentication,
-        ) {
-            Ok(Some((uuid, resolved_name))) => {
-                server
-                    .data
-                    .user_cache
-                    .write()
-                    .await
-                    .upsert(uuid, resolved_name.clone());
-                return Ok(vec![profile_from_uuid_name(uuid, resolved_name)]);
-            }
-            Ok(None) | Err(_) => return Err(syntax_player_unknown(raw_arg)),
-        }
-    }
-
-    if let Ok(uuid) = offline_uuid(raw_arg.value) {
-        let profile = profile_from_uuid_name(uuid, raw_arg.value.to_string());
-        server
-            .data
-            .user_cache
-            .write()
-            .await
-            .upsert(profile.id, profile.name.clone());
-        return Ok(vec![profile]);
-    }
-
-    Err(syntax_player_unknown(raw_arg))
+    server
+        .profile_resolver
+        .resolve_by_name(server, raw_arg.value)
+        .await
+        .map(|profile| vec![profile])
+        .ok_or_else(|| syntax_player_unknown(raw_arg))
 }
 
 async fn resolve_known_profile_by_name(server: &Server, name: &str) -> Option<GameProfile> {
@@ -113,7 +113,8 @@ pub enum Arg<'a> {
     Players(Vec<Arc<Player>>),
     GameProfiles(Vec<crate::net::GameProfile>),
     BlockPos(BlockPos),
-    Pos3D(Vector3<f64>),
+    /// (position, whether every coordinate is relative)
+    Pos3D(Vector3<f64>, bool),
     Pos2D(Vector2<f64>),
     /// (yaw, `is_yaw_relative`, pitch, `is_pitch_relative`)
     Rotation(f32, bool, f32, bool),
@@ -40,8 +40,13 @@ impl ArgumentConsumer for Position3DArgumentConsumer {
         };
 
         let result: Option<Arg<'a>> = MaybeRelativePosition3D::try_new(x_str, y_str, z_str)
-            .and_then(|pos| pos.try_to_absolute(sender.position()))
-            .map(Arg::Pos3D);
+            .and_then(|pos| {
+                let relative = pos.is_relative();
+                Some(Arg::Pos3D(
+                    pos.try_to_absolute(sender.position())?,
+                    relative,
+                ))
+            });
 
         Box::pin(async move { result })
     }
@@ -62,6 +67,10 @@ impl MaybeRelativePosition3D {
         ))
     }
 
+    const fn is_relative(&self) -> bool {
+        self.0.is_relative() && self.1.is_relative() && self.2.is_relative()
+    }
+
     fn try_to_absolute(self, origin: Option<Vector3<f64>>) -> Option<Vector3<f64>> {
         Some(Vector3::new(
             self.0.into_absolute(origin.map(|o| o.x))?,
@@ -82,8 +91,15 @@ impl<'a> FindArg<'a> for Position3DArgumentConsumer {
 
     fn find_arg(args: &'a super::ConsumedArgs, name: &str) -> Result<Self::Data, CommandError> {
         match args.get(name) {
-            Some(Arg::Pos3D(data)) => Ok(*data),
+            Some(Arg::Pos3D(data, _)) => Ok(*data),
             _ => Err(CommandError::InvalidConsumption(Some(name.to_string()))),
         }
     }
 }
+
+impl Position3DArgumentConsumer {
+    /// Whether every coordinate of the position was given relative to the sender (`~`).
+    pub fn is_relative(args: &super::ConsumedArgs, name: &str) -> bool {
+        matches!(args.get(name), Some(Arg::Pos3D(_, true)))
+    }
+}
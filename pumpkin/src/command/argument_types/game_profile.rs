@@ -10,8 +10,7 @@ use crate::command::errors::command_syntax_error::CommandSyntaxError;
 use crate::command::errors::error_types::CommandErrorType;
 use crate::command::string_reader::StringReader;
 use crate::command::suggestion::suggestions::{Suggestions, SuggestionsBuilder};
-use crate::net::authentication::lookup_profile_by_name;
-use crate::net::{GameProfile, offline_uuid};
+use crate::net::GameProfile;
 use crate::server::Server;
 use arc_swap::ArcSwap;
 use pumpkin_data::translation;
@@ -61,43 +60,16 @@ impl GameProfileResult {
                     return Ok(vec![player.gameprofile.clone()]);
                 }
 
-                let cached_entry = server.data.user_cache.write().await.get_by_name(name);
-                if let Some(entry) = cached_entry {
-                    return Ok(vec![Self::profile_from_uuid_name(entry.uuid, entry.name)]);
-                }
-
                 if let Some(profile) = Self::resolve_known_profile_by_name(server, name).await {
                     return Ok(vec![profile]);
                 }
 
-                if server.basic_config.online_mode {
-                    return match lookup_profile_by_name(
-                        name,
-                        &server.advanced_config.networking.authentication,
-                    ) {
-                        Ok(Some((uuid, resolved_name))) => {
-                            server
-                                .data
-                                .user_cache
-                                .write()
-                                .await
-                                .upsert(uuid, resolved_name.clone());
-                            Ok(vec![Self::profile_from_uuid_name(uuid, resolved_name)])
-                        }
-                        _ => Err(Self::unknown_player_syntax_error()),
-                    };
-                } else if let Ok(uuid) = offline_uuid(name) {
-                    let profile = Self::profile_from_uuid_name(uuid, name.clone());
-                    server
-                        .data
-                        .user_cache
-                        .write()
-                        .await
-                        .upsert(profile.id, profile.name.clone());
-                    return Ok(vec![profile]);
-                }
-
-                return Err(Self::unknown_player_syntax_error());
+                return server
+                    .profile_resolver
+                    .resolve_by_name(server, name)
+                    .await
+                    .map(|profile| vec![profile])
+                    .ok_or_else(Self::unknown_player_syntax_error);
             }
             Self::Uuid(uuid) => {
                 let server = source.server();
@@ -0,0 +1,186 @@
+use std::{
+    ffi::OsString,
+    io,
+    path::{Path, PathBuf},
+};
+
+use pumpkin_config::CommandAuditConfig;
+use pumpkin_util::text::TextComponent;
+use time::OffsetDateTime;
+use tokio::{
+    fs::{self, File, OpenOptions},
+    io::AsyncWriteExt,
+    sync::Mutex,
+};
+use tracing::warn;
+
+use crate::block::entities::BlockEntity;
+use crate::command::{CommandSender, context::command_source::CommandSource};
+
+/// Appends the commands run by privileged senders to a size-rotated log file.
+#[derive(Default)]
+pub struct CommandAuditLog {
+    file: Mutex<Option<AuditFile>>,
+}
+
+struct AuditFile {
+    file: File,
+    size: u64,
+}
+
+impl CommandAuditLog {
+    /// Records a command about to be run by `source`, if its permission level is high enough to
+    /// be audited, and tells the other operators about it if configured.
+    pub async fn record(&self, source: &CommandSource, config: &CommandAuditConfig, command: &str) {
+        if !config.enabled || source.output.permission_lvl() < config.min_permission_level {
+            return;
+        }
+        // Redstone clocks would flood the log otherwise
+        if !config.include_command_blocks
+            && matches!(
+                source.output,
+                CommandSender::CommandBlock(..) | CommandSender::Block(..)
+            )
+        {
+            return;
+        }
+        let command = command.strip_prefix('/').unwrap_or(command);
+
+        let entry = format_entry(
+            OffsetDateTime::now_utc(),
+            &source.output,
+            &source.name,
+            command,
+        );
+        if let Err(err) = self.append(config, &entry).await {
+            warn!("Failed to write to the command audit log: {err}");
+        }
+
+        if config.notify_ops && source.output.should_broadcast_console_to_ops() {
+            source
+                .send_to_ops(TextComponent::text(format!("Issued command /{command}")))
+                .await;
+        }
+    }
+
+    async fn append(&self, config: &CommandAuditConfig, entry: &str) -> io::Result<()> {
+        let path = Path::new(&config.path);
+        let entry_len = entry.len() as u64 + 1;
+        // Tokio's mutex is fair, so entries are written in the order their commands ran
+        let mut current = self.file.lock().await;
+
+        let mut file = match current.take() {
+            Some(file) if file.size > 0 && file.size + entry_len > config.max_file_size => {
+                drop(file);
+                rotate(path, config.max_files).await?;
+                open(path).await?
+            }
+            Some(file) => file,
+            None => open(path).await?,
+        };
+        file.file.write_all(format!("{entry}\n").as_bytes()).await?;
+        file.file.flush().await?;
+        file.size += entry_len;
+        *current = Some(file);
+        Ok(())
+    }
+}
+
+async fn open(path: &Path) -> io::Result<AuditFile> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).await?;
+    }
+    let file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .await?;
+    let size = file.metadata().await?.len();
+    Ok(AuditFile { file, size })
+}
+
+fn format_entry(time: OffsetDateTime, sender: &CommandSender, name: &str, command: &str) -> String {
+    let format =
+        time::macros::format_description!("[year]-[month]-[day] [hour]:[minute]:[second] UTC");
+    let time = time.format(&format).unwrap_or_default();
+
+    match sender {
+        CommandSender::Player(player) => {
+            format!("[{time}] {name} ({}): /{command}", player.gameprofile.id)
+        }
+        CommandSender::CommandBlock(command_block, world) => {
+            let pos = command_block.get_position();
+            format!(
+                "[{time}] Command block at {} {} {} in {}: /{command}",
+                pos.0.x, pos.0.y, pos.0.z, world.dimension.minecraft_name
+            )
+        }
+        CommandSender::Block(pos, world, _) => format!(
+            "[{time}] Block at {} {} {} in {}: /{command}",
+            pos.0.x, pos.0.y, pos.0.z, world.dimension.minecraft_name
+        ),
+        CommandSender::Console | CommandSender::Rcon(_) | CommandSender::Dummy => {
+            format!("[{time}] {name}: /{command}")
+        }
+    }
+}
+
+/// Returns the path of the `index`th rotated log, e.g. `commands.log.2`.
+fn rotated_path(path: &Path, index: u32) -> PathBuf {
+    let mut name = OsString::from(path.as_os_str());
+    name.push(format!(".{index}"));
+    PathBuf::from(name)
+}
+
+/// Moves the current log to `.1`, shifting older logs up by one and dropping the oldest.
+async fn rotate(path: &Path, max_files: u32) -> io::Result<()> {
+    if max_files == 0 {
+        return fs::remove_file(path).await;
+    }
+
+    let oldest = rotated_path(path, max_files);
+    if fs::try_exists(&oldest).await? {
+        fs::remove_file(oldest).await?;
+    }
+    for index in (1..max_files).rev() {
+        let from = rotated_path(path, index);
+        if fs::try_exists(&from).await? {
+            fs::rename(from, rotated_path(path, index + 1)).await?;
+        }
+    }
+    fs::rename(path, rotated_path(path, 1)).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn console_entry_includes_time_and_command() {
+        let time = OffsetDateTime::from_unix_timestamp(0).unwrap();
+        let entry = format_entry(time, &CommandSender::Console, "Server", "op Steve");
+        assert_eq!(entry, "[1970-01-01 00:00:00 UTC] Server: /op Steve");
+    }
+
+    #[tokio::test]
+    async fn rotation_keeps_the_newest_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("commands.log");
+
+        for content in ["first", "second", "third"] {
+            fs::write(&path, content).await.unwrap();
+            rotate(&path, 2).await.unwrap();
+        }
+
+        assert!(!path.exists());
+        assert_eq!(
+            fs::read_to_string(rotated_path(&path, 1)).await.unwrap(),
+            "third"
+        );
+        assert_eq!(
+            fs::read_to_string(rotated_path(&path, 2)).await.unwrap(),
+            "second"
+        );
+        assert!(!rotated_path(&path, 3).exists());
+    }
+}
@@ -9,8 +9,7 @@ use crate::{
         },
         tree::{CommandTree, builder::argument},
     },
-    data::{SaveJSONConfiguration, banlist_serializer::BannedPlayerEntry},
-    net::{DisconnectReason, GameProfile},
+    net::GameProfile,
 };
 use CommandError::InvalidConsumption;
 use pumpkin_data::translation;
@@ -96,32 +95,15 @@ async fn ban_profile(
     profile: &GameProfile,
     reason: Option<String>,
 ) -> bool {
-    let mut banned_players = server.data.banned_player_list.write().await;
-
     let reason = reason.unwrap_or_else(|| "Banned by an operator.".to_string());
 
-    if let Some(entry) = banned_players
-        .banned_players
-        .iter_mut()
-        .find(|entry| entry.uuid == profile.id)
+    if !server
+        .ban_player(profile, sender.to_string(), reason.clone(), None)
+        .await
     {
-        if entry.name != profile.name {
-            entry.name.clone_from(&profile.name);
-            banned_players.save();
-        }
         return false;
     }
 
-    banned_players.banned_players.push(BannedPlayerEntry::new(
-        profile,
-        sender.to_string(),
-        None,
-        reason.clone(),
-    ));
-
-    banned_players.save();
-    drop(banned_players);
-
     // Send messages
     sender
         .send_message(TextComponent::translate_cross(
@@ -134,19 +116,6 @@ async fn ban_profile(
         ))
         .await;
 
-    if let Some(player) = server.get_player_by_uuid(profile.id) {
-        player
-            .kick(
-                DisconnectReason::Kicked,
-                TextComponent::translate_cross(
-                    translation::java::MULTIPLAYER_DISCONNECT_BANNED,
-                    translation::bedrock::DISCONNECTIONSCREEN_TITLE_BANNEDBYHOST,
-                    [],
-                ),
-            )
-            .await;
-    }
-
     true
 }
 
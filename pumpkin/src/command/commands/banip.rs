@@ -6,8 +6,6 @@ use crate::{
         args::{Arg, ConsumedArgs, message::MsgArgConsumer, simple::SimpleArgConsumer},
         tree::{CommandTree, builder::argument},
     },
-    data::{SaveJSONConfiguration, banlist_serializer::BannedIpEntry},
-    net::DisconnectReason,
     server::Server,
 };
 use CommandError::InvalidConsumption;
@@ -90,9 +88,13 @@ async fn ban_ip(
         )));
     };
 
-    let mut banned_ips = server.data.banned_ip_list.write().await;
+    // Collect the players before they get kicked
+    let affected = server.get_players_by_ip(target_ip).await;
 
-    if banned_ips.get_entry(&target_ip).is_some() {
+    if !server
+        .ban_ip(target_ip, sender.to_string(), reason.clone(), None)
+        .await
+    {
         return Err(CommandError::CommandFailed(TextComponent::translate_cross(
             translation::java::COMMANDS_BANIP_FAILED,
             translation::java::COMMANDS_BANIP_FAILED,
@@ -100,18 +102,7 @@ async fn ban_ip(
         )));
     }
 
-    banned_ips.banned_ips.push(BannedIpEntry::new(
-        target_ip,
-        sender.to_string(),
-        None,
-        reason.clone(),
-    ));
-
-    banned_ips.save();
-    drop(banned_ips);
-
     // Send messages
-    let affected = server.get_players_by_ip(target_ip).await;
     let names = affected
         .iter()
         .map(|p| p.gameprofile.name.clone())
@@ -140,21 +131,7 @@ async fn ban_ip(
         ))
         .await;
 
-    let count = affected.len();
-    for target in affected {
-        target
-            .kick(
-                DisconnectReason::Kicked,
-                TextComponent::translate_cross(
-                    translation::java::MULTIPLAYER_DISCONNECT_IP_BANNED,
-                    translation::java::MULTIPLAYER_DISCONNECT_IP_BANNED,
-                    [],
-                ),
-            )
-            .await;
-    }
-
-    Ok(count as i32)
+    Ok(affected.len() as i32)
 }
 
 pub fn init_command_tree() -> CommandTree {
@@ -1,4 +1,6 @@
+use pumpkin_data::entity::EntityStatus;
 use pumpkin_data::game_rules::{GameRule, GameRuleRegistry, GameRuleValue};
+use pumpkin_protocol::java::client::play::{CEntityStatus, CGameEvent, GameEvent};
 
 use crate::command::args::FindArg;
 use crate::command::args::bool::BoolArgConsumer;
@@ -92,6 +94,48 @@ impl CommandExecutor for SetExecutor {
 
             server.level_info.store(std::sync::Arc::new(new_info));
 
+            // These rules are applied by the clients, so tell them about the new value
+            let level_info = server.level_info.load_full();
+            let game_rules = &level_info.game_rules;
+            match self.0 {
+                GameRule::ImmediateRespawn => {
+                    server.broadcast_packet_all(&CGameEvent::new(
+                        GameEvent::EnabledRespawnScreen,
+                        if game_rules.immediate_respawn {
+                            1.0
+                        } else {
+                            0.0
+                        },
+                    ));
+                }
+                GameRule::LimitedCrafting => {
+                    server.broadcast_packet_all(&CGameEvent::new(
+                        GameEvent::LimitedCrafting,
+                        if game_rules.limited_crafting {
+                            1.0
+                        } else {
+                            0.0
+                        },
+                    ));
+                }
+                GameRule::ReducedDebugInfo => {
+                    let status = if server.basic_config.reduced_debug_info
+                        || game_rules.reduced_debug_info
+                    {
+                        EntityStatus::ReducedDebugInfo
+                    } else {
+                        EntityStatus::FullDebugInfo
+                    };
+                    for player in server.get_all_players() {
+                        player
+                            .client
+                            .enqueue_packet(&CEntityStatus::new(player.entity_id(), status as i8))
+                            .await;
+                    }
+                }
+                _ => {}
+            }
+
             let value_component = TextComponent::text(output_value);
             sender
                 .send_message(TextComponent::translate_cross(
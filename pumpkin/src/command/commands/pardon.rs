@@ -1,13 +1,10 @@
-use crate::{
-    command::{
-        CommandError, CommandExecutor, CommandResult, CommandSender,
-        args::{
-            Arg, ConsumedArgs,
-            gameprofile::{GameProfileSuggestionMode, GameProfilesArgumentConsumer},
-        },
-        tree::{CommandTree, builder::argument},
+use crate::command::{
+    CommandError, CommandExecutor, CommandResult, CommandSender,
+    args::{
+        Arg, ConsumedArgs,
+        gameprofile::{GameProfileSuggestionMode, GameProfilesArgumentConsumer},
     },
-    data::SaveJSONConfiguration,
+    tree::{CommandTree, builder::argument},
 };
 use CommandError::InvalidConsumption;
 use pumpkin_util::text::TextComponent;
@@ -31,17 +28,10 @@ impl CommandExecutor for Executor {
                 return Err(InvalidConsumption(Some(ARG_TARGET.into())));
             };
 
-            let mut lock = server.data.banned_player_list.write().await;
             let mut successes = 0;
 
             for target in targets {
-                let idx = lock
-                    .banned_players
-                    .iter()
-                    .position(|entry| entry.uuid == target.id);
-
-                if let Some(idx) = idx {
-                    lock.banned_players.remove(idx);
+                if server.pardon(&target.id).await {
                     sender
                         .send_message(TextComponent::translate_cross(
                             "commands.pardon.success",
@@ -54,7 +44,6 @@ impl CommandExecutor for Executor {
             }
 
             if successes > 0 {
-                lock.save();
                 Ok(successes)
             } else {
                 Err(CommandError::CommandFailed(TextComponent::translate_cross(
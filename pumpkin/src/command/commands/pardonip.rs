@@ -1,12 +1,9 @@
 use std::{net::IpAddr, str::FromStr};
 
-use crate::{
-    command::{
-        CommandError, CommandExecutor, CommandResult, CommandSender,
-        args::{Arg, ConsumedArgs, simple::SimpleArgConsumer},
-        tree::{CommandTree, builder::argument},
-    },
-    data::SaveJSONConfiguration,
+use crate::command::{
+    CommandError, CommandExecutor, CommandResult, CommandSender,
+    args::{Arg, ConsumedArgs, simple::SimpleArgConsumer},
+    tree::{CommandTree, builder::argument},
 };
 use CommandError::InvalidConsumption;
 use pumpkin_util::text::TextComponent;
@@ -38,11 +35,7 @@ impl CommandExecutor for Executor {
                 )));
             };
 
-            let mut lock = server.data.banned_ip_list.write().await;
-
-            let result = if let Some(idx) = lock.banned_ips.iter().position(|entry| entry.ip == ip)
-            {
-                lock.banned_ips.remove(idx);
+            if server.pardon_ip(&ip).await {
                 sender
                     .send_message(TextComponent::translate_cross(
                         "commands.pardonip.success",
@@ -57,11 +50,7 @@ impl CommandExecutor for Executor {
                     "commands.pardonip.failed",
                     [],
                 )))
-            };
-
-            lock.save();
-
-            result
+            }
         })
     }
 }
@@ -68,6 +68,7 @@ impl CommandExecutor for Executor {
                 CommandSender::CommandBlock(c, w) => {
                     (w.clone(), c.get_position().to_centered_f64())
                 }
+                CommandSender::Block(block_pos, w, _) => (w.clone(), block_pos.to_centered_f64()),
             };
 
             world.spawn_particle(pos, delta, speed, count, &*particle);
@@ -53,7 +53,7 @@ impl CommandExecutor for Executor {
                         .ok_or(CommandError::InvalidRequirement)?
                 }
                 CommandSender::Player(player) => player.world().clone(),
-                CommandSender::CommandBlock(_, w) => w.clone(),
+                CommandSender::CommandBlock(_, w) | CommandSender::Block(_, w, _) => w.clone(),
             };
             let pos = BlockPosArgumentConsumer::find_loaded_arg(args, ARG_BLOCK_POS, &world)?;
 
@@ -1,5 +1,3 @@
-use std::sync::Arc;
-
 use crate::command::CommandResult;
 use crate::command::dispatcher::CommandError::InvalidConsumption;
 use crate::command::{
@@ -140,15 +138,9 @@ async fn setworldspawn(
     new_yaw = event.new_yaw;
     new_pitch = event.new_pitch;
 
-    let mut new_info = (**current_info).clone();
-
-    new_info.spawn_x = new_position.0.x;
-    new_info.spawn_y = new_position.0.y;
-    new_info.spawn_z = new_position.0.z;
-    new_info.spawn_yaw = new_yaw;
-    new_info.spawn_pitch = new_pitch;
-
-    server.level_info.store(Arc::new(new_info));
+    world
+        .set_spawn_point(new_position, new_yaw, new_pitch)
+        .await;
 
     sender
         .send_message(TextComponent::translate_cross(
@@ -9,6 +9,7 @@ use crate::{
         tree::{CommandTree, builder::argument},
     },
     entity::r#type::from_type,
+    plugin::api::events::entity::entity_spawn::EntitySpawnReason,
 };
 use pumpkin_data::translation;
 use pumpkin_util::{math::vector3::Vector3, text::TextComponent};
@@ -64,10 +65,23 @@ impl CommandExecutor for Executor {
                     let pos = pos.unwrap_or(c.get_position().to_centered_f64());
                     (w.clone(), pos)
                 }
+                CommandSender::Block(block_pos, w, _) => {
+                    let pos = pos.unwrap_or(block_pos.to_centered_f64());
+                    (w.clone(), pos)
+                }
             };
             let entity = from_type(entity_type, pos, &world, Uuid::new_v4());
             let name = entity.get_display_name().await;
-            world.spawn_entity(entity).await;
+            if !world
+                .spawn_entity_checked(entity, EntitySpawnReason::Command)
+                .await
+            {
+                return Err(CommandError::CommandFailed(TextComponent::translate_cross(
+                    translation::java::COMMANDS_SUMMON_FAILED,
+                    translation::bedrock::COMMANDS_SUMMON_FAILED,
+                    [],
+                )));
+            }
             sender
                 .send_message(TextComponent::translate_cross(
                     translation::java::COMMANDS_SUMMON_SUCCESS,
@@ -313,10 +313,16 @@ impl CommandExecutor for SelfToPosExecutor {
                             [],
                         )));
                     }
-                    player
-                        .clone()
-                        .teleport(pos, Some(yaw), Some(pitch), player.world().clone())
-                        .await;
+                    if Position3DArgumentConsumer::is_relative(args, ARG_LOCATION) {
+                        // Let the client keep the movement it made since its last report
+                        let delta = pos - player.get_entity().pos.load();
+                        player.teleport_relative(delta, 0.0, 0.0).await;
+                    } else {
+                        player
+                            .clone()
+                            .teleport(pos, Some(yaw), Some(pitch), player.world().clone())
+                            .await;
+                    }
 
                     Ok(1)
                 }
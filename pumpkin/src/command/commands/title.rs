@@ -1,4 +1,3 @@
-use pumpkin_protocol::java::client::play::CClearTitle;
 use pumpkin_util::text::TextComponent;
 
 use crate::command::CommandResult;
@@ -44,7 +43,11 @@ impl CommandExecutor for ClearOrResetExecutor {
             let reset = self.0;
 
             for target in targets {
-                target.client.enqueue_packet(&CClearTitle::new(reset)).await;
+                if reset {
+                    target.reset_title().await;
+                } else {
+                    target.clear_title().await;
+                }
             }
             sender
                 .send_message(if targets.len() == 1 {
@@ -96,7 +99,7 @@ impl CommandExecutor for TitleExecutor {
             let mode = &self.0;
 
             for target in targets {
-                target.show_title(&text, mode).await;
+                target.show_title(&text, mode, false).await;
             }
 
             let mode_name = format!("{mode:?}").to_lowercase();
@@ -140,7 +143,7 @@ impl CommandExecutor for TimesTitleExecutor {
             let fade_out = TimeArgumentConsumer::find_arg(args, ARG_FADE_OUT)?;
 
             for target in targets {
-                target.send_title_animation(fade_in, stay, fade_out).await;
+                target.set_title_times(fade_in, stay, fade_out).await;
             }
 
             sender
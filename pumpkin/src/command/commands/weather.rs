@@ -41,14 +41,10 @@ impl CommandExecutor for Executor {
                     .cloned()
                     .ok_or(CommandError::InvalidRequirement)?
             };
-            let mut weather = world.weather.lock().await;
 
             match self.mode {
                 WeatherMode::Clear => {
-                    let processed_duration =
-                        duration.unwrap_or_else(|| rand::random_range(12_000..=180_000));
-
-                    weather.set_weather_parameters(&world, processed_duration, 0, false, false);
+                    world.set_weather(false, false, duration).await;
                     sender
                         .send_message(TextComponent::translate_cross(
                             translation::java::COMMANDS_WEATHER_SET_CLEAR,
@@ -58,10 +54,7 @@ impl CommandExecutor for Executor {
                         .await;
                 }
                 WeatherMode::Rain => {
-                    let processed_duration =
-                        duration.unwrap_or_else(|| rand::random_range(12_000..=24_000));
-
-                    weather.set_weather_parameters(&world, 0, processed_duration, true, false);
+                    world.set_weather(true, false, duration).await;
                     sender
                         .send_message(TextComponent::translate_cross(
                             translation::java::COMMANDS_WEATHER_SET_RAIN,
@@ -71,10 +64,7 @@ impl CommandExecutor for Executor {
                         .await;
                 }
                 WeatherMode::Thunder => {
-                    let processed_duration =
-                        duration.unwrap_or_else(|| rand::random_range(3_600..=15_600));
-
-                    weather.set_weather_parameters(&world, 0, processed_duration, true, true);
+                    world.set_weather(true, true, duration).await;
                     sender
                         .send_message(TextComponent::translate_cross(
                             translation::java::COMMANDS_WEATHER_SET_THUNDER,
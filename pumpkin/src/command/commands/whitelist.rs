@@ -1,53 +1,26 @@
 use std::sync::atomic::Ordering;
 
-use pumpkin_config::whitelist::WhitelistEntry;
 use pumpkin_data::translation;
 use pumpkin_util::text::TextComponent;
 
 use crate::command::CommandResult;
-use crate::{
-    command::{
-        CommandExecutor, CommandSender,
-        args::{
-            Arg, ConsumedArgs,
-            gameprofile::{GameProfileSuggestionMode, GameProfilesArgumentConsumer},
-        },
-        dispatcher::CommandError,
-        tree::{
-            CommandTree,
-            builder::{argument, literal},
-        },
+use crate::command::{
+    CommandExecutor, CommandSender,
+    args::{
+        Arg, ConsumedArgs,
+        gameprofile::{GameProfileSuggestionMode, GameProfilesArgumentConsumer},
+    },
+    dispatcher::CommandError,
+    tree::{
+        CommandTree,
+        builder::{argument, literal},
     },
-    data::{LoadJSONConfiguration, SaveJSONConfiguration, whitelist::WhitelistConfig},
-    net::DisconnectReason,
-    server::Server,
 };
 
 const NAMES: [&str; 1] = ["whitelist"];
 const DESCRIPTION: &str = "Manage server whitelists.";
 const ARG_TARGETS: &str = "targets";
 
-async fn kick_non_whitelisted_players(server: &Server) {
-    let whitelist = server.data.whitelist_config.read().await;
-    if server.basic_config.enforce_whitelist && server.white_list.load(Ordering::Relaxed) {
-        for player in server.get_all_players() {
-            if whitelist.is_whitelisted(&player.gameprofile) {
-                continue;
-            }
-            player
-                .kick(
-                    DisconnectReason::Kicked,
-                    TextComponent::translate_cross(
-                        translation::java::MULTIPLAYER_DISCONNECT_NOT_WHITELISTED,
-                        translation::java::MULTIPLAYER_DISCONNECT_NOT_WHITELISTED,
-                        &[],
-                    ),
-                )
-                .await;
-        }
-    }
-}
-
 struct OnExecutor;
 
 impl CommandExecutor for OnExecutor {
@@ -66,7 +39,7 @@ impl CommandExecutor for OnExecutor {
                     &[],
                 )))
             } else {
-                kick_non_whitelisted_players(server).await;
+                server.kick_non_whitelisted_players().await;
                 sender
                     .send_message(TextComponent::translate_cross(
                         translation::java::COMMANDS_WHITELIST_ENABLED,
@@ -167,8 +140,7 @@ impl CommandExecutor for ReloadExecutor {
         _args: &'a ConsumedArgs<'a>,
     ) -> CommandResult<'a> {
         Box::pin(async move {
-            *server.data.whitelist_config.write().await = WhitelistConfig::load();
-            kick_non_whitelisted_players(server).await;
+            server.whitelist_reload().await;
             sender
                 .send_message(TextComponent::translate_cross(
                     translation::java::COMMANDS_WHITELIST_RELOADED,
@@ -195,22 +167,11 @@ impl CommandExecutor for AddExecutor {
                 return Err(CommandError::InvalidConsumption(Some(ARG_TARGETS.into())));
             };
 
-            let mut whitelist = server.data.whitelist_config.write().await;
             let mut successes: i32 = 0;
             for profile in targets {
-                if let Some(existing_entry) = whitelist
-                    .whitelist
-                    .iter_mut()
-                    .find(|entry| entry.uuid == profile.id)
-                {
-                    if existing_entry.name != profile.name {
-                        existing_entry.name.clone_from(&profile.name);
-                    }
+                if !server.whitelist_add(profile.id, profile.name.clone()).await {
                     continue;
                 }
-                whitelist
-                    .whitelist
-                    .push(WhitelistEntry::new(profile.id, profile.name.clone()));
                 sender
                     .send_message(TextComponent::translate_cross(
                         translation::java::COMMANDS_WHITELIST_ADD_SUCCESS,
@@ -221,8 +182,6 @@ impl CommandExecutor for AddExecutor {
                 successes += 1;
             }
 
-            whitelist.save();
-
             if successes == 0 {
                 Err(CommandError::CommandFailed(TextComponent::translate_cross(
                     translation::java::COMMANDS_WHITELIST_ADD_FAILED,
@@ -250,16 +209,9 @@ impl CommandExecutor for RemoveExecutor {
                 return Err(CommandError::InvalidConsumption(Some(ARG_TARGETS.into())));
             };
 
-            let mut whitelist = server.data.whitelist_config.write().await;
             let mut successes: i32 = 0;
             for player in targets {
-                let i = whitelist
-                    .whitelist
-                    .iter()
-                    .position(|entry| entry.uuid == player.id);
-
-                if let Some(i) = i {
-                    whitelist.whitelist.remove(i);
+                if server.whitelist_remove(&player.id).await {
                     sender
                         .send_message(TextComponent::translate_cross(
                             translation::java::COMMANDS_WHITELIST_REMOVE_SUCCESS,
@@ -271,11 +223,6 @@ impl CommandExecutor for RemoveExecutor {
                 }
             }
 
-            whitelist.save();
-            drop(whitelist);
-
-            kick_non_whitelisted_players(server).await;
-
             if successes == 0 {
                 Err(CommandError::CommandFailed(TextComponent::translate_cross(
                     translation::java::COMMANDS_WHITELIST_REMOVE_FAILED,
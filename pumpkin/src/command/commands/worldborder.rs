@@ -57,7 +57,7 @@ impl CommandExecutor for GetExecutor {
                 .expect("There should always be at least one world");
             let border = world.worldborder.lock().await;
 
-            let diameter = border.new_diameter.round() as i32;
+            let diameter = border.diameter.round() as i32;
             sender
                 .send_message(TextComponent::translate_cross(
                     "commands.worldborder.get",
@@ -225,7 +225,7 @@ impl CommandExecutor for AddExecutor {
                 )));
             }
 
-            let distance = border.new_diameter + distance_add;
+            let distance = border.diameter + distance_add;
 
             let dist = format!("{distance:.1}");
             sender
@@ -271,9 +271,9 @@ impl CommandExecutor for AddTimeExecutor {
                 ))));
             };
 
-            let distance = distance_add + border.new_diameter;
+            let distance = distance_add + border.diameter;
 
-            match distance.total_cmp(&border.new_diameter) {
+            match distance.total_cmp(&border.diameter) {
                 std::cmp::Ordering::Equal => {
                     return Err(CommandError::CommandFailed(TextComponent::text(format!(
                         "{} is out of bounds.",
@@ -410,7 +410,7 @@ impl CommandSource {
     }
 
     /// Sends a message to all online operators.
-    async fn send_to_ops(&self, message: TextComponent) {
+    pub async fn send_to_ops(&self, message: TextComponent) {
         let text = TextComponent::translate_cross(
             "chat.type.admin",
             "chat.type.admin",
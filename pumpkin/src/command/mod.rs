@@ -2,6 +2,7 @@ use std::fmt;
 use std::pin::Pin;
 use std::str::FromStr;
 use std::sync::Arc;
+use std::sync::atomic::{AtomicU32, Ordering};
 
 use crate::entity::player::Player;
 use crate::server::Server;
@@ -18,6 +19,7 @@ use pumpkin_data::{
     block_properties::{BlockProperties, CommandBlockLikeProperties, Facing},
     dimension::Dimension,
 };
+use pumpkin_util::math::position::BlockPos;
 use pumpkin_util::math::vector2::Vector2;
 use pumpkin_util::math::vector3::Vector3;
 use pumpkin_util::permission::{PermissionDefault, PermissionLvl};
@@ -27,6 +29,7 @@ use pumpkin_util::translation::Locale;
 pub mod args;
 pub mod argument_builder;
 pub mod argument_types;
+pub mod audit;
 pub mod client_suggestions;
 pub mod commands;
 pub mod context;
@@ -66,6 +69,11 @@ pub enum CommandSender {
     /// Contains the block entity responsible for the command and the
     /// world context it exists in for coordinate-relative execution (e.g., `~ ~ ~`).
     CommandBlock(Arc<CommandBlockEntity>, Arc<World>),
+    /// A virtual sender at a block position, such as one a plugin runs commands as.
+    ///
+    /// It has the permissions of a `CommandBlock` and writes everything it
+    /// receives to its [`CommandOutput`] instead of a block entity.
+    Block(BlockPos, Arc<World>, Arc<CommandOutput>),
     /// Nothingness. Anything sent to this sender is void.
     /// Has the same permissions as that of `CommandBlock`.
     Dummy,
@@ -80,7 +88,7 @@ impl fmt::Display for CommandSender {
                 Self::Console => "Server",
                 Self::Rcon(_) => "Rcon",
                 Self::Player(p) => &p.gameprofile.name,
-                Self::CommandBlock(..) => "@",
+                Self::CommandBlock(..) | Self::Block(..) => "@",
                 Self::Dummy => "",
             }
         )
@@ -105,17 +113,26 @@ impl CommandSender {
 
                 *last_output = format!("[{}] {}", timestamp, text.get_text());
             }
+            Self::Block(_, _, output) => output.messages.lock().await.push(text),
             Self::Dummy => {}
         }
     }
 
     pub fn set_success_count(&self, count: u32) {
-        if let Self::CommandBlock(c, _) = self {
-            c.success_count
-                .store(count, std::sync::atomic::Ordering::SeqCst);
+        match self {
+            Self::CommandBlock(c, _) => c.success_count.store(count, Ordering::SeqCst),
+            Self::Block(_, _, output) => output.success_count.store(count, Ordering::SeqCst),
+            _ => {}
         }
     }
 
+    /// Creates a [`Self::Block`] sender at `pos` in `world`, along with the output it captures.
+    #[must_use]
+    pub fn at_block(pos: BlockPos, world: Arc<World>) -> (Self, Arc<CommandOutput>) {
+        let output = Arc::new(CommandOutput::default());
+        (Self::Block(pos, world, output.clone()), output)
+    }
+
     #[must_use]
     pub const fn is_player(&self) -> bool {
         matches!(self, Self::Player(_))
@@ -139,7 +156,7 @@ impl CommandSender {
         match self {
             Self::Console | Self::Rcon(_) => PermissionLvl::Four,
             Self::Player(p) => p.permission_lvl.load(),
-            Self::CommandBlock(..) | Self::Dummy => PermissionLvl::Two,
+            Self::CommandBlock(..) | Self::Block(..) | Self::Dummy => PermissionLvl::Two,
         }
     }
 
@@ -148,7 +165,7 @@ impl CommandSender {
         match self {
             Self::Console | Self::Rcon(_) => true,
             Self::Player(p) => p.permission_lvl.load().ge(&lvl),
-            Self::CommandBlock(..) | Self::Dummy => PermissionLvl::Two >= lvl,
+            Self::CommandBlock(..) | Self::Block(..) | Self::Dummy => PermissionLvl::Two >= lvl,
         }
     }
 
@@ -157,7 +174,7 @@ impl CommandSender {
         match self {
             Self::Console | Self::Rcon(_) => true, // Console and RCON always have all permissions
             Self::Player(p) => p.has_permission(server, node).await,
-            Self::CommandBlock(..) | Self::Dummy => {
+            Self::CommandBlock(..) | Self::Block(..) | Self::Dummy => {
                 let perm_reg = server.permission_registry.read().await;
                 let Some(p) = perm_reg.get_permission(node) else {
                     return false;
@@ -177,6 +194,7 @@ impl CommandSender {
             Self::Console | Self::Rcon(..) | Self::Dummy => None,
             Self::Player(p) => Some(p.living_entity.entity.pos.load()),
             Self::CommandBlock(c, _) => Some(c.get_position().to_centered_f64()),
+            Self::Block(pos, ..) => Some(pos.to_centered_f64()),
         }
     }
 
@@ -185,6 +203,7 @@ impl CommandSender {
         match self {
             Self::Console | Self::Rcon(..) | Self::Dummy => None,
             Self::Player(player) => Some(player.rotation()),
+            Self::Block(..) => Some((0.0, 0.0)),
             Self::CommandBlock(command_block, world) => {
                 let pos = command_block.get_position();
                 let (chunk_coordinate, relative) = pos.chunk_and_chunk_relative_position();
@@ -212,14 +231,18 @@ impl CommandSender {
             // TODO: maybe return first world when console
             Self::Console | Self::Rcon(..) | Self::Dummy => None,
             Self::Player(p) => Some(p.living_entity.entity.world.load_full()),
-            Self::CommandBlock(_, w) => Some(w.clone()),
+            Self::CommandBlock(_, w) | Self::Block(_, w, _) => Some(w.clone()),
         }
     }
 
     #[must_use]
     pub fn get_locale(&self) -> Locale {
         match self {
-            Self::CommandBlock(..) | Self::Console | Self::Rcon(..) | Self::Dummy => Locale::EnUs, // Default locale for console and RCON
+            Self::CommandBlock(..)
+            | Self::Block(..)
+            | Self::Console
+            | Self::Rcon(..)
+            | Self::Dummy => Locale::EnUs, // Default locale for console and RCON
             Self::Player(player) => {
                 Locale::from_str(&player.config.load().locale).unwrap_or(Locale::EnUs)
             }
@@ -240,7 +263,7 @@ impl CommandSender {
                     .game_rules
                     .send_command_feedback
             }
-            Self::Console | Self::Rcon(_) => true,
+            Self::Console | Self::Rcon(_) | Self::Block(..) => true,
             Self::Dummy => false,
         }
     }
@@ -248,7 +271,9 @@ impl CommandSender {
     #[must_use]
     pub fn should_broadcast_console_to_ops(&self) -> bool {
         match self {
-            Self::CommandBlock(_, world) => world.level_info.load().game_rules.command_block_output,
+            Self::CommandBlock(_, world) | Self::Block(_, world, _) => {
+                world.level_info.load().game_rules.command_block_output
+            }
             // TODO: should Console and Rcon be decided by server config?
             Self::Player(..) | Self::Console | Self::Rcon(_) => true,
             Self::Dummy => false,
@@ -259,7 +284,11 @@ impl CommandSender {
     pub const fn should_track_output(&self) -> bool {
         match self {
             Self::Dummy => false,
-            Self::Player(..) | Self::Console | Self::Rcon(_) | Self::CommandBlock(..) => true,
+            Self::Player(..)
+            | Self::Console
+            | Self::Rcon(_)
+            | Self::CommandBlock(..)
+            | Self::Block(..) => true,
         }
     }
 
@@ -331,6 +360,19 @@ impl CommandSender {
                     server.clone(),
                 )
             }
+            Self::Block(pos, world, output) => {
+                let name = TextComponent::text("@");
+                CommandSource::new(
+                    Self::Block(pos, world.clone(), output),
+                    world,
+                    None,
+                    pos.to_centered_f64(),
+                    Vector2::new(0.0, 0.0),
+                    name.clone().get_text(),
+                    name,
+                    server.clone(),
+                )
+            }
             Self::Dummy => {
                 let (world, spawn_point) = Self::get_world_and_spawn_point(server);
                 CommandSource::new(
@@ -359,6 +401,22 @@ impl CommandSender {
     }
 }
 
+/// What a [`CommandSender::Block`] captured while running commands.
+#[derive(Default)]
+pub struct CommandOutput {
+    /// Every message sent to the sender, in order.
+    pub messages: tokio::sync::Mutex<Vec<TextComponent>>,
+    /// The result of the last command, like a command block's success count.
+    pub success_count: AtomicU32,
+}
+
+impl CommandOutput {
+    #[must_use]
+    pub fn success_count(&self) -> u32 {
+        self.success_count.load(Ordering::SeqCst)
+    }
+}
+
 const fn command_block_y_rot(facing: Facing) -> f32 {
     match facing {
         Facing::North => 180.0,
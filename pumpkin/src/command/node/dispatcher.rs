@@ -386,19 +386,30 @@ impl CommandDispatcher {
             input = sliced;
         }
 
-        let output = self.execute_input(input, source).await;
+        let server = source.server();
+        server
+            .command_audit_log
+            .record(source, &server.advanced_config.commands.audit, input)
+            .await;
 
-        if let Err(error) = output {
+        match self.execute_input(input, source).await {
+            // Command blocks keep this for comparators and conditional chains.
+            Ok(result) => source
+                .output
+                .set_success_count(u32::try_from(result).unwrap_or(0)),
             // We check if the error came because a command could not be found.
             // Note: 'Permission denied' also falls under this error as
             //       no executable node could be found.
-            if error.is(&DISPATCHER_UNKNOWN_COMMAND) {
+            Err(error) if error.is(&DISPATCHER_UNKNOWN_COMMAND) => {
                 // Run the fallback dispatcher instead.
                 // It might have the command we're looking for.
+                // It stores the success count itself.
                 self.fallback_dispatcher
                     .handle_command(&source.output, source.server().as_ref(), input)
                     .await;
-            } else {
+            }
+            Err(error) => {
+                source.output.set_success_count(0);
                 // Print the error to the output.
                 Self::send_error_to_source(source, error, input).await;
             }
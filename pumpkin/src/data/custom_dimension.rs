@@ -0,0 +1,335 @@
+use std::fs;
+use std::io::Cursor;
+use std::path::{Path, PathBuf};
+
+use pumpkin_data::dimension::Dimension;
+use pumpkin_nbt::{Nbt, deserializer::NbtReadHelperJava};
+use pumpkin_protocol::java::client::config::RegistryEntry;
+use serde::Deserialize;
+use tracing::warn;
+
+const DATAPACKS_FOLDER: &str = "datapacks";
+
+/// The vanilla dimension types take the registry ids `0..=3`, so custom ones follow them.
+const FIRST_CUSTOM_TYPE_ID: u8 = 4;
+
+/// A dimension type defined by a data pack, either in
+/// `<world>/datapacks/<pack>/data/<namespace>/dimension_type/<name>.json` or inline in a
+/// dimension. It is sent to clients as an additional `minecraft:dimension_type` registry entry.
+#[derive(Debug, Clone)]
+pub struct CustomDimensionType {
+    /// The type's properties, with `minecraft_name` being the type's registry name.
+    pub dimension_type: Dimension,
+}
+
+/// A dimension defined by a data pack in `<world>/datapacks/<pack>/data/<namespace>/dimension/<name>.json`.
+///
+/// Its type is a vanilla dimension type, a [`CustomDimensionType`] or defined inline.
+#[derive(Debug, Clone)]
+pub struct CustomDimension {
+    pub dimension: Dimension,
+}
+
+/// Everything the data packs of a world define about dimensions.
+#[derive(Debug, Default)]
+pub struct DataPackDimensions {
+    /// Sorted by their registry id.
+    pub types: Vec<CustomDimensionType>,
+    pub dimensions: Vec<CustomDimension>,
+}
+
+#[derive(Deserialize)]
+struct DimensionFile {
+    #[serde(rename = "type")]
+    dimension_type: DimensionTypeReference,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum DimensionTypeReference {
+    Named(String),
+    Inline(DimensionTypeFile),
+}
+
+/// The fields of a dimension type we support. Missing ones are taken from the overworld.
+#[derive(Deserialize)]
+struct DimensionTypeFile {
+    min_y: Option<i32>,
+    height: Option<i32>,
+    logical_height: Option<i32>,
+    ambient_light: Option<f32>,
+    coordinate_scale: Option<f64>,
+    has_skylight: Option<bool>,
+    has_ceiling: Option<bool>,
+}
+
+impl DimensionTypeFile {
+    /// Builds the dimension type called `name` with the registry id following `types`.
+    fn build(self, name: String, types: &[CustomDimensionType]) -> Result<Dimension, String> {
+        let id = u8::try_from(types.len())
+            .ok()
+            .and_then(|index| FIRST_CUSTOM_TYPE_ID.checked_add(index))
+            .ok_or("too many custom dimension types")?;
+        let overworld = Dimension::OVERWORLD;
+        let height = self.height.unwrap_or(overworld.height);
+        let dimension = Dimension {
+            id,
+            // Only loaded once on startup, so leaking the name is fine
+            minecraft_name: Box::leak(name.into_boxed_str()),
+            min_y: self.min_y.unwrap_or(overworld.min_y),
+            height,
+            logical_height: self.logical_height.unwrap_or(height),
+            ambient_light: self.ambient_light.unwrap_or(overworld.ambient_light),
+            coordinate_scale: self.coordinate_scale.unwrap_or(overworld.coordinate_scale),
+            has_skylight: self.has_skylight.unwrap_or(overworld.has_skylight),
+            has_ceiling: self.has_ceiling.unwrap_or(overworld.has_ceiling),
+            ..overworld
+        };
+        // The same limits vanilla enforces
+        if dimension.min_y % 16 != 0 || dimension.height % 16 != 0 {
+            return Err("min_y and height must be multiples of 16".to_string());
+        }
+        if dimension.height < 16
+            || dimension.min_y < -2032
+            || dimension.min_y + dimension.height > 2032
+        {
+            return Err("the dimension must lie between y -2032 and 2031".to_string());
+        }
+        if dimension.logical_height > dimension.height {
+            return Err("logical_height can't be greater than height".to_string());
+        }
+        // Proto chunks store their bottom as an `i8`
+        if i8::try_from(dimension.min_y).is_err() {
+            return Err("min_y must lie between -128 and 112".to_string());
+        }
+        Ok(dimension)
+    }
+}
+
+impl CustomDimensionType {
+    fn parse(name: String, json: &str, types: &[Self]) -> Result<Self, String> {
+        let file: DimensionTypeFile = serde_json::from_str(json).map_err(|err| err.to_string())?;
+        Ok(Self {
+            dimension_type: file.build(name, types)?,
+        })
+    }
+
+    /// Builds the `minecraft:dimension_type` registry entry of this type.
+    ///
+    /// The entry is based on the overworld's entry of the client's version, so it contains every
+    /// field that version expects.
+    #[must_use]
+    pub fn registry_entry(&self, overworld_entry: &[u8]) -> Option<RegistryEntry> {
+        let mut nbt =
+            Nbt::read_unnamed(&mut NbtReadHelperJava::new(Cursor::new(overworld_entry))).ok()?;
+        let dimension_type = &self.dimension_type;
+        nbt.root_tag.put_int("min_y", dimension_type.min_y);
+        nbt.root_tag.put_int("height", dimension_type.height);
+        nbt.root_tag
+            .put_int("logical_height", dimension_type.logical_height);
+        nbt.root_tag
+            .put_float("ambient_light", dimension_type.ambient_light);
+        nbt.root_tag
+            .put_double("coordinate_scale", dimension_type.coordinate_scale);
+        nbt.root_tag
+            .put_bool("has_skylight", dimension_type.has_skylight);
+        nbt.root_tag
+            .put_bool("has_ceiling", dimension_type.has_ceiling);
+        Some(RegistryEntry::new(
+            dimension_type.minecraft_name.to_string(),
+            Some(nbt.write_unnamed().to_vec().into_boxed_slice()),
+        ))
+    }
+}
+
+impl CustomDimension {
+    /// Parses the dimension called `name`. Its type is looked up in `types`, and an inline type
+    /// is added to them.
+    fn parse(
+        name: String,
+        json: &str,
+        types: &mut Vec<CustomDimensionType>,
+    ) -> Result<Self, String> {
+        let file: DimensionFile = serde_json::from_str(json).map_err(|err| err.to_string())?;
+        let mut dimension = match file.dimension_type {
+            DimensionTypeReference::Named(type_name) => Dimension::from_name(&type_name)
+                .or_else(|| {
+                    types
+                        .iter()
+                        .map(|custom| &custom.dimension_type)
+                        .find(|dimension_type| dimension_type.minecraft_name == type_name)
+                })
+                .ok_or_else(|| format!("unknown dimension type {type_name}"))?
+                .clone(),
+            DimensionTypeReference::Inline(dimension_type) => {
+                // Inline types are registered under the name of their dimension
+                let dimension_type = dimension_type.build(name.clone(), types)?;
+                types.push(CustomDimensionType {
+                    dimension_type: dimension_type.clone(),
+                });
+                dimension_type
+            }
+        };
+        // Only loaded once on startup, so leaking the name is fine
+        dimension.minecraft_name = Box::leak(name.into_boxed_str());
+        Ok(Self { dimension })
+    }
+}
+
+/// Finds the `<namespace>:<name>` of every JSON file in the `folder` of each data pack's
+/// namespaces, sorted by name. A name defined by multiple data packs is only kept once.
+fn find_data_pack_files(world_path: &Path, folder: &str) -> Vec<(String, PathBuf)> {
+    let mut files = Vec::new();
+    let Ok(packs) = fs::read_dir(world_path.join(DATAPACKS_FOLDER)) else {
+        return files;
+    };
+    for pack in packs.flatten() {
+        let Ok(namespaces) = fs::read_dir(pack.path().join("data")) else {
+            continue;
+        };
+        for namespace in namespaces.flatten() {
+            let Ok(entries) = fs::read_dir(namespace.path().join(folder)) else {
+                continue;
+            };
+            for entry in entries.flatten() {
+                let path = entry.path();
+                if path
+                    .extension()
+                    .is_some_and(|extension| extension == "json")
+                    && let Some(file_name) = path.file_stem().and_then(|stem| stem.to_str())
+                {
+                    let name = format!("{}:{file_name}", namespace.file_name().to_string_lossy());
+                    files.push((name, path));
+                }
+            }
+        }
+    }
+    files.sort();
+    files.dedup_by(|(a, _), (b, _)| a == b);
+    files
+}
+
+/// Loads the dimension types and dimensions of all data packs in the world's `datapacks`
+/// folder. Types are loaded first, so dimensions can reference them by name.
+///
+/// Invalid entries and ones which would replace a vanilla dimension or type are skipped.
+#[must_use]
+pub fn load_custom_dimensions(world_path: &Path) -> DataPackDimensions {
+    let mut loaded = DataPackDimensions::default();
+
+    for (name, path) in find_data_pack_files(world_path, "dimension_type") {
+        if Dimension::from_name(&name).is_some() {
+            warn!(
+                "Ignoring data pack dimension type {name}, replacing vanilla dimension types is not supported"
+            );
+            continue;
+        }
+        let parsed = fs::read_to_string(&path)
+            .map_err(|err| err.to_string())
+            .and_then(|json| CustomDimensionType::parse(name.clone(), &json, &loaded.types));
+        match parsed {
+            Ok(dimension_type) => loaded.types.push(dimension_type),
+            Err(err) => warn!(
+                "Failed to load dimension type {name} from {}: {err}",
+                path.display()
+            ),
+        }
+    }
+
+    for (name, path) in find_data_pack_files(world_path, "dimension") {
+        if Dimension::from_name(&name).is_some() {
+            warn!(
+                "Ignoring data pack dimension {name}, replacing vanilla dimensions is not supported"
+            );
+            continue;
+        }
+        let parsed = fs::read_to_string(&path)
+            .map_err(|err| err.to_string())
+            .and_then(|json| CustomDimension::parse(name.clone(), &json, &mut loaded.types));
+        match parsed {
+            Ok(custom_dimension) => loaded.dimensions.push(custom_dimension),
+            Err(err) => warn!(
+                "Failed to load dimension {name} from {}: {err}",
+                path.display()
+            ),
+        }
+    }
+    loaded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn inline_types_get_new_ids() {
+        let mut types = Vec::new();
+        let json = r#"{"type": {"min_y": 0, "height": 256, "ambient_light": 0.5, "coordinate_scale": 8.0}, "generator": {}}"#;
+        let custom = CustomDimension::parse("test:flat".to_string(), json, &mut types)
+            .expect("valid dimension");
+
+        assert_eq!(custom.dimension.id, FIRST_CUSTOM_TYPE_ID);
+        assert_eq!(custom.dimension.minecraft_name, "test:flat");
+        assert_eq!(custom.dimension.height, 256);
+        assert_eq!(custom.dimension.logical_height, 256);
+        assert!((custom.dimension.ambient_light - 0.5).abs() < f32::EPSILON);
+        assert_eq!(types.len(), 1);
+        assert_eq!(types[0].dimension_type.minecraft_name, "test:flat");
+    }
+
+    #[test]
+    fn vanilla_types_are_reused() {
+        let mut types = Vec::new();
+        let json = r#"{"type": "minecraft:the_nether", "generator": {}}"#;
+        let custom = CustomDimension::parse("test:hell".to_string(), json, &mut types)
+            .expect("valid dimension");
+
+        assert_eq!(custom.dimension.id, Dimension::THE_NETHER.id);
+        assert_eq!(custom.dimension.minecraft_name, "test:hell");
+        assert!(types.is_empty());
+    }
+
+    #[test]
+    fn named_custom_types_are_resolved() {
+        let mut types = vec![
+            CustomDimensionType::parse(
+                "test:tall".to_string(),
+                r#"{"min_y": -128, "height": 512}"#,
+                &[],
+            )
+            .expect("valid dimension type"),
+        ];
+        let json = r#"{"type": "test:tall", "generator": {}}"#;
+        let custom = CustomDimension::parse("test:world".to_string(), json, &mut types)
+            .expect("valid dimension");
+
+        assert_eq!(custom.dimension.id, FIRST_CUSTOM_TYPE_ID);
+        assert_eq!(custom.dimension.minecraft_name, "test:world");
+        assert_eq!(custom.dimension.min_y, -128);
+        assert_eq!(custom.dimension.height, 512);
+        assert_eq!(types.len(), 1);
+    }
+
+    #[test]
+    fn unknown_types_are_rejected() {
+        let mut types = Vec::new();
+        let json = r#"{"type": "test:missing"}"#;
+        assert!(CustomDimension::parse("test:world".to_string(), json, &mut types).is_err());
+    }
+
+    #[test]
+    fn min_y_outside_chunk_range_is_rejected() {
+        let mut types = Vec::new();
+        let json = r#"{"type": {"min_y": -256, "height": 512}}"#;
+        assert!(CustomDimension::parse("test:deep".to_string(), json, &mut types).is_err());
+        assert!(types.is_empty());
+    }
+
+    #[test]
+    fn unaligned_heights_are_rejected() {
+        let mut types = Vec::new();
+        let json = r#"{"type": {"height": 100}}"#;
+        assert!(CustomDimension::parse("test:odd".to_string(), json, &mut types).is_err());
+        assert!(types.is_empty());
+    }
+}
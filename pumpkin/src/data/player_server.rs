@@ -109,9 +109,15 @@ impl ServerPlayerData {
 
     /// Saves all players' data immediately.
     ///
-    /// This function immediately saves all online players' data to disk.
-    /// Useful for server shutdown or backup operations.
-    pub async fn save_all_players(&self, server: &Server) -> Result<(), PlayerDataError> {
+    /// This function immediately saves all online players' data to disk and
+    /// restarts the periodic save interval. Useful for server shutdown, autosaves
+    /// or backup operations.
+    ///
+    /// # Returns
+    ///
+    /// The number of players that were saved.
+    pub async fn save_all_players(&self, server: &Server) -> Result<usize, PlayerDataError> {
+        self.last_save.store(Instant::now());
         let mut total_players = 0;
 
         // Save players from all worlds
@@ -123,7 +129,7 @@ impl ServerPlayerData {
         }
 
         debug!("Saved data for {total_players} online players");
-        Ok(())
+        Ok(total_players)
     }
 
     /// Loads player data and applies it to a player.
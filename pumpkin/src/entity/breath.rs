@@ -1,6 +1,8 @@
 use crate::entity::EntityBase;
 use crate::entity::player::Player;
+use pumpkin_data::Enchantment;
 use pumpkin_data::damage::DamageType;
+use pumpkin_data::data_component_impl::EquipmentSlot;
 use pumpkin_data::effect::StatusEffect;
 use pumpkin_data::meta_data_type::MetaDataType;
 use pumpkin_data::tag;
@@ -10,6 +12,7 @@ use pumpkin_protocol::codec::var_int::VarInt;
 use pumpkin_protocol::java::client::play::Metadata;
 use pumpkin_util::GameMode;
 use pumpkin_util::math::position::BlockPos;
+use rand::RngExt;
 use std::sync::Arc;
 use std::sync::atomic::{AtomicI32, Ordering};
 
@@ -54,6 +57,10 @@ impl BreathManager {
             .living_entity
             .has_effect(&StatusEffect::WATER_BREATHING)
             .await
+            || player
+                .living_entity
+                .has_effect(&StatusEffect::CONDUIT_POWER)
+                .await
         {
             if self.air_supply.swap(MAX_AIR, Ordering::Relaxed) != MAX_AIR {
                 self.send_air_supply(player);
@@ -65,6 +72,10 @@ impl BreathManager {
         let in_water = Self::is_eye_in_water(player);
 
         if in_water {
+            if Self::keeps_breath(player).await {
+                return;
+            }
+
             let prev = self
                 .air_supply
                 .fetch_sub(AIR_DEPLETION_RATE, Ordering::Relaxed);
@@ -96,7 +107,18 @@ impl BreathManager {
         }
     }
 
-    fn is_eye_in_water(player: &Player) -> bool {
+    /// Respiration gives a `level / (level + 1)` chance to not lose air this tick.
+    async fn keeps_breath(player: &Player) -> bool {
+        let respiration = {
+            let equipment = player.living_entity.entity_equipment.lock().await;
+            let helmet = equipment.get(&EquipmentSlot::HEAD);
+            let helmet_stack = helmet.lock().await;
+            helmet_stack.get_enchantment_level(&Enchantment::RESPIRATION)
+        };
+        respiration > 0 && rand::rng().random_range(0..=respiration) > 0
+    }
+
+    pub(crate) fn is_eye_in_water(player: &Player) -> bool {
         let e = &player.get_entity();
         let pos = e.pos.load();
         let eye_y = e.get_eye_y();
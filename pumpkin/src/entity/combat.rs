@@ -8,7 +8,7 @@ use pumpkin_data::{
 use pumpkin_util::math::vector3::Vector3;
 
 use crate::{
-    entity::{Entity, player::Player},
+    entity::{Entity, living::LivingEntity, player::Player},
     world::World,
 };
 
@@ -61,13 +61,18 @@ impl AttackType {
     }
 }
 
-pub fn handle_knockback(attacker: &Entity, victim: &Entity, strength: f64) {
+/// Knocks `victim` away from `attacker`, honouring the victim's knockback resistance.
+/// The resulting velocity is clamped against solid blocks before it is sent, so the victim
+/// is never pushed into a wall.
+pub async fn handle_knockback(attacker: &Entity, victim: &LivingEntity, strength: f64) {
     let yaw = attacker.yaw.load();
-    victim.knockback(
+    victim.take_knockback(
         strength * 0.5,
         f64::from((yaw.to_radians()).sin()),
         f64::from(-(yaw.to_radians()).cos()),
     );
+    victim.entity.clamp_velocity_to_blocks(victim).await;
+    victim.entity.send_velocity();
 
     let velocity = attacker.velocity.load();
     attacker.velocity.store(velocity.multiply(0.6, 1.0, 0.6));
@@ -91,28 +96,13 @@ pub fn spawn_sweep_particle(attacker_entity: &Entity, world: &World, pos: &Vecto
 }
 
 pub async fn player_attack_sound(pos: &Vector3<f64>, world: &World, attack_type: AttackType) {
-    match attack_type {
-        AttackType::Knockback => {
-            world.play_sound(
-                Sound::EntityPlayerAttackKnockback,
-                SoundCategory::Players,
-                pos,
-            );
-        }
-        AttackType::Critical => {
-            world.play_sound(Sound::EntityPlayerAttackCrit, SoundCategory::Players, pos);
-        }
-        AttackType::Sweeping => {
-            world.play_sound(Sound::EntityPlayerAttackSweep, SoundCategory::Players, pos);
-        }
-        AttackType::Strong => {
-            world.play_sound(Sound::EntityPlayerAttackStrong, SoundCategory::Players, pos);
-        }
-        AttackType::Weak => {
-            world.play_sound(Sound::EntityPlayerAttackWeak, SoundCategory::Players, pos);
-        }
-        AttackType::MaceSmash => {
-            world.play_sound(Sound::ItemMaceSmashAir, SoundCategory::Players, pos);
-        }
-    }
+    let sound = match attack_type {
+        AttackType::Knockback => Sound::EntityPlayerAttackKnockback,
+        AttackType::Critical => Sound::EntityPlayerAttackCrit,
+        AttackType::Sweeping => Sound::EntityPlayerAttackSweep,
+        AttackType::Strong => Sound::EntityPlayerAttackStrong,
+        AttackType::Weak => Sound::EntityPlayerAttackWeak,
+        AttackType::MaceSmash => Sound::ItemMaceSmashAir,
+    };
+    world.play_sound_broadcast(sound, SoundCategory::Players, pos, 1.0, 1.0, None);
 }
@@ -7,7 +7,10 @@ use std::sync::{
 use pumpkin_data::entity::EntityType;
 use pumpkin_util::math::vector3::Vector3;
 
-use crate::{entity::EntityBaseFuture, server::Server, world::World};
+use crate::{
+    entity::EntityBaseFuture, plugin::api::events::entity::entity_spawn::EntitySpawnReason,
+    server::Server, world::World,
+};
 
 use super::{Entity, EntityBase, NBTStorage, living::LivingEntity, player::Player};
 
@@ -34,7 +37,9 @@ impl ExperienceOrbEntity {
             amount -= i;
             let entity = Entity::new(world.clone(), position, &EntityType::EXPERIENCE_ORB);
             let orb = Arc::new(Self::new(entity, i));
-            world.spawn_entity(orb).await;
+            world
+                .spawn_entity_checked(orb, EntitySpawnReason::Drop)
+                .await;
         }
     }
 
@@ -63,6 +68,14 @@ impl ExperienceOrbEntity {
             1
         }
     }
+
+    /// Ages the orb and removes it once it is 5 minutes old.
+    async fn tick_age(&self) {
+        let age = self.orb_age.fetch_add(1, Ordering::Relaxed);
+        if age >= 6000 {
+            self.entity.remove().await;
+        }
+    }
 }
 
 impl NBTStorage for ExperienceOrbEntity {}
@@ -98,13 +111,18 @@ impl EntityBase for ExperienceOrbEntity {
 
             entity.tick_block_collisions(caller, server).await;
 
-            let age = self.orb_age.fetch_add(1, Ordering::Relaxed);
-            if age >= 6000 {
-                self.entity.remove().await;
-            }
+            self.tick_age().await;
         })
     }
 
+    fn inactive_tick<'a>(
+        &'a self,
+        _caller: &'a Arc<dyn EntityBase>,
+        _server: &'a Server,
+    ) -> EntityBaseFuture<'a, ()> {
+        Box::pin(self.tick_age())
+    }
+
     fn get_entity(&self) -> &Entity {
         &self.entity
     }
@@ -11,6 +11,16 @@ const MAX_FOOD: u8 = 20;
 const EXHAUSTION_COST: f32 = 4.0;
 const MAX_EXHAUSTION: f32 = 40.0;
 
+// Exhaustion caused by player actions, matching vanilla's `FoodConstants`.
+pub const EXHAUSTION_HEAL: f32 = 6.0;
+pub const EXHAUSTION_JUMP: f32 = 0.05;
+pub const EXHAUSTION_SPRINT_JUMP: f32 = 0.2;
+pub const EXHAUSTION_ATTACK: f32 = 0.1;
+/// Per meter sprinted on the ground. Walking and crouching cost nothing.
+pub const EXHAUSTION_SPRINT: f32 = 0.1;
+/// Per meter swum or walked through water.
+pub const EXHAUSTION_SWIM: f32 = 0.01;
+
 pub struct HungerManager {
     pub level: AtomicCell<u8>,
     pub saturation: AtomicCell<f32>,
@@ -59,10 +69,10 @@ impl HungerManager {
         if natural_regen && saturation > 0.0 && can_heal && level >= 20 {
             timer += 1;
             if timer >= 10 {
-                let cost = saturation.min(6.0);
+                let cost = saturation.min(EXHAUSTION_HEAL);
                 saturation -= cost;
                 exhaustion += cost;
-                heal_amount = cost / 6.0;
+                heal_amount = cost / EXHAUSTION_HEAL;
                 timer = 0;
                 needs_sync = true;
             }
@@ -70,7 +80,7 @@ impl HungerManager {
             timer += 1;
             if timer >= 80 {
                 heal_amount = 1.0;
-                exhaustion += 6.0;
+                exhaustion += EXHAUSTION_HEAL;
                 timer = 0;
                 needs_sync = true;
             }
@@ -154,9 +164,11 @@ impl HungerManager {
         self.level.store(level.min(MAX_FOOD));
     }
 
+    /// Sets the saturation, which can't be negative or exceed the food level.
     pub fn set_saturation(&self, saturation: f32) {
+        let saturation = if saturation.is_nan() { 0.0 } else { saturation };
         self.saturation
-            .store(saturation.min(f32::from(self.level.load())));
+            .store(saturation.clamp(0.0, f32::from(self.level.load())));
     }
 
     pub fn get_exhaustion(&self) -> f32 {
@@ -164,7 +176,8 @@ impl HungerManager {
     }
 
     pub fn set_exhaustion(&self, exhaustion: f32) {
-        self.exhaustion.store(exhaustion.min(MAX_EXHAUSTION));
+        let exhaustion = if exhaustion.is_nan() { 0.0 } else { exhaustion };
+        self.exhaustion.store(exhaustion.clamp(0.0, MAX_EXHAUSTION));
     }
 
     pub fn restart(&self) {
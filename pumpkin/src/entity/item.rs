@@ -311,18 +311,30 @@ impl ItemEntity {
         entity.velocity.store(velo);
     }
 
+    /// Ages the item and removes it once it is 5 minutes old. Returns the new age, or `None`
+    /// if the item never despawns or was removed.
+    async fn tick_despawn_timer(&self) -> Option<u32> {
+        if self.never_despawn.load(Ordering::Relaxed) {
+            return None;
+        }
+
+        let age = self.item_age.fetch_add(1, Ordering::Relaxed) + 1;
+        if age >= 6000 {
+            self.entity.remove().await;
+            return None;
+        }
+        Some(age)
+    }
+
     async fn process_age_and_merge(&self) -> bool {
         if self.never_despawn.load(Ordering::Relaxed) {
             return true;
         }
 
         let entity = &self.entity;
-        let age = self.item_age.fetch_add(1, Ordering::Relaxed) + 1;
-
-        if age >= 6000 {
-            entity.remove().await;
+        let Some(age) = self.tick_despawn_timer().await else {
             return false;
-        }
+        };
 
         let n = if entity
             .last_pos
@@ -400,6 +412,17 @@ impl EntityBase for ItemEntity {
         })
     }
 
+    fn inactive_tick<'a>(
+        &'a self,
+        _caller: &'a Arc<dyn EntityBase>,
+        _server: &'a Server,
+    ) -> EntityBaseFuture<'a, ()> {
+        Box::pin(async move {
+            self.decrement_pickup_delay();
+            self.tick_despawn_timer().await;
+        })
+    }
+
     fn init_data_tracker(&self) -> EntityBaseFuture<'_, ()> {
         Box::pin(async {
             self.entity.send_meta_data(&[Metadata::new(
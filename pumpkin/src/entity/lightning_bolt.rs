@@ -0,0 +1,180 @@
+use std::sync::{
+    Arc,
+    atomic::{
+        AtomicI32,
+        Ordering::{Relaxed, SeqCst},
+    },
+};
+
+use pumpkin_data::{damage::DamageType, entity::EntityType};
+use pumpkin_util::{
+    difficulty::Difficulty,
+    math::{boundingbox::BoundingBox, position::BlockPos, vector3::Vector3},
+};
+use rand::RngExt;
+use tokio::sync::Mutex;
+
+use super::{Entity, EntityBase, EntityBaseFuture, NBTStorage, living::LivingEntity};
+use crate::{
+    block::blocks::fire::{FireBlockBase, fire::FireBlock},
+    server::Server,
+    world::{BlockFlags, World},
+};
+
+/// A lightning bolt, which lives for a couple of ticks, flashes a few times and strikes
+/// everything close to it.
+///
+/// Wiki: <https://minecraft.wiki/w/Lightning>
+pub struct LightningBoltEntity {
+    entity: Entity,
+    /// Only show the bolt, without fire or damage.
+    effect_only: bool,
+    life: AtomicI32,
+    flashes: AtomicI32,
+    /// Entities already struck, so each one is hit only once per bolt.
+    hit_entities: Mutex<Vec<i32>>,
+}
+
+impl LightningBoltEntity {
+    pub fn new(entity: Entity, effect_only: bool) -> Self {
+        Self {
+            entity,
+            effect_only,
+            life: AtomicI32::new(2),
+            flashes: AtomicI32::new(rand::rng().random_range(1..=3)),
+            hit_entities: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Lights fire at the strike position and at `spread` random positions around it.
+    async fn spawn_fire(&self, world: &Arc<World>, spread: usize) {
+        if self.effect_only
+            || world
+                .level_info
+                .load()
+                .game_rules
+                .fire_spread_radius_around_player
+                == 0
+            || matches!(
+                world.level_info.load().difficulty,
+                Difficulty::Peaceful | Difficulty::Easy
+            )
+        {
+            return;
+        }
+
+        let origin = self.entity.block_pos.load();
+        Self::try_place_fire(world, &origin).await;
+        for _ in 0..spread {
+            let mut rng = rand::rng();
+            let offset = Vector3::new(
+                rng.random_range(-1..=1),
+                rng.random_range(-1..=1),
+                rng.random_range(-1..=1),
+            );
+            Self::try_place_fire(world, &origin.offset(offset)).await;
+        }
+    }
+
+    async fn try_place_fire(world: &Arc<World>, pos: &BlockPos) {
+        if !FireBlockBase::can_place_at(world, pos) {
+            return;
+        }
+        let fire_block = FireBlockBase::get_fire_type(world, pos);
+        let state_id = FireBlock.get_state_for_position(world, &fire_block, pos);
+        world
+            .set_block_state(pos, state_id, BlockFlags::NOTIFY_ALL)
+            .await;
+    }
+
+    /// Strikes every entity close to the bolt that it has not struck yet.
+    async fn strike_entities(&self, caller: &Arc<dyn EntityBase>, world: &Arc<World>) {
+        let pos = self.entity.pos.load();
+        let strike_box =
+            BoundingBox::new(pos.add_raw(-3.0, -3.0, -3.0), pos.add_raw(3.0, 9.0, 3.0));
+        for target in world.get_all_at_box(&strike_box) {
+            let target_entity = target.get_entity();
+            if !target_entity.is_alive() {
+                continue;
+            }
+            {
+                let mut hit_entities = self.hit_entities.lock().await;
+                if hit_entities.contains(&target_entity.entity_id) {
+                    continue;
+                }
+                hit_entities.push(target_entity.entity_id);
+            }
+            target
+                .on_struck_by_lightning(&target, caller.as_ref())
+                .await;
+        }
+    }
+}
+
+/// Sets `target` on fire and deals lightning damage to it, as every entity struck by lightning
+/// does unless it overrides [`EntityBase::on_struck_by_lightning`].
+pub async fn apply_lightning_strike(target: &Arc<dyn EntityBase>, lightning: &dyn EntityBase) {
+    let entity = target.get_entity();
+    if entity.fire_ticks.fetch_add(1, Relaxed) + 1 == 0 {
+        entity.set_on_fire_for(8.0);
+    }
+    target
+        .damage_with_context(
+            target.as_ref(),
+            5.0,
+            DamageType::LIGHTNING_BOLT,
+            None,
+            Some(lightning),
+            None,
+        )
+        .await;
+}
+
+impl NBTStorage for LightningBoltEntity {}
+
+impl EntityBase for LightningBoltEntity {
+    fn tick<'a>(
+        &'a self,
+        caller: &'a Arc<dyn EntityBase>,
+        _server: &'a Server,
+    ) -> EntityBaseFuture<'a, ()> {
+        Box::pin(async move {
+            let world = self.entity.world.load_full();
+            if self.life.load(Relaxed) == 2 {
+                self.spawn_fire(&world, 4).await;
+            }
+
+            let life = self.life.fetch_sub(1, SeqCst) - 1;
+            if life < 0 {
+                if self.flashes.load(Relaxed) == 0 {
+                    self.entity.remove().await;
+                    return;
+                } else if life < -rand::rng().random_range(0..10) {
+                    self.flashes.fetch_sub(1, Relaxed);
+                    self.life.store(1, Relaxed);
+                    self.spawn_fire(&world, 0).await;
+                }
+            }
+
+            if self.life.load(Relaxed) >= 0 && !self.effect_only {
+                self.strike_entities(caller, &world).await;
+            }
+        })
+    }
+
+    fn get_entity(&self) -> &Entity {
+        &self.entity
+    }
+
+    fn get_living_entity(&self) -> Option<&LivingEntity> {
+        None
+    }
+
+    fn as_nbt_storage(&self) -> &dyn NBTStorage {
+        self
+    }
+
+    fn cast_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
@@ -30,14 +30,17 @@ use crate::entity::attributes::ModifierOperation;
 use crate::entity::mob::slime::SlimeEntity;
 use crate::entity::player::statistics::{CustomStatistic, StatisticCategory};
 use crate::entity::{EntityBaseFuture, NbtFuture};
+use crate::plugin::entity::entity_damage::EntityDamageEvent;
 use crate::server::Server;
+use crate::world::World;
 use crate::world::loot::{LootContextParameters, LootTableExt};
 use crossbeam::atomic::AtomicCell;
+use pumpkin_data::Enchantment;
 use pumpkin_data::attributes::Attributes;
 use pumpkin_data::damage::DeathMessageType;
 use pumpkin_data::data_component_impl::Operation;
 use pumpkin_data::data_component_impl::{
-    BlocksAttacksImpl, DeathProtectionImpl, EquipmentSlot, EquippableImpl, FoodImpl,
+    BlocksAttacksImpl, CustomNameImpl, DeathProtectionImpl, EquipmentSlot, EquippableImpl, FoodImpl,
 };
 use pumpkin_data::effect::StatusEffect;
 use pumpkin_data::entity::{EntityPose, EntityStatus, EntityType};
@@ -59,10 +62,27 @@ use pumpkin_protocol::{
 use pumpkin_util::math::vector3::Vector3;
 use pumpkin_util::text::TextComponent;
 use rand::RngExt;
-use std::sync::RwLock;
+use std::sync::{LazyLock, RwLock};
 use tokio::sync::Mutex;
 use uuid::Uuid;
 
+/// The id of the movement speed modifier applied while sprinting.
+static SPRINTING_SPEED_MODIFIER_ID: LazyLock<Uuid> =
+    LazyLock::new(|| Uuid::new_v3(&Uuid::NAMESPACE_OID, b"minecraft:sprinting"));
+/// How much faster sprinting makes an entity, as a multiplier of its total movement speed.
+const SPRINTING_SPEED_BOOST: f64 = 0.3;
+
+/// Who a death message credits for the kill.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum DeathCredit {
+    /// Nobody; the death was an accident.
+    None,
+    /// The entity that last attacked the victim, when the final damage had no attacker.
+    KillCredit { named_weapon: bool },
+    /// The entity that dealt the final damage.
+    Attacker { named_weapon: bool },
+}
+
 /// Represents a living entity within the game world.
 ///
 /// This struct encapsulates the core properties and behaviors of living entities, including players, mobs, and other creatures.
@@ -249,6 +269,46 @@ impl LivingEntity {
         self.set_living_flag(Self::USING_ITEM_FLAG, false);
     }
 
+    /// Lets plugins change or cancel damage this entity is about to take, returning the damage
+    /// to deal or `None` if it was cancelled.
+    async fn fire_damage_event(
+        &self,
+        world: &World,
+        amount: f32,
+        damage_type: DamageType,
+        source: Option<&dyn EntityBase>,
+        cause: Option<&dyn EntityBase>,
+    ) -> Option<f32> {
+        let Some(server) = world.server.upgrade() else {
+            return Some(amount);
+        };
+        // The entities have to be looked up for the event, which is only worth it if a plugin
+        // listens
+        if !server
+            .plugin_manager
+            .has_listeners::<EntityDamageEvent>()
+            .await
+        {
+            return Some(amount);
+        }
+        let Some(entity) = world.get_entity_by_id(self.entity.entity_id) else {
+            return Some(amount);
+        };
+        let source = source.and_then(|s| world.get_entity_by_id(s.get_entity().entity_id));
+        let cause = cause.and_then(|c| world.get_entity_by_id(c.get_entity().entity_id));
+        let event = server
+            .plugin_manager
+            .fire(EntityDamageEvent::new(
+                entity,
+                amount,
+                damage_type,
+                source,
+                cause,
+            ))
+            .await;
+        (!event.cancelled).then(|| event.amount.max(0.0))
+    }
+
     pub async fn is_blocking(&self) -> bool {
         let item_in_use = self.item_in_use.lock().await;
         if let Some(item) = item_in_use.as_ref()
@@ -309,8 +369,9 @@ impl LivingEntity {
 
     /// Sets the current absorption amount for this entity (yellow hearts)
     pub async fn set_absorption(&self, new_abs: f32) {
-        // Must be at least 0
-        let new_abs = new_abs.max(0.0);
+        // Must be between 0 and the max absorption attribute, like vanilla
+        let max_abs = self.get_attribute_value(&Attributes::MAX_ABSORPTION) as f32;
+        let new_abs = new_abs.min(max_abs).max(0.0);
 
         // Set local state
         self.absorption.store(new_abs);
@@ -332,7 +393,7 @@ impl LivingEntity {
 
     /// Returns the absorption ID for this (player) entity
     /// TODO: don't hardcode these here?
-    fn player_absorption_id(&self) -> Option<TrackedId> {
+    pub(crate) fn player_absorption_id(&self) -> Option<TrackedId> {
         (self.entity.entity_type == &EntityType::PLAYER).then_some(TrackedId {
             v1_21: 17u8,
             v1_21_2: 17u8,
@@ -381,6 +442,28 @@ impl LivingEntity {
         inst.dirty.store(true, Ordering::Relaxed);
     }
 
+    /// Starts or stops sprinting, adding or removing the sprint speed boost and sending the
+    /// updated movement speed to clients.
+    pub async fn set_sprinting(&self, sprinting: bool) {
+        self.entity.set_sprinting(sprinting).await;
+        self.update_attribute(&Attributes::MOVEMENT_SPEED, |inst| {
+            if sprinting {
+                inst.add_or_replace_modifier(Modifier {
+                    id: *SPRINTING_SPEED_MODIFIER_ID,
+                    amount: SPRINTING_SPEED_BOOST,
+                    operation: ModifierOperation::MultiplyTotal,
+                });
+            } else {
+                inst.remove_modifier(*SPRINTING_SPEED_MODIFIER_ID);
+            }
+        });
+        crate::entity::attributes::send_attribute_updates_for_living(
+            self,
+            vec![Attributes::MOVEMENT_SPEED],
+        )
+        .await;
+    }
+
     /// Returns the computed value for `attribute` using the local instance, falling back
     /// to `attribute.default_value` if no local instance exists.
     pub fn get_attribute_value(&self, attribute: &Attributes) -> f64 {
@@ -389,6 +472,23 @@ impl LivingEntity {
             .map_or(attribute.default_value, AttributeInstance::value)
     }
 
+    /// Applies knockback scaled by this entity's `knockback_resistance` attribute.
+    /// A resistance of 1.0 fully negates the knockback.
+    pub fn take_knockback(&self, strength: f64, x: f64, z: f64) {
+        let resistance = self.get_attribute_value(&Attributes::KNOCKBACK_RESISTANCE);
+        self.entity.knockback(
+            Self::resisted_knockback_strength(strength, resistance),
+            x,
+            z,
+        );
+    }
+
+    /// Scales a knockback `strength` by `1 - resistance`, with the resistance clamped to `0..=1`.
+    #[must_use]
+    pub fn resisted_knockback_strength(strength: f64, resistance: f64) -> f64 {
+        strength * (1.0 - resistance.clamp(0.0, 1.0))
+    }
+
     /// Returns the base attribute value for `attribute` for this entity's type.
     pub fn get_attribute_base(&self, attribute: &Attributes) -> f64 {
         // Check the local base value first (could be modified)
@@ -464,25 +564,7 @@ impl LivingEntity {
             // Effects that modify attributes (ex. speed) should also update the
             // entity's attribute instances (server-side) and then notify clients.
             if !effect.effect_type.attribute_modifiers.is_empty() {
-                // Apply each attribute modifier into the local AttributeInstance
-                for m in effect.effect_type.attribute_modifiers {
-                    let uuid = Uuid::new_v3(&Uuid::NAMESPACE_OID, m.id.as_bytes());
-                    let op = match m.operation {
-                        Operation::AddValue => ModifierOperation::Add,
-                        Operation::AddMultipliedBase => ModifierOperation::MultiplyBase,
-                        Operation::AddMultipliedTotal => ModifierOperation::MultiplyTotal,
-                    };
-                    let scaled_amount = m.base_value * (f64::from(effect.amplifier) + 1.);
-                    let mod_inst = Modifier {
-                        id: uuid,
-                        amount: scaled_amount,
-                        operation: op,
-                    };
-
-                    self.update_attribute(m.attribute, |inst| {
-                        inst.add_or_replace_modifier(mod_inst.clone());
-                    });
-                }
+                self.apply_effect_attribute_modifiers(&effect);
 
                 // Recompute packet modifiers from active effects for each affected attribute
                 let mut touched_attrs: Vec<pumpkin_data::attributes::Attributes> = Vec::new();
@@ -501,11 +583,11 @@ impl LivingEntity {
                 }
             }
 
-            // Apply absorption effect (+4 absorption per level)
+            // Apply absorption effect (4 absorption per level, never lowering what is left)
             if effect.effect_type == &StatusEffect::ABSORPTION {
-                let added = 4.0 * (effect.amplifier as f32 + 1.0);
+                let granted = 4.0 * (effect.amplifier as f32 + 1.0);
                 let max_abs = self.get_attribute_value(&Attributes::MAX_ABSORPTION) as f32;
-                let new_abs = (self.absorption.load() + added).min(max_abs);
+                let new_abs = self.absorption.load().max(granted).min(max_abs);
                 self.set_absorption(new_abs).await;
             }
 
@@ -546,6 +628,29 @@ impl LivingEntity {
         self.entity.world.load().broadcast_packet_all(&packet);
     }
 
+    /// Applies an effect's attribute modifiers, scaled by its amplifier, to the local attribute
+    /// instances. Clients still have to be sent the updated attributes.
+    fn apply_effect_attribute_modifiers(&self, effect: &Effect) {
+        for m in effect.effect_type.attribute_modifiers {
+            let uuid = Uuid::new_v3(&Uuid::NAMESPACE_OID, m.id.as_bytes());
+            let op = match m.operation {
+                Operation::AddValue => ModifierOperation::Add,
+                Operation::AddMultipliedBase => ModifierOperation::MultiplyBase,
+                Operation::AddMultipliedTotal => ModifierOperation::MultiplyTotal,
+            };
+            let scaled_amount = m.base_value * (f64::from(effect.amplifier) + 1.);
+            let mod_inst = Modifier {
+                id: uuid,
+                amount: scaled_amount,
+                operation: op,
+            };
+
+            self.update_attribute(m.attribute, |inst| {
+                inst.add_or_replace_modifier(mod_inst.clone());
+            });
+        }
+    }
+
     pub async fn remove_effect(&self, effect_type: &'static StatusEffect) -> bool {
         // Remove the effect
         let succeeded = self
@@ -834,6 +939,102 @@ impl LivingEntity {
 
         if suffocating {
             self.damage(&**caller, 1.0, DamageType::IN_WALL).await;
+        } else if self.entity.entity_type == &EntityType::PLAYER {
+            self.tick_border_damage(caller).await;
+        }
+    }
+
+    /// Pushes this entity away from every living entity its bounding box overlaps, like
+    /// vanilla's `pushEntities`.
+    ///
+    /// Players move themselves on the client, which applies the same push, so only
+    /// server-controlled entities are moved here.
+    async fn push_away_from_entities(&self, caller: &Arc<dyn EntityBase>) {
+        if caller.get_player().is_some() || !is_pushable(caller.as_ref()) {
+            return;
+        }
+
+        let own_id = self.entity.entity_id;
+        // Entities riding together don't push each other. Only our own mounts are locked, so
+        // this stays cheap no matter how many entities are close by.
+        let mut mounted_ids: Vec<i32> = self
+            .entity
+            .passengers
+            .lock()
+            .await
+            .iter()
+            .map(|passenger| passenger.get_entity().entity_id)
+            .collect();
+        let own_vehicle = self.entity.vehicle.lock().await.clone();
+        if let Some(vehicle) = own_vehicle {
+            let vehicle = vehicle.get_entity();
+            mounted_ids.push(vehicle.entity_id);
+            mounted_ids.extend(
+                vehicle
+                    .passengers
+                    .lock()
+                    .await
+                    .iter()
+                    .map(|passenger| passenger.get_entity().entity_id),
+            );
+        }
+        let position = self.entity.pos.load();
+        let bounding_box = self.entity.bounding_box.load();
+        let world = self.entity.world.load();
+
+        let mut push = Vector3::new(0.0, 0.0, 0.0);
+        {
+            let entities = world.entities.load();
+            let players = world.players.load();
+            let others = entities.iter().map(AsRef::as_ref).chain(
+                players
+                    .iter()
+                    .map(|player| player.as_ref() as &dyn EntityBase),
+            );
+            for other in others {
+                let other_entity = other.get_entity();
+                if other_entity.entity_id == own_id
+                    || !other_entity.bounding_box.load().intersects(&bounding_box)
+                    || !is_pushable(other)
+                    || mounted_ids.contains(&other_entity.entity_id)
+                {
+                    continue;
+                }
+                if let Some((x, z)) = push_away_impulse(position, other_entity.pos.load()) {
+                    push.x += x;
+                    push.z += z;
+                }
+            }
+        }
+
+        let length = push.x.hypot(push.z);
+        if length < f64::EPSILON {
+            return;
+        }
+        // A crowd would otherwise add up to a launch.
+        let scale = (MAX_ENTITY_PUSH_PER_TICK / length).min(1.0);
+        self.entity
+            .velocity
+            .store(self.entity.velocity.load() + push * scale);
+        self.entity.velocity_dirty.store(true, SeqCst);
+    }
+
+    /// Damages the entity for every block it is past the world border's safe zone.
+    async fn tick_border_damage(&self, caller: &Arc<dyn EntityBase>) {
+        let pos = self.entity.pos.load();
+        let (distance, damage_per_block) = {
+            let world = self.entity.world.load();
+            let border = world.worldborder.lock().await;
+            (
+                border.distance_inside(pos.x, pos.z) + f64::from(border.buffer),
+                f64::from(border.damage_per_block),
+            )
+        };
+
+        if distance < 0.0 && damage_per_block > 0.0 {
+            let amount = (-distance * damage_per_block).floor().max(1.0);
+            self.damage(&**caller, amount as f32, DamageType::OUTSIDE_BORDER)
+                .await;
         }
     }
 
@@ -1227,9 +1428,21 @@ impl LivingEntity {
 
         // Fetches the safe fall distance attribute
         let safe_fall_distance = self.get_attribute_value(&Attributes::SAFE_FALL_DISTANCE) as f32;
-        let unsafe_fall_distance = fall_distance + 1.0E-6 - safe_fall_distance;
+        // Jump boost extends the safe distance by one block per level
+        let jump_boost = self
+            .get_effect(&StatusEffect::JUMP_BOOST)
+            .await
+            .map_or(0.0, |effect| f32::from(effect.amplifier) + 1.0);
+        let unsafe_fall_distance = fall_distance + 1.0E-6 - safe_fall_distance - jump_boost;
 
         let damage = (unsafe_fall_distance * damage_per_distance).floor();
+        let feather_falling = {
+            let equipment = self.entity_equipment.lock().await;
+            let boots = equipment.get(&EquipmentSlot::FEET);
+            let boots_stack = boots.lock().await;
+            boots_stack.get_enchantment_level(&Enchantment::FEATHER_FALLING)
+        };
+        let damage = Self::feather_falling_damage(damage, feather_falling);
         if damage > 0.0 {
             let check_damage = self.damage(caller, damage, DamageType::FALL).await; // Fall
             if check_damage {
@@ -1239,6 +1452,14 @@ impl LivingEntity {
         }
     }
 
+    /// Reduces fall `damage` for boots enchanted with feather falling at `level`.
+    /// Each level is worth 3 protection points out of 25, capped at 20 like vanilla.
+    #[must_use]
+    pub fn feather_falling_damage(damage: f32, level: i32) -> f32 {
+        let protection = (level.max(0) * 3).min(20) as f32;
+        damage * (1.0 - protection / 25.0)
+    }
+
     const fn get_fall_sound(distance: i32) -> Sound {
         if distance > 4 {
             Sound::EntityGenericBigFall
@@ -1247,49 +1468,114 @@ impl LivingEntity {
         }
     }
 
+    /// Picks the vanilla translation key for a death caused by `damage_type`.
+    #[must_use]
+    pub fn death_message_key(damage_type: &DamageType, credit: DeathCredit) -> String {
+        let named_weapon = matches!(
+            credit,
+            DeathCredit::Attacker { named_weapon: true }
+                | DeathCredit::KillCredit { named_weapon: true }
+        );
+        match damage_type.death_message_type {
+            DeathMessageType::Default => {
+                let base = format!("death.attack.{}", damage_type.message_id);
+                match credit {
+                    DeathCredit::None => base,
+                    DeathCredit::KillCredit { .. } => format!("{base}.player"),
+                    DeathCredit::Attacker { .. } if named_weapon => format!("{base}.item"),
+                    DeathCredit::Attacker { .. } => base,
+                }
+            }
+            // TODO: Track the fall location (ladder, vines, water...) for the accident variants
+            DeathMessageType::FallVariants => match credit {
+                DeathCredit::None => translation::java::DEATH_FELL_ACCIDENT_GENERIC.to_string(),
+                _ if named_weapon => translation::java::DEATH_FELL_ASSIST_ITEM.to_string(),
+                _ => translation::java::DEATH_FELL_ASSIST.to_string(),
+            },
+            DeathMessageType::IntentionalGameDesign => {
+                format!("death.attack.{}.message", damage_type.message_id)
+            }
+        }
+    }
+
     pub async fn get_death_message(
         dyn_self: &dyn EntityBase,
         damage_type: DamageType,
         source: Option<&dyn EntityBase>,
         cause: Option<&dyn EntityBase>,
     ) -> TextComponent {
-        match damage_type.death_message_type {
-            DeathMessageType::Default => {
-                if let Some(cause) = cause
-                    && source.is_some()
-                {
-                    TextComponent::translate_cross(
-                        format!("death.attack.{}.player", damage_type.message_id),
-                        format!("death.attack.{}.player", damage_type.message_id),
-                        [
-                            dyn_self.get_display_name().await,
-                            cause.get_display_name().await,
-                        ],
-                    )
-                } else {
-                    TextComponent::translate_cross(
-                        format!("death.attack.{}", damage_type.message_id),
-                        format!("death.attack.{}", damage_type.message_id),
-                        [dyn_self.get_display_name().await],
-                    )
-                }
-            }
-            DeathMessageType::FallVariants => {
-                //TODO
-                TextComponent::translate_cross(
-                    translation::java::DEATH_FELL_ACCIDENT_GENERIC,
-                    translation::bedrock::DEATH_FELL_ACCIDENT_GENERIC,
-                    [dyn_self.get_display_name().await],
-                )
-            }
-            DeathMessageType::IntentionalGameDesign => TextComponent::text("[")
-                .add_child(TextComponent::translate_cross(
-                    format!("death.attack.{}.message", damage_type.message_id),
-                    format!("death.attack.{}.message", damage_type.message_id),
-                    [dyn_self.get_display_name().await],
-                ))
-                .add_child(TextComponent::text("]")),
+        let mut args = vec![dyn_self.get_display_name().await];
+
+        if damage_type.death_message_type == DeathMessageType::IntentionalGameDesign {
+            args.push(
+                TextComponent::text("[")
+                    .add_child(TextComponent::translate_cross(
+                        translation::java::DEATH_ATTACK_BADRESPAWNPOINT_LINK,
+                        translation::java::DEATH_ATTACK_BADRESPAWNPOINT_LINK,
+                        [],
+                    ))
+                    .add_child(TextComponent::text("]")),
+            );
+            let key = Self::death_message_key(&damage_type, DeathCredit::None);
+            return TextComponent::translate_cross(key.clone(), key, args);
         }
+
+        let kill_credit = if cause.is_none() && source.is_none() {
+            Self::get_kill_credit(dyn_self)
+        } else {
+            None
+        };
+        let credit = if let Some(killer) = cause.or(source) {
+            args.push(killer.get_display_name().await);
+            let weapon = Self::named_weapon(cause).await;
+            let named_weapon = weapon.is_some();
+            args.extend(weapon);
+            DeathCredit::Attacker { named_weapon }
+        } else if let Some(killer) = &kill_credit {
+            args.push(killer.get_display_name().await);
+            // Only the fall assist messages name the weapon of a killer that did not deal the final blow
+            let weapon = if damage_type.death_message_type == DeathMessageType::FallVariants {
+                Self::named_weapon(Some(&**killer)).await
+            } else {
+                None
+            };
+            let named_weapon = weapon.is_some();
+            args.extend(weapon);
+            DeathCredit::KillCredit { named_weapon }
+        } else {
+            DeathCredit::None
+        };
+
+        let key = Self::death_message_key(&damage_type, credit);
+        TextComponent::translate_cross(key.clone(), key, args)
+    }
+
+    /// The entity that last attacked `dyn_self` within the last 5 seconds, if it is still around.
+    fn get_kill_credit(dyn_self: &dyn EntityBase) -> Option<Arc<dyn EntityBase>> {
+        let living = dyn_self.get_living_entity()?;
+        let attacker_id = living.last_attacker_id.load(Relaxed);
+        let since = living.entity.age.load(Relaxed) - living.last_attacked_time.load(Relaxed);
+        if attacker_id == 0 || since > 100 {
+            return None;
+        }
+        living.entity.world.load().get_entity_by_id(attacker_id)
+    }
+
+    /// The custom name of the item `killer` holds in its main hand, if it has one.
+    async fn named_weapon(killer: Option<&dyn EntityBase>) -> Option<TextComponent> {
+        let killer = killer?;
+        let living = killer.get_living_entity()?;
+        let item = living.held_item(killer).await;
+        let item = item.lock().await;
+        if item.is_empty() {
+            return None;
+        }
+        let name = item.get_data_component::<CustomNameImpl>()?;
+        Some(
+            TextComponent::text("[")
+                .add_child(TextComponent::text(name.name.clone()))
+                .add_child(TextComponent::text("]")),
+        )
     }
 
     pub async fn on_death(
@@ -1310,6 +1596,10 @@ impl LivingEntity {
             self.movement_input.store(Vector3::default());
             self.jumping.store(false, Relaxed);
 
+            // A dead vehicle can't carry anyone
+            self.entity.eject_passengers().await;
+            self.entity.stop_riding().await;
+
             // Statistics updates
             self.update_death_stats(&*dyn_self, cause).await;
 
@@ -1381,12 +1671,9 @@ impl LivingEntity {
         let world = self.entity.world.load();
         let show_death_messages = { world.level_info.load().game_rules.show_death_messages };
         if self.entity.entity_type == &EntityType::PLAYER && show_death_messages {
-            //TODO: KillCredit
             let death_message = Self::get_death_message(dyn_self, damage_type, source, cause).await;
             if let Some(server) = world.server.upgrade() {
-                for player in server.get_all_players() {
-                    player.send_system_message(&death_message).await;
-                }
+                server.broadcast_system_message(&death_message, false).await;
             }
         }
     }
@@ -1491,23 +1778,14 @@ impl LivingEntity {
         let effect_type = effect.effect_type;
 
         if effect_type == &StatusEffect::REGENERATION {
-            if duration <= 0 {
-                return false;
-            }
-            let tick_rate = 50 >> effect.amplifier.min(4);
-            duration % tick_rate == 0
+            Self::is_effect_interval_tick(50, effect.amplifier, duration)
         } else if effect_type == &StatusEffect::POISON {
-            if duration <= 0 {
-                return false;
-            }
-            let tick_rate = 25 >> effect.amplifier.min(4);
-            duration % tick_rate == 0
+            Self::is_effect_interval_tick(25, effect.amplifier, duration)
         } else if effect_type == &StatusEffect::WITHER {
-            if duration <= 0 {
-                return false;
-            }
-            let tick_rate = 40 >> effect.amplifier.min(4);
-            duration % tick_rate == 0
+            Self::is_effect_interval_tick(40, effect.amplifier, duration)
+        } else if effect_type == &StatusEffect::ABSORPTION {
+            // Checked every tick so the effect ends once its hearts are used up
+            true
         } else if effect_type == &StatusEffect::HUNGER {
             // Hunger every 20 ticks
             duration % 20 == 0
@@ -1520,6 +1798,16 @@ impl LivingEntity {
         }
     }
 
+    /// Vanilla halves the interval of periodic effects per amplifier level (`base >> amplifier`),
+    /// applying them every tick once the interval reaches 0.
+    fn is_effect_interval_tick(base: i32, amplifier: u8, duration: i32) -> bool {
+        if duration <= 0 {
+            return false;
+        }
+        let interval = base.checked_shr(u32::from(amplifier)).unwrap_or(0);
+        interval == 0 || duration % interval == 0
+    }
+
     /// Applies the actual effect to the entity
     /// This is called by `tick_effects` when an effect should trigger this tick
     async fn apply_effect_tick(&self, effect_type: &'static StatusEffect, amplifier: u8) {
@@ -1529,6 +1817,10 @@ impl LivingEntity {
             if current_health < max_health && current_health > 0.0 {
                 self.heal(1.0);
             }
+        } else if effect_type == &StatusEffect::ABSORPTION {
+            if self.absorption.load() <= 0.0 {
+                self.remove_effect(effect_type).await;
+            }
         } else if effect_type == &StatusEffect::POISON {
             let current_health = self.health.load();
             if current_health > 1.0
@@ -1860,6 +2152,45 @@ impl LivingEntity {
     }
 }
 
+/// Whether `entity` takes part in entity pushing: living, not a spectator and not clipping
+/// through everything.
+fn is_pushable(entity: &dyn EntityBase) -> bool {
+    entity
+        .get_living_entity()
+        .is_some_and(|living| !living.dead.load(Relaxed) && !living.entity.no_clip.load(Relaxed))
+        && !entity.is_spectator()
+}
+
+/// Reads the fall distance, accepting the current `fall_distance` double as well as the
+/// float written by older Pumpkin versions and the pre-1.21.5 `FallDistance` key.
+fn fall_distance_from_nbt(nbt: &NbtCompound) -> f32 {
+    match nbt.get("fall_distance").or_else(|| nbt.get("FallDistance")) {
+        Some(NbtTag::Double(distance)) => *distance as f32,
+        Some(NbtTag::Float(distance)) => *distance,
+        _ => 0.0,
+    }
+}
+
+/// The horizontal push between two overlapping entities at full strength.
+const ENTITY_PUSH_STRENGTH: f64 = 0.05;
+/// The most an entity is pushed by overlapping entities in a single tick.
+const MAX_ENTITY_PUSH_PER_TICK: f64 = 0.2;
+
+/// Computes vanilla's `Entity.push` impulse moving an entity at `position` away from one at
+/// `other`, as an `(x, z)` velocity change. Returns `None` when both are at nearly the same
+/// spot, where vanilla does not push either.
+fn push_away_impulse(position: Vector3<f64>, other: Vector3<f64>) -> Option<(f64, f64)> {
+    let dx = other.x - position.x;
+    let dz = other.z - position.z;
+    let max = dx.abs().max(dz.abs());
+    if max < 0.01 {
+        return None;
+    }
+    let distance = max.sqrt();
+    let strength = (1.0 / distance).min(1.0) * ENTITY_PUSH_STRENGTH / distance;
+    Some((-dx * strength, -dz * strength))
+}
+
 impl NBTStorage for LivingEntity {
     fn write_nbt<'a>(&'a self, nbt: &'a mut NbtCompound) -> NbtFuture<'a, ()> {
         Box::pin(async move {
@@ -1873,7 +2204,7 @@ impl NBTStorage for LivingEntity {
             };
             // Persist current absorption amount
             nbt.put("AbsorptionAmount", NbtTag::Float(self.absorption.load()));
-            nbt.put("fall_distance", NbtTag::Float(fall_distance));
+            nbt.put("fall_distance", NbtTag::Double(f64::from(fall_distance)));
             {
                 let effects = self.active_effects.lock().await;
                 if !effects.is_empty() {
@@ -1897,15 +2228,9 @@ impl NBTStorage for LivingEntity {
             self.entity.read_nbt_non_mut(nbt).await;
             self.health.store(nbt.get_float("Health").unwrap_or(0.0));
 
-            // Clamp any persisted absorption to the entity's configured max
-            let raw_abs = nbt.get_float("AbsorptionAmount").unwrap_or(0.0);
-            let max_abs = self.get_attribute_value(&Attributes::MAX_ABSORPTION) as f32;
-            let clamped_abs = raw_abs.max(0.0).min(max_abs);
-            self.absorption.store(clamped_abs);
-
             // Load fall distance, but if this entity is currently marked dead ensure we don't restore
             // a lethal fall distance that would immediately re-kill on spawn.
-            let fd = nbt.get_float("fall_distance").unwrap_or(0.0);
+            let fd = fall_distance_from_nbt(nbt);
             if self.dead.load(Relaxed) {
                 self.fall_distance.store(0.0);
             } else {
@@ -1924,11 +2249,19 @@ impl NBTStorage for LivingEntity {
                             }
                             let mut effect = effect.unwrap();
                             effect.blend = true; // TODO: change, is taken from effect give command
+                            self.apply_effect_attribute_modifiers(&effect);
                             active_effects.insert(effect.effect_type, effect);
                         }
                     }
                 }
             }
+
+            // Clamp any persisted absorption to the entity's configured max. This has to happen
+            // after the effects are loaded, as Absorption raises the max.
+            let raw_abs = nbt.get_float("AbsorptionAmount").unwrap_or(0.0);
+            let max_abs = self.get_attribute_value(&Attributes::MAX_ABSORPTION) as f32;
+            let clamped_abs = raw_abs.max(0.0).min(max_abs);
+            self.absorption.store(clamped_abs);
         })
         // todo more...
     }
@@ -1946,8 +2279,6 @@ impl EntityBase for LivingEntity {
         cause: Option<&'a dyn EntityBase>,
     ) -> EntityBaseFuture<'a, bool> {
         Box::pin(async move {
-            let mut amount = amount;
-
             // Check invulnerability before applying damage
             if self.entity.is_invulnerable_to(&damage_type).await {
                 return false;
@@ -1962,6 +2293,16 @@ impl EntityBase for LivingEntity {
             }
 
             let world = self.entity.world.load();
+
+            // Plugins see the damage before anything else touches it
+            let Some(amount) = self
+                .fire_damage_event(&world, amount, damage_type, source, cause)
+                .await
+            else {
+                return false;
+            };
+            let mut amount = amount;
+
             let is_fire_damage = damage_type == DamageType::IN_FIRE
                 || damage_type == DamageType::ON_FIRE
                 || damage_type == DamageType::LAVA
@@ -2084,41 +2425,37 @@ impl EntityBase for LivingEntity {
                         }
                     }
 
-                    let active_hand = self.active_hand.lock().await;
-                    if let Some(hand) = *active_hand {
-                        let slot = if hand == Hand::Left {
-                            EquipmentSlot::MAIN_HAND
+                    // Vanilla only wears the shield down when it blocks 3 or more damage
+                    let active_hand = *self.active_hand.lock().await;
+                    if effective_amount >= 3.0
+                        && let Some(hand) = active_hand
+                    {
+                        let durability_damage = 1 + effective_amount.floor() as i32;
+                        if let Some(player) = caller.get_player() {
+                            player.damage_item_in_hand(hand, durability_damage).await;
+                            let stack = player.inventory().get_stack_in_hand(hand).await;
+                            if stack.lock().await.is_empty() {
+                                self.clear_active_hand().await;
+                            }
                         } else {
-                            EquipmentSlot::OFF_HAND
-                        };
-
-                        let equipment_lock = self.entity_equipment.lock().await;
-                        let stack_arc = equipment_lock.get(&slot);
-                        let mut stack = stack_arc.lock().await;
-
-                        let durability_damage = (amount / 1.0).floor().max(1.0) as i32;
-                        if stack.damage_item(durability_damage) == DamageResult::Broken {
-                            if let Some(player) = caller.get_player() {
-                                player
-                                    .increment_stat(
-                                        StatisticCategory::Broken,
-                                        stack.item.id as i32,
-                                        1,
-                                    )
-                                    .await;
+                            let slot = if hand == Hand::Right {
+                                EquipmentSlot::MAIN_HAND
+                            } else {
+                                EquipmentSlot::OFF_HAND
+                            };
+                            let stack_arc = self.entity_equipment.lock().await.get(&slot);
+                            let mut stack = stack_arc.lock().await;
+                            if stack.damage_item(durability_damage) == DamageResult::Broken {
+                                world.send_entity_status(
+                                    &self.entity,
+                                    crate::entity::equipment_break_status(&slot),
+                                );
+                                *stack = ItemStack::EMPTY.clone();
+                                drop(stack);
+
+                                self.send_equipment_changes(&[(slot, ItemStack::EMPTY.clone())]);
+                                self.clear_active_hand().await;
                             }
-                            world.send_entity_status(
-                                &self.entity,
-                                crate::entity::equipment_break_status(&slot),
-                            );
-                            *stack = ItemStack::EMPTY.clone();
-                            let broken_stack = stack.clone();
-                            drop(stack);
-                            drop(stack_arc);
-                            drop(equipment_lock);
-
-                            self.send_equipment_changes(&[(slot, broken_stack)]);
-                            self.clear_active_hand().await;
                         }
                     }
 
@@ -2188,6 +2525,7 @@ impl EntityBase for LivingEntity {
                     let dx = source_pos.x - target_pos.x;
                     let dz = source_pos.z - target_pos.z;
                     self.entity.apply_knockback(0.4, dx, dz);
+                    self.entity.clamp_velocity_to_blocks(caller).await;
                     self.entity.send_velocity();
                 }
             }
@@ -2319,6 +2657,9 @@ impl EntityBase for LivingEntity {
                 self.health.load() <= 0.0 && self.death_time.load(Relaxed) < 20;
             if is_alive || (in_death_animation && self.entity.entity_type != &EntityType::PLAYER) {
                 self.tick_movement(server, caller).await;
+                if is_alive {
+                    self.push_away_from_entities(caller).await;
+                }
                 // Vanilla-like order: freeze logic runs after movement/collisions.
                 self.entity.tick_frozen(caller.as_ref()).await;
             }
@@ -2540,6 +2881,16 @@ impl EntityBase for LivingEntity {
                             player
                                 .start_cooldown(group, (cooldown.seconds * 20.0) as i32)
                                 .await;
+                        } else if item.item == &Item::ENCHANTED_GOLDEN_APPLE {
+                            let ticks = server.advanced_config.pvp.enchanted_golden_apple_cooldown;
+                            if ticks > 0 {
+                                player
+                                    .start_cooldown(
+                                        Item::ENCHANTED_GOLDEN_APPLE.registry_key.to_string(),
+                                        ticks as i32,
+                                    )
+                                    .await;
+                            }
                         }
                     }
 
@@ -2637,6 +2988,101 @@ pub(crate) const fn bypasses_armor_durability(damage_type: &DamageType) -> bool
 mod tests {
     use super::*;
 
+    // ── is_effect_interval_tick ───────────────────────────────────────
+
+    #[test]
+    fn death_message_keys_match_vanilla() {
+        let key = LivingEntity::death_message_key;
+        assert_eq!(
+            key(&DamageType::LAVA, DeathCredit::None),
+            "death.attack.lava"
+        );
+        assert_eq!(
+            key(
+                &DamageType::LAVA,
+                DeathCredit::KillCredit { named_weapon: true }
+            ),
+            "death.attack.lava.player"
+        );
+        assert_eq!(
+            key(&DamageType::OUT_OF_WORLD, DeathCredit::None),
+            "death.attack.outOfWorld"
+        );
+        assert_eq!(
+            key(
+                &DamageType::PLAYER_ATTACK,
+                DeathCredit::Attacker {
+                    named_weapon: false
+                }
+            ),
+            "death.attack.player"
+        );
+        assert_eq!(
+            key(
+                &DamageType::PLAYER_ATTACK,
+                DeathCredit::Attacker { named_weapon: true }
+            ),
+            "death.attack.player.item"
+        );
+        assert_eq!(
+            key(
+                &DamageType::PLAYER_EXPLOSION,
+                DeathCredit::Attacker {
+                    named_weapon: false
+                }
+            ),
+            "death.attack.explosion.player"
+        );
+        assert_eq!(
+            key(&DamageType::FALL, DeathCredit::None),
+            "death.fell.accident.generic"
+        );
+        assert_eq!(
+            key(
+                &DamageType::FALL,
+                DeathCredit::KillCredit {
+                    named_weapon: false
+                }
+            ),
+            "death.fell.assist"
+        );
+        assert_eq!(
+            key(
+                &DamageType::FALL,
+                DeathCredit::KillCredit { named_weapon: true }
+            ),
+            "death.fell.assist.item"
+        );
+        assert_eq!(
+            key(&DamageType::BAD_RESPAWN_POINT, DeathCredit::None),
+            "death.attack.badRespawnPoint.message"
+        );
+    }
+
+    #[test]
+    fn regeneration_interval_halves_per_amplifier() {
+        assert!(LivingEntity::is_effect_interval_tick(50, 0, 100));
+        assert!(!LivingEntity::is_effect_interval_tick(50, 0, 75));
+        assert!(LivingEntity::is_effect_interval_tick(50, 1, 75));
+        assert!(!LivingEntity::is_effect_interval_tick(50, 0, 0));
+    }
+
+    #[test]
+    fn high_amplifier_effects_apply_every_tick() {
+        assert!(LivingEntity::is_effect_interval_tick(50, 6, 7));
+        assert!(LivingEntity::is_effect_interval_tick(50, 255, 7));
+    }
+
+    // ── feather_falling_damage ────────────────────────────────────────
+
+    #[test]
+    fn feather_falling_reduces_fall_damage() {
+        assert!((LivingEntity::feather_falling_damage(10.0, 0) - 10.0).abs() < f32::EPSILON);
+        assert!((LivingEntity::feather_falling_damage(10.0, 4) - 5.2).abs() < 1.0E-5);
+        // Protection is capped at 20 points
+        assert!((LivingEntity::feather_falling_damage(10.0, 10) - 2.0).abs() < 1.0E-5);
+    }
+
     // ── bypasses_armor_durability ─────────────────────────────────────
 
     /// Every member of `minecraft:bypasses_armor` (1.21.11) must return `true`.
@@ -2697,6 +3143,25 @@ mod tests {
         }
     }
 
+    #[test]
+    fn fully_knockback_resistant_entity_is_not_moved() {
+        let velocity = Vector3::new(0.1, -0.08, 0.2);
+        let strength = LivingEntity::resisted_knockback_strength(0.9, 1.0);
+        assert_eq!(
+            Entity::knockback_velocity(velocity, true, strength, 0.5, -0.5),
+            velocity
+        );
+    }
+
+    #[test]
+    fn partial_knockback_resistance_scales_strength() {
+        let strength = LivingEntity::resisted_knockback_strength(1.0, 0.6);
+        assert!((strength - 0.4).abs() < 1e-9);
+        let velocity =
+            Entity::knockback_velocity(Vector3::new(0.0, 0.0, 0.0), false, strength, 1.0, 0.0);
+        assert!((velocity.x + 0.4).abs() < 1e-9);
+    }
+
     #[test]
     fn hurt_sound_for_entity_uses_zombie_family_sounds() {
         let cases = [
@@ -2747,4 +3212,31 @@ mod tests {
             Sound::EntityGenericHurt
         );
     }
+
+    #[test]
+    fn fall_distance_reads_current_and_legacy_keys() {
+        let mut nbt = NbtCompound::new();
+        assert!(fall_distance_from_nbt(&nbt).abs() < f32::EPSILON);
+
+        nbt.put("FallDistance", NbtTag::Float(2.5));
+        assert!((fall_distance_from_nbt(&nbt) - 2.5).abs() < f32::EPSILON);
+
+        nbt.put("fall_distance", NbtTag::Double(7.75));
+        assert!((fall_distance_from_nbt(&nbt) - 7.75).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn push_moves_entities_apart_on_the_dominant_axis() {
+        let (x, z) = push_away_impulse(Vector3::new(0.0, 64.0, 0.0), Vector3::new(0.25, 64.0, 0.0))
+            .expect("overlapping entities should be pushed");
+        assert!(x < 0.0);
+        assert!(z.abs() < f64::EPSILON);
+        assert!(x.abs() <= ENTITY_PUSH_STRENGTH);
+    }
+
+    #[test]
+    fn push_is_skipped_for_entities_at_the_same_spot() {
+        let position = Vector3::new(3.0, 70.0, -2.0);
+        assert!(push_away_impulse(position, position + Vector3::new(0.005, 0.0, 0.0)).is_none());
+    }
 }
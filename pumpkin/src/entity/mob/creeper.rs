@@ -22,6 +22,7 @@ use crate::entity::{
         melee_attack::MeleeAttackGoal, revenge::RevengeGoal, swim::SwimGoal,
         wander_around::WanderAroundGoal,
     },
+    lightning_bolt::apply_lightning_strike,
     mob::{Mob, MobEntity},
     player::Player,
 };
@@ -196,6 +197,26 @@ impl Mob for CreeperEntity {
         })
     }
 
+    fn mob_struck_by_lightning<'a>(
+        &'a self,
+        caller: &'a Arc<dyn EntityBase>,
+        lightning: &'a dyn EntityBase,
+    ) -> EntityBaseFuture<'a, bool> {
+        Box::pin(async move {
+            apply_lightning_strike(caller, lightning).await;
+            self.charged.store(true, Ordering::Relaxed);
+            self.mob_entity
+                .living_entity
+                .entity
+                .send_meta_data(&[Metadata::new(
+                    TrackedData::CHARGED,
+                    MetaDataType::BOOLEAN,
+                    true,
+                )]);
+            true
+        })
+    }
+
     fn mob_interact<'a>(
         &'a self,
         player: &'a Arc<Player>,
@@ -226,7 +247,6 @@ impl Mob for CreeperEntity {
             )]);
 
             if player.gamemode.load() != pumpkin_util::GameMode::Creative {
-                // TODO: Handle DamageResult::Broken to broadcast item break and update player slot.
                 let _ = item_stack.damage_item(1);
             }
 
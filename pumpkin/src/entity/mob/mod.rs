@@ -4,6 +4,7 @@ use crate::entity::ai::control::MoveControlTrait;
 use crate::entity::ai::control::look_control::LookControl;
 use crate::entity::ai::control::move_control::MoveControl;
 use crate::entity::ai::goal::goal_selector::GoalSelector;
+use crate::entity::lightning_bolt::apply_lightning_strike;
 use crate::entity::player::Player;
 use crate::server::Server;
 use crate::world::World;
@@ -468,6 +469,16 @@ pub trait Mob: EntityBase + Send + Sync {
         Box::pin(async {})
     }
 
+    /// Called when a lightning bolt strikes this mob. Return `true` if the strike was fully
+    /// handled (e.g. the mob transformed), which skips the default fire and damage.
+    fn mob_struck_by_lightning<'a>(
+        &'a self,
+        _caller: &'a Arc<dyn EntityBase>,
+        _lightning: &'a dyn EntityBase,
+    ) -> EntityBaseFuture<'a, bool> {
+        Box::pin(async { false })
+    }
+
     fn get_owner_uuid(&self) -> Option<Uuid> {
         None
     }
@@ -592,6 +603,22 @@ impl<T: Mob + Send + 'static> EntityBase for T {
         })
     }
 
+    fn inactive_tick<'a>(
+        &'a self,
+        _caller: &'a Arc<dyn EntityBase>,
+        _server: &'a Server,
+    ) -> EntityBaseFuture<'a, ()> {
+        Box::pin(async move {
+            let mob_entity = self.get_mob_entity();
+            if mob_entity.breeding_cooldown.load(Relaxed) > 0 {
+                mob_entity.breeding_cooldown.fetch_sub(1, Relaxed);
+            }
+            if mob_entity.love_ticks.load(Relaxed) > 0 {
+                mob_entity.love_ticks.fetch_sub(1, Relaxed);
+            }
+        })
+    }
+
     fn is_collidable(&self, _entity: Option<Box<dyn EntityBase>>) -> bool {
         true
     }
@@ -640,6 +667,18 @@ impl<T: Mob + Send + 'static> EntityBase for T {
         Box::pin(async move { self.mob_player_collision(player).await })
     }
 
+    fn on_struck_by_lightning<'a>(
+        &'a self,
+        caller: &'a Arc<dyn EntityBase>,
+        lightning: &'a dyn EntityBase,
+    ) -> EntityBaseFuture<'a, ()> {
+        Box::pin(async move {
+            if !self.mob_struck_by_lightning(caller, lightning).await {
+                apply_lightning_strike(caller, lightning).await;
+            }
+        })
+    }
+
     fn get_entity(&self) -> &Entity {
         &self.get_mob_entity().living_entity.entity
     }
@@ -4,7 +4,6 @@ use crate::{
     server::Server,
     world::{
         World,
-        chunker::is_within_view_distance,
         portal::{NetherPortal, PortalManager, PortalSearchResult, SourcePortalInfo},
     },
 };
@@ -32,9 +31,10 @@ use pumpkin_data::{
     sound::{Sound, SoundCategory},
 };
 use pumpkin_nbt::{compound::NbtCompound, tag::NbtTag};
-use pumpkin_protocol::bedrock::client::{CAddActor, CSetActorMotion};
+use pumpkin_protocol::bedrock::client::{CAddActor, CSetActorLink, CSetActorMotion, EntityLink};
 use pumpkin_protocol::codec::var_long::VarLong;
 use pumpkin_protocol::java::client::play::{CUpdateEntityPos, CUpdateEntityPosRot};
+use pumpkin_protocol::{BClientPacket, ClientPacket};
 use pumpkin_protocol::{
     PositionFlag,
     bedrock::client::{
@@ -92,6 +92,7 @@ pub mod experience_orb;
 pub mod falling;
 pub mod hunger;
 pub mod item;
+pub mod lightning_bolt;
 pub mod living;
 pub mod mob;
 pub mod passive;
@@ -105,6 +106,23 @@ pub mod vehicle;
 mod combat;
 pub mod predicate;
 
+/// How many delta movement packets are sent before an absolute position sync corrects the
+/// rounding errors they accumulate, like vanilla's teleport delay.
+const MAX_POSITION_DELTAS: u32 = 400;
+
+/// Encodes the movement from `old` to `new` in 1/4096 blocks, as the Java delta movement packets
+/// do. Returns `None` if any axis moved too far for the short encoding, i.e. about 8 blocks.
+fn encode_position_delta(old: Vector3<f64>, new: Vector3<f64>) -> Option<Vector3<i16>> {
+    let encode = |old: f64, new: f64| {
+        i16::try_from((new * 4096.0).round() as i64 - (old * 4096.0).round() as i64).ok()
+    };
+    Some(Vector3::new(
+        encode(old.x, new.x)?,
+        encode(old.y, new.y)?,
+        encode(old.z, new.z)?,
+    ))
+}
+
 /// Returns the [`EntityStatus`] that should be broadcast when the given
 /// equipment slot breaks.
 #[must_use]
@@ -147,6 +165,18 @@ pub trait EntityBase: Send + Sync + NBTStorage + std::any::Any {
         })
     }
 
+    /// Called every tick instead of [`Self::tick`] while the entity is outside the activation
+    /// range of every player.
+    ///
+    /// Should only advance timers, such as despawn counters, and skip AI and movement.
+    fn inactive_tick<'a>(
+        &'a self,
+        _caller: &'a Arc<dyn EntityBase>,
+        _server: &'a Server,
+    ) -> EntityBaseFuture<'a, ()> {
+        Box::pin(async {})
+    }
+
     fn as_any(&self) -> &dyn std::any::Any
     where
         Self: Sized,
@@ -272,7 +302,7 @@ pub trait EntityBase: Send + Sync + NBTStorage + std::any::Any {
                     int_properties: std::collections::HashMap::new(),
                     float_properties: std::collections::HashMap::new(),
                 },
-                Vec::new(),
+                entity.bedrock_passenger_links().await,
             );
             client.send_game_packet(&packet).await;
         })
@@ -316,6 +346,16 @@ pub trait EntityBase: Send + Sync + NBTStorage + std::any::Any {
         Box::pin(async {})
     }
 
+    /// Called when a lightning bolt strikes this entity. Sets the entity on fire and deals
+    /// lightning damage by default; entities that transform when struck override this.
+    fn on_struck_by_lightning<'a>(
+        &'a self,
+        caller: &'a Arc<dyn EntityBase>,
+        lightning: &'a dyn EntityBase,
+    ) -> EntityBaseFuture<'a, ()> {
+        Box::pin(lightning_bolt::apply_lightning_strike(caller, lightning))
+    }
+
     fn set_paddle_state(&self, _left: bool, _right: bool) -> EntityBaseFuture<'_, ()> {
         Box::pin(async {})
     }
@@ -568,6 +608,8 @@ pub struct Entity {
     pub last_sent_pos: AtomicCell<Vector3<f64>>,
     /// Cache for the last sent head yaw byte
     pub last_sent_head_yaw: AtomicU8,
+    /// Delta movement packets sent since the last absolute position sync
+    pub position_deltas_sent: AtomicU32,
 }
 
 impl Entity {
@@ -686,6 +728,7 @@ impl Entity {
             last_sent_pitch: AtomicU8::new(0),
             last_sent_head_yaw: AtomicU8::new(0),
             last_sent_pos: AtomicCell::new(position),
+            position_deltas_sent: AtomicU32::new(0),
         }
     }
 
@@ -783,16 +826,17 @@ impl Entity {
 
     pub fn send_velocity(&self) {
         let velocity = self.velocity.load();
-        let chunk_pos = self.chunk_pos.load();
-        self.world.load().broadcast_to_chunk_editioned_sync(
-            chunk_pos,
-            &CEntityVelocity::new(self.entity_id.into(), velocity),
-            &CSetActorMotion::new(
-                VarULong(self.entity_id as u64),
-                Vector3::new(velocity.x as f32, velocity.y as f32, velocity.z as f32),
-                VarULong(0),
-            ),
-        );
+        self.world
+            .load()
+            .broadcast_to_entity_viewers_editioned_sync(
+                self,
+                &CEntityVelocity::new(self.entity_id.into(), velocity),
+                &CSetActorMotion::new(
+                    VarULong(self.entity_id as u64),
+                    Vector3::new(velocity.x as f32, velocity.y as f32, velocity.z as f32),
+                    VarULong(0),
+                ),
+            );
     }
 
     #[must_use]
@@ -879,7 +923,6 @@ impl Entity {
     pub fn send_rotation(&self) {
         let yaw = self.yaw.load();
         let pitch = self.pitch.load();
-        let chunk_pos = self.chunk_pos.load();
 
         // Broadcast the update packet.
 
@@ -893,8 +936,8 @@ impl Entity {
         self.last_sent_yaw.store(yaw, Relaxed);
         self.last_sent_pitch.store(pitch, Relaxed);
 
-        self.world.load().broadcast_to_chunk(
-            chunk_pos,
+        self.world.load().broadcast_to_entity_viewers(
+            self,
             &CUpdateEntityRot::new(
                 self.entity_id.into(),
                 yaw,
@@ -907,7 +950,6 @@ impl Entity {
     }
 
     pub fn send_head_rot(&self, head_yaw: u8) {
-        let chunk_pos = self.chunk_pos.load();
         if head_yaw == self.last_sent_head_yaw.load(Relaxed) {
             return;
         }
@@ -915,7 +957,7 @@ impl Entity {
 
         self.world
             .load()
-            .broadcast_to_chunk(chunk_pos, &CHeadRot::new(self.entity_id.into(), head_yaw));
+            .broadcast_to_entity_viewers(self, &CHeadRot::new(self.entity_id.into(), head_yaw));
     }
 
     fn default_portal_cooldown(&self) -> u32 {
@@ -1034,6 +1076,48 @@ impl Entity {
         adjusted_movement
     }
 
+    /// Shortens this entity's velocity so that the next move stops at the first solid block
+    /// instead of carrying it into a wall. Unlike `adjust_movement_for_collisions`, this
+    /// leaves the ground and collision flags alone, so it is safe to call right after knockback.
+    #[expect(clippy::float_cmp)]
+    pub async fn clamp_velocity_to_blocks(&self, caller: &dyn EntityBase) -> Vector3<f64> {
+        let velocity = self.velocity.load();
+        if velocity.length_squared() == 0.0 {
+            return velocity;
+        }
+
+        let bounding_box = self.bounding_box.load();
+        let (collisions, _) = self
+            .world
+            .load()
+            .get_block_collisions(bounding_box.stretch(velocity), caller)
+            .await;
+
+        let mut clamped = velocity;
+        for axis in [Axis::Y, Axis::X, Axis::Z] {
+            if clamped.get_axis(axis) == 0.0 {
+                continue;
+            }
+            let mut max_time = 1.0;
+            for inert_box in &collisions {
+                if let Some(collision_time) =
+                    bounding_box.calculate_collision_time(inert_box, clamped, axis, max_time)
+                {
+                    max_time = collision_time;
+                }
+            }
+            if max_time != 1.0 {
+                clamped.set_axis(axis, clamped.get_axis(axis) * max_time);
+            }
+        }
+
+        if clamped != velocity {
+            self.velocity.store(clamped);
+            self.velocity_dirty.store(true, Ordering::SeqCst);
+        }
+        clamped
+    }
+
     /// Applies knockback to the entity, following vanilla Minecraft's mechanics.
     /// `LivingEntity.takeKnockback()`
     /// This function calculates the entity's new velocity based on the specified knockback strength and direction.
@@ -1230,13 +1314,8 @@ impl Entity {
     pub fn send_pos_rot(&self) {
         let old = self.last_sent_pos.load();
         let new = self.pos.load();
-        let chunk_pos = self.chunk_pos.load();
 
-        let converted = Vector3::new(
-            new.x.mul_add(4096.0, -(old.x * 4096.0)) as i16,
-            new.y.mul_add(4096.0, -(old.y * 4096.0)) as i16,
-            new.z.mul_add(4096.0, -(old.z * 4096.0)) as i16,
-        );
+        let delta = encode_position_delta(old, new);
 
         let yaw = self.yaw.load();
 
@@ -1245,7 +1324,7 @@ impl Entity {
         let pitch = (pitch * 256.0 / 360.0).rem_euclid(256.0) as u8;
 
         // Only broadcast when position or rotation has actually changed.
-        let pos_changed = converted.x != 0 || converted.y != 0 || converted.z != 0;
+        let pos_changed = delta != Some(Vector3::new(0, 0, 0));
         let rot_changed =
             yaw != self.last_sent_yaw.load(Relaxed) || pitch != self.last_sent_pitch.load(Relaxed);
 
@@ -1257,6 +1336,9 @@ impl Entity {
         self.last_sent_yaw.store(yaw, Relaxed);
         self.last_sent_pitch.store(pitch, Relaxed);
 
+        let sync = pos_changed && self.needs_position_sync(delta);
+        let converted = delta.unwrap_or_default();
+
         // Dynamically pick the most efficient packet
         if pos_changed && rot_changed {
             let je_packet = CUpdateEntityPosRot::new(
@@ -1267,8 +1349,8 @@ impl Entity {
                 self.on_ground.load(Relaxed),
             );
             if self.entity_type == &EntityType::PLAYER {
-                self.world.load().broadcast_to_chunk_editioned_sync(
-                    chunk_pos,
+                self.broadcast_movement(
+                    sync,
                     &je_packet,
                     &CMovePlayer::new(
                         VarULong(self.entity_id as u64),
@@ -1294,8 +1376,8 @@ impl Entity {
                 if self.on_ground.load(Relaxed) {
                     flags |= MOVE_ACTOR_DELTA_FLAG_ON_GROUND;
                 }
-                self.world.load().broadcast_to_chunk_editioned_sync(
-                    chunk_pos,
+                self.broadcast_movement(
+                    sync,
                     &je_packet,
                     &CMoveActorDelta::new(
                         VarULong(self.entity_id as u64),
@@ -1316,8 +1398,8 @@ impl Entity {
                 self.on_ground.load(Relaxed),
             );
             if self.entity_type == &EntityType::PLAYER {
-                self.world.load().broadcast_to_chunk_editioned_sync(
-                    chunk_pos,
+                self.broadcast_movement(
+                    sync,
                     &je_packet,
                     &CMovePlayer::new(
                         VarULong(self.entity_id as u64),
@@ -1341,8 +1423,8 @@ impl Entity {
                     flags |= MOVE_ACTOR_DELTA_FLAG_ON_GROUND;
                 }
 
-                self.world.load().broadcast_to_chunk_editioned_sync(
-                    chunk_pos,
+                self.broadcast_movement(
+                    sync,
                     &je_packet,
                     &CMoveActorDelta::new(
                         VarULong(self.entity_id as u64),
@@ -1364,23 +1446,25 @@ impl Entity {
                 self.on_ground.load(Relaxed),
             );
             if self.entity_type == &EntityType::PLAYER {
-                self.world.load().broadcast_to_chunk_editioned_sync(
-                    chunk_pos,
-                    &je_packet,
-                    &CMovePlayer::new(
-                        VarULong(self.entity_id as u64),
-                        Vector3::new(new.x as f32, new.y as f32, new.z as f32),
-                        self.pitch.load(),
-                        self.yaw.load(),
-                        self.yaw.load(),
-                        CMovePlayer::MODE_ROTATION,
-                        self.on_ground.load(Relaxed),
-                        VarULong(0),
-                        0,
-                        0,
-                        VarULong(0),
-                    ),
-                );
+                self.world
+                    .load()
+                    .broadcast_to_entity_viewers_editioned_sync(
+                        self,
+                        &je_packet,
+                        &CMovePlayer::new(
+                            VarULong(self.entity_id as u64),
+                            Vector3::new(new.x as f32, new.y as f32, new.z as f32),
+                            self.pitch.load(),
+                            self.yaw.load(),
+                            self.yaw.load(),
+                            CMovePlayer::MODE_ROTATION,
+                            self.on_ground.load(Relaxed),
+                            VarULong(0),
+                            0,
+                            0,
+                            VarULong(0),
+                        ),
+                    );
             } else {
                 let mut flags = MOVE_ACTOR_DELTA_FLAG_HAS_PITCH
                     | MOVE_ACTOR_DELTA_FLAG_HAS_YAW
@@ -1388,25 +1472,62 @@ impl Entity {
                 if self.on_ground.load(Relaxed) {
                     flags |= MOVE_ACTOR_DELTA_FLAG_ON_GROUND;
                 }
-                self.world.load().broadcast_to_chunk_editioned_sync(
-                    chunk_pos,
-                    &je_packet,
-                    &CMoveActorDelta::new(
-                        VarULong(self.entity_id as u64),
-                        flags,
-                        new.x as f32,
-                        new.y as f32,
-                        new.z as f32,
-                        pitch,
-                        yaw,
-                        yaw,
-                    ),
-                );
+                self.world
+                    .load()
+                    .broadcast_to_entity_viewers_editioned_sync(
+                        self,
+                        &je_packet,
+                        &CMoveActorDelta::new(
+                            VarULong(self.entity_id as u64),
+                            flags,
+                            new.x as f32,
+                            new.y as f32,
+                            new.z as f32,
+                            pitch,
+                            yaw,
+                            yaw,
+                        ),
+                    );
             }
         }
         self.send_head_rot(yaw);
     }
 
+    /// Whether Java clients need an absolute position instead of `delta`, because the movement is
+    /// too far for the delta encoding or enough deltas were sent to let rounding errors build up.
+    fn needs_position_sync(&self, delta: Option<Vector3<i16>>) -> bool {
+        if delta.is_some() && self.position_deltas_sent.fetch_add(1, Relaxed) < MAX_POSITION_DELTAS
+        {
+            return false;
+        }
+        self.position_deltas_sent.store(0, Relaxed);
+        true
+    }
+
+    /// Broadcasts a movement to the entity's viewers, sending Java clients an absolute position
+    /// sync instead of `je_packet` if `sync` is set.
+    fn broadcast_movement<J: ClientPacket, B: BClientPacket>(
+        &self,
+        sync: bool,
+        je_packet: &J,
+        be_packet: &B,
+    ) {
+        let world = self.world.load();
+        if sync {
+            let sync_packet = CEntityPositionSync::new(
+                self.entity_id.into(),
+                self.pos.load(),
+                self.velocity.load(),
+                self.yaw.load(),
+                self.pitch.load(),
+                self.on_ground.load(Relaxed),
+            );
+            world.broadcast_to_entity_viewers_editioned_sync(self, &sync_packet, be_packet);
+        } else {
+            world.broadcast_to_entity_viewers_editioned_sync(self, je_packet, be_packet);
+        }
+    }
+
     pub fn update_last_pos(&self) -> Vector3<f64> {
         let pos = self.pos.load();
         let old = self.last_pos.load();
@@ -1418,21 +1539,19 @@ impl Entity {
     pub fn send_pos(&self) {
         let old = self.last_sent_pos.load();
         let new = self.pos.load();
-        let chunk_pos = self.chunk_pos.load();
 
-        let converted = Vector3::new(
-            new.x.mul_add(4096.0, -(old.x * 4096.0)) as i16,
-            new.y.mul_add(4096.0, -(old.y * 4096.0)) as i16,
-            new.z.mul_add(4096.0, -(old.z * 4096.0)) as i16,
-        );
+        let delta = encode_position_delta(old, new);
 
         // Only broadcast when position has actually changed.
-        if converted.x == 0 && converted.y == 0 && converted.z == 0 {
+        if delta == Some(Vector3::new(0, 0, 0)) {
             return;
         }
 
         self.last_sent_pos.store(new);
 
+        let sync = self.needs_position_sync(delta);
+        let converted = delta.unwrap_or_default();
+
         let je_packet = CUpdateEntityPos::new(
             self.entity_id.into(),
             Vector3::new(converted.x, converted.y, converted.z),
@@ -1440,8 +1559,8 @@ impl Entity {
         );
 
         if self.entity_type == &EntityType::PLAYER {
-            self.world.load().broadcast_to_chunk_editioned_sync(
-                chunk_pos,
+            self.broadcast_movement(
+                sync,
                 &je_packet,
                 &CMovePlayer::new(
                     VarULong(self.entity_id as u64),
@@ -1465,8 +1584,8 @@ impl Entity {
                 flags |= MOVE_ACTOR_DELTA_FLAG_ON_GROUND;
             }
 
-            self.world.load().broadcast_to_chunk_editioned_sync(
-                chunk_pos,
+            self.broadcast_movement(
+                sync,
                 &je_packet,
                 &CMoveActorDelta::new(
                     VarULong(self.entity_id as u64),
@@ -1900,11 +2019,14 @@ impl Entity {
                 let source_axis = source_portal.as_ref().map(|p| p.axis);
                 drop(portal_manager);
 
-                let is_end_portal = dest_world.dimension == Dimension::THE_END
-                    || self.world.load().dimension == Dimension::THE_END;
+                // Dimensions reusing the End's type are separate levels, so compare names
+                let is_end_portal = dest_world.dimension.minecraft_name
+                    == Dimension::THE_END.minecraft_name
+                    || self.world.load().dimension.minecraft_name
+                        == Dimension::THE_END.minecraft_name;
 
                 let (teleport_pos, new_yaw) = if is_end_portal {
-                    if dest_world.dimension == Dimension::THE_END {
+                    if dest_world.dimension.minecraft_name == Dimension::THE_END.minecraft_name {
                         // Entering the End: spawn on the obsidian platform at (100, 50, 0)
                         (Vector3::new(100.5f64, 50.0f64, 0.5f64), None)
                     } else {
@@ -1947,8 +2069,12 @@ impl Entity {
                     )
                     .await
                 {
-                    NetherPortal::build_portal_frame(&dest_world, build_pos, axis, is_fallback)
-                        .await;
+                    if !NetherPortal::build_portal_frame(&dest_world, build_pos, axis, is_fallback)
+                        .await
+                    {
+                        // A plugin cancelled the destination portal, so the entity stays put
+                        return;
+                    }
                     let new_portal = PortalSearchResult {
                         lower_corner: build_pos,
                         axis,
@@ -2034,14 +2160,14 @@ impl Entity {
             return;
         }
 
-        if (portal_world.dimension == Dimension::THE_NETHER
+        if (portal_world.dimension.minecraft_name == Dimension::THE_NETHER.minecraft_name
             && !portal_world
                 .server
                 .upgrade()
                 .unwrap()
                 .basic_config
                 .allow_nether)
-            || (portal_world.dimension == Dimension::THE_END
+            || (portal_world.dimension.minecraft_name == Dimension::THE_END.minecraft_name
                 && !portal_world
                     .server
                     .upgrade()
@@ -2213,6 +2339,8 @@ impl Entity {
 
     /// Removes the `Entity` from their current `World`
     pub async fn remove(&self) {
+        self.eject_passengers().await;
+        self.stop_riding().await;
         self.world.load().remove_entity(self).await;
     }
 
@@ -2251,23 +2379,44 @@ impl Entity {
             z = (rand::random::<f64>() - rand::random::<f64>()) * 0.01;
         }
 
+        self.velocity.store(Self::knockback_velocity(
+            self.velocity.load(),
+            self.on_ground.load(Relaxed),
+            strength,
+            x,
+            z,
+        ));
+    }
+
+    /// Returns `velocity` after a knockback of `strength` in the `(x, z)` direction.
+    /// Half of the current velocity is kept, like vanilla; non-positive strengths leave it untouched.
+    #[must_use]
+    pub fn knockback_velocity(
+        velocity: Vector3<f64>,
+        on_ground: bool,
+        strength: f64,
+        x: f64,
+        z: f64,
+    ) -> Vector3<f64> {
+        if strength <= 0.0 {
+            return velocity;
+        }
         let var8 = Vector3::new(x, 0.0, z).normalize() * strength;
-        let velocity = self.velocity.load();
-        self.velocity.store(Vector3::new(
+        Vector3::new(
             velocity.x / 2.0 - var8.x,
-            if self.on_ground.load(Relaxed) {
+            if on_ground {
                 (velocity.y / 2.0 + strength).min(0.4)
             } else {
                 velocity.y
             },
             velocity.z / 2.0 - var8.z,
-        ));
+        )
     }
 
     pub async fn set_sneaking(&self, sneaking: bool) {
         //assert!(self.sneaking.load(Relaxed) != sneaking);
         self.sneaking.store(sneaking, Relaxed);
-        self.set_flag(Flag::Sneaking, sneaking).await;
+        self.write_flag(Flag::Sneaking, sneaking).await;
     }
     pub fn is_sneaking(&self) -> bool {
         self.sneaking.load(Ordering::Relaxed)
@@ -2276,7 +2425,7 @@ impl Entity {
     pub async fn set_swimming(&self, invisible: bool) {
         if self.swimming.load(Ordering::Relaxed) != invisible {
             self.swimming.store(invisible, Relaxed);
-            self.set_flag(Flag::Swimming, invisible).await;
+            self.write_flag(Flag::Swimming, invisible).await;
         }
     }
 
@@ -2284,7 +2433,7 @@ impl Entity {
     pub async fn set_invisible(&self, invisible: bool) {
         if self.invisible.load(Ordering::Relaxed) != invisible {
             self.invisible.store(invisible, Relaxed);
-            self.set_flag(Flag::Invisible, invisible).await;
+            self.write_flag(Flag::Invisible, invisible).await;
         }
     }
 
@@ -2292,7 +2441,7 @@ impl Entity {
     pub async fn set_glowing(&self, glowing: bool) {
         if self.glowing.load(Ordering::Relaxed) != glowing {
             self.glowing.store(glowing, Ordering::Relaxed);
-            self.set_flag(Flag::Glowing, glowing).await;
+            self.write_flag(Flag::Glowing, glowing).await;
         }
     }
 
@@ -2300,7 +2449,7 @@ impl Entity {
     pub async fn set_on_fire(&self, on_fire: bool) {
         if self.has_visual_fire.load(Ordering::Relaxed) != on_fire {
             self.has_visual_fire.store(on_fire, Ordering::Relaxed);
-            self.set_flag(Flag::OnFire, on_fire).await;
+            self.write_flag(Flag::OnFire, on_fire).await;
         }
     }
 
@@ -2412,26 +2561,49 @@ impl Entity {
     pub async fn set_sprinting(&self, sprinting: bool) {
         //assert!(self.sprinting.load(Relaxed) != sprinting);
         self.sprinting.store(sprinting, Relaxed);
-        self.set_flag(Flag::Sprinting, sprinting).await;
+        self.write_flag(Flag::Sprinting, sprinting).await;
     }
 
     pub fn is_sprinting(&self) -> bool {
         self.sprinting.load(Ordering::Relaxed)
     }
-    pub fn check_fall_flying(&self) -> bool {
-        !self.on_ground.load(Relaxed)
-    }
 
+    /// Sets whether the entity is gliding and sends updated metadata.
+    ///
+    /// Landing is detected both by the movement handler and the tick, so this ignores a value
+    /// that is already set.
     pub async fn set_fall_flying(&self, fall_flying: bool) {
-        assert_ne!(self.fall_flying.load(Relaxed), fall_flying);
-        self.fall_flying.store(fall_flying, Relaxed);
-        self.set_flag(Flag::FallFlying, fall_flying).await;
+        if self.fall_flying.swap(fall_flying, Relaxed) != fall_flying {
+            self.write_flag(Flag::FallFlying, fall_flying).await;
+        }
     }
     pub fn is_fall_flying(&self) -> bool {
         self.fall_flying.load(Ordering::Relaxed)
     }
 
-    async fn set_flag(&self, flag: Flag, value: bool) {
+    /// Sets one of the shared entity flags and broadcasts the updated metadata.
+    ///
+    /// This goes through the dedicated setter of each flag, so the entity's own state (e.g.
+    /// [`Self::is_sneaking`]) stays in sync with what clients see.
+    pub async fn set_flag(&self, flag: Flag, value: bool) {
+        match flag {
+            Flag::OnFire => self.set_on_fire(value).await,
+            Flag::Sneaking => self.set_sneaking(value).await,
+            Flag::Sprinting => self.set_sprinting(value).await,
+            Flag::Swimming => self.set_swimming(value).await,
+            Flag::Invisible => self.set_invisible(value).await,
+            Flag::Glowing => self.set_glowing(value).await,
+            Flag::FallFlying => self.set_fall_flying(value).await,
+        }
+    }
+
+    /// Returns whether one of the shared entity flags is currently set.
+    pub fn has_flag(&self, flag: Flag) -> bool {
+        let mask = (1i8).wrapping_shl(flag as u32);
+        self.flags.load(Ordering::Relaxed) & mask != 0
+    }
+
+    async fn write_flag(&self, flag: Flag, value: bool) {
         let index = flag as u8;
         let mask = (1i8).wrapping_shl(index as u32);
         let new_je_flags = if value {
@@ -2470,35 +2642,30 @@ impl Entity {
             }
 
             let world = self.world.load();
-            let chunk_pos = self.chunk_pos.load();
             for player in world.players.load().iter() {
-                if let ClientPlatform::Bedrock(client) = &player.client {
-                    let center = player.get_entity().chunk_pos.load();
-                    let view_distance =
-                        crate::world::chunker::get_view_distance(player).get() as i32;
-
-                    if is_within_view_distance(chunk_pos, center, view_distance) {
-                        let mut metadata = EntityMetadata(std::collections::HashMap::new());
-                        metadata.set(
-                            entity_data_key::FLAGS,
-                            MetadataValue::Long(self.bedrock_flags.load(Ordering::Relaxed)),
-                        );
-                        metadata.set(
-                            entity_data_key::FLAGS_TWO,
-                            MetadataValue::Long(self.bedrock_flags_two.load(Ordering::Relaxed)),
-                        );
-                        client
-                            .send_game_packet(&CSetActorData {
-                                actor_runtime_id: VarULong(self.entity_id as u64),
-                                metadata,
-                                synced_properties: PropertySyncData {
-                                    int_properties: std::collections::HashMap::new(),
-                                    float_properties: std::collections::HashMap::new(),
-                                },
-                                tick: VarULong(0),
-                            })
-                            .await;
-                    }
+                if let ClientPlatform::Bedrock(client) = &player.client
+                    && world.is_entity_visible_to(self, player)
+                {
+                    let mut metadata = EntityMetadata(std::collections::HashMap::new());
+                    metadata.set(
+                        entity_data_key::FLAGS,
+                        MetadataValue::Long(self.bedrock_flags.load(Ordering::Relaxed)),
+                    );
+                    metadata.set(
+                        entity_data_key::FLAGS_TWO,
+                        MetadataValue::Long(self.bedrock_flags_two.load(Ordering::Relaxed)),
+                    );
+                    client
+                        .send_game_packet(&CSetActorData {
+                            actor_runtime_id: VarULong(self.entity_id as u64),
+                            metadata,
+                            synced_properties: PropertySyncData {
+                                int_properties: std::collections::HashMap::new(),
+                                float_properties: std::collections::HashMap::new(),
+                            },
+                            tick: VarULong(0),
+                        })
+                        .await;
                 }
             }
         }
@@ -2513,24 +2680,19 @@ impl Entity {
 
     pub fn send_meta_data<T: Serialize>(&self, meta: &[Metadata<T>]) {
         let world = self.world.load();
-        let chunk_pos = self.chunk_pos.load();
         for player in world.players.load().iter() {
-            if let ClientPlatform::Java(client) = &player.client {
-                // Apply Chebyshev distance check
-                let center = player.get_entity().chunk_pos.load();
-                let view_distance = crate::world::chunker::get_view_distance(player).get() as i32;
-
-                if is_within_view_distance(chunk_pos, center, view_distance) {
-                    let mut buf = Vec::new();
-                    for m in meta {
-                        m.write(&mut buf, &client.version.load()).unwrap();
-                    }
-                    buf.put_u8(255);
-                    player.client.try_enqueue_packet(&CSetEntityMetadata::new(
-                        self.entity_id.into(),
-                        buf.into(),
-                    ));
+            if let ClientPlatform::Java(client) = &player.client
+                && world.is_entity_visible_to(self, player)
+            {
+                let mut buf = Vec::new();
+                for m in meta {
+                    m.write(&mut buf, &client.version.load()).unwrap();
                 }
+                buf.put_u8(255);
+                player.client.try_enqueue_packet(&CSetEntityMetadata::new(
+                    self.entity_id.into(),
+                    buf.into(),
+                ));
             }
         }
     }
@@ -2660,6 +2822,7 @@ impl Entity {
         }
         // Update cache so we don't send rubberbanding deltas
         self.last_sent_pos.store(position);
+        self.position_deltas_sent.store(0, Relaxed);
         if let Some(yaw) = yaw {
             self.last_sent_yaw
                 .store((yaw * 256.0 / 360.0).rem_euclid(256.0) as u8, Relaxed);
@@ -2670,9 +2833,8 @@ impl Entity {
             self.last_sent_pitch
                 .store((pitch * 256.0 / 360.0).rem_euclid(256.0) as u8, Relaxed);
         }
-        let chunk_pos = self.chunk_pos.load();
-        self.world.load().broadcast_to_chunk(
-            chunk_pos,
+        self.world.load().broadcast_to_entity_viewers(
+            self,
             &CEntityPositionSync::new(
                 self.entity_id.into(),
                 position,
@@ -2723,6 +2885,12 @@ impl Entity {
         *passenger_entity.vehicle.lock().await = Some(vehicle);
 
         let mut passengers = self.passengers.lock().await;
+        // Bedrock links each passenger on its own, the first one controls the vehicle
+        let link_type = if passengers.is_empty() {
+            EntityLink::TYPE_RIDER
+        } else {
+            EntityLink::TYPE_PASSENGER
+        };
         passengers.push(passenger);
 
         let passenger_ids: Vec<VarInt> = passengers
@@ -2731,13 +2899,88 @@ impl Entity {
             .collect();
 
         let world = self.world.load();
-        let chunk_pos = self.chunk_pos.load();
-        world.broadcast_to_chunk(
-            chunk_pos,
+        world.broadcast_to_entity_viewers_editioned_sync(
+            self,
             &CSetPassengers::new(VarInt(self.entity_id), &passenger_ids),
+            &CSetActorLink::new(EntityLink::new(
+                self.entity_id,
+                passenger_entity.entity_id,
+                link_type,
+            )),
         );
     }
 
+    /// The links of this entity's passengers, sent to Bedrock clients when spawning it.
+    pub async fn bedrock_passenger_links(&self) -> Vec<EntityLink> {
+        self.passengers
+            .lock()
+            .await
+            .iter()
+            .enumerate()
+            .map(|(i, passenger)| {
+                let link_type = if i == 0 {
+                    EntityLink::TYPE_RIDER
+                } else {
+                    EntityLink::TYPE_PASSENGER
+                };
+                EntityLink::new(self.entity_id, passenger.get_entity().entity_id, link_type)
+            })
+            .collect()
+    }
+
+    /// Dismounts this entity from its vehicle, if it has one.
+    pub async fn stop_riding(&self) {
+        let vehicle = self.vehicle.lock().await.clone();
+        if let Some(vehicle) = vehicle {
+            vehicle.get_entity().remove_passenger(self.entity_id).await;
+        }
+    }
+
+    /// Dismounts every passenger riding this entity.
+    pub async fn eject_passengers(&self) {
+        let passenger_ids: Vec<i32> = self
+            .passengers
+            .lock()
+            .await
+            .iter()
+            .map(|p| p.get_entity().entity_id)
+            .collect();
+        for passenger_id in passenger_ids {
+            self.remove_passenger(passenger_id).await;
+        }
+    }
+
+    /// Moves this entity along with its vehicle, seating it on the vehicle's passenger
+    /// attachment point.
+    async fn follow_vehicle(&self) {
+        let vehicle = self.vehicle.lock().await.clone();
+        if let Some(vehicle) = vehicle {
+            let vehicle = vehicle.get_entity();
+            let offset = vehicle.passenger_attachment_height() - self.vehicle_attachment_height();
+            self.set_pos(vehicle.pos.load().add_raw(0.0, offset, 0.0));
+        }
+    }
+
+    /// How far above its feet this entity seats its passengers (vanilla `getPassengerAttachmentPoint`).
+    fn passenger_attachment_height(&self) -> f64 {
+        match self.entity_type.id {
+            id if id == EntityType::MINECART.id => 0.1875,
+            id if id == EntityType::PIG.id => 0.86875,
+            id if id == EntityType::HORSE.id => 1.44375,
+            _ => f64::from(self.entity_dimension.load().height),
+        }
+    }
+
+    /// How far above this entity's feet the point is that sits on a vehicle's passenger
+    /// attachment point (vanilla `getVehicleAttachmentPoint`).
+    fn vehicle_attachment_height(&self) -> f64 {
+        if self.entity_type == &EntityType::PLAYER {
+            0.6
+        } else {
+            0.0
+        }
+    }
+
     #[allow(clippy::too_many_lines)]
     pub async fn remove_passenger(&self, passenger_id: i32) {
         let mut passengers = self.passengers.lock().await;
@@ -2794,15 +3037,30 @@ impl Entity {
             // then broadcasts to other players separately.
             let world = self.world.load();
             let passengers_packet = CSetPassengers::new(VarInt(self.entity_id), &passenger_ids);
+            let link_packet = CSetActorLink::new(EntityLink::new(
+                self.entity_id,
+                passenger_entity.entity_id,
+                EntityLink::TYPE_REMOVE,
+            ));
             if let Some(player) = passenger.get_player() {
-                player.client.enqueue_packet(&passengers_packet).await;
-                world.broadcast_to_chunk_except(
+                player
+                    .client
+                    .enqueue_packet_editioned(&passengers_packet, &link_packet)
+                    .await;
+                world
+                    .broadcast_to_chunk_except_editioned(
+                        chunk_pos,
+                        &[player.get_entity().entity_uuid],
+                        &passengers_packet,
+                        &link_packet,
+                    )
+                    .await;
+            } else {
+                world.broadcast_to_chunk_editioned_sync(
                     chunk_pos,
-                    &[player.get_entity().entity_uuid],
                     &passengers_packet,
+                    &link_packet,
                 );
-            } else {
-                world.broadcast_to_chunk(chunk_pos, &passengers_packet);
             }
 
             // Calculate dismount offset (vanilla getPassengerDismountOffset)
@@ -2909,6 +3167,26 @@ impl Entity {
                         ],
                     ))
                     .await;
+                player
+                    .client
+                    .enqueue_be_packet(&CMovePlayer::new(
+                        VarULong(passenger_entity.entity_id as u64),
+                        Vector3::new(
+                            dismount_pos.x as f32,
+                            dismount_pos.y as f32 + passenger_entity.entity_type.eye_height,
+                            dismount_pos.z as f32,
+                        ),
+                        passenger_entity.pitch.load(),
+                        passenger_entity.yaw.load(),
+                        passenger_entity.head_yaw.load(),
+                        CMovePlayer::MODE_TELEPORT,
+                        false,
+                        VarULong(0),
+                        0,
+                        0,
+                        VarULong(0),
+                    ))
+                    .await;
                 // Vanilla: setSneaking(false) after dismount via sneak input
                 if passenger_entity.sneaking.load(Relaxed) {
                     passenger_entity.set_sneaking(false).await;
@@ -2965,6 +3243,31 @@ impl Entity {
     }
 }
 
+/// Encodes a vector as the three-double list vanilla uses for `Pos` and `Motion`.
+fn vector3_to_nbt(vector: Vector3<f64>) -> NbtTag {
+    NbtTag::List(vec![vector.x.into(), vector.y.into(), vector.z.into()])
+}
+
+/// Decodes a three-double list such as `Pos` or `Motion`, if present and well formed.
+fn vector3_from_nbt(nbt: &NbtCompound, name: &str) -> Option<Vector3<f64>> {
+    match nbt.get_list(name)? {
+        [x, y, z] => Some(Vector3::new(
+            x.extract_double()?,
+            y.extract_double()?,
+            z.extract_double()?,
+        )),
+        _ => None,
+    }
+}
+
+/// Decodes the `Rotation` list as `(yaw, pitch)`.
+fn rotation_from_nbt(nbt: &NbtCompound) -> Option<(f32, f32)> {
+    match nbt.get_list("Rotation")? {
+        [yaw, pitch] => Some((yaw.extract_float()?, pitch.extract_float()?)),
+        _ => None,
+    }
+}
+
 impl NBTStorage for Entity {
     fn write_nbt<'a>(&'a self, nbt: &'a mut NbtCompound) -> NbtFuture<'a, ()> {
         Box::pin(async move {
@@ -2983,23 +3286,8 @@ impl NBTStorage for Entity {
                     (uuid & 0xFFFF_FFFF) as i32,
                 ]),
             );
-            nbt.put(
-                "Pos",
-                NbtTag::List(vec![
-                    position.x.into(),
-                    position.y.into(),
-                    position.z.into(),
-                ]),
-            );
-            let velocity = self.velocity.load();
-            nbt.put(
-                "Motion",
-                NbtTag::List(vec![
-                    velocity.x.into(),
-                    velocity.y.into(),
-                    velocity.z.into(),
-                ]),
-            );
+            nbt.put("Pos", vector3_to_nbt(position));
+            nbt.put("Motion", vector3_to_nbt(self.velocity.load()));
             nbt.put(
                 "Rotation",
                 NbtTag::List(vec![self.yaw.load().into(), self.pitch.load().into()]),
@@ -3018,22 +3306,15 @@ impl NBTStorage for Entity {
 
     fn read_nbt_non_mut<'a>(&'a self, nbt: &'a NbtCompound) -> NbtFuture<'a, ()> {
         Box::pin(async {
-            let position = nbt.get_list("Pos").unwrap();
-            let x = position[0].extract_double().unwrap_or(0.0);
-            let y = position[1].extract_double().unwrap_or(0.0);
-            let z = position[2].extract_double().unwrap_or(0.0);
-            let pos = Vector3::new(x, y, z);
-            self.set_pos(pos);
-            self.last_sent_pos.store(pos);
-            self.first_loaded_chunk_position.store(Some(pos.to_i32()));
-            let velocity = nbt.get_list("Motion").unwrap();
-            let x = velocity[0].extract_double().unwrap_or(0.0);
-            let y = velocity[1].extract_double().unwrap_or(0.0);
-            let z = velocity[2].extract_double().unwrap_or(0.0);
-            self.velocity.store(Vector3::new(x, y, z));
-            let rotation = nbt.get_list("Rotation").unwrap();
-            let yaw = rotation[0].extract_float().unwrap_or(0.0);
-            let pitch = rotation[1].extract_float().unwrap_or(0.0);
+            if let Some(pos) = vector3_from_nbt(nbt, "Pos") {
+                self.set_pos(pos);
+                self.last_sent_pos.store(pos);
+                self.first_loaded_chunk_position.store(Some(pos.to_i32()));
+            }
+            if let Some(velocity) = vector3_from_nbt(nbt, "Motion") {
+                self.velocity.store(velocity);
+            }
+            let (yaw, pitch) = rotation_from_nbt(nbt).unwrap_or((0.0, 0.0));
             self.set_rotation(yaw, pitch);
             let yaw_byte = (yaw * 256.0 / 360.0).rem_euclid(256.0) as u8;
             let pitch_byte = (pitch * 256.0 / 360.0).rem_euclid(256.0) as u8;
@@ -3078,6 +3359,7 @@ impl EntityBase for Entity {
             }
 
             self.update_last_pos();
+            self.follow_vehicle().await;
             self.tick_portal(caller).await;
             self.update_fluid_state(caller).await;
             self.check_out_of_world(&**caller).await;
@@ -3216,6 +3498,53 @@ impl Flag {
 mod tests {
     use super::*;
 
+    #[test]
+    fn transform_nbt_round_trips_fractional_values() {
+        let position = Vector3::new(12.375, 64.5, -803.0625);
+        let velocity = Vector3::new(0.25, -0.0784, 0.0);
+        let mut nbt = NbtCompound::new();
+        nbt.put("Pos", vector3_to_nbt(position));
+        nbt.put("Motion", vector3_to_nbt(velocity));
+        nbt.put(
+            "Rotation",
+            NbtTag::List(vec![(-137.5f32).into(), 42.25f32.into()]),
+        );
+
+        assert_eq!(vector3_from_nbt(&nbt, "Pos"), Some(position));
+        assert_eq!(vector3_from_nbt(&nbt, "Motion"), Some(velocity));
+        assert_eq!(rotation_from_nbt(&nbt), Some((-137.5, 42.25)));
+    }
+
+    #[test]
+    fn transform_nbt_rejects_missing_or_malformed_lists() {
+        let mut nbt = NbtCompound::new();
+        assert_eq!(vector3_from_nbt(&nbt, "Pos"), None);
+        assert_eq!(rotation_from_nbt(&nbt), None);
+
+        nbt.put("Pos", NbtTag::List(vec![1.0f64.into(), 2.0f64.into()]));
+        nbt.put("Rotation", NbtTag::List(vec![1.0f32.into()]));
+        assert_eq!(vector3_from_nbt(&nbt, "Pos"), None);
+        assert_eq!(rotation_from_nbt(&nbt), None);
+    }
+
+    #[test]
+    fn position_delta_falls_back_beyond_short_range() {
+        let old = Vector3::new(0.0, 64.0, 0.0);
+        assert_eq!(
+            encode_position_delta(old, Vector3::new(0.5, 63.0, -7.5)),
+            Some(Vector3::new(2048, -4096, -30720))
+        );
+        assert_eq!(encode_position_delta(old, old), Some(Vector3::new(0, 0, 0)));
+        assert_eq!(
+            encode_position_delta(old, Vector3::new(8.0, 64.0, 0.0)),
+            None
+        );
+        assert_eq!(
+            encode_position_delta(old, Vector3::new(0.0, 50.0, 0.0)),
+            None
+        );
+    }
+
     #[test]
     fn equipment_break_status_maps_all_slots() {
         // Status bytes from vanilla EntityEvent: mainhand=47, offhand=48,
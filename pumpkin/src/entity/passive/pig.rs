@@ -1,10 +1,12 @@
-use std::sync::{Arc, Weak};
+use std::sync::{Arc, Weak, atomic::Ordering::Relaxed};
 
 use pumpkin_data::item_stack::ItemStack;
 use pumpkin_data::particle;
 use pumpkin_data::sound::{Sound, SoundCategory};
 use pumpkin_data::{entity::EntityType, item::Item};
+use pumpkin_util::Difficulty;
 use pumpkin_util::math::vector3::Vector3;
+use uuid::Uuid;
 
 use crate::entity::{
     Entity, EntityBase, EntityBaseFuture, NBTStorage, NbtFuture,
@@ -15,6 +17,7 @@ use crate::entity::{
     },
     mob::{Mob, MobEntity},
     player::Player,
+    r#type::from_type,
 };
 use pumpkin_nbt::compound::NbtCompound;
 
@@ -77,6 +80,41 @@ impl Mob for PigEntity {
         &self.mob_entity
     }
 
+    fn mob_struck_by_lightning<'a>(
+        &'a self,
+        _caller: &'a Arc<dyn EntityBase>,
+        _lightning: &'a dyn EntityBase,
+    ) -> EntityBaseFuture<'a, bool> {
+        Box::pin(async move {
+            let entity = &self.mob_entity.living_entity.entity;
+            let world = entity.world.load_full();
+            if world.level_info.load().difficulty == Difficulty::Peaceful {
+                return false;
+            }
+
+            let piglin = from_type(
+                &EntityType::ZOMBIFIED_PIGLIN,
+                entity.pos.load(),
+                &world,
+                Uuid::new_v4(),
+            );
+            let piglin_entity = piglin.get_entity();
+            piglin_entity.set_rotation(entity.yaw.load(), entity.pitch.load());
+            piglin_entity.head_yaw.store(entity.head_yaw.load());
+            piglin_entity.body_yaw.store(entity.body_yaw.load());
+            piglin_entity
+                .custom_name
+                .store(entity.custom_name.load_full());
+            piglin_entity
+                .custom_name_visible
+                .store(entity.custom_name_visible.load(Relaxed), Relaxed);
+
+            entity.remove().await;
+            world.spawn_entity(piglin).await;
+            true
+        })
+    }
+
     fn mob_interact<'a>(
         &'a self,
         player: &'a Arc<Player>,
@@ -19,14 +19,16 @@ use pumpkin_data::meta_data_type::MetaDataType;
 use pumpkin_data::tracked_data::TrackedData;
 use pumpkin_inventory::player::ender_chest_inventory::EnderChestInventory;
 use pumpkin_protocol::bedrock::client::AbilityLayer;
+use pumpkin_protocol::bedrock::client::modal_form_request::CModalFormRequest;
 use pumpkin_protocol::bedrock::client::play_status::CPlayStatus;
 use pumpkin_protocol::bedrock::client::set_time::CSetTime;
 use pumpkin_protocol::bedrock::client::update_abilities::{Ability, CUpdateAbilities};
+use pumpkin_protocol::bedrock::client::update_block::CUpdateBlock;
 use pumpkin_protocol::bedrock::server::text::SText;
 use pumpkin_protocol::codec::item_stack_seralizer::ItemStackSerializer;
 use pumpkin_util::translation::Locale;
 use pumpkin_world::chunk::{ChunkData, ChunkEntityData};
-use pumpkin_world::inventory::Inventory;
+use pumpkin_world::inventory::{Clearable, Inventory};
 use tokio::sync::Mutex;
 use tokio::task::JoinHandle;
 use tracing::{debug, warn};
@@ -36,17 +38,25 @@ use advancement::PlayerAdvancement;
 use pumpkin_data::attributes::Attributes;
 use pumpkin_data::block_properties::{BlockProperties, HorizontalFacing};
 use pumpkin_data::damage::DamageType;
-use pumpkin_data::data_component_impl::{AttributeModifiersImpl, EnchantmentsImpl, Operation};
-use pumpkin_data::data_component_impl::{EquipmentSlot, EquippableImpl, ToolImpl, WeaponImpl};
+use pumpkin_data::data_component::DataComponent;
+use pumpkin_data::data_component_impl::{
+    AttributeModifiersImpl, CanBreakImpl, EnchantmentsImpl, Operation,
+};
+use pumpkin_data::data_component_impl::{
+    DataComponentImpl, EquipmentSlot, EquippableImpl, ToolImpl, WeaponImpl, WrittenBookContentImpl,
+};
 use pumpkin_data::effect::StatusEffect;
 use pumpkin_data::entity::{EntityPose, EntityStatus, EntityType};
 use pumpkin_data::item_stack::ItemStack;
 use pumpkin_data::sound::{Sound, SoundCategory};
 use pumpkin_data::statistic::StatisticCategory;
 use pumpkin_data::tag::Taggable;
-use pumpkin_data::{Block, BlockState, Enchantment, screen::WindowType, tag, translation};
+use pumpkin_data::{
+    Block, BlockDirection, BlockState, Enchantment, screen::WindowType, tag, translation,
+};
 use pumpkin_inventory::player::{
-    player_inventory::PlayerInventory, player_screen_handler::PlayerScreenHandler,
+    player_inventory::{InventorySnapshot, PlayerInventory},
+    player_screen_handler::PlayerScreenHandler,
 };
 use pumpkin_inventory::screen_handler::{
     ClickType, InventoryPlayer, PlayerFuture, ScreenHandler, ScreenHandlerFactory,
@@ -57,31 +67,40 @@ use pumpkin_macros::send_cancellable;
 use pumpkin_nbt::compound::NbtCompound;
 use pumpkin_nbt::tag::NbtTag;
 use pumpkin_protocol::IdOr;
+use pumpkin_protocol::PositionFlag;
 use pumpkin_protocol::SoundEvent;
 use pumpkin_protocol::bedrock::client::container_open::CContainerOpen;
 use pumpkin_protocol::codec::var_int::VarInt;
 use pumpkin_protocol::codec::var_long::VarLong;
 use pumpkin_protocol::java::client::play::{
-    Animation, CAcknowledgeBlockChange, CActionBar, CAwardStats, CChangeDifficulty,
-    CCloseContainer, CCombatDeath, CCustomPayload, CDisguisedChatMessage, CEntityAnimation,
-    CEntityPositionSync, CGameEvent, CItemCooldown, CMapItemData, COpenScreen, CParticle,
-    CPlayerAbilities, CPlayerInfoUpdate, CPlayerPosition, CPlayerSpawnPosition, CRespawn,
-    CSetContainerContent, CSetContainerProperty, CSetContainerSlot, CSetCursorItem, CSetEquipment,
-    CSetExperience, CSetHealth, CSetPlayerInventory, CSetSelectedSlot, CSoundEffect, CStopSound,
-    CSubtitle, CSystemChatMessage, CTabList, CTitleAnimation, CTitleText, CUnloadChunk,
+    Animation, CAcknowledgeBlockChange, CActionBar, CAddResourcePack, CAwardStats, CBlockUpdate,
+    CChangeDifficulty, CClearTitle, CCloseContainer, CCombatDeath, CCustomPayload,
+    CDisguisedChatMessage, CEntityAnimation, CEntityPositionSync, CGameEvent, CItemCooldown,
+    CMapItemData, CMultiBlockUpdate, COpenBook, COpenScreen, CParticle, CPlayerAbilities,
+    CPlayerInfoUpdate, CPlayerPosition, CPlayerSpawnPosition, CRemoveEntities, CRemovePlayerInfo,
+    CRemoveResourcePack, CRespawn, CSetCamera, CSetContainerContent, CSetContainerProperty,
+    CSetContainerSlot, CSetCursorItem, CSetEntityMetadata, CSetEquipment, CSetExperience,
+    CSetHealth, CSetPlayerInventory, CSetSelectedSlot, CSetSimulationDistance, CSoundEffect,
+    CStopSound, CSubtitle, CSystemChatMessage, CTabList, CTitleAnimation, CTitleText, CUnloadChunk,
     CUpdateMobEffect, CUpdateTime, GameEvent, MapIcon, MapPatch, Metadata, PlayerAction,
-    PlayerInfoFlags, PreviousMessage, Statistic,
+    PlayerInfoFlags, PreviousMessage, SerializeParticleData, Statistic,
 };
 use pumpkin_protocol::java::server::play::{
-    SClickSlot, SContainerButtonClick, SRenameItem, SlotActionType,
+    SClickSlot, SContainerButtonClick, SPlayerLoaded, SRenameItem, SlotActionType,
 };
+use pumpkin_protocol::packet::MultiVersionJavaPacket;
 use pumpkin_util::math::{
-    boundingbox::BoundingBox, experience, position::BlockPos, vector2::Vector2, vector3::Vector3,
+    boundingbox::BoundingBox,
+    experience,
+    position::{BlockPos, chunk_section_from_pos},
+    vector2::Vector2,
+    vector3::Vector3,
 };
 use pumpkin_util::permission::PermissionLvl;
 use pumpkin_util::resource_location::ResourceLocation;
 use pumpkin_util::text::TextComponent;
 use pumpkin_util::text::click::ClickEvent;
+use pumpkin_util::text::color::NamedColor;
 use pumpkin_util::text::hover::HoverEvent;
 use pumpkin_util::{GameMode, Hand};
 use pumpkin_world::biome;
@@ -94,30 +113,34 @@ use crate::command::context::command_source::CommandSource;
 use crate::command::node::dispatcher::CommandDispatcher;
 use crate::command::{CommandSender, client_suggestions};
 use crate::data::SaveJSONConfiguration;
+use crate::entity::attributes::Modifier;
 use crate::entity::{EntityBaseFuture, NbtFuture, TeleportFuture};
 use crate::net::{ClientPlatform, GameProfile};
 use crate::net::{DisconnectReason, PlayerConfig};
+use crate::plugin::entity::entity_spawn::EntitySpawnReason;
 use crate::plugin::player::exp_change::PlayerExpChangeEvent;
 use crate::plugin::player::inventory_interact::InventoryClickEvent;
+use crate::plugin::player::player_camera_change::PlayerCameraChangeEvent;
 use crate::plugin::player::player_change_world::PlayerChangeWorldEvent;
 use crate::plugin::player::player_gamemode_change::PlayerGamemodeChangeEvent;
 use crate::plugin::player::player_permission_check::PlayerPermissionCheckEvent;
 use crate::plugin::player::player_teleport::PlayerTeleportEvent;
 use crate::plugin::server::packet::PacketSentEvent;
 use crate::server::Server;
-use crate::world::World;
-use bytes::Bytes;
+use crate::world::scoreboard::{CollisionRule, NameTagVisibility, Team};
+use crate::world::{World, chunker};
+use bytes::{BufMut, Bytes};
 
 use super::breath::BreathManager;
 use super::combat::{self, AttackType, player_attack_sound};
-use super::hunger::HungerManager;
+use super::hunger::{self, HungerManager};
 use super::item::ItemEntity;
 use super::living::LivingEntity;
 use super::{Entity, EntityBase, NBTStorage, NBTStorageInit};
 use pumpkin_data::potion::Effect;
 use pumpkin_world::chunk_system::ChunkLoading;
-const MAX_CACHED_SIGNATURES: u8 = 128; // Vanilla: 128
-const MAX_PREVIOUS_MESSAGES: u8 = 20; // Vanilla: 20
+pub const MAX_CACHED_SIGNATURES: u8 = 128; // Vanilla: 128
+pub const MAX_PREVIOUS_MESSAGES: u8 = 20; // Vanilla: 20
 
 pub const DATA_VERSION: i32 = 4790; // 26.1.2
 
@@ -395,6 +418,42 @@ impl ChunkManager {
     }
 }
 
+/// The block a player is looking at, as found by [`Player::ray_trace_blocks`].
+pub struct BlockRayHit {
+    /// The position of the block that was hit.
+    pub position: BlockPos,
+    /// The face of the block the ray entered through.
+    pub face: BlockDirection,
+    /// The exact point where the ray hit the block's outline.
+    pub hit_pos: Vector3<f64>,
+    pub block: &'static Block,
+    pub state: &'static BlockState,
+}
+
+/// Returns where the segment from `start` to `end` first enters the outline of `state` placed at
+/// `pos`, or `None` if it misses every outline shape.
+fn outline_hit(
+    state: &BlockState,
+    pos: BlockPos,
+    start: Vector3<f64>,
+    end: Vector3<f64>,
+) -> Option<Vector3<f64>> {
+    state
+        .get_block_outline_shapes()
+        .filter_map(|shape| shape.at_pos(pos).raycast(start, end))
+        .min_by(|a, b| {
+            a.squared_distance_to_vec(&start)
+                .total_cmp(&b.squared_distance_to_vec(&start))
+        })
+}
+
+/// The entity a player is looking at, as found by [`Player::ray_trace_entities`].
+pub struct EntityRayHit {
+    pub entity: Arc<dyn EntityBase>,
+    /// The exact point where the ray hit the entity's bounding box.
+    pub hit_pos: Vector3<f64>,
+}
+
 /// Represents a Minecraft player entity.
 ///
 /// A `Player` is a special type of entity that represents a human player connected to the server.
@@ -423,13 +482,19 @@ pub struct Player {
     pub previous_gamemode: AtomicCell<Option<GameMode>>,
     /// The player's spawnpoint
     pub respawn_point: Mutex<Option<RespawnPoint>>,
+    /// The dimension and position where the player last died, pointed at by recovery compasses.
+    pub last_death_location: Mutex<Option<(ResourceLocation, BlockPos)>>,
     /// The player's sleep status
     pub sleeping_since: AtomicCell<Option<u8>>,
+    /// The head of the bed the player is sleeping in
+    pub sleeping_pos: AtomicCell<Option<BlockPos>>,
+    /// The color of the scoreboard team the player is on, if that team has one.
+    pub team_color: AtomicCell<Option<NamedColor>>,
     /// Manages the player's breath level
     pub breath_manager: BreathManager,
     /// Manages the player's hunger level.
     pub hunger_manager: HungerManager,
-    /// The ID of the currently open container (if any).
+    /// The sync ID of the currently open container screen (if any).
     pub open_container: AtomicCell<Option<u64>>,
     /// The block position of the currently open container screen (if any).
     pub open_container_pos: AtomicCell<Option<BlockPos>>,
@@ -449,6 +514,7 @@ pub struct Player {
     pub mining: AtomicBool,
     pub start_mining_time: AtomicI32,
     pub tick_counter: AtomicI32,
+    /// The highest block action sequence received this tick, or -1 if there is none to acknowledge.
     pub packet_sequence: AtomicI32,
     pub mining_pos: Mutex<BlockPos>,
     /// A counter for teleport IDs used to track pending teleports.
@@ -457,6 +523,8 @@ pub struct Player {
     pub awaiting_teleport: Mutex<Option<(VarInt, Vector3<f64>)>>,
     /// The coordinates of the chunk section the player is currently watching.
     pub watched_section: AtomicCell<Cylindrical>,
+    /// The simulation distance requested for this player, or 0 to use the server default.
+    pub simulation_distance: AtomicU8,
     /// The last time the player performed an action (for idle timeout).
     pub last_action_time: AtomicCell<Instant>,
     /// The ping in millis.
@@ -489,6 +557,10 @@ pub struct Player {
     pub experience_pick_up_delay: Mutex<u32>,
     pub chunk_manager: Mutex<ChunkManager>,
     pub has_played_before: AtomicBool,
+    /// Whether the player has seen the end credits, which are skipped on later wins.
+    pub seen_credits: AtomicBool,
+    /// Whether the player left the End and is watching the credits before respawning.
+    pub won_game: AtomicBool,
     pub chat_session: Arc<Mutex<ChatSession>>,
     pub signature_cache: Mutex<MessageCache>,
     pub player_screen_handler: Arc<Mutex<PlayerScreenHandler>>,
@@ -655,7 +727,10 @@ impl Player {
             previous_gamemode: AtomicCell::new(None),
             // TODO: Send the CPlayerSpawnPosition packet when the client connects with proper values
             respawn_point: Mutex::new(None),
+            last_death_location: Mutex::new(None),
             sleeping_since: AtomicCell::new(None),
+            sleeping_pos: AtomicCell::new(None),
+            team_color: AtomicCell::new(None),
             // We want this to be an impossible watched section so that `chunker::update_position`
             // will mark chunks as watched for a new join rather than a respawn.
             // (We left shift by one so we can search around that chunk)
@@ -664,12 +739,15 @@ impl Player {
                 // Since 1 is not possible in vanilla it is used as uninit
                 NonZeroU8::new(1).unwrap(),
             )),
+            simulation_distance: AtomicU8::new(0),
             last_action_time: AtomicCell::new(std::time::Instant::now()),
             ping: AtomicU32::new(0),
             last_attacked_ticks: AtomicU32::new(0),
             client_loaded: AtomicBool::new(false),
             bedrock_spawned: AtomicBool::new(false),
-            client_loaded_timeout: AtomicU32::new(60),
+            client_loaded_timeout: AtomicU32::new(
+                server.advanced_config.networking.client_load_timeout,
+            ),
             // Item usage tracking
             using_item: AtomicBool::new(false),
             item_use_start_time: AtomicI32::new(0),
@@ -703,6 +781,8 @@ impl Player {
             last_sent_food: AtomicU8::new(0),
             last_food_saturation: AtomicBool::new(true),
             has_played_before: AtomicBool::new(false),
+            seen_credits: AtomicBool::new(false),
+            won_game: AtomicBool::new(false),
             chat_session: Arc::new(Mutex::new(ChatSession::default())), // Placeholder value until the player actually sets their session id
             signature_cache: Mutex::new(MessageCache::default()),
             player_screen_handler: player_screen_handler.clone(),
@@ -722,9 +802,28 @@ impl Player {
         }
     }
 
-    pub async fn set_tab_list_header_footer(&self, header: TextComponent, footer: TextComponent) {
-        *self.tab_list_header.lock().await = header.clone();
-        *self.tab_list_footer.lock().await = footer.clone();
+    /// Sets the text shown above and below the player list.
+    ///
+    /// `None` keeps the current text, while an empty component clears it.
+    pub async fn set_tab_list_header_footer(
+        &self,
+        header: Option<TextComponent>,
+        footer: Option<TextComponent>,
+    ) {
+        let header = {
+            let mut current = self.tab_list_header.lock().await;
+            if let Some(header) = header {
+                *current = header;
+            }
+            current.clone()
+        };
+        let footer = {
+            let mut current = self.tab_list_footer.lock().await;
+            if let Some(footer) = footer {
+                *current = footer;
+            }
+            current.clone()
+        };
         self.client
             .enqueue_packet(&CTabList::new(&header, &footer))
             .await;
@@ -768,33 +867,80 @@ impl Player {
         false
     }
 
+    /// Overrides the name shown in chat and, unless a tab list name is set, in the tab list.
+    ///
+    /// `None` restores the plain username. The name above the player's head can only be
+    /// decorated, see [`Player::set_name_tag_affixes`].
     pub async fn set_display_name(&self, display_name: Option<TextComponent>) {
-        *self.display_name.lock().await = display_name.clone();
-        // Update the tab list for everyone
-        let world = self.world();
-        world.broadcast_packet_all(&CPlayerInfoUpdate::new(
+        *self.display_name.lock().await = display_name;
+        self.broadcast_listed_name().await;
+    }
+
+    /// The name other players see in the tab list: the tab list name if one is set, then the
+    /// display name. `None` means the client shows the plain username.
+    pub async fn get_listed_name(&self) -> Option<TextComponent> {
+        match self.tab_list_name.lock().await.clone() {
+            Some(name) => Some(name),
+            None => self.display_name.lock().await.clone(),
+        }
+    }
+
+    async fn broadcast_listed_name(&self) {
+        let name = self.get_listed_name().await;
+        self.world().broadcast_packet_all(&CPlayerInfoUpdate::new(
             PlayerInfoFlags::UPDATE_DISPLAY_NAME.bits(),
             &[pumpkin_protocol::java::client::play::Player {
                 uuid: self.gameprofile.id,
-                actions: &[PlayerAction::UpdateDisplayName(display_name.as_ref())],
+                actions: &[PlayerAction::UpdateDisplayName(name.as_ref())],
             }],
         ));
     }
 
+    /// Shows `prefix` and `suffix` around the name above the player's head and in the tab list.
+    ///
+    /// This uses a scoreboard team dedicated to the player, so it replaces any team the
+    /// player was on. Passing `None` for both removes the team again.
+    pub async fn set_name_tag_affixes(
+        &self,
+        prefix: Option<TextComponent>,
+        suffix: Option<TextComponent>,
+    ) {
+        let world = self.world();
+        let team_name = format!("nametag.{}", self.gameprofile.id.simple());
+        let mut scoreboard = world.scoreboard.lock().await;
+
+        if prefix.is_none() && suffix.is_none() {
+            if scoreboard.has_team(&team_name) {
+                scoreboard.remove_team(&world, &team_name);
+            }
+            return;
+        }
+
+        let team = Team {
+            name: team_name.clone(),
+            display_name: TextComponent::text(self.gameprofile.name.clone()),
+            options: 0,
+            nametag_visibility: NameTagVisibility::Always,
+            collision_rule: CollisionRule::Always,
+            color: NamedColor::White,
+            player_prefix: prefix.unwrap_or_else(|| TextComponent::text("")),
+            player_suffix: suffix.unwrap_or_else(|| TextComponent::text("")),
+            players: vec![self.gameprofile.name.clone()],
+        };
+        if scoreboard.has_team(&team_name) {
+            scoreboard.update_team(&world, team);
+        } else {
+            scoreboard.add_team(&world, team);
+        }
+    }
+
     pub async fn get_tab_list_name(&self) -> Option<TextComponent> {
         self.tab_list_name.lock().await.clone()
     }
 
     pub async fn set_tab_list_name(&self, name: Option<TextComponent>) {
-        *self.tab_list_name.lock().await = name.clone();
-        let world = self.world();
-        world.broadcast_packet_all(&CPlayerInfoUpdate::new(
-            PlayerInfoFlags::UPDATE_DISPLAY_NAME.bits(),
-            &[pumpkin_protocol::java::client::play::Player {
-                uuid: self.gameprofile.id,
-                actions: &[PlayerAction::UpdateDisplayName(name.as_ref())],
-            }],
-        ));
+        *self.tab_list_name.lock().await = name;
+        self.broadcast_listed_name().await;
     }
 
     pub fn set_tab_list_order(&self, order: i32) {
@@ -809,6 +955,12 @@ impl Player {
         ));
     }
 
+    /// Returns this player's round-trip latency in milliseconds, measured from keep-alives.
+    pub fn ping(&self) -> u32 {
+        self.ping.load(Ordering::Relaxed)
+    }
+
+    /// Overrides the latency shown in the player list until the next periodic latency refresh.
     pub fn set_tab_list_latency(&self, latency: i32) {
         self.tab_list_latency.store(latency, Ordering::Relaxed);
         let world = self.world();
@@ -821,6 +973,153 @@ impl Player {
         ));
     }
 
+    /// Sets how many chunks around this player are ticked, independently of how many are sent.
+    /// The effective distance is still capped by the server's simulation distance and by this
+    /// player's view distance; see [`chunker::get_simulation_distance`].
+    pub async fn set_simulation_distance(&self, distance: NonZeroU8) {
+        self.simulation_distance
+            .store(distance.get(), Ordering::Relaxed);
+        let effective = chunker::get_simulation_distance(self);
+        self.client
+            .enqueue_packet(&CSetSimulationDistance::new(VarInt(i32::from(
+                effective.get(),
+            ))))
+            .await;
+    }
+
+    /// Views the world through `target`'s camera, or returns the camera to this player with
+    /// `None`. Only spectators and operators may change their camera. Returns whether the
+    /// camera was changed.
+    pub async fn set_camera(self: &Arc<Self>, target: Option<&dyn EntityBase>) -> bool {
+        if self.gamemode.load() != GameMode::Spectator
+            && self.permission_lvl.load() < PermissionLvl::Two
+        {
+            return false;
+        }
+        let Some(server) = self.world().server.upgrade() else {
+            return false;
+        };
+
+        let event = server
+            .plugin_manager
+            .fire(PlayerCameraChangeEvent::new(
+                self.clone(),
+                target.map(|target| target.get_entity().entity_id),
+            ))
+            .await;
+        if event.cancelled {
+            return false;
+        }
+
+        let camera_id = event.target_id.unwrap_or_else(|| self.entity_id());
+        self.client
+            .enqueue_packet(&CSetCamera::new(VarInt(camera_id)))
+            .await;
+        true
+    }
+
+    /// Changes the player's skin to the given signed `textures` profile property.
+    ///
+    /// Clients only load skins when a player is added to their player list, so the player is
+    /// removed from and re-added to everyone's list and respawned for the players who see them.
+    /// The player's own client is sent a same-dimension respawn, which keeps the loaded chunks
+    /// but recreates its local player with the new skin.
+    pub async fn set_skin(self: &Arc<Self>, textures_value: String, signature: String) {
+        let mut properties = (**self.gameprofile.properties.load()).clone();
+        properties.retain(|property| property.name.as_ref() != "textures");
+        properties.push(Property {
+            name: "textures".into(),
+            value: textures_value.into(),
+            signature: Some(signature.into()),
+        });
+        let properties = Arc::new(properties);
+        self.gameprofile.properties.store(properties.clone());
+
+        let bedrock_properties = properties.clone();
+        let bedrock_skin = tokio::task::spawn_blocking(move || {
+            Self::fetch_skin(&bedrock_properties)
+                .unwrap_or_else(pumpkin_protocol::bedrock::client::Skin::steve)
+        })
+        .await
+        .unwrap_or_else(|_| pumpkin_protocol::bedrock::client::Skin::steve());
+        self.bedrock_skin.store(Arc::new(bedrock_skin));
+
+        let world = self.world();
+        let uuid = self.gameprofile.id;
+        world.broadcast_packet_all(&CRemovePlayerInfo::new(&[uuid]));
+        let listed_name = self.get_listed_name().await;
+        let actions = [
+            PlayerAction::AddPlayer {
+                name: &self.gameprofile.name,
+                properties: &properties,
+            },
+            PlayerAction::UpdateGameMode(VarInt(self.gamemode.load() as i32)),
+            PlayerAction::UpdateListed(self.tab_list_listed.load(Ordering::Relaxed)),
+            PlayerAction::UpdateLatency(VarInt(self.tab_list_latency.load(Ordering::Relaxed))),
+            PlayerAction::UpdateDisplayName(listed_name.as_ref()),
+            PlayerAction::UpdateListOrder(VarInt(self.tab_list_order.load(Ordering::Relaxed))),
+        ];
+        world.broadcast_packet_all(&CPlayerInfoUpdate::new(
+            (PlayerInfoFlags::ADD_PLAYER
+                | PlayerInfoFlags::UPDATE_GAME_MODE
+                | PlayerInfoFlags::UPDATE_LISTED
+                | PlayerInfoFlags::UPDATE_LATENCY
+                | PlayerInfoFlags::UPDATE_LIST_PRIORITY
+                | PlayerInfoFlags::UPDATE_DISPLAY_NAME)
+                .bits(),
+            &[pumpkin_protocol::java::client::play::Player {
+                uuid,
+                actions: &actions,
+            }],
+        ));
+
+        // Respawn the player's entity for everyone who can see it
+        let entity = self.get_entity();
+        let chunk_pos = entity.chunk_pos.load();
+        world.broadcast_to_chunk_except(
+            chunk_pos,
+            &[uuid],
+            &CRemoveEntities::new(&[entity.entity_id.into()]),
+        );
+        world.broadcast_to_chunk_except(chunk_pos, &[uuid], &entity.create_spawn_packet());
+        self.send_client_information();
+        world.send_player_equipment(self).await;
+
+        // Recreate the player's own local player, keeping its attributes and metadata
+        let position = entity.pos.load();
+        let death_location = self.last_death_location.lock().await.clone();
+        self.client
+            .send_packet_now(&CRespawn::new(
+                world.dimension.id.into(),
+                world.dimension.minecraft_name.to_string(),
+                biome::hash_seed(world.level.seed.0),
+                self.gamemode.load() as u8,
+                self.previous_gamemode
+                    .load()
+                    .map_or(-1, |gamemode| gamemode as i8),
+                false,
+                false,
+                death_location,
+                VarInt(entity.portal_cooldown.load(Ordering::Relaxed) as i32),
+                world.sea_level.into(),
+                0x03,
+            ))
+            .await;
+        self.send_permission_lvl_update();
+        self.request_teleport(position, entity.yaw.load(), entity.pitch.load())
+            .await;
+        self.send_abilities_update().await;
+        self.enqueue_set_held_item_packet(&CSetSelectedSlot::new(
+            self.get_inventory().get_selected_slot() as i8,
+        ))
+        .await;
+        self.on_screen_handler_opened(self.player_screen_handler.clone())
+            .await;
+        self.send_health().await;
+        self.last_sent_xp.store(-1, Ordering::Relaxed);
+        self.send_active_effects().await;
+    }
+
     pub fn set_tab_list_listed(&self, listed: bool) {
         self.tab_list_listed.store(listed, Ordering::Relaxed);
         let world = self.world();
@@ -860,6 +1159,12 @@ impl Player {
             .lock()
             .await
             .increment_custom(statistics::CustomStatistic::LeaveGame, 1);
+        // Leave the bed so it doesn't stay occupied
+        if self.is_sleeping() {
+            self.wake_up().await;
+        }
+        self.stop_riding().await;
+        self.get_entity().eject_passengers().await;
         let world = self.world();
         world.remove_player(self, true).await;
 
@@ -998,10 +1303,13 @@ impl Player {
             )
             .await
         {
-            world.play_sound(
+            world.play_sound_broadcast(
                 Sound::EntityPlayerAttackNodamage,
                 SoundCategory::Players,
                 &self.living_entity.entity.pos.load(),
+                1.0,
+                1.0,
+                None,
             );
             return;
         }
@@ -1021,7 +1329,7 @@ impl Player {
         if is_mace_smash {
             let fall_distance = self.living_entity.fall_distance.load();
             self.living_entity.fall_distance.store(0.0);
-            world.play_sound(
+            world.play_sound_broadcast(
                 if fall_distance > 5.0 {
                     Sound::ItemMaceSmashGroundHeavy
                 } else {
@@ -1029,10 +1337,14 @@ impl Player {
                 },
                 SoundCategory::Players,
                 &pos,
+                1.0,
+                1.0,
+                None,
             );
         }
 
         player_attack_sound(&pos, &world, attack_type).await;
+        self.add_exhaustion(hunger::EXHAUSTION_ATTACK).await;
 
         self.living_entity.last_attacking_id.store(
             victim_entity.entity_id,
@@ -1046,8 +1358,17 @@ impl Player {
             std::sync::atomic::Ordering::Relaxed,
         );
 
-        if victim.get_living_entity().is_some() {
-            let mut knockback_strength = 1.0;
+        if let Some(victim_living) = victim.get_living_entity() {
+            let mut knockback_strength = 1.0
+                + self
+                    .living_entity
+                    .get_attribute_value(&Attributes::ATTACK_KNOCKBACK)
+                + f64::from(
+                    item_stack
+                        .lock()
+                        .await
+                        .get_enchantment_level(&Enchantment::KNOCKBACK),
+                );
             match attack_type {
                 AttackType::Knockback => knockback_strength += 1.0,
                 AttackType::Sweeping => {
@@ -1092,7 +1413,7 @@ impl Player {
                 _ => {}
             }
             if config.knockback {
-                combat::handle_knockback(attacker_entity, victim_entity, knockback_strength);
+                combat::handle_knockback(attacker_entity, victim_living, knockback_strength).await;
             }
         }
 
@@ -1126,6 +1447,73 @@ impl Player {
             .map_or(0, |w| w.item_damage_per_attack as i32)
     }
 
+    /// Returns every equipment slot other players can see on this player:
+    /// main hand, off hand and the four armor slots.
+    pub async fn visible_equipment(&self) -> Vec<(EquipmentSlot, ItemStack)> {
+        let mut equipment = Vec::with_capacity(6);
+        equipment.push((
+            EquipmentSlot::MAIN_HAND,
+            self.inventory.held_item().lock().await.clone(),
+        ));
+        for (slot, index) in [
+            (EquipmentSlot::OFF_HAND, PlayerInventory::OFF_HAND_SLOT),
+            (EquipmentSlot::FEET, 36),
+            (EquipmentSlot::LEGS, 37),
+            (EquipmentSlot::CHEST, 38),
+            (EquipmentSlot::HEAD, 39),
+        ] {
+            let stack = self.inventory.get_stack(index).await.lock().await.clone();
+            equipment.push((slot, stack));
+        }
+        equipment
+    }
+
+    /// Shows other players the equipment that differs from `equipment_before`, as returned by
+    /// [`Self::visible_equipment`].
+    async fn send_changed_equipment(&self, equipment_before: Vec<(EquipmentSlot, ItemStack)>) {
+        let changed_equipment: Vec<_> = self
+            .visible_equipment()
+            .await
+            .into_iter()
+            .zip(equipment_before)
+            .filter(|((_, after), (_, before))| !ItemStack::are_equal(after, before))
+            .map(|(after, _)| after)
+            .collect();
+        if !changed_equipment.is_empty() {
+            self.living_entity
+                .send_equipment_changes(&changed_equipment);
+        }
+    }
+
+    /// Replaces the whole inventory with `snapshot`, e.g. when a minigame ends, and sends it to
+    /// the client at once.
+    pub async fn restore_inventory(&self, snapshot: InventorySnapshot) {
+        // Holding the open screen keeps slot clicks from interleaving with the restore
+        let screen_handler = self.current_screen_handler.lock().await.clone();
+        let mut screen_handler = screen_handler.lock().await;
+        let equipment_before = self.visible_equipment().await;
+
+        self.inventory.restore(snapshot).await;
+
+        screen_handler.sync_state().await;
+        drop(screen_handler);
+        self.send_changed_equipment(equipment_before).await;
+    }
+
+    /// Empties the whole inventory, including the armor and off-hand, and sends it to the client
+    /// at once.
+    pub async fn clear_inventory(&self) {
+        let screen_handler = self.current_screen_handler.lock().await.clone();
+        let mut screen_handler = screen_handler.lock().await;
+        let equipment_before = self.visible_equipment().await;
+
+        self.inventory.clear().await;
+
+        screen_handler.sync_state().await;
+        drop(screen_handler);
+        self.send_changed_equipment(equipment_before).await;
+    }
+
     pub async fn sync_hand_slot(&self, slot_index: usize, stack: ItemStack) {
         self.enqueue_slot_set_packet(&CSetPlayerInventory::new(
             (slot_index as i32).into(),
@@ -1142,6 +1530,23 @@ impl Player {
         }
     }
 
+    /// Moves this player's hotbar selection to `slot`, syncing the client and showing the newly
+    /// held item to observers. Returns `false` without changing anything if `slot` is not `0..9`.
+    pub async fn set_selected_slot(&self, slot: u8) -> bool {
+        if !PlayerInventory::is_valid_hotbar_index(slot as usize) {
+            return false;
+        }
+
+        self.inventory.set_selected_slot(slot);
+        self.enqueue_set_held_item_packet(&CSetSelectedSlot::new(slot as i8))
+            .await;
+
+        let stack = self.inventory.held_item().lock().await.clone();
+        self.living_entity
+            .send_equipment_changes(&[(EquipmentSlot::MAIN_HAND, stack)]);
+        true
+    }
+
     /// Applies `amount` durability damage to the item in `slot`.
     /// Broadcasts an [`EntityStatus`] break event and syncs the slot if the item is destroyed.
     pub async fn damage_item_in_slot(&self, slot: &EquipmentSlot, amount: i32) -> bool {
@@ -1211,6 +1616,15 @@ impl Player {
             .await
     }
 
+    /// Damages the item in `hand`, e.g. the bow that was drawn with it.
+    pub async fn damage_item_in_hand(&self, hand: Hand, amount: i32) -> bool {
+        let slot = match hand {
+            Hand::Right => &EquipmentSlot::MAIN_HAND,
+            Hand::Left => &EquipmentSlot::OFF_HAND,
+        };
+        self.damage_item_in_slot(slot, amount).await
+    }
+
     pub async fn apply_tool_damage_for_block_break(&self, state: &BlockState) {
         if matches!(
             self.gamemode.load(),
@@ -1553,9 +1967,45 @@ impl Player {
         )]);
         self.get_entity().set_velocity(Vector3::default());
 
+        self.sleeping_pos.store(Some(bed_head_pos));
         self.sleeping_since.store(Some(0));
     }
 
+    /// Sets whether the player has a glowing outline, visible to every other player.
+    pub async fn set_glowing(&self, glowing: bool) {
+        self.get_entity().set_glowing(glowing).await;
+    }
+
+    /// Sets whether the player is invisible to every other player.
+    pub async fn set_invisible(&self, invisible: bool) {
+        self.get_entity().set_invisible(invisible).await;
+    }
+
+    /// Mounts the player on the given vehicle, dismounting them from their current one first.
+    ///
+    /// Returns `false` if the vehicle is the player themselves or has been removed.
+    pub async fn start_riding(self: &Arc<Self>, vehicle: Arc<dyn EntityBase>) -> bool {
+        let vehicle_entity = vehicle.get_entity();
+        if vehicle_entity.entity_id == self.entity_id() || vehicle_entity.is_removed() {
+            return false;
+        }
+        if self.is_sleeping() {
+            self.wake_up().await;
+        }
+        self.stop_riding().await;
+
+        self.get_entity().set_pos(vehicle_entity.pos.load());
+        vehicle_entity
+            .add_passenger(vehicle.clone(), self.clone() as Arc<dyn EntityBase>)
+            .await;
+        true
+    }
+
+    /// Dismounts the player from their vehicle, if they have one.
+    pub async fn stop_riding(&self) {
+        self.get_entity().stop_riding().await;
+    }
+
     pub async fn get_off_ground_speed(&self) -> f64 {
         let sprinting = self.get_entity().is_sprinting();
 
@@ -1580,22 +2030,59 @@ impl Player {
     }
 
     fn is_sleeping(&self) -> bool {
-        // TODO: Track sleeping position state explicitly (vanilla checks sleepingPosition.isPresent()).
-        self.sleeping_since.load().is_some()
+        self.sleeping_pos.load().is_some()
     }
 
-    async fn is_swimming(&self, flying: bool) -> bool {
+    /// Sprinting players start swimming once their eyes are under water, and keep swimming
+    /// while sprinting in water.
+    async fn update_swimming(&self) {
         let entity = self.get_entity();
-        let swim_height = self.living_entity.get_swim_height();
+        let swimming = if self.is_flying().await || entity.has_vehicle().await {
+            false
+        } else if entity.swimming.load(Ordering::Relaxed) {
+            entity.is_sprinting() && entity.touching_water.load(Ordering::Relaxed)
+        } else {
+            entity.is_sprinting() && BreathManager::is_eye_in_water(self)
+        };
+        entity.set_swimming(swimming).await;
+    }
+
+    /// Whether the player can glide, which needs an elytra that is not about to break.
+    pub async fn can_glide(&self) -> bool {
+        let entity = self.get_entity();
+        if entity.on_ground.load(Ordering::Relaxed)
+            || entity.has_vehicle().await
+            || self
+                .living_entity
+                .has_effect(&StatusEffect::LEVITATION)
+                .await
+        {
+            return false;
+        }
+        let chest = self
+            .living_entity
+            .entity_equipment
+            .lock()
+            .await
+            .get(&EquipmentSlot::CHEST);
+        let chest = chest.lock().await;
+        chest.item.id == pumpkin_data::item::Item::ELYTRA.id
+            && chest
+                .get_max_damage()
+                .is_none_or(|max_damage| chest.get_damage() < max_damage - 1)
+    }
 
-        // TODO: Replace this inferred check with vanilla-equivalent swimming state tracking
-        // (LivingEntity#updateSwimming + entity swimming flag).
-        entity.touching_water.load(Ordering::Relaxed)
-            && entity.water_height.load() > swim_height
-            && entity.is_sprinting()
-            && !entity.on_ground.load(Ordering::Relaxed)
-            && !flying
-            && !entity.has_vehicle().await
+    /// Starts gliding when the player jumps while falling with an elytra, and returns whether
+    /// the player glides now.
+    pub async fn try_start_fall_flying(&self) -> bool {
+        let entity = self.get_entity();
+        if !entity.is_fall_flying()
+            && !entity.touching_water.load(Ordering::Relaxed)
+            && self.can_glide().await
+        {
+            entity.set_fall_flying(true).await;
+        }
+        entity.is_fall_flying()
     }
 
     const fn is_auto_spin_attack() -> bool {
@@ -1621,12 +2108,12 @@ impl Player {
         }
 
         let flying = self.is_flying().await;
-        let desired_pose = if self.is_sleeping() {
+        let desired_pose = if entity.is_fall_flying() {
+            EntityPose::FallFlying
+        } else if self.is_sleeping() {
             EntityPose::Sleeping
-        } else if self.is_swimming(flying).await {
+        } else if entity.swimming.load(Ordering::Relaxed) {
             EntityPose::Swimming
-        } else if entity.is_fall_flying() {
-            EntityPose::FallFlying
         } else if Self::is_auto_spin_attack() {
             EntityPose::SpinAttack
         } else if entity.is_sneaking() && !flying {
@@ -1653,14 +2140,13 @@ impl Player {
 
     pub async fn wake_up(&self) {
         let world = self.world();
-        let respawn_point = self.respawn_point.lock().await;
-        let Some(respawn_point) = respawn_point.as_ref() else {
-            warn!("Player waking up should have it's respawn point set on the bed");
-            return;
-        };
-
-        let (bed, bed_state) = world.get_block_and_state_id(&respawn_point.position);
-        BedBlock::set_occupied(false, &world, bed, &respawn_point.position, bed_state).await;
+        // The bed may have been broken while we slept
+        if let Some(bed_pos) = self.sleeping_pos.swap(None) {
+            let (bed, bed_state) = world.get_block_and_state_id(&bed_pos);
+            if bed.has_tag(&tag::Block::MINECRAFT_BEDS) {
+                BedBlock::set_occupied(false, &world, bed, &bed_pos, bed_state).await;
+            }
+        }
 
         self.living_entity.entity.set_pose(EntityPose::Standing);
         self.living_entity.entity.set_pos(self.position());
@@ -1686,7 +2172,12 @@ impl Player {
         self.sleeping_since.store(None);
     }
 
-    pub async fn show_title(&self, text: &TextComponent, mode: &TitleMode) {
+    /// Shows `text` in the given title slot. With `reset_times`, the fade-in/stay/fade-out
+    /// timings go back to vanilla's defaults (10, 70 and 20 ticks) first.
+    pub async fn show_title(&self, text: &TextComponent, mode: &TitleMode, reset_times: bool) {
+        if reset_times {
+            self.set_title_times(10, 70, 20).await;
+        }
         match &self.client {
             ClientPlatform::Java(client) => match mode {
                 TitleMode::Title => client.enqueue_packet(&CTitleText::new(text)).await,
@@ -1714,7 +2205,8 @@ impl Player {
         }
     }
 
-    pub async fn send_title_animation(&self, fade_in: i32, stay: i32, fade_out: i32) {
+    /// Sets the fade-in, stay and fade-out durations, in ticks, used by subsequent titles.
+    pub async fn set_title_times(&self, fade_in: i32, stay: i32, fade_out: i32) {
         match &self.client {
             ClientPlatform::Java(client) => {
                 client
@@ -1737,24 +2229,134 @@ impl Player {
         }
     }
 
-    pub fn spawn_particle(
+    /// Opens a read-only book showing `pages`.
+    ///
+    /// Java players are shown a written book that is only placed in their hand client-side, so it
+    /// never ends up in their inventory. Bedrock players get a form with the pages instead.
+    pub async fn open_book(&self, pages: Vec<TextComponent>) {
+        match &self.client {
+            ClientPlatform::Java(client) => {
+                let slot = VarInt(i32::from(self.inventory.get_selected_slot()));
+                let held = self.inventory.held_item().lock().await.clone();
+                let book = ItemStack::new_with_component(
+                    1,
+                    &pumpkin_data::item::Item::WRITTEN_BOOK,
+                    vec![(
+                        DataComponent::WrittenBookContent,
+                        Some(
+                            WrittenBookContentImpl {
+                                title: String::new(),
+                                author: String::new(),
+                                generation: 0,
+                                pages,
+                                resolved: true,
+                            }
+                            .to_dyn(),
+                        ),
+                    )],
+                );
+
+                client
+                    .enqueue_packet(&CSetPlayerInventory::new(
+                        slot,
+                        &ItemStackSerializer::from(book),
+                    ))
+                    .await;
+                // Main hand
+                client.enqueue_packet(&COpenBook::new(VarInt(0))).await;
+                client
+                    .enqueue_packet(&CSetPlayerInventory::new(
+                        slot,
+                        &ItemStackSerializer::from(held),
+                    ))
+                    .await;
+            }
+            ClientPlatform::Bedrock(client) => {
+                let locale = Locale::from_str(&self.config.load().locale).unwrap_or(Locale::EnUs);
+                let content = pages
+                    .into_iter()
+                    .map(|page| page.0.get_text(locale))
+                    .collect::<Vec<_>>()
+                    .join("\n\n");
+                let form_id = client.next_form_id.fetch_add(1, Ordering::Relaxed);
+
+                client
+                    .send_game_packet(&CModalFormRequest {
+                        form_id: VarInt(form_id as i32),
+                        form_data: serde_json::json!({
+                            "type": "form",
+                            "title": "",
+                            "content": content,
+                            "buttons": [],
+                        })
+                        .to_string(),
+                    })
+                    .await;
+            }
+        }
+    }
+
+    /// Removes the title and subtitle currently on screen, keeping the current timings.
+    pub async fn clear_title(&self) {
+        self.send_clear_title(false).await;
+    }
+
+    /// Removes the title and subtitle currently on screen and resets the timings to their defaults.
+    pub async fn reset_title(&self) {
+        self.send_clear_title(true).await;
+    }
+
+    async fn send_clear_title(&self, reset: bool) {
+        match &self.client {
+            ClientPlatform::Java(client) => {
+                client.enqueue_packet(&CClearTitle::new(reset)).await;
+            }
+            ClientPlatform::Bedrock(client) => {
+                client
+                    .send_game_packet(
+                        &pumpkin_protocol::bedrock::client::set_title::CSetTitle::new(
+                            i32::from(reset),
+                            String::new(),
+                            0,
+                            0,
+                            0,
+                        ),
+                    )
+                    .await;
+            }
+        }
+    }
+
+    /// Spawns particles only this player can see, such as a [`Dust`] particle with its own
+    /// color and scale.
+    ///
+    /// `long_distance` particles are rendered by the client from further away.
+    ///
+    /// [`Dust`]: pumpkin_data::particle::Dust
+    pub fn spawn_particle<P: SerializeParticleData>(
         &self,
         position: Vector3<f64>,
         offset: Vector3<f32>,
         max_speed: f32,
         particle_count: i32,
-        id: VarInt,
-        data: &[u8],
+        particle: P,
+        long_distance: bool,
     ) {
+        let mut data = [0u8; 512];
+        let Ok(size) = particle.to_bytes(&mut data) else {
+            warn!("Failed to serialize the data of particle {particle:?}");
+            return;
+        };
+
         self.client.try_enqueue_packet(&CParticle::new(
-            false,
+            long_distance,
             false,
             position,
             offset,
             max_speed,
             particle_count,
-            id,
-            data,
+            VarInt(particle.id()),
+            &data[..size],
         ));
     }
 
@@ -1827,14 +2429,23 @@ impl Player {
             .send_content_updates()
             .await;
 
+        // Close block-backed screens once the player walks out of reach, like vanilla
+        if let Some(pos) = self.open_container_pos.load()
+            && !self.can_interact_with_block_at(&pos, 4.0)
+        {
+            self.close_handled_screen().await;
+        }
+
         // if self.client.closed.load(Ordering::Relaxed) {
         //     return;
         // }
 
+        // Enqueue the acknowledgement behind the block updates caused by the actions, so the
+        // client doesn't roll its prediction back to a stale state before they arrive.
         let seq = self.packet_sequence.swap(-1, Ordering::Relaxed);
         if seq != -1 {
             self.client
-                .send_packet_now(&CAcknowledgeBlockChange::new(seq.into()))
+                .enqueue_packet(&CAcknowledgeBlockChange::new(seq.into()))
                 .await;
         }
 
@@ -1922,6 +2533,12 @@ impl Player {
 
         self.last_attacked_ticks.fetch_add(1, Ordering::Relaxed);
 
+        self.update_swimming().await;
+        let entity = self.get_entity();
+        if entity.is_fall_flying() && !self.can_glide().await {
+            entity.set_fall_flying(false).await;
+        }
+
         let caller: Arc<dyn EntityBase> = self.clone();
         self.living_entity.tick(&caller, server).await;
         // Vanilla updates pose in PlayerEntity#tick after super.tick().
@@ -1935,7 +2552,7 @@ impl Player {
         self.tick_maps(server).await;
 
         // Timeout/keep alive handling
-        self.tick_client_load_timeout();
+        self.tick_client_load_timeout().await;
 
         // Idle timeout handling
         let now = Instant::now();
@@ -1982,38 +2599,64 @@ impl Player {
             .await
             .increment_custom(statistics::CustomStatistic::Jump, 1);
         if self.living_entity.entity.is_sprinting() {
-            self.add_exhaustion(0.2).await;
+            self.add_exhaustion(hunger::EXHAUSTION_SPRINT_JUMP).await;
         } else {
-            self.add_exhaustion(0.05).await;
+            self.add_exhaustion(hunger::EXHAUSTION_JUMP).await;
         }
     }
 
     pub async fn progress_motion(&self, delta_pos: Vector3<f64>) {
-        // TODO: Swimming, gliding...
-        if self.living_entity.entity.on_ground.load(Ordering::Relaxed) {
-            let delta = (delta_pos.horizontal_length() * 100.0).round() as f32;
-            if delta > 0.0 {
-                if self.living_entity.entity.is_sprinting() {
-                    self.add_exhaustion(0.1 * delta * 0.01).await;
-                } else {
-                    self.add_exhaustion(0.0 * delta * 0.01).await;
-                }
-            }
+        // TODO: Gliding...
+        let entity = &self.living_entity.entity;
+        let (distance, exhaustion_per_meter) = if entity.swimming.load(Ordering::Relaxed) {
+            (delta_pos.length(), hunger::EXHAUSTION_SWIM)
+        } else if entity.touching_water.load(Ordering::Relaxed) {
+            (delta_pos.horizontal_length(), hunger::EXHAUSTION_SWIM)
+        } else if entity.on_ground.load(Ordering::Relaxed) && entity.is_sprinting() {
+            (delta_pos.horizontal_length(), hunger::EXHAUSTION_SPRINT)
+        } else {
+            return;
+        };
+
+        // Vanilla counts whole centimeters
+        let centimeters = (distance * 100.0).round() as f32;
+        if centimeters > 0.0 {
+            self.add_exhaustion(exhaustion_per_meter * centimeters * 0.01)
+                .await;
         }
     }
 
     pub fn has_client_loaded(&self) -> bool {
         self.client_loaded.load(Ordering::Relaxed)
-            || self.client_loaded_timeout.load(Ordering::Relaxed) == 0
     }
 
+    /// Marks whether the client has finished loading the world. Clearing it restarts the load
+    /// timeout.
+    ///
+    /// Bedrock clients only report loading once per login, so they stay loaded across respawns
+    /// and world changes.
     pub fn set_client_loaded(&self, loaded: bool) {
         if !loaded {
-            self.client_loaded_timeout.store(60, Ordering::Relaxed);
+            if matches!(self.client, ClientPlatform::Bedrock(_)) {
+                return;
+            }
+            let timeout = self.world().server.upgrade().map_or(300, |server| {
+                server.advanced_config.networking.client_load_timeout
+            });
+            self.client_loaded_timeout.store(timeout, Ordering::Relaxed);
         }
         self.client_loaded.store(loaded, Ordering::Relaxed);
     }
 
+    /// Whether this client reports when it has finished loading the world.
+    /// Java clients before 1.21.4 have no player loaded packet.
+    fn reports_client_loaded(&self) -> bool {
+        match &self.client {
+            ClientPlatform::Java(client) => SPlayerLoaded::to_id(client.version.load()) != -1,
+            ClientPlatform::Bedrock(_) => true,
+        }
+    }
+
     pub fn get_attack_cooldown_progress(&self, tps: f64, base_time: f64, attack_speed: f64) -> f64 {
         let x = f64::from(self.last_attacked_ticks.load(Ordering::Acquire)) + base_time;
 
@@ -2070,6 +2713,78 @@ impl Player {
         )
     }
 
+    /// Returns the end of the player's line of sight, `max_distance` blocks from their eyes.
+    fn look_target(&self, max_distance: f64) -> Vector3<f64> {
+        let entity = &self.living_entity.entity;
+        let direction = Vector3::from_yaw_pitch(entity.yaw.load(), entity.pitch.load());
+        self.eye_position() + direction * max_distance
+    }
+
+    /// Returns the first block within `max_distance` blocks along the player's line of sight
+    /// whose outline the sight actually crosses. Fluids and blocks without an outline are
+    /// looked through.
+    pub async fn ray_trace_blocks(&self, max_distance: f64) -> Option<BlockRayHit> {
+        let start = self.eye_position();
+        let end = self.look_target(max_distance);
+        let world = self.world();
+
+        let (position, face) = world
+            .raycast(start, end, async |pos, world| {
+                outline_hit(world.get_block_state(pos), *pos, start, end).is_some()
+            })
+            .await?;
+        let (block, state) = world.get_block_and_state(&position);
+        let hit_pos = outline_hit(state, position, start, end)?;
+
+        Some(BlockRayHit {
+            position,
+            face,
+            hit_pos,
+            block,
+            state,
+        })
+    }
+
+    /// Returns the closest entity within `max_distance` blocks along the player's line of sight
+    /// that is not hidden behind a block.
+    pub async fn ray_trace_entities(&self, max_distance: f64) -> Option<EntityRayHit> {
+        let start = self.eye_position();
+        let mut end = self.look_target(max_distance);
+        if let Some(block_hit) = self.ray_trace_blocks(max_distance).await {
+            end = block_hit.hit_pos;
+        }
+
+        let world = self.world();
+        let own_id = self.living_entity.entity.entity_id;
+        let search_box = BoundingBox::new(start, start)
+            .stretch(end - start)
+            .expand_all(1.0);
+
+        world
+            .get_entities_at_box(&search_box)
+            .into_iter()
+            .chain(
+                world
+                    .get_players_at_box(&search_box)
+                    .into_iter()
+                    .map(|player| player as Arc<dyn EntityBase>),
+            )
+            .filter(|entity| entity.get_entity().entity_id != own_id)
+            .filter_map(|entity| {
+                let hit_pos = entity
+                    .get_entity()
+                    .bounding_box
+                    .load()
+                    .raycast(start, end)?;
+                Some(EntityRayHit { entity, hit_pos })
+            })
+            .min_by(|a, b| {
+                a.hit_pos
+                    .squared_distance_to_vec(&start)
+                    .total_cmp(&b.hit_pos.squared_distance_to_vec(&start))
+            })
+    }
+
     /// Returns the player's rotation.
     /// Yaw then Pitch
     pub fn rotation(&self) -> (f32, f32) {
@@ -2079,6 +2794,34 @@ impl Player {
         )
     }
 
+    /// Allows or forbids this player to fly, regardless of their gamemode.
+    ///
+    /// The choice survives respawns and ability resyncs until the gamemode changes.
+    pub async fn set_allow_flying(&self, allow_flying: bool) {
+        {
+            let mut abilities = self.abilities.lock().await;
+            abilities.allow_flying = allow_flying;
+            if !allow_flying {
+                abilities.flying = false;
+            }
+            abilities.flight_override = true;
+        }
+        self.send_abilities_update().await;
+    }
+
+    /// Starts or stops this player's flight. Starting has no effect unless flying is allowed.
+    pub async fn set_flying(&self, flying: bool) {
+        {
+            let mut abilities = self.abilities.lock().await;
+            if flying && !abilities.allow_flying {
+                return;
+            }
+            abilities.flying = flying;
+            abilities.flight_override = true;
+        }
+        self.send_abilities_update().await;
+    }
+
     /// Updates the current abilities the player has.
     pub async fn send_abilities_update(&self) {
         match &self.client {
@@ -2365,6 +3108,114 @@ impl Player {
         }
     }
 
+    /// Shows the block state `state_id` at `pos` to only this player, leaving the world untouched.
+    ///
+    /// The change is purely visual and will be overwritten by the next real block or chunk update
+    /// for that position.
+    pub async fn send_block_change(&self, pos: BlockPos, state_id: u16) {
+        self.client
+            .enqueue_packet_editioned(
+                &CBlockUpdate::new(pos, VarInt(i32::from(state_id))),
+                &CUpdateBlock::new(pos, u32::from(BlockState::to_be_network_id(state_id))),
+            )
+            .await;
+    }
+
+    /// Shows several block changes within a single chunk section to only this player, leaving the
+    /// world untouched. Changes outside of `chunk_section` are ignored.
+    ///
+    /// Like [`Self::send_block_change`], this is purely visual and will be overwritten by the next
+    /// real block or chunk update.
+    pub async fn send_multi_block_change(
+        &self,
+        chunk_section: Vector3<i32>,
+        changes: &[(BlockPos, u16)],
+    ) {
+        let changes: Vec<_> = changes
+            .iter()
+            .copied()
+            .filter(|(pos, _)| chunk_section_from_pos(pos) == chunk_section)
+            .collect();
+        if changes.is_empty() {
+            return;
+        }
+
+        match &self.client {
+            ClientPlatform::Java(java_client) => {
+                java_client
+                    .enqueue_packet(&CMultiBlockUpdate::new(&changes))
+                    .await;
+            }
+            ClientPlatform::Bedrock(bedrock_client) => {
+                for (pos, state_id) in changes {
+                    bedrock_client
+                        .enqueue_packet(&CUpdateBlock::new(
+                            pos,
+                            u32::from(BlockState::to_be_network_id(state_id)),
+                        ))
+                        .await;
+                }
+            }
+        }
+    }
+
+    /// Pushes a resource pack onto this player's stack of server resource packs.
+    ///
+    /// Pushing a `uuid` that is already applied replaces that pack. Only Java clients support
+    /// changing resource packs during play.
+    pub async fn add_resource_pack(
+        &self,
+        uuid: Uuid,
+        url: &str,
+        sha1: &str,
+        forced: bool,
+        prompt_message: Option<TextComponent>,
+    ) {
+        if let ClientPlatform::Java(java_client) = &self.client {
+            {
+                let mut resource_packs = java_client.resource_packs.lock().await;
+                if !resource_packs.contains(&uuid) {
+                    resource_packs.push(uuid);
+                }
+            }
+            java_client
+                .enqueue_packet(&CAddResourcePack::new(
+                    &uuid,
+                    url,
+                    sha1,
+                    forced,
+                    prompt_message,
+                ))
+                .await;
+        }
+    }
+
+    /// Removes a single resource pack from this player. Returns `false` if it was never applied.
+    pub async fn remove_resource_pack(&self, uuid: Uuid) -> bool {
+        let ClientPlatform::Java(java_client) = &self.client else {
+            return false;
+        };
+        {
+            let mut resource_packs = java_client.resource_packs.lock().await;
+            let Some(index) = resource_packs.iter().position(|applied| *applied == uuid) else {
+                return false;
+            };
+            resource_packs.remove(index);
+        }
+        java_client
+            .enqueue_packet(&CRemoveResourcePack::new(Some(uuid)))
+            .await;
+        true
+    }
+
+    /// Returns the UUIDs of the resource packs currently applied to this player, in push order.
+    pub async fn resource_packs(&self) -> Vec<Uuid> {
+        match &self.client {
+            ClientPlatform::Java(java_client) => java_client.resource_packs.lock().await.clone(),
+            ClientPlatform::Bedrock(_) => Vec::new(),
+        }
+    }
+
     pub async fn unload_watched_chunks(&self, world: &World) {
         let radial_chunks = self.watched_section.load().all_chunks_within();
         let level = &world.level;
@@ -2428,23 +3279,20 @@ impl Player {
                 // Update the entity's world reference for correct dimension-based operations
                 self.living_entity.entity.set_world(new_world.clone());
 
-                let last_pos = self.living_entity.entity.last_pos.load();
-                let death_dimension = ResourceLocation::from(self.world().dimension.minecraft_name);
-                let death_location = BlockPos(Vector3::new(
-                    last_pos.x.round() as i32,
-                    last_pos.y.round() as i32,
-                    last_pos.z.round() as i32,
-                ));
+                let death_location = self.last_death_location.lock().await.clone();
                 self.client
                     .send_packet_now(&CRespawn::new(
                         (new_world.dimension.id).into(),
                         new_world.dimension.minecraft_name.to_string(),
                         biome::hash_seed(new_world.level.seed.0), // seed
                         self.gamemode.load() as u8,
-                        self.gamemode.load() as i8,
+                        self
+                            .previous_gamemode
+                            .load()
+                            .map_or(-1, |gamemode| gamemode as i8),
                         false,
                         false,
-                        Some((death_dimension, death_location)),
+                        death_location,
                         VarInt(self.get_entity().portal_cooldown.load(Ordering::Relaxed) as i32),
                         new_world.sea_level.into(),
                         1,
@@ -2511,6 +3359,63 @@ impl Player {
         }}
     }
 
+    /// Moves the player by `delta` and turns them by `yaw_delta` and `pitch_delta` degrees.
+    ///
+    /// Unlike [`Self::request_teleport`], the client applies the offsets to its own position,
+    /// rotation and velocity, so it keeps any movement it made since it last reported its
+    /// position. The player should respond with the `SConfirmTeleport` packet, which snaps them
+    /// to the absolute target computed here, as vanilla does.
+    pub async fn teleport_relative(
+        self: &Arc<Self>,
+        delta: Vector3<f64>,
+        yaw_delta: f32,
+        pitch_delta: f32,
+    ) {
+        let server = self.world().server.upgrade().unwrap();
+        let from = self.living_entity.entity.pos.load();
+        send_cancellable! {{
+            server;
+            PlayerTeleportEvent {
+                player: self.clone(),
+                from,
+                to: from + delta,
+                cancelled: false,
+            };
+
+            'after: {
+                // Plugins may have changed the target, so offset by what is left of the move
+                let position = event.to;
+                let delta = position - from;
+                let teleport_id = self.teleport_id_count.fetch_add(1, Ordering::Relaxed) + 1;
+                let entity = &self.living_entity.entity;
+                entity.set_pos(position);
+                entity.set_rotation(
+                    entity.yaw.load() + yaw_delta,
+                    (entity.pitch.load() + pitch_delta).clamp(-90.0, 90.0),
+                );
+                *self.awaiting_teleport.lock().await = Some((teleport_id.into(), position));
+                self.client
+                    .send_packet_now(&CPlayerPosition::new(
+                        teleport_id.into(),
+                        delta,
+                        Vector3::new(0.0, 0.0, 0.0),
+                        yaw_delta,
+                        pitch_delta,
+                        vec![
+                            PositionFlag::X,
+                            PositionFlag::Y,
+                            PositionFlag::Z,
+                            PositionFlag::YRot,
+                            PositionFlag::XRot,
+                            PositionFlag::DeltaX,
+                            PositionFlag::DeltaY,
+                            PositionFlag::DeltaZ,
+                        ],
+                    )).await;
+            }
+        }}
+    }
+
     pub fn block_interaction_range(&self) -> f64 {
         if self.gamemode.load() == GameMode::Creative {
             5.0
@@ -2563,6 +3468,16 @@ impl Player {
             return;
         }
 
+        // Keep `tick_health` from sending the same values again
+        self.last_sent_health
+            .store(self.living_entity.health.load() as i32, Ordering::Relaxed);
+        self.last_sent_food
+            .store(self.hunger_manager.level.load(), Ordering::Relaxed);
+        self.last_food_saturation.store(
+            self.hunger_manager.saturation.load() == 0.0,
+            Ordering::Relaxed,
+        );
+
         match &self.client {
             ClientPlatform::Java(client) => {
                 client
@@ -2572,6 +3487,24 @@ impl Player {
                         self.hunger_manager.saturation.load(),
                     ))
                     .await;
+                // Absorption hearts are entity metadata rather than part of the health packet
+                if let Some(tracked_id) = self.living_entity.player_absorption_id() {
+                    let mut buf = Vec::new();
+                    Metadata::new(
+                        tracked_id,
+                        MetaDataType::FLOAT,
+                        self.living_entity.get_absorption(),
+                    )
+                    .write(&mut buf, &client.version.load())
+                    .unwrap();
+                    buf.put_u8(255);
+                    client
+                        .enqueue_packet(&CSetEntityMetadata::new(
+                            self.entity_id().into(),
+                            buf.into(),
+                        ))
+                        .await;
+                }
             }
             ClientPlatform::Bedrock(client) => {
                 client
@@ -2599,10 +3532,6 @@ impl Player {
         let last_saturation = self.last_food_saturation.load(Ordering::Relaxed);
 
         if health != last_health || food != last_food || (saturation == 0.0) != last_saturation {
-            self.last_sent_health.store(health, Ordering::Relaxed);
-            self.last_sent_food.store(food, Ordering::Relaxed);
-            self.last_food_saturation
-                .store(saturation == 0.0, Ordering::Relaxed);
             self.send_health().await;
         }
     }
@@ -2617,11 +3546,51 @@ impl Player {
         self.send_health().await;
     }
 
+    /// Sets the base value of `attribute` and syncs it to this player and everyone around them.
+    pub async fn set_attribute_base(&self, attribute: &Attributes, value: f64) {
+        self.living_entity.set_attribute_base(attribute, value);
+        self.sync_attribute(attribute).await;
+    }
+
+    /// Adds `modifier` to `attribute`, replacing any modifier with the same id.
+    pub async fn add_attribute_modifier(&self, attribute: &Attributes, modifier: Modifier) {
+        self.living_entity.update_attribute(attribute, |instance| {
+            instance.add_or_replace_modifier(modifier)
+        });
+        self.sync_attribute(attribute).await;
+    }
+
+    /// Removes the modifier with the given `id` from `attribute`.
+    pub async fn remove_attribute_modifier(&self, attribute: &Attributes, id: Uuid) {
+        self.living_entity
+            .update_attribute(attribute, |instance| instance.remove_modifier(id));
+        self.sync_attribute(attribute).await;
+    }
+
+    async fn sync_attribute(&self, attribute: &Attributes) {
+        crate::entity::attributes::send_attribute_updates_for_living(
+            &self.living_entity,
+            vec![attribute.clone()],
+        )
+        .await;
+
+        if *attribute == Attributes::MAX_HEALTH {
+            // Lowering the max health also lowers the current health
+            let max_health = self.living_entity.get_max_health();
+            if self.living_entity.health.load() > max_health {
+                self.living_entity.set_health(max_health);
+            }
+            self.send_health().await;
+        }
+    }
+
+    /// Sets the food level, capped at 20, and syncs it to the client.
     pub async fn set_food_level(&self, food_level: u8) {
         self.hunger_manager.set_level(food_level);
         self.send_health().await;
     }
 
+    /// Sets the saturation, clamped between 0 and the food level, and syncs it to the client.
     pub async fn set_saturation(&self, saturation: f32) {
         self.hunger_manager.set_saturation(saturation);
         self.send_health().await;
@@ -2648,8 +3617,64 @@ impl Player {
         self.client.address().await.to_string()
     }
 
+    /// Resets the player for a new life and moves them to their respawn point,
+    /// falling back to the world spawn if none is valid.
+    ///
+    /// Health, hunger, saturation and exhaustion are restored, all effects are cleared,
+    /// `CRespawn` is sent and the chunks around the new position are streamed again.
     pub async fn respawn(self: &Arc<Self>) {
+        self.remove_all_effects().await;
         self.world().respawn_player(self, false).await;
+        self.finish_respawn().await;
+    }
+
+    /// Whether leaving the End through its exit portal should show this player the credits.
+    ///
+    /// Like vanilla, they are only shown once. Bedrock clients can't show them at all.
+    pub fn should_show_credits(&self) -> bool {
+        matches!(self.client, ClientPlatform::Java(_)) && !self.seen_credits.load(Ordering::Relaxed)
+    }
+
+    /// Shows the end credits after the player leaves the End through its exit portal.
+    ///
+    /// The client asks to respawn once they are over, which is handled by
+    /// [`Self::respawn_after_credits`].
+    pub async fn win_game(&self) {
+        if self.won_game.swap(true, Ordering::Relaxed) {
+            return;
+        }
+        self.seen_credits.store(true, Ordering::Relaxed);
+        self.client
+            .enqueue_packet(&CGameEvent::new(GameEvent::WinGame, 1.0))
+            .await;
+    }
+
+    /// Respawns a player who finished watching the credits, keeping their health,
+    /// inventory and effects. Returns `false` if the player has not won the game.
+    pub async fn respawn_after_credits(self: &Arc<Self>) -> bool {
+        if !self.won_game.swap(false, Ordering::Relaxed) {
+            return false;
+        }
+        self.world().respawn_player(self, true).await;
+        self.finish_respawn().await;
+        true
+    }
+
+    async fn finish_respawn(&self) {
+        self.send_health().await;
+
+        {
+            let screen_handler = self.current_screen_handler.lock().await;
+            let mut screen_handler = screen_handler.lock().await;
+            screen_handler.sync_state().await;
+        };
+
+        // Restore abilities based on gamemode after respawn
+        {
+            let mut abilities = self.abilities.lock().await;
+            abilities.set_for_gamemode(self.gamemode.load());
+        };
+        self.send_abilities_update().await;
     }
 
     pub async fn ban(&self, server: &Server, reason: Option<TextComponent>) {
@@ -2730,17 +3755,43 @@ impl Player {
         }
     }
 
-    pub fn tick_client_load_timeout(&self) {
-        if !self.client_loaded.load(Ordering::Relaxed) {
-            let timeout = self.client_loaded_timeout.load(Ordering::Relaxed);
-            self.client_loaded_timeout
-                .store(timeout.saturating_sub(1), Ordering::Relaxed);
+    /// Counts down the time the client has left to report that it finished loading.
+    ///
+    /// A client that never reports it is kicked once the window runs out. Clients that
+    /// cannot report it at all are treated as loaded instead. The countdown is paused
+    /// while the player sits on the death screen.
+    pub async fn tick_client_load_timeout(&self) {
+        if self.client_loaded.load(Ordering::Relaxed)
+            || self.living_entity.dead.load(Ordering::Relaxed)
+        {
+            return;
+        }
+        let timeout = self
+            .client_loaded_timeout
+            .load(Ordering::Relaxed)
+            .saturating_sub(1);
+        self.client_loaded_timeout.store(timeout, Ordering::Relaxed);
+        if timeout > 0 {
+            return;
+        }
+        if self.reports_client_loaded() {
+            self.kick(
+                DisconnectReason::LoadingStateTimeout,
+                TextComponent::text("Took too long to load"),
+            )
+            .await;
+        } else {
+            self.set_client_loaded(true);
         }
     }
 
     async fn handle_killed(&self, death_msg: TextComponent) {
         self.set_client_loaded(false);
         let block_pos = self.position().to_block_pos();
+        *self.last_death_location.lock().await = Some((
+            ResourceLocation::from(self.world().dimension.minecraft_name),
+            block_pos,
+        ));
 
         let keep_inventory = { self.world().level_info.load().game_rules.keep_inventory };
 
@@ -2787,13 +3838,14 @@ impl Player {
 
             'after: {
                 let gamemode = event.new_gamemode;
-                self.gamemode.store(gamemode);
-                // TODO: Fix this when mojang fixes it
-                // This is intentional to keep the pure vanilla mojang experience
-                // self.previous_gamemode.store(self.previous_gamemode.load());
+                // Vanilla remembers the mode being left; the F3+F4 switcher and the
+                // `previousPlayerGameType` NBT tag both rely on it.
+                let previous_gamemode = self.gamemode.swap(gamemode);
+                self.previous_gamemode.store(Some(previous_gamemode));
                 {
                     // Use another scope so that we instantly unlock `abilities`.
                     let mut abilities = self.abilities.lock().await;
+                    abilities.flight_override = false;
                     abilities.set_for_gamemode(gamemode);
                 };
                 self.send_abilities_update().await;
@@ -2886,6 +3938,22 @@ impl Player {
                 .is_correct_for_drops(block)
     }
 
+    /// Whether the game mode keeps this player from breaking `block`. Spectators never can, and
+    /// adventure mode players only with a held item whose `can_break` lists the block.
+    pub async fn is_block_break_restricted(&self, block: &'static Block) -> bool {
+        match self.gamemode.load() {
+            GameMode::Spectator => true,
+            GameMode::Adventure => !self
+                .inventory
+                .held_item()
+                .lock()
+                .await
+                .get_data_component::<CanBreakImpl>()
+                .is_some_and(|can_break| can_break.matches(block)),
+            GameMode::Survival | GameMode::Creative => false,
+        }
+    }
+
     pub async fn get_mining_speed(&self, block: &'static Block) -> f32 {
         let mut speed = self.inventory.held_item().lock().await.get_speed(block);
         // Haste
@@ -2951,8 +4019,9 @@ impl Player {
             .await;
     }
 
-    /// Sends a custom payload packet to this player (Java edition only).
-    pub async fn send_custom_payload(&self, channel: &str, data: &[u8]) {
+    /// Sends a plugin message on a custom `channel` (e.g. `bungeecord:main`) to this player.
+    /// Bedrock clients have no equivalent, so nothing is sent to them.
+    pub async fn send_plugin_message(&self, channel: &str, data: &[u8]) {
         if let ClientPlatform::Java(java) = &self.client {
             java.enqueue_packet(&CCustomPayload::new(channel, data))
                 .await;
@@ -2996,7 +4065,9 @@ impl Player {
         let item_entity = Arc::new(ItemEntity::new_with_velocity(
             entity, item_stack, velocity, 40,
         ));
-        self.world().spawn_entity(item_entity).await;
+        self.world()
+            .spawn_entity_checked(item_entity, EntitySpawnReason::Drop)
+            .await;
     }
 
     pub async fn drop_held_item(&self, drop_stack: bool) {
@@ -3210,6 +4281,65 @@ impl Player {
         }
     }
 
+    /// Sends the player's whole state to their client again, e.g. after a plugin changed much of
+    /// it at once and the client may have fallen out of sync.
+    ///
+    /// This sends, in order: the health and food (`CSetHealth`), the experience
+    /// (`CSetExperience`), the abilities (`CPlayerAbilities`), the selected hotbar slot
+    /// (`CSetSelectedSlot`), the content of the open screen, including the player inventory and
+    /// the cursor (`CSetContainerContent`), every active effect (`CUpdateMobEffect`) and the
+    /// player's own entry in their tab list (`CPlayerInfoUpdate`).
+    pub async fn full_resync(&self) {
+        self.send_health().await;
+
+        let level = self.experience_level.load(Ordering::Relaxed);
+        self.last_sent_xp.store(level, Ordering::Relaxed);
+        self.client
+            .enqueue_packet(&CSetExperience::new(
+                self.experience_progress.load().clamp(0.0, 1.0),
+                level.into(),
+                self.experience_points.load(Ordering::Relaxed).into(),
+            ))
+            .await;
+
+        self.send_abilities_update().await;
+        self.enqueue_set_held_item_packet(&CSetSelectedSlot::new(
+            self.inventory.get_selected_slot() as i8,
+        ))
+        .await;
+
+        let screen_handler = self.current_screen_handler.lock().await.clone();
+        screen_handler.lock().await.sync_state().await;
+
+        self.send_active_effects().await;
+
+        let listed_name = self.get_listed_name().await;
+        self.client
+            .enqueue_packet(&CPlayerInfoUpdate::new(
+                (PlayerInfoFlags::UPDATE_GAME_MODE
+                    | PlayerInfoFlags::UPDATE_LISTED
+                    | PlayerInfoFlags::UPDATE_LATENCY
+                    | PlayerInfoFlags::UPDATE_LIST_PRIORITY
+                    | PlayerInfoFlags::UPDATE_DISPLAY_NAME)
+                    .bits(),
+                &[pumpkin_protocol::java::client::play::Player {
+                    uuid: self.gameprofile.id,
+                    actions: &[
+                        PlayerAction::UpdateGameMode(VarInt(self.gamemode.load() as i32)),
+                        PlayerAction::UpdateListed(self.tab_list_listed.load(Ordering::Relaxed)),
+                        PlayerAction::UpdateLatency(VarInt(
+                            self.tab_list_latency.load(Ordering::Relaxed),
+                        )),
+                        PlayerAction::UpdateDisplayName(listed_name.as_ref()),
+                        PlayerAction::UpdateListOrder(VarInt(
+                            self.tab_list_order.load(Ordering::Relaxed),
+                        )),
+                    ],
+                }],
+            ))
+            .await;
+    }
+
     /**
      * Send a clientside only effect to the player.
      * It won't be tracked on the server.
@@ -3449,6 +4579,7 @@ impl Player {
         }
 
         *self.current_screen_handler.lock().await = self.player_screen_handler.clone();
+        self.open_container.store(None);
         self.open_container_pos.store(None);
     }
 
@@ -3546,6 +4677,7 @@ impl Player {
             drop(screen_handler_temp);
             self.on_screen_handler_opened(screen_handler.clone()).await;
             *self.current_screen_handler.lock().await = screen_handler;
+            self.open_container.store(Some(u64::from(sync_id)));
             self.open_container_pos.store(block_pos);
             Some(self.screen_handler_sync_id.load(Ordering::Relaxed))
         } else {
@@ -3612,6 +4744,7 @@ impl Player {
         drop(screen_handler_temp);
         self.on_screen_handler_opened(screen_handler.clone()).await;
         *self.current_screen_handler.lock().await = screen_handler;
+        self.open_container.store(Some(u64::from(sync_id)));
         self.open_container_pos.store(None);
     }
 
@@ -3661,6 +4794,27 @@ impl Player {
             return;
         }
 
+        // The client predicts the result of its click, so a click made on stale contents
+        // (e.g. a slot a hopper just emptied, or before an update the server already sent)
+        // would apply to items the client never saw.
+        let slot_count = screen_handler.get_behaviour().slots.len();
+        let changed_slots_valid = packet
+            .array_of_changed_slots
+            .iter()
+            .all(|(changed_slot, _)| usize::try_from(*changed_slot).is_ok_and(|s| s < slot_count));
+        if !changed_slots_valid
+            || !screen_handler
+                .is_click_in_sync(i32::from(slot), packet.revision.0)
+                .await
+        {
+            debug!(
+                "Ignoring desynced click of {} on slot {slot}",
+                self.gameprofile.name
+            );
+            screen_handler.update_to_client().await;
+            return;
+        }
+
         // Fire InventoryClickEvent
         let clicked_item = if slot >= 0 {
             let slot_obj = &screen_handler.get_behaviour().slots[slot as usize];
@@ -3802,6 +4956,8 @@ impl Player {
                 .revision
                 .load(Ordering::Relaxed) as i32);
 
+        let equipment_before = self.visible_equipment().await;
+
         screen_handler.disable_sync();
         screen_handler
             .on_slot_click(
@@ -3812,6 +4968,10 @@ impl Player {
             )
             .await;
 
+        // Clicks can move armor in or out through many paths (swaps, drags, shift-clicks),
+        // so compare before and after instead of tracking each one.
+        self.send_changed_equipment(equipment_before).await;
+
         for (key, value) in packet.array_of_changed_slots {
             screen_handler.set_received_hash(key as usize, value);
         }
@@ -4050,6 +5210,25 @@ impl PartialEq for Player {
     }
 }
 
+/// Reads a player's experience as `(level, progress, points)`.
+///
+/// Vanilla keeps `XpLevel` and `XpP` authoritative, as that is what the client shows, while
+/// `XpTotal` keeps counting points that were already spent on enchanting. `XpTotal` is therefore
+/// only used when the level is missing.
+fn experience_from_nbt(nbt: &NbtCompound) -> (i32, f32, i32) {
+    if let Some(level) = nbt.get_int("XpLevel") {
+        let level = level.max(0);
+        let progress = nbt.get_float("XpP").unwrap_or(0.0).clamp(0.0, 1.0);
+        let points = (progress * experience::points_in_level(level) as f32) as i32;
+        return (level, progress, points);
+    }
+
+    let total_exp = nbt.get_int("XpTotal").unwrap_or(0).max(0);
+    let (level, points) = experience::total_to_level_and_points(total_exp);
+    let progress = experience::progress_in_level(points, level);
+    (level, progress, points)
+}
+
 impl NBTStorage for Player {
     fn write_nbt<'a>(&'a self, nbt: &'a mut NbtCompound) -> NbtFuture<'a, ()> {
         Box::pin(async move {
@@ -4063,6 +5242,8 @@ impl NBTStorage for Player {
             let total_exp =
                 experience::points_to_level(self.experience_level.load(Ordering::Relaxed))
                     + self.experience_points.load(Ordering::Relaxed);
+            nbt.put_int("XpLevel", self.experience_level.load(Ordering::Relaxed));
+            nbt.put_float("XpP", self.experience_progress.load());
             nbt.put_int("XpTotal", total_exp);
             nbt.put_byte("playerGameType", self.gamemode.load() as i8);
             if let Some(previous_gamemode) = self.previous_gamemode.load() {
@@ -4073,6 +5254,7 @@ impl NBTStorage for Player {
                 "HasPlayedBefore",
                 self.has_played_before.load(Ordering::Relaxed),
             );
+            nbt.put_bool("seenCredits", self.seen_credits.load(Ordering::Relaxed));
 
             // Store food level, saturation, exhaustion, and tick timer
             self.hunger_manager.write_nbt(nbt).await;
@@ -4092,6 +5274,12 @@ impl NBTStorage for Player {
                 );
                 nbt.put_bool("SpawnForced", respawn.force);
             }
+            if let Some((dimension, pos)) = self.last_death_location.lock().await.as_ref() {
+                let mut death_location = NbtCompound::new();
+                death_location.put_string("dimension", dimension.clone());
+                death_location.put("pos", NbtTag::IntArray(vec![pos.0.x, pos.0.y, pos.0.z]));
+                nbt.put_compound("LastDeathLocation", death_location);
+            }
             nbt.put_int("XpSeed", self.enchantment_seed.load(Ordering::Relaxed));
             self.stats.lock().await.write_nbt(nbt);
         })
@@ -4104,26 +5292,33 @@ impl NBTStorage for Player {
             self.ender_chest_inventory.read_nbt_non_mut(nbt).await;
             self.abilities.lock().await.read_nbt(nbt).await;
 
-            // Load from total XP
-            let total_exp = nbt.get_int("XpTotal").unwrap_or(0);
-            let (level, points) = experience::total_to_level_and_points(total_exp);
-            let progress = experience::progress_in_level(level, points);
+            let (level, progress, points) = experience_from_nbt(nbt);
             self.experience_level.store(level, Ordering::Relaxed);
             self.experience_progress.store(progress);
             self.experience_points.store(points, Ordering::Relaxed);
 
-            self.gamemode.store(
-                GameMode::try_from(nbt.get_byte("playerGameType").unwrap_or(0))
-                    .unwrap_or(GameMode::Survival),
-            );
+            // Without a saved gamemode, keep the server's default one the player was created with
+            if let Some(gamemode) = nbt
+                .get_byte("playerGameType")
+                .and_then(|byte| GameMode::try_from(byte).ok())
+            {
+                self.gamemode.store(gamemode);
+            }
 
             self.previous_gamemode.store(
                 nbt.get_byte("previousPlayerGameType")
                     .and_then(|byte| GameMode::try_from(byte).ok()),
             );
 
+            // Vanilla player data has no `HasPlayedBefore`; a saved position means the player
+            // has joined before and must not be moved back to the world spawn.
             self.has_played_before.store(
-                nbt.get_bool("HasPlayedBefore").unwrap_or(false),
+                nbt.get_bool("HasPlayedBefore")
+                    .unwrap_or_else(|| nbt.has("Pos")),
+                Ordering::Relaxed,
+            );
+            self.seen_credits.store(
+                nbt.get_bool("seenCredits").unwrap_or(false),
                 Ordering::Relaxed,
             );
 
@@ -4135,10 +5330,16 @@ impl NBTStorage for Player {
                 nbt.get_int("SpawnY"),
                 nbt.get_int("SpawnZ"),
             ) {
+                let world = self.world();
                 let dim = nbt
                     .get_string("SpawnDimension")
-                    .and_then(|s| Dimension::from_name(s).cloned())
-                    .unwrap_or_else(|| self.world().dimension.clone());
+                    .and_then(|s| {
+                        world
+                            .server
+                            .upgrade()
+                            .and_then(|server| server.get_dimension_by_name(s))
+                    })
+                    .unwrap_or_else(|| world.dimension.clone());
                 let force = nbt.get_bool("SpawnForced").unwrap_or(false);
                 *self.respawn_point.lock().await = Some(RespawnPoint {
                     dimension: dim,
@@ -4147,6 +5348,13 @@ impl NBTStorage for Player {
                     force,
                 });
             }
+            if let Some(death_location) = nbt.get_compound("LastDeathLocation")
+                && let Some(dimension) = death_location.get_string("dimension")
+                && let Some(&[x, y, z]) = death_location.get_int_array("pos")
+            {
+                *self.last_death_location.lock().await =
+                    Some((dimension.to_owned(), BlockPos(Vector3::new(x, y, z))));
+            }
             self.enchantment_seed.store(
                 nbt.get_int("XpSeed").unwrap_or(rand::random()),
                 Ordering::Relaxed,
@@ -4409,8 +5617,11 @@ impl EntityBase for Player {
     }
 
     fn get_name(&self) -> TextComponent {
-        //TODO: team color
-        TextComponent::text(self.gameprofile.name.clone())
+        let name = TextComponent::text(self.gameprofile.name.clone());
+        match self.team_color.load() {
+            Some(color) => name.color_named(color),
+            None => name,
+        }
     }
 
     fn get_display_name(&self) -> EntityBaseFuture<'_, TextComponent> {
@@ -4478,6 +5689,9 @@ pub struct Abilities {
     pub fly_speed: f32,
     /// The field of view adjustment when the player is walking or sprinting.
     pub walk_speed: f32,
+    /// Whether flight was set manually (e.g. by a plugin), so that survival and adventure
+    /// keep it until the gamemode changes.
+    pub flight_override: bool,
 }
 
 impl NBTStorage for Abilities {
@@ -4522,6 +5736,7 @@ impl Default for Abilities {
             allow_modify_world: true,
             fly_speed: 0.05,
             walk_speed: 0.1,
+            flight_override: false,
         }
     }
 }
@@ -4542,8 +5757,10 @@ impl Abilities {
                 self.invulnerable = true;
             }
             _ => {
-                self.flying = false;
-                self.allow_flying = false;
+                if !self.flight_override {
+                    self.flying = false;
+                    self.allow_flying = false;
+                }
                 self.creative = false;
                 self.invulnerable = false;
             }
@@ -4867,3 +6084,180 @@ impl InventoryPlayer for Player {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use pumpkin_nbt::nbt_compress::read_gzip_compound_tag;
+
+    use super::*;
+    use crate::server::test_server;
+
+    #[test]
+    fn rays_pass_through_fluids() {
+        let pos = BlockPos::new(0, 0, 0);
+        let start = Vector3::new(-1.0, 0.5, 0.5);
+        let end = Vector3::new(2.0, 0.5, 0.5);
+
+        assert!(outline_hit(Block::WATER.default_state, pos, start, end).is_none());
+        assert!(outline_hit(Block::AIR.default_state, pos, start, end).is_none());
+    }
+
+    #[test]
+    fn rays_hit_full_blocks_on_their_face() {
+        let pos = BlockPos::new(0, 0, 0);
+        let hit = outline_hit(
+            Block::STONE.default_state,
+            pos,
+            Vector3::new(-1.0, 0.5, 0.5),
+            Vector3::new(2.0, 0.5, 0.5),
+        )
+        .expect("the ray crosses the block");
+
+        assert!(hit.x.abs() < 1.0e-9);
+    }
+
+    #[test]
+    fn rays_only_hit_the_filled_part_of_partial_blocks() {
+        // The default slab is a bottom slab
+        let slab = Block::STONE_SLAB.default_state;
+        let pos = BlockPos::new(0, 0, 0);
+
+        let above = outline_hit(
+            slab,
+            pos,
+            Vector3::new(-1.0, 0.75, 0.5),
+            Vector3::new(2.0, 0.75, 0.5),
+        );
+        assert!(above.is_none());
+
+        let through = outline_hit(
+            slab,
+            pos,
+            Vector3::new(-1.0, 0.25, 0.5),
+            Vector3::new(2.0, 0.25, 0.5),
+        )
+        .expect("the ray crosses the slab");
+        assert!(through.x.abs() < 1.0e-9);
+    }
+
+    /// Creates a player in the overworld of `server`, connected over a local socket.
+    async fn test_player(server: &Server) -> Player {
+        use crate::net::java::JavaClient;
+        use crate::net::{ClientPlatform, GameProfile};
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        let stream = tokio::net::TcpStream::connect(address).await.unwrap();
+        let profile = GameProfile {
+            id: Uuid::new_v4(),
+            name: "Tester".to_string(),
+            properties: ArcSwap::new(Arc::new(Vec::new())),
+            profile_actions: None,
+        };
+        Player::new(
+            ClientPlatform::Java(JavaClient::new(stream, address, 0)),
+            profile,
+            PlayerConfig::default(),
+            server.get_world_from_dimension(&Dimension::OVERWORLD),
+            GameMode::Survival,
+        )
+        .await
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn player_nbt_round_trips_position_and_state() {
+        let dir = tempfile::tempdir().unwrap();
+        let server = test_server(dir.path()).await;
+
+        let player = test_player(&server).await;
+        let entity = &player.living_entity.entity;
+        entity.set_pos(Vector3::new(12.375, 70.5, -803.0625));
+        entity.set_rotation(-137.5, 42.25);
+        player.living_entity.fall_distance.store(3.5);
+        player.has_played_before.store(true, Ordering::Relaxed);
+
+        let mut nbt = NbtCompound::new();
+        player.write_nbt(&mut nbt).await;
+
+        let mut loaded = test_player(&server).await;
+        assert!(!loaded.has_played_before.load(Ordering::Relaxed));
+        loaded.read_nbt(&mut nbt).await;
+
+        let entity = &loaded.living_entity.entity;
+        assert_eq!(entity.pos.load(), Vector3::new(12.375, 70.5, -803.0625));
+        assert!((entity.yaw.load() + 137.5).abs() < f32::EPSILON);
+        assert!((entity.pitch.load() - 42.25).abs() < f32::EPSILON);
+        assert!((loaded.living_entity.fall_distance.load() - 3.5).abs() < f32::EPSILON);
+        assert!(loaded.has_played_before.load(Ordering::Relaxed));
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn relative_teleport_snaps_to_the_offset_target_on_confirm() {
+        use crate::net::ClientPlatform;
+        use pumpkin_protocol::java::server::play::SConfirmTeleport;
+
+        let dir = tempfile::tempdir().unwrap();
+        let server = test_server(dir.path()).await;
+
+        let player = Arc::new(test_player(&server).await);
+        let entity = &player.living_entity.entity;
+        entity.set_pos(Vector3::new(10.5, 64.0, -3.5));
+        entity.set_rotation(90.0, 80.0);
+
+        player
+            .teleport_relative(Vector3::new(0.0, 5.0, 1.0), 10.0, 20.0)
+            .await;
+
+        let target = Vector3::new(10.5, 69.0, -2.5);
+        assert_eq!(entity.pos.load(), target);
+        assert!((entity.yaw.load() - 100.0).abs() < f32::EPSILON);
+        assert!((entity.pitch.load() - 90.0).abs() < f32::EPSILON);
+        let teleport_id = {
+            let awaiting = player.awaiting_teleport.lock().await;
+            let (id, position) = awaiting.as_ref().expect("the teleport awaits confirmation");
+            assert_eq!(*position, target);
+            *id
+        };
+
+        // Movement the client reports before confirming is dropped
+        entity.set_pos(Vector3::new(11.0, 64.0, -3.5));
+        let ClientPlatform::Java(client) = &player.client else {
+            panic!("test players connect over Java");
+        };
+        client
+            .handle_confirm_teleport(&player, SConfirmTeleport { teleport_id })
+            .await;
+
+        assert_eq!(entity.pos.load(), target);
+        assert!(player.awaiting_teleport.lock().await.is_none());
+    }
+
+    #[test]
+    fn vanilla_player_experience_prefers_level_and_progress() {
+        let nbt = read_gzip_compound_tag(Cursor::new(include_bytes!(
+            "../../assets/player_1_21_4.dat"
+        )))
+        .unwrap();
+
+        let (level, progress, points) = experience_from_nbt(&nbt);
+
+        // The fixture's XpTotal includes levels spent on enchanting and must not win.
+        assert_eq!(level, 30);
+        assert!((progress - 0.5).abs() < f32::EPSILON);
+        assert_eq!(points, experience::points_in_level(30) / 2);
+    }
+
+    #[test]
+    fn experience_falls_back_to_total_without_level() {
+        let mut nbt = NbtCompound::new();
+        nbt.put_int("XpTotal", experience::points_to_level(16) + 10);
+
+        let (level, progress, points) = experience_from_nbt(&nbt);
+
+        assert_eq!(level, 16);
+        assert_eq!(points, 10);
+        assert!((progress - experience::progress_in_level(10, 16)).abs() < f32::EPSILON);
+    }
+}
@@ -7,6 +7,7 @@ use crate::{
         projectile::{ProjectileHit, ThrownItemEntity},
     },
     server::Server,
+    world::explosion::ExplosionBlockInteraction,
 };
 
 const EXPLOSION_POWER: f32 = 1.0;
@@ -93,8 +94,21 @@ impl EntityBase for FireballEntity {
             }
 
             let hit_pos = hit.hit_pos();
-            // Explosion sets fire if mob griefing is enabled (assuming true for now)
-            world.explode(hit_pos, self.explosion_power).await;
+            // Ghast fireballs only set fire and break blocks if mob griefing is enabled
+            let mob_griefing = world.level_info.load().game_rules.mob_griefing;
+            let block_interaction = if mob_griefing {
+                ExplosionBlockInteraction::DestroyWithDecay
+            } else {
+                ExplosionBlockInteraction::Keep
+            };
+            world
+                .create_explosion(
+                    hit_pos,
+                    self.explosion_power,
+                    mob_griefing,
+                    block_interaction,
+                )
+                .await;
         })
     }
 }
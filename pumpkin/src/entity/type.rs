@@ -13,6 +13,7 @@ use crate::entity::decoration::{
 use crate::entity::experience_orb::ExperienceOrbEntity;
 use crate::entity::falling::FallingEntity;
 use crate::entity::item::ItemEntity;
+use crate::entity::lightning_bolt::LightningBoltEntity;
 use crate::entity::living::LivingEntity;
 use crate::entity::mob::bat::{self, BatEntity};
 use crate::entity::mob::blaze::BlazeEntity;
@@ -237,6 +238,9 @@ pub fn from_type(
         }
         id if id == EntityType::EXPERIENCE_ORB.id => Arc::new(ExperienceOrbEntity::new(entity, 1)),
         id if id == EntityType::TNT.id => Arc::new(TNTEntity::new(entity, 4.0, 80)),
+        id if id == EntityType::LIGHTNING_BOLT.id => {
+            Arc::new(LightningBoltEntity::new(entity, false))
+        }
         id if id == EntityType::ITEM.id => {
             Arc::new(ItemEntity::new(entity, ItemStack::new(1, &Item::AIR)))
         }
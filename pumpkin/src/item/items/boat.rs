@@ -10,6 +10,7 @@ use pumpkin_data::Block;
 use pumpkin_data::entity::EntityType;
 use pumpkin_data::fluid::Fluid;
 use pumpkin_data::item::Item;
+use pumpkin_util::Hand;
 use pumpkin_util::math::boundingbox::{BoundingBox, EntityDimensions};
 use pumpkin_util::math::position::BlockPos;
 use pumpkin_util::math::vector3::Vector3;
@@ -93,6 +94,7 @@ impl ItemBehaviour for BoatItem {
         &'a self,
         item: &'a Item,
         player: &'a Player,
+        hand: Hand,
     ) -> Pin<Box<dyn Future<Output = ()> + Send + 'a>> {
         Box::pin(async move {
             let world = player.world();
@@ -182,7 +184,7 @@ impl ItemBehaviour for BoatItem {
             world.spawn_entity(boat_entity).await;
 
             // Decrement item unless in creative mode
-            let held_item = player.inventory.held_item();
+            let held_item = player.inventory.get_stack_in_hand(hand).await;
             let mut stack = held_item.lock().await;
             stack.decrement_unless_creative(player.gamemode.load(), 1);
 
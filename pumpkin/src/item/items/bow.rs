@@ -14,7 +14,7 @@ use pumpkin_data::item_stack::ItemStack;
 use pumpkin_data::sound::{Sound, SoundCategory};
 use pumpkin_protocol::IdOr;
 use pumpkin_protocol::java::client::play::CSoundEffect;
-use pumpkin_util::GameMode;
+use pumpkin_util::{GameMode, Hand};
 
 pub struct BowItem;
 
@@ -29,6 +29,7 @@ impl ItemBehaviour for BowItem {
         &'a self,
         _item: &'a Item,
         player: &'a Player,
+        hand: Hand,
     ) -> Pin<Box<dyn Future<Output = ()> + Send + 'a>> {
         Box::pin(async move {
             // Check if player has arrows (or is in creative mode)
@@ -39,15 +40,14 @@ impl ItemBehaviour for BowItem {
                 return;
             }
 
-            // Get the held item stack
-            let inventory = player.inventory();
-            let held = inventory.held_item();
+            // Get the item stack of the hand the bow is drawn with
+            let held = player.inventory().get_stack_in_hand(hand).await;
             let stack = held.lock().await.clone();
 
             // Start the bow drawing animation
             player
                 .living_entity
-                .set_active_hand(pumpkin_util::Hand::Right, stack, Self::USE_DURATION)
+                .set_active_hand(hand, stack, Self::USE_DURATION)
                 .await;
         })
     }
@@ -108,7 +108,13 @@ impl BowItem {
         }
 
         // Damage bow
-        player.damage_held_item(1).await;
+        let hand = player
+            .living_entity
+            .active_hand
+            .lock()
+            .await
+            .unwrap_or(Hand::Right);
+        player.damage_item_in_hand(hand, 1).await;
     }
 
     /// Check if player has arrows in their inventory
@@ -13,10 +13,10 @@ use pumpkin_data::{
     sound::{Sound, SoundCategory},
 };
 use pumpkin_util::{
-    GameMode,
+    GameMode, Hand,
     math::{position::BlockPos, vector3::Vector3},
 };
-use pumpkin_world::{inventory::Inventory, tick::TickPriority, world::BlockFlags};
+use pumpkin_world::{tick::TickPriority, world::BlockFlags};
 
 use crate::world::World;
 
@@ -98,7 +98,7 @@ fn set_waterlogged(block: &Block, state: u16, waterlogged: bool) -> u16 {
     block.from_properties(&props).to_state_id(block)
 }
 
-async fn give_player_bucket_item(player: &Player, item: &'static Item) {
+async fn give_player_bucket_item(player: &Player, hand: Hand, item: &'static Item) {
     if player.gamemode.load() == GameMode::Creative {
         for i in 0..player.inventory.main_inventory.len() {
             if player.inventory.main_inventory[i].lock().await.item.id == item.id {
@@ -111,14 +111,15 @@ async fn give_player_bucket_item(player: &Player, item: &'static Item) {
             .insert_stack_anywhere(&mut item_stack)
             .await;
     } else {
-        let item_stack = ItemStack::new(1, item);
-        player
-            .inventory
-            .set_stack(player.inventory.get_selected_slot().into(), item_stack)
-            .await;
+        set_stack_in_hand(player, hand, ItemStack::new(1, item)).await;
     }
 }
 
+/// Replaces the bucket in `hand` with `stack`.
+async fn set_stack_in_hand(player: &Player, hand: Hand, stack: ItemStack) {
+    *player.inventory.get_stack_in_hand(hand).await.lock().await = stack;
+}
+
 async fn try_pickup_bucket_item(
     world: &Arc<World>,
     block_pos: BlockPos,
@@ -277,6 +278,7 @@ impl ItemBehaviour for EmptyBucketItem {
         &'a self,
         _block: &'a Item,
         player: &'a Player,
+        hand: Hand,
     ) -> Pin<Box<dyn Future<Output = ()> + Send + 'a>> {
         Box::pin(async move {
             let world = player.world();
@@ -305,7 +307,7 @@ impl ItemBehaviour for EmptyBucketItem {
                 return;
             };
 
-            give_player_bucket_item(player, item).await;
+            give_player_bucket_item(player, hand, item).await;
         })
     }
 
@@ -319,6 +321,7 @@ impl ItemBehaviour for FilledBucketItem {
         &'a self,
         item: &'a Item,
         player: &'a Player,
+        hand: Hand,
     ) -> Pin<Box<dyn Future<Output = ()> + Send + 'a>> {
         Box::pin(async move {
             let world = player.world();
@@ -345,11 +348,7 @@ impl ItemBehaviour for FilledBucketItem {
 
             //TODO: Spawn entity if applicable
             if player.gamemode.load() != GameMode::Creative {
-                let item_stack = ItemStack::new(1, &Item::BUCKET);
-                player
-                    .inventory
-                    .set_stack(player.inventory.get_selected_slot().into(), item_stack)
-                    .await;
+                set_stack_in_hand(player, hand, ItemStack::new(1, &Item::BUCKET)).await;
             }
         })
     }
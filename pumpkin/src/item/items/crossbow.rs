@@ -15,7 +15,7 @@ use pumpkin_data::entity::EntityType;
 use pumpkin_data::item::Item;
 use pumpkin_data::item_stack::ItemStack;
 use pumpkin_data::sound::{Sound, SoundCategory};
-use pumpkin_util::GameMode;
+use pumpkin_util::{GameMode, Hand};
 use pumpkin_world::inventory::Inventory;
 
 pub struct CrossbowItem;
@@ -31,17 +31,17 @@ impl ItemBehaviour for CrossbowItem {
         &'a self,
         _item: &'a Item,
         player: &'a Player,
+        hand: Hand,
     ) -> Pin<Box<dyn Future<Output = ()> + Send + 'a>> {
         Box::pin(async move {
-            let inventory = player.inventory();
-            let held = inventory.held_item();
+            let held = player.inventory().get_stack_in_hand(hand).await;
             let stack = held.lock().await.clone();
 
             if stack
                 .get_data_component::<ChargedProjectilesImpl>()
                 .is_some()
             {
-                Self::fire_projectiles(player, &held).await;
+                Self::fire_projectiles(player, &held, hand).await;
                 return;
             }
 
@@ -52,7 +52,7 @@ impl ItemBehaviour for CrossbowItem {
 
             player
                 .living_entity
-                .set_active_hand(pumpkin_util::Hand::Right, stack, 72000)
+                .set_active_hand(hand, stack, 72000)
                 .await;
         })
     }
@@ -67,7 +67,13 @@ impl ItemBehaviour for CrossbowItem {
             let use_ticks = 72000 - use_ticks;
 
             let mut charge_time = 25;
-            let held = player.inventory().held_item();
+            let hand = player
+                .living_entity
+                .active_hand
+                .lock()
+                .await
+                .unwrap_or(Hand::Right);
+            let held = player.inventory().get_stack_in_hand(hand).await;
             let stack = held.lock().await;
 
             if let Some(enchantments) = stack.get_data_component::<EnchantmentsImpl>() {
@@ -137,7 +143,7 @@ impl ItemBehaviour for CrossbowItem {
 }
 
 impl CrossbowItem {
-    async fn fire_projectiles(player: &Player, held: &Arc<Mutex<ItemStack>>) {
+    async fn fire_projectiles(player: &Player, held: &Arc<Mutex<ItemStack>>, hand: Hand) {
         let mut stack = held.lock().await;
         let projectiles = stack
             .get_data_component::<ChargedProjectilesImpl>()
@@ -189,7 +195,8 @@ impl CrossbowItem {
             stack
                 .patch
                 .retain(|(id, _)| *id != DataComponent::ChargedProjectiles);
-            player.damage_held_item(1).await;
+            drop(stack);
+            player.damage_item_in_hand(hand, 1).await;
         }
     }
 }
@@ -10,6 +10,7 @@ use pumpkin_data::entity::EntityType;
 use pumpkin_data::item::Item;
 use pumpkin_data::item_stack::ItemStack;
 use pumpkin_data::sound::Sound;
+use pumpkin_util::Hand;
 
 pub struct EggItem;
 
@@ -26,6 +27,7 @@ impl ItemBehaviour for EggItem {
         &'a self,
         _block: &'a Item,
         player: &'a Player,
+        hand: Hand,
     ) -> Pin<Box<dyn Future<Output = ()> + Send + 'a>> {
         Box::pin(async move {
             let position = player.position();
@@ -37,7 +39,13 @@ impl ItemBehaviour for EggItem {
             );
 
             // Capture the held item stack and pass it to the thrown egg entity
-            let item_stack: ItemStack = player.inventory.held_item().lock().await.clone();
+            let item_stack: ItemStack = player
+                .inventory
+                .get_stack_in_hand(hand)
+                .await
+                .lock()
+                .await
+                .clone();
 
             let entity = Entity::new(world.clone(), position, &EntityType::EGG);
             let egg = EggEntity::new_shot(entity, player.get_entity());
@@ -51,24 +59,13 @@ impl ItemBehaviour for EggItem {
             world.spawn_entity(Arc::new(egg)).await;
 
             // Consume item
-            let held_item = player.inventory.held_item();
-            let consumed = {
-                let mut main_hand = held_item.lock().await;
-                if !main_hand.is_empty() && Self::ids().contains(&main_hand.item.id) {
-                    main_hand.decrement_unless_creative(player.gamemode.load(), 1);
-                    true
-                } else {
-                    false
-                }
-            };
-
-            if !consumed {
-                let off_hand_item = player.inventory.off_hand_item().await;
-                let mut off_hand = off_hand_item.lock().await;
-                if !off_hand.is_empty() && Self::ids().contains(&off_hand.item.id) {
-                    off_hand.decrement_unless_creative(player.gamemode.load(), 1);
-                }
-            }
+            player
+                .inventory
+                .get_stack_in_hand(hand)
+                .await
+                .lock()
+                .await
+                .decrement_unless_creative(player.gamemode.load(), 1);
         })
     }
 
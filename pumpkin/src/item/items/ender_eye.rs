@@ -15,6 +15,7 @@ use pumpkin_data::sound::{Sound, SoundCategory};
 use pumpkin_data::structures::StructureSet;
 use pumpkin_data::world::WorldEvent;
 use pumpkin_data::{Block, BlockDirection};
+use pumpkin_util::Hand;
 use pumpkin_util::math::position::BlockPos;
 use pumpkin_util::math::vector3::Vector3;
 use pumpkin_world::generation::generator::structure_finder::find_nearest_structure;
@@ -78,6 +79,7 @@ impl ItemBehaviour for EnderEyeItem {
         &'a self,
         _item: &'a Item,
         player: &'a Player,
+        hand: Hand,
     ) -> Pin<Box<dyn Future<Output = ()> + Send + 'a>> {
         Box::pin(async move {
             let world = player.world();
@@ -127,7 +129,13 @@ impl ItemBehaviour for EnderEyeItem {
                 pitch,
             );
 
-            player.inventory.held_item().lock().await.decrement(1);
+            player
+                .inventory
+                .get_stack_in_hand(hand)
+                .await
+                .lock()
+                .await
+                .decrement(1);
         })
     }
 
@@ -11,6 +11,7 @@ use crate::item::{ItemBehaviour, ItemMetadata};
 use pumpkin_data::entity::EntityType;
 use pumpkin_data::item::Item;
 use pumpkin_data::sound::Sound;
+use pumpkin_util::Hand;
 
 pub struct EnderPearlItem;
 
@@ -30,6 +31,7 @@ impl ItemBehaviour for EnderPearlItem {
         &'a self,
         _item: &'a Item,
         player: &'a Player,
+        hand: Hand,
     ) -> Pin<Box<dyn Future<Output = ()> + Send + 'a>> {
         Box::pin(async move {
             let position = player.position();
@@ -56,24 +58,13 @@ impl ItemBehaviour for EnderPearlItem {
             world.spawn_entity(Arc::new(pearl)).await;
 
             // Consume item
-            let held_item = player.inventory.held_item();
-            let consumed = {
-                let mut main_hand = held_item.lock().await;
-                if !main_hand.is_empty() && main_hand.item.id == Item::ENDER_PEARL.id {
-                    main_hand.decrement_unless_creative(player.gamemode.load(), 1);
-                    true
-                } else {
-                    false
-                }
-            };
-
-            if !consumed {
-                let off_hand_item = player.inventory.off_hand_item().await;
-                let mut off_hand = off_hand_item.lock().await;
-                if !off_hand.is_empty() && off_hand.item.id == Item::ENDER_PEARL.id {
-                    off_hand.decrement_unless_creative(player.gamemode.load(), 1);
-                }
-            }
+            player
+                .inventory
+                .get_stack_in_hand(hand)
+                .await
+                .lock()
+                .await
+                .decrement_unless_creative(player.gamemode.load(), 1);
         })
     }
 
@@ -11,6 +11,7 @@ use pumpkin_data::BlockDirection;
 use pumpkin_data::entity::EntityType;
 use pumpkin_data::item::Item;
 use pumpkin_data::item_stack::ItemStack;
+use pumpkin_util::Hand;
 use pumpkin_util::math::position::BlockPos;
 use pumpkin_util::math::vector3::Vector3;
 
@@ -53,6 +54,7 @@ impl ItemBehaviour for FireworkRocketItem {
         &'a self,
         _item: &'a Item,
         player: &'a Player,
+        _hand: Hand,
     ) -> Pin<Box<dyn Future<Output = ()> + Send + 'a>> {
         Box::pin(async {
             if player.get_entity().is_fall_flying() {
@@ -97,7 +97,6 @@ impl ItemBehaviour for HoeItem {
                 }
 
                 if changed && player.gamemode.load() != GameMode::Creative {
-                    // TODO: Handle DamageResult::Broken to broadcast item break and update player slot.
                     let _ = item.damage_item(1);
                 }
             }
@@ -6,7 +6,7 @@ use pumpkin_data::data_component_impl::DataComponentImpl;
 use pumpkin_data::data_component_impl::MapIdImpl;
 use pumpkin_data::item::Item;
 use pumpkin_data::item_stack::ItemStack;
-use pumpkin_util::GameMode;
+use pumpkin_util::{GameMode, Hand};
 use std::any::Any;
 use std::future::Future;
 use std::pin::Pin;
@@ -24,22 +24,16 @@ impl ItemBehaviour for MapItem {
         &'a self,
         _item: &'a Item,
         player: &'a Player,
+        hand: Hand,
     ) -> Pin<Box<dyn Future<Output = ()> + Send + 'a>> {
         Box::pin(async move {
             let server = player.world().server.upgrade().unwrap();
 
             let inventory = player.inventory();
-            let main_hand_item = inventory.held_item();
-            let off_hand_item = inventory.off_hand_item().await;
-            let mut hand_stack = main_hand_item.lock().await;
+            let hand_item = inventory.get_stack_in_hand(hand).await;
+            let mut hand_stack = hand_item.lock().await;
 
-            let found = if !hand_stack.is_empty() && hand_stack.item.id == Item::MAP.id {
-                true
-            } else {
-                drop(hand_stack);
-                hand_stack = off_hand_item.lock().await;
-                !hand_stack.is_empty() && hand_stack.item.id == Item::MAP.id
-            };
+            let found = !hand_stack.is_empty() && hand_stack.item.id == Item::MAP.id;
 
             if found {
                 let map_id = server.next_map_id();
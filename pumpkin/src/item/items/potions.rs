@@ -10,8 +10,8 @@ use crate::entity::projectile::{
 use crate::item::{ItemBehaviour, ItemMetadata};
 use pumpkin_data::entity::EntityType;
 use pumpkin_data::item::Item;
-use pumpkin_data::item_stack::ItemStack;
 use pumpkin_data::sound::Sound;
+use pumpkin_util::Hand;
 
 pub struct PotionItem;
 pub struct SplashPotionItem;
@@ -42,6 +42,7 @@ impl ItemBehaviour for PotionItem {
         &'a self,
         _item: &'a Item,
         _player: &'a Player,
+        _hand: Hand,
     ) -> Pin<Box<dyn Future<Output = ()> + Send + 'a>> {
         // Drinking is handled by the consumable flow in the server (active hand + consumption tick).
         Box::pin(async move {})
@@ -57,6 +58,7 @@ impl ItemBehaviour for SplashPotionItem {
         &'a self,
         _item: &'a Item,
         player: &'a Player,
+        hand: Hand,
     ) -> Pin<Box<dyn Future<Output = ()> + Send + 'a>> {
         Box::pin(async move {
             let position = player.position();
@@ -69,23 +71,9 @@ impl ItemBehaviour for SplashPotionItem {
             let entity = Entity::new(world.clone(), position, &EntityType::SPLASH_POTION);
             let splash = SplashPotionEntity::new_shot(entity, player.get_entity());
 
-            // Copy the held item stack data into the projectile
-            let main = player.inventory.held_item();
-            let mut used_main = true;
-            let mut stack = {
-                let s = main.lock().await.clone();
-                (!s.is_empty() && s.item.id == pumpkin_data::item::Item::SPLASH_POTION.id)
-                    .then_some(s)
-            };
-            if stack.is_none() {
-                let off = player.inventory.off_hand_item().await;
-                let s = off.lock().await.clone();
-                if !s.is_empty() && s.item.id == pumpkin_data::item::Item::SPLASH_POTION.id {
-                    stack = Some(s);
-                    used_main = false;
-                }
-            }
-            let stack = stack.unwrap_or_else(|| ItemStack::EMPTY.clone());
+            // Copy the used item stack data into the projectile
+            let held_item = player.inventory.get_stack_in_hand(hand).await;
+            let stack = held_item.lock().await.clone();
             splash.set_item_stack(stack).await;
 
             let (yaw, pitch) = player.rotation();
@@ -96,22 +84,10 @@ impl ItemBehaviour for SplashPotionItem {
             world.spawn_entity(Arc::new(splash)).await;
 
             // Decrement the used stack (clear)
-            if used_main {
-                player
-                    .inventory
-                    .held_item()
-                    .lock()
-                    .await
-                    .decrement_unless_creative(player.gamemode.load(), 1);
-            } else {
-                player
-                    .inventory
-                    .off_hand_item()
-                    .await
-                    .lock()
-                    .await
-                    .decrement_unless_creative(player.gamemode.load(), 1);
-            }
+            held_item
+                .lock()
+                .await
+                .decrement_unless_creative(player.gamemode.load(), 1);
         })
     }
 
@@ -125,6 +101,7 @@ impl ItemBehaviour for LingeringPotionItem {
         &'a self,
         _item: &'a Item,
         player: &'a Player,
+        hand: Hand,
     ) -> Pin<Box<dyn Future<Output = ()> + Send + 'a>> {
         Box::pin(async move {
             let position = player.position();
@@ -137,23 +114,9 @@ impl ItemBehaviour for LingeringPotionItem {
             let entity = Entity::new(world.clone(), position, &EntityType::LINGERING_POTION);
             let ling = LingeringPotionEntity::new_shot(entity, player.get_entity());
 
-            // Copy the held item stack data into the projectile
-            let main = player.inventory.held_item();
-            let mut used_main = true;
-            let mut stack = {
-                let s = main.lock().await.clone();
-                (!s.is_empty() && s.item.id == pumpkin_data::item::Item::LINGERING_POTION.id)
-                    .then_some(s)
-            };
-            if stack.is_none() {
-                let off = player.inventory.off_hand_item().await;
-                let s = off.lock().await.clone();
-                if !s.is_empty() && s.item.id == pumpkin_data::item::Item::LINGERING_POTION.id {
-                    stack = Some(s);
-                    used_main = false;
-                }
-            }
-            let stack = stack.unwrap_or_else(|| ItemStack::EMPTY.clone());
+            // Copy the used item stack data into the projectile
+            let held_item = player.inventory.get_stack_in_hand(hand).await;
+            let stack = held_item.lock().await.clone();
             ling.set_item_stack(stack).await;
 
             let (yaw, pitch) = player.rotation();
@@ -163,22 +126,10 @@ impl ItemBehaviour for LingeringPotionItem {
             world.spawn_entity(Arc::new(ling)).await;
 
             // Decrement the used stack (clear)
-            if used_main {
-                player
-                    .inventory
-                    .held_item()
-                    .lock()
-                    .await
-                    .decrement_unless_creative(player.gamemode.load(), 1);
-            } else {
-                player
-                    .inventory
-                    .off_hand_item()
-                    .await
-                    .lock()
-                    .await
-                    .decrement_unless_creative(player.gamemode.load(), 1);
-            }
+            held_item
+                .lock()
+                .await
+                .decrement_unless_creative(player.gamemode.load(), 1);
         })
     }
 
@@ -9,6 +9,7 @@ use crate::item::{ItemBehaviour, ItemMetadata};
 use pumpkin_data::entity::EntityType;
 use pumpkin_data::item::Item;
 use pumpkin_data::sound::Sound;
+use pumpkin_util::Hand;
 
 pub struct SnowBallItem;
 
@@ -25,6 +26,7 @@ impl ItemBehaviour for SnowBallItem {
         &'a self,
         _block: &'a Item,
         player: &'a Player,
+        hand: Hand,
     ) -> Pin<Box<dyn Future<Output = ()> + Send + 'a>> {
         Box::pin(async move {
             let position = player.position();
@@ -43,24 +45,13 @@ impl ItemBehaviour for SnowBallItem {
             world.spawn_entity(Arc::new(snowball)).await;
 
             // Consume item
-            let held_item = player.inventory.held_item();
-            let consumed = {
-                let mut main_hand = held_item.lock().await;
-                if !main_hand.is_empty() && main_hand.item.id == Item::SNOWBALL.id {
-                    main_hand.decrement_unless_creative(player.gamemode.load(), 1);
-                    true
-                } else {
-                    false
-                }
-            };
-
-            if !consumed {
-                let off_hand_item = player.inventory.off_hand_item().await;
-                let mut off_hand = off_hand_item.lock().await;
-                if !off_hand.is_empty() && off_hand.item.id == Item::SNOWBALL.id {
-                    off_hand.decrement_unless_creative(player.gamemode.load(), 1);
-                }
-            }
+            player
+                .inventory
+                .get_stack_in_hand(hand)
+                .await
+                .lock()
+                .await
+                .decrement_unless_creative(player.gamemode.load(), 1);
         })
     }
 
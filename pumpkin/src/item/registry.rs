@@ -5,6 +5,7 @@ use pumpkin_data::Block;
 use pumpkin_data::BlockDirection;
 use pumpkin_data::item::Item;
 use pumpkin_data::item_stack::ItemStack;
+use pumpkin_util::Hand;
 use pumpkin_util::math::position::BlockPos;
 use pumpkin_util::math::vector3::Vector3;
 use rustc_hash::FxHashMap;
@@ -26,7 +27,7 @@ impl ItemRegistry {
         }
     }
 
-    pub async fn on_use(&self, stack: &ItemStack, player: &Player) {
+    pub async fn on_use(&self, stack: &ItemStack, player: &Player, hand: Hand) {
         let item = stack.item;
         let cooldown = stack.get_use_cooldown();
         let cooldown_group = cooldown
@@ -39,7 +40,7 @@ impl ItemRegistry {
 
         let pumpkin_item = self.get_pumpkin_item(item.id);
         if let Some(pumpkin_item) = pumpkin_item {
-            pumpkin_item.normal_use(item, player).await;
+            pumpkin_item.normal_use(item, player, hand).await;
         }
 
         if let Some(cooldown) = cooldown {
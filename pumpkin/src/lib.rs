@@ -6,7 +6,7 @@ use crate::data::VanillaData;
 use crate::logging::{GzipRollingLogger, PumpkinCommandCompleter, ReadlineLogWrapper};
 use crate::net::bedrock::BedrockClient;
 use crate::net::java::JavaClient;
-use crate::net::{ClientPlatform, DisconnectReason, PacketHandlerResult};
+use crate::net::{ClientPlatform, PacketHandlerResult};
 use crate::net::{lan_broadcast::LANBroadcast, query, rcon::RCONServer};
 use crate::server::{Server, ticker::Ticker};
 use plugin::server::server_command::ServerCommandEvent;
@@ -382,21 +382,10 @@ impl PumpkinServer {
 
         info!("Stopped accepting incoming connections");
 
-        if let Err(e) = self
-            .server
-            .player_data_storage
-            .save_all_players(&self.server)
-            .await
-        {
-            error!("Error saving all players during shutdown: {e}");
-        }
-
         let kick_message = TextComponent::text("Server stopped");
-        for player in self.server.get_all_players() {
-            player
-                .kick(DisconnectReason::Shutdown, kick_message.clone())
-                .await;
-        }
+        self.server
+            .disconnect_all_players(kick_message.clone())
+            .await;
 
         info!("Ending player tasks");
 
@@ -407,7 +396,7 @@ impl PumpkinServer {
 
         info!("Starting save.");
 
-        self.server.shutdown().await;
+        self.server.shutdown(kick_message).await;
 
         info!("Completed save!");
 
@@ -458,9 +447,11 @@ impl PumpkinServer {
                                      java_client.await_tasks().await;
                                 },
                                 PacketHandlerResult::ReadyToPlay(profile,config) => {
-                                     if let Some((player, world)) = server_clone
-                                     .add_player(ClientPlatform::Java(java_client), profile, Some(config))
-                                          .await
+                                     let added = server_clone
+                                         .add_player(ClientPlatform::Java(java_client), profile, Some(config))
+                                         .await;
+                                     server_clone.release_player_slot();
+                                     if let Some((player, world)) = added
                                 {
                                     world
                                         .spawn_java_player(&server_clone.basic_config, &player, &server_clone)
@@ -530,9 +521,11 @@ impl PumpkinServer {
                                                 client_clone.close().await;
                                             }
                                             PacketHandlerResult::ReadyToPlay(profile, config) => {
-                                                if let Some((player, _world)) = server_clone
+                                                let added = server_clone
                                                     .add_player(ClientPlatform::Bedrock(client_clone.clone()), profile, Some(config))
-                                                    .await
+                                                    .await;
+                                                server_clone.release_player_slot();
+                                                if let Some((player, _world)) = added
                                                 {
                                                     *client_clone.player.lock().await = Some(player.clone());
 
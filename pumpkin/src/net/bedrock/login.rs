@@ -5,6 +5,7 @@ use crate::{
     server::Server,
 };
 use arc_swap::ArcSwap;
+use pumpkin_data::translation;
 use pumpkin_protocol::bedrock::{
     client::{
         network_settings::CNetworkSettings, play_status::CPlayStatus,
@@ -178,6 +179,16 @@ impl BedrockClient {
             profile_actions: None,
         };
 
+        // Don't allow new logons when the server is full.
+        if !server.try_reserve_player_slot(&profile).await {
+            self.kick(
+                DisconnectReason::ServerFull,
+                translation::bedrock::DISCONNECTIONSCREEN_SERVERFULL.to_string(),
+            )
+            .await;
+            return Ok(PacketHandlerResult::Stop);
+        }
+
         let mut frame_set = FrameSet::default();
 
         self.write_game_packet_to_set(&CPlayStatus::LoginSuccess, &mut frame_set)
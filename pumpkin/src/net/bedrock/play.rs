@@ -18,7 +18,7 @@ use pumpkin_protocol::{
             player_auth_input::{InputData, SPlayerAuthInput},
             request_chunk_radius::SRequestChunkRadius,
             set_local_player_as_initialized::SSetLocalPlayerAsInitialized,
-            text::SText,
+            text::{SText, TextPacketType},
         },
     },
     codec::{var_int::VarInt, var_long::VarLong, var_ulong::VarULong},
@@ -31,7 +31,9 @@ use pumpkin_world::world::BlockFlags;
 use crate::{
     block::{BlockHitResult, registry::BlockActionResult},
     entity::{EntityBase, player::Player},
-    net::{DisconnectReason, bedrock::BedrockClient},
+    error::PumpkinError,
+    log_at_level,
+    net::{DisconnectReason, bedrock::BedrockClient, java::play::validate_chat_text},
     plugin::player::{
         player_chat::PlayerChatEvent, player_command_send::PlayerCommandSendEvent,
         player_toggle_flight_event::PlayerToggleFlightEvent,
@@ -240,9 +242,9 @@ impl BedrockClient {
         let input_data = packet.input_data;
 
         if input_data.get(InputData::StartSprinting as usize) {
-            entity.set_sprinting(true).await;
+            player.living_entity.set_sprinting(true).await;
         } else if input_data.get(InputData::StopSprinting as usize) {
-            entity.set_sprinting(false).await;
+            player.living_entity.set_sprinting(false).await;
         }
 
         if input_data.get(InputData::StartSneaking as usize) {
@@ -508,8 +510,27 @@ impl BedrockClient {
     }
 
     pub async fn handle_chat_message(&self, server: &Server, player: &Arc<Player>, packet: SText) {
+        // Clients only ever author plain chat messages
+        if packet.r#type != TextPacketType::Chat || packet.message.trim().is_empty() {
+            return;
+        }
+        player.update_last_action_time();
         let gameprofile = &player.gameprofile;
 
+        if let Err(err) = validate_chat_text(&packet.message) {
+            log_at_level!(
+                err.severity(),
+                "{} (uuid {}) {}",
+                gameprofile.name,
+                gameprofile.id,
+                err
+            );
+            if let Some(reason) = err.client_kick_reason() {
+                self.kick(DisconnectReason::Kicked, reason).await;
+            }
+            return;
+        }
+
         send_cancellable! {{
             server;
             PlayerChatEvent::new(player.clone(), packet.message, vec![]);
@@ -530,22 +551,17 @@ impl BedrockClient {
                     &message,
                 );
 
-                let entity = &player.get_entity();
-                if server.basic_config.allow_chat_reports {
-                    //TODO Alex help, what is this?
-                    //world.broadcast_secure_player_chat(player, &message, decorated_message).await;
-                } else {
-                    let je_packet = CSystemChatMessage::new(
-                        &decorated_message,
-                        false,
-                    );
-
-                    let be_packet = SText::new(
-                        message, gameprofile.name.clone()
-                    );
+                // Bedrock chat is never signed, so it is broadcast as unsigned chat
+                // even when secure chat is enabled.
+                let je_packet = CSystemChatMessage::new(
+                    &decorated_message,
+                    false,
+                );
+                let be_packet = SText::new(
+                    message, gameprofile.name.clone()
+                );
 
-                    entity.world.load().broadcast_editioned(&je_packet, &be_packet).await;
-                }
+                player.world().broadcast_editioned(&je_packet, &be_packet).await;
             }
         }}
     }
@@ -594,11 +610,16 @@ impl BedrockClient {
                     let speed = crate::block::calc_block_breaking(player, state, block).await;
                     if speed >= 1.0 {
                         let broken_state = world.get_block_state(&location);
+                        let block_drop = player.can_harvest(broken_state, block).await;
                         let new_state = world
                             .break_block(
                                 &location,
                                 Some(player.clone()),
-                                BlockFlags::NOTIFY_NEIGHBORS,
+                                if block_drop {
+                                    BlockFlags::NOTIFY_NEIGHBORS
+                                } else {
+                                    BlockFlags::SKIP_DROPS | BlockFlags::NOTIFY_NEIGHBORS
+                                },
                             )
                             .await;
                         if new_state.is_some() {
@@ -12,7 +12,7 @@ use crate::{
     server::Server,
 };
 use core::str;
-use pumpkin_data::{registry::Registry, translation};
+use pumpkin_data::{dimension::Dimension, registry::Registry, translation};
 use pumpkin_protocol::{
     ConnectionState,
     java::{
@@ -25,6 +25,7 @@ use pumpkin_protocol::{
 };
 use pumpkin_util::{Hand, text::TextComponent, version::JavaMinecraftVersion};
 use tracing::{debug, trace, warn};
+use uuid::Uuid;
 
 const BRAND_CHANNEL_PREFIX: &str = "minecraft:brand";
 
@@ -74,74 +75,95 @@ impl JavaClient {
         }
     }
 
-    pub async fn handle_resource_pack_response(
-        &self,
-        server: &Server,
-        packet: SConfigResourcePack,
-    ) {
-        let resource_config = &server.advanced_config.resource_pack.java;
-        if resource_config.enabled {
-            let expected_uuid =
-                uuid::Uuid::new_v3(&uuid::Uuid::NAMESPACE_DNS, resource_config.url.as_bytes());
+    pub async fn handle_resource_pack_response(&self, packet: SConfigResourcePack) {
+        let mut pending = self.pending_resource_packs.lock().await;
+        let Some(index) = pending.iter().position(|uuid| *uuid == packet.uuid) else {
+            warn!(
+                "Client {} returned a response for a resource pack we did not set!",
+                self.id
+            );
+            return;
+        };
 
-            if packet.uuid == expected_uuid {
-                match packet.response_result() {
-                    ResourcePackResponseResult::DownloadSuccess => {
-                        trace!(
-                            "Client {} successfully downloaded the resource pack",
-                            self.id
-                        );
-                    }
-                    ResourcePackResponseResult::DownloadFail => {
-                        warn!(
-                            "Client {} failed to downloaded the resource pack. Is it available on the internet?",
-                            self.id
-                        );
-                    }
-                    ResourcePackResponseResult::Downloaded => {
-                        trace!("Client {} already has the resource pack", self.id);
-                    }
-                    ResourcePackResponseResult::Accepted => {
-                        trace!("Client {} accepted the resource pack", self.id);
+        // Wait for the next response update
+        let Some(applied) = self.resource_pack_result(packet.uuid, packet.response_result()) else {
+            return;
+        };
+        if !applied {
+            self.resource_packs
+                .lock()
+                .await
+                .retain(|uuid| *uuid != packet.uuid);
+        }
+
+        pending.swap_remove(index);
+        // Only continue the configuration once every configured pack was answered
+        if pending.is_empty() {
+            drop(pending);
+            self.send_known_packs().await;
+        }
+    }
 
-                        // Return here to wait for the next response update
-                        return;
-                    }
-                    ResourcePackResponseResult::Declined => {
-                        trace!("Client {} declined the resource pack", self.id);
-                    }
-                    ResourcePackResponseResult::InvalidUrl => {
-                        warn!(
-                            "Client {} reported that the resource pack URL is invalid!",
-                            self.id
-                        );
-                    }
-                    ResourcePackResponseResult::ReloadFailed => {
-                        trace!("Client {} failed to reload the resource pack", self.id);
-                    }
-                    ResourcePackResponseResult::Discarded => {
-                        trace!("Client {} discarded the resource pack", self.id);
-                    }
-                    ResourcePackResponseResult::Unknown(result) => {
-                        warn!(
-                            "Client {} responded with a bad result: {}!",
-                            self.id, result
-                        );
-                    }
-                }
-            } else {
+    /// Logs a resource pack response, returning whether the pack ended up applied or `None`
+    /// while the client is still working on it.
+    pub(super) fn resource_pack_result(
+        &self,
+        uuid: Uuid,
+        result: ResourcePackResponseResult,
+    ) -> Option<bool> {
+        match result {
+            ResourcePackResponseResult::DownloadSuccess => {
+                trace!(
+                    "Client {} successfully downloaded the resource pack {uuid}",
+                    self.id
+                );
+                Some(true)
+            }
+            ResourcePackResponseResult::DownloadFail => {
                 warn!(
-                    "Client {} returned a response for a resource pack we did not set!",
+                    "Client {} failed to downloaded the resource pack {uuid}. Is it available on the internet?",
                     self.id
                 );
+                Some(false)
+            }
+            ResourcePackResponseResult::Downloaded => {
+                trace!("Client {} downloaded the resource pack {uuid}", self.id);
+                None
+            }
+            ResourcePackResponseResult::Accepted => {
+                trace!("Client {} accepted the resource pack {uuid}", self.id);
+                None
+            }
+            ResourcePackResponseResult::Declined => {
+                trace!("Client {} declined the resource pack {uuid}", self.id);
+                Some(false)
+            }
+            ResourcePackResponseResult::InvalidUrl => {
+                warn!(
+                    "Client {} reported that the resource pack URL of {uuid} is invalid!",
+                    self.id
+                );
+                Some(false)
+            }
+            ResourcePackResponseResult::ReloadFailed => {
+                trace!(
+                    "Client {} failed to reload the resource pack {uuid}",
+                    self.id
+                );
+                Some(false)
+            }
+            ResourcePackResponseResult::Discarded => {
+                trace!("Client {} discarded the resource pack {uuid}", self.id);
+                Some(false)
+            }
+            ResourcePackResponseResult::Unknown(result) => {
+                warn!(
+                    "Client {} responded with a bad result: {}!",
+                    self.id, result
+                );
+                Some(false)
             }
-        } else {
-            warn!(
-                "Client {} returned a response for a resource pack that was not enabled!",
-                self.id
-            );
         }
-        self.send_known_packs().await;
     }
 
     pub fn handle_config_cookie_response(&self, packet: &SConfigCookieResponse) {
@@ -164,11 +186,26 @@ impl JavaClient {
         let version = self.version.load();
         let registry = Registry::get_synced(version);
         for registry in registry {
-            let entries: Vec<RegistryEntry> = registry
+            let mut entries: Vec<RegistryEntry> = registry
                 .registry_entries
                 .iter()
                 .map(|r| RegistryEntry::new(r.entry_id.clone(), r.data.clone()))
                 .collect();
+            if registry.registry_id == "minecraft:dimension_type"
+                && let Some(overworld_entry) = registry
+                    .registry_entries
+                    .iter()
+                    .find(|r| r.entry_id == Dimension::OVERWORLD.minecraft_name)
+                    .and_then(|r| r.data.as_deref())
+            {
+                // Custom types get the ids after the vanilla ones, in the order they were loaded
+                entries.extend(
+                    server
+                        .custom_dimension_types
+                        .iter()
+                        .filter_map(|custom| custom.registry_entry(overworld_entry)),
+                );
+            }
             self.send_packet_now(&CRegistryData::new(&registry.registry_id, &entries))
                 .await;
             // if let Some(tag) = RegistryKey::from_string(&registry.registry_id.path)
@@ -259,6 +296,17 @@ impl JavaClient {
             return PacketHandlerResult::Stop;
         }
 
+        // Don't allow new logons when the server is full.
+        if !server.try_reserve_player_slot(&profile).await {
+            self.kick(TextComponent::translate_cross(
+                translation::java::MULTIPLAYER_DISCONNECT_SERVER_FULL,
+                translation::java::MULTIPLAYER_DISCONNECT_SERVER_FULL,
+                [],
+            ))
+            .await;
+            return PacketHandlerResult::Stop;
+        }
+
         let config = self.config.lock().await;
         PacketHandlerResult::ReadyToPlay(profile, config.clone().unwrap_or_default())
     }
@@ -13,7 +13,6 @@ use pumpkin_protocol::{
 use pumpkin_util::{text::TextComponent, version::JavaMinecraftVersion};
 use std::sync::Arc;
 use tracing::debug;
-use uuid::Uuid;
 
 use crate::{
     net::{
@@ -31,20 +30,6 @@ impl JavaClient {
     pub async fn handle_login_start(&self, server: &Server, login_start: SLoginStart) {
         debug!("login start");
 
-        // Don't allow new logons when the server is full.
-        // If `max_players` is set to zero, then there is no max player count enforced.
-        // TODO: If client is an operator or has otherwise suitable elevated permissions, allow the client to bypass this requirement.
-        let max_players = server.basic_config.max_players;
-        if max_players > 0 && server.get_player_count() >= max_players as usize {
-            self.kick(TextComponent::translate_cross(
-                translation::java::MULTIPLAYER_DISCONNECT_SERVER_FULL,
-                translation::java::MULTIPLAYER_DISCONNECT_SERVER_FULL,
-                [],
-            ))
-            .await;
-            return;
-        }
-
         if !is_valid_player_name(&login_start.name) {
             self.kick(TextComponent::text("Invalid characters in username"))
                 .await;
@@ -372,23 +357,26 @@ impl JavaClient {
         }
 
         let resource_config = &server.advanced_config.resource_pack.java;
-        if resource_config.enabled {
-            let uuid = Uuid::new_v3(&uuid::Uuid::NAMESPACE_DNS, resource_config.url.as_bytes());
-            let resource_pack = CConfigAddResourcePack::new(
-                &uuid,
-                &resource_config.url,
-                &resource_config.sha1,
-                resource_config.force,
-                if resource_config.prompt_message.is_empty() {
-                    None
-                } else {
-                    Some(TextComponent::text(resource_config.prompt_message.clone()))
-                },
-            );
-
-            self.send_packet_now(&resource_pack).await;
+        if resource_config.enabled && !resource_config.packs.is_empty() {
+            for pack in &resource_config.packs {
+                self.resource_packs.lock().await.push(pack.uuid);
+                self.pending_resource_packs.lock().await.push(pack.uuid);
+                let resource_pack = CConfigAddResourcePack::new(
+                    &pack.uuid,
+                    &pack.url,
+                    &pack.sha1,
+                    pack.force,
+                    if pack.prompt_message.is_empty() {
+                        None
+                    } else {
+                        Some(TextComponent::text(pack.prompt_message.clone()))
+                    },
+                );
+
+                self.send_packet_now(&resource_pack).await;
+            }
         } else {
-            // This will be invoked by our resource pack handler in the case of the above branch.
+            // This will be invoked by our resource pack handler once every pack above was answered.
             self.send_known_packs().await;
         }
         debug!("login acknowledged");
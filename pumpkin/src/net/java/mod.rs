@@ -3,13 +3,14 @@ use pumpkin_protocol::java::client::play::{
 };
 use pumpkin_world::level::SyncChunk;
 use std::net::SocketAddr;
-use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicI64, Ordering};
 use std::time::{Instant, SystemTime, UNIX_EPOCH};
 use std::{io::Write, sync::Arc};
 
 use bytes::Bytes;
 use crossbeam::atomic::AtomicCell;
 use pumpkin_config::networking::compression::CompressionInfo;
+use pumpkin_config::networking::rate_limit::RateLimitAction;
 use pumpkin_data::packet::CURRENT_MC_VERSION;
 use pumpkin_data::translation;
 use pumpkin_protocol::java::server::play::{
@@ -17,10 +18,11 @@ use pumpkin_protocol::java::server::play::{
     SClientInformationPlay, SClientTickEnd, SCloseContainer, SCommandSuggestion, SConfirmTeleport,
     SContainerButtonClick, SCookieResponse as SPCookieResponse, SCustomPayload, SInteract,
     SJigsawGenerate, SMoveVehicle, SPaddleBoat, SPickItemFromBlock, SPlaceRecipe, SPlayPingRequest,
-    SPlayerAbilities, SPlayerAction, SPlayerCommand, SPlayerInput, SPlayerLoaded, SPlayerPosition,
-    SPlayerPositionRotation, SPlayerRotation, SPlayerSession, SRecipeBookChangeSettings,
-    SRecipeBookSeenRecipe, SRenameItem, SSelectTrade, SSetCommandBlock, SSetCreativeSlot,
-    SSetHeldItem, SSetJigsawBlock, SSetPlayerGround, SSwingArm, SUpdateSign, SUseItem, SUseItemOn,
+    SPlayResourcePack, SPlayerAbilities, SPlayerAction, SPlayerCommand, SPlayerInput,
+    SPlayerLoaded, SPlayerPosition, SPlayerPositionRotation, SPlayerRotation, SPlayerSession,
+    SRecipeBookChangeSettings, SRecipeBookSeenRecipe, SRenameItem, SSelectTrade, SSetCommandBlock,
+    SSetCreativeSlot, SSetHeldItem, SSetJigsawBlock, SSetPlayerGround, SSwingArm, SUpdateSign,
+    SUseItem, SUseItemOn,
 };
 use pumpkin_protocol::packet::MultiVersionJavaPacket;
 use pumpkin_protocol::{
@@ -62,16 +64,21 @@ use tokio::{
 use tokio_util::sync::CancellationToken;
 use tokio_util::task::TaskTracker;
 use tracing::{debug, error, warn};
+use uuid::Uuid;
 
 pub mod config;
 pub mod handshake;
 pub mod login;
+pub mod movement;
 pub mod play;
+pub mod rate_limit;
 pub mod recipe_helper;
 pub mod status;
 
 use crate::entity::player::Player;
-use crate::net::{GameProfile, PacketHandlerResult, PlayerConfig};
+use crate::net::java::movement::MovementValidator;
+use crate::net::java::rate_limit::{PacketCategory, PacketRateLimiter};
+use crate::net::{DisconnectReason, GameProfile, PacketHandlerResult, PlayerConfig};
 use crate::plugin::api::events::world::chunk_send::ChunkSend;
 use crate::plugin::player::player_custom_payload::PlayerCustomPayloadEvent;
 use crate::{error::PumpkinError, net::EncryptionError, server::Server};
@@ -92,6 +99,10 @@ pub struct JavaClient {
     /// The client's brand or modpack information, Optional.
     pub brand: Mutex<Option<String>>,
     pub player: Mutex<Option<Arc<Player>>>,
+    /// The UUIDs of the resource packs pushed to this client that have not been removed.
+    pub resource_packs: Mutex<Vec<Uuid>>,
+    /// The configured resource packs the client still has to answer during configuration.
+    pending_resource_packs: Mutex<Vec<Uuid>>,
     /// A collection of tasks associated with this client. The tasks await completion when removing the client.
     tasks: TaskTracker,
     /// An notifier that is triggered when this client is closed.
@@ -116,6 +127,12 @@ pub struct JavaClient {
     pub keep_alive_id: AtomicCell<i64>,
     /// The last time we sent a keep alive packet.
     pub last_keep_alive_time: AtomicCell<Instant>,
+    /// Token buckets limiting how fast the client may send play packets.
+    rate_limiter: Mutex<PacketRateLimiter>,
+    /// Tracks the client's moves to catch impossible ones.
+    movement_validator: Mutex<MovementValidator>,
+    /// The timestamp of the last signed chat message, which later messages may not predate.
+    last_chat_timestamp: AtomicI64,
 }
 
 pub enum OutgoingPacketType {
@@ -126,6 +143,8 @@ pub enum OutgoingPacketType {
 struct OutgoingPacket {
     data: Bytes,
     completion: Option<oneshot::Sender<()>>,
+    /// Whether to log the compression ratio once written; set for chunk data.
+    log_compression: bool,
 }
 
 impl OutgoingPacket {
@@ -133,6 +152,7 @@ impl OutgoingPacket {
         Self {
             data,
             completion: None,
+            log_compression: false,
         }
     }
 
@@ -140,6 +160,15 @@ impl OutgoingPacket {
         Self {
             data,
             completion: Some(completion),
+            log_compression: false,
+        }
+    }
+
+    const fn chunk(data: Bytes, completion: oneshot::Sender<()>) -> Self {
+        Self {
+            data,
+            completion: Some(completion),
+            log_compression: true,
         }
     }
 }
@@ -168,9 +197,14 @@ impl JavaClient {
             network_reader: Mutex::new(TCPNetworkDecoder::new(BufReader::new(read))),
             brand: Mutex::new(None),
             player: Mutex::new(None),
+            resource_packs: Mutex::new(Vec::new()),
+            pending_resource_packs: Mutex::new(Vec::new()),
             wait_for_keep_alive: AtomicBool::new(false),
             keep_alive_id: AtomicCell::new(0),
             last_keep_alive_time: AtomicCell::new(std::time::Instant::now()),
+            rate_limiter: Mutex::new(PacketRateLimiter::default()),
+            movement_validator: Mutex::new(MovementValidator::default()),
+            last_chat_timestamp: AtomicI64::new(i64::MIN),
         }
     }
     pub async fn set_encryption(
@@ -345,7 +379,8 @@ impl JavaClient {
             CChunkData(chunk)
                 .write_packet_data(&mut buf, &version)
                 .unwrap();
-            self.send_packet_now_data(buf.into()).await;
+            self.send_outgoing_now(|completion| OutgoingPacket::chunk(buf.into(), completion))
+                .await;
         }
         self.send_packet_now(&CChunkBatchEnd::new(chunks.len() as u16))
             .await;
@@ -492,11 +527,16 @@ impl JavaClient {
     }
 
     pub async fn send_packet_now_data(&self, packet: Bytes) {
+        self.send_outgoing_now(|completion| OutgoingPacket::high_priority(packet, completion))
+            .await;
+    }
+
+    async fn send_outgoing_now(&self, packet: impl FnOnce(oneshot::Sender<()>) -> OutgoingPacket) {
         let (completion_tx, completion_rx) = oneshot::channel();
 
         if let Err(err) = self
             .outgoing_packet_priority_send
-            .send(OutgoingPacket::high_priority(packet, completion_tx))
+            .send(packet(completion_tx))
             .await
         {
             // It is expected that the packet will fail if we are closed
@@ -696,6 +736,15 @@ impl JavaClient {
                             }
                             break;
                         }
+                        if packet.log_compression
+                            && let Some(compressed_len) = writer.last_compressed_len()
+                        {
+                            let len = packet.data.len();
+                            debug!(
+                                "Compressed chunk for client {id}: {len} -> {compressed_len} bytes ({:.1}%)",
+                                compressed_len as f64 * 100.0 / len as f64
+                            );
+                        }
                     }
 
                     if !failed && let Err(err) = writer.flush().await {
@@ -831,11 +880,8 @@ impl JavaClient {
                 )?);
             }
             id if id == SConfigResourcePack::to_id(version) => {
-                self.handle_resource_pack_response(
-                    server,
-                    SConfigResourcePack::read(payload, &version)?,
-                )
-                .await;
+                self.handle_resource_pack_response(SConfigResourcePack::read(payload, &version)?)
+                    .await;
             }
             _ => {
                 error!(
@@ -857,6 +903,31 @@ impl JavaClient {
         let payload = &packet.payload[..];
         let version = self.version.load();
 
+        let rate_limit = &server.advanced_config.networking.packet_rate_limit;
+        if rate_limit.enabled {
+            let category = PacketCategory::from_packet_id(packet.id, version);
+            let allowed =
+                self.rate_limiter
+                    .lock()
+                    .await
+                    .try_acquire(category, rate_limit, Instant::now());
+            if !allowed {
+                if rate_limit.action == RateLimitAction::Kick && category.can_kick() {
+                    warn!(
+                        "{} exceeded the {category:?} packet rate limit, kicking",
+                        player.gameprofile.name
+                    );
+                    player
+                        .kick(
+                            DisconnectReason::Kicked,
+                            TextComponent::translate(translation::java::DISCONNECT_SPAM, []),
+                        )
+                        .await;
+                }
+                return Ok(());
+            }
+        }
+
         let mut event = crate::plugin::server::packet::PacketReceivedEvent::new(
             player.clone(),
             packet.id,
@@ -990,6 +1061,12 @@ impl JavaClient {
                 self.handle_play_ping_request(SPlayPingRequest::read(payload, &version)?)
                     .await;
             }
+            id if id == SPlayResourcePack::to_id(version) => {
+                self.handle_play_resource_pack_response(SPlayResourcePack::read(
+                    payload, &version,
+                )?)
+                .await;
+            }
             id if id == SClickSlot::to_id(version) => {
                 player
                     .on_slot_click(SClickSlot::read(payload, &version)?, server)
@@ -1006,7 +1083,7 @@ impl JavaClient {
             }
             id if id == SSetCreativeSlot::to_id(version) => {
                 self.handle_set_creative_slot(player, SSetCreativeSlot::read(payload, &version)?)
-                    .await?;
+                    .await;
             }
             id if id == SSwingArm::to_id(version) => {
                 self.handle_swing_arm(player, SSwingArm::read(payload, &version)?)
@@ -0,0 +1,165 @@
+use std::time::Instant;
+
+use pumpkin_config::MovementConfig;
+use pumpkin_util::math::vector3::Vector3;
+
+/// The length of a game tick in seconds.
+const TICK_SECONDS: f64 = 0.05;
+/// The most ticks a single move is credited for, so idling can't be saved up for one long move.
+const MAX_CREDITED_TICKS: f64 = 20.0;
+/// Moving down faster than this counts as falling rather than floating (vanilla threshold).
+const FALLING_DELTA: f64 = -0.031_25;
+
+/// What the player is currently allowed to do, gathered before checking a move.
+#[derive(Clone, Copy)]
+pub struct MovementContext {
+    /// Whether the player skips all checks, e.g. in creative mode or while riding.
+    pub exempt: bool,
+    /// Whether the player may stay in mid-air, e.g. because they may fly or are climbing.
+    pub may_float: bool,
+    /// Whether the player is gliding with an elytra.
+    pub fall_flying: bool,
+    /// Multiplier for the allowed speed, e.g. from the Speed effect.
+    pub speed_multiplier: f64,
+}
+
+/// The outcome of checking a move.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum MovementVerdict {
+    /// The move is possible.
+    Valid,
+    /// The move covered more distance than allowed and must be reverted.
+    TooFast,
+    /// The player has been floating in mid-air for too long.
+    Floating,
+}
+
+/// Per-connection state for validating the moves a client reports.
+#[derive(Default)]
+pub struct MovementValidator {
+    last_move: Option<Instant>,
+    floating_ticks: f64,
+    violations: u32,
+}
+
+impl MovementValidator {
+    /// Checks a move from `from` to `to` against the limits in `config`.
+    pub fn check(
+        &mut self,
+        from: Vector3<f64>,
+        to: Vector3<f64>,
+        on_ground: bool,
+        context: MovementContext,
+        config: &MovementConfig,
+        now: Instant,
+    ) -> MovementVerdict {
+        let ticks = self.last_move.map_or(1.0, |last| {
+            (now.saturating_duration_since(last).as_secs_f64() / TICK_SECONDS)
+                .clamp(1.0, MAX_CREDITED_TICKS)
+        });
+        self.last_move = Some(now);
+
+        if context.exempt {
+            self.floating_ticks = 0.0;
+            self.violations = 0;
+            return MovementVerdict::Valid;
+        }
+
+        let max_speed = if context.fall_flying {
+            config.max_fall_flying_speed
+        } else {
+            config.max_speed
+        };
+        let max_distance = max_speed * context.speed_multiplier * config.tolerance * ticks;
+        let delta = to - from;
+        if delta.length_squared() > max_distance * max_distance {
+            self.violations += 1;
+            return MovementVerdict::TooFast;
+        }
+        self.violations = 0;
+
+        if on_ground || context.may_float || context.fall_flying || delta.y < FALLING_DELTA {
+            self.floating_ticks = 0.0;
+        } else {
+            self.floating_ticks += ticks;
+            if config.max_floating_ticks > 0
+                && self.floating_ticks > f64::from(config.max_floating_ticks)
+            {
+                return MovementVerdict::Floating;
+            }
+        }
+        MovementVerdict::Valid
+    }
+
+    /// Returns how many moves in a row were rejected.
+    pub const fn violations(&self) -> u32 {
+        self.violations
+    }
+
+    /// Forgets the previous move, e.g. after the player was teleported.
+    pub const fn reset(&mut self) {
+        self.last_move = None;
+        self.floating_ticks = 0.0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    const WALKING: MovementContext = MovementContext {
+        exempt: false,
+        may_float: false,
+        fall_flying: false,
+        speed_multiplier: 1.0,
+    };
+
+    #[test]
+    fn rejects_moves_beyond_the_speed_limit() {
+        let config = MovementConfig::default();
+        let mut validator = MovementValidator::default();
+        let start = Instant::now();
+        let origin = Vector3::new(0.0, 64.0, 0.0);
+
+        let step = Vector3::new(0.3, 64.0, 0.0);
+        let verdict = validator.check(origin, step, true, WALKING, &config, start);
+        assert_eq!(verdict, MovementVerdict::Valid);
+
+        let later = start + Duration::from_millis(50);
+        let far = Vector3::new(100.0, 64.0, 0.0);
+        let verdict = validator.check(step, far, true, WALKING, &config, later);
+        assert_eq!(verdict, MovementVerdict::TooFast);
+        assert_eq!(validator.violations(), 1);
+
+        let exempt = MovementContext {
+            exempt: true,
+            ..WALKING
+        };
+        let verdict = validator.check(step, far, true, exempt, &config, later);
+        assert_eq!(verdict, MovementVerdict::Valid);
+        assert_eq!(validator.violations(), 0);
+    }
+
+    #[test]
+    fn kicks_players_floating_too_long() {
+        let config = MovementConfig::default();
+        let mut validator = MovementValidator::default();
+        let start = Instant::now();
+        let pos = Vector3::new(0.0, 100.0, 0.0);
+
+        let mut verdict = MovementVerdict::Valid;
+        for tick in 0..=config.max_floating_ticks {
+            let now = start + Duration::from_millis(50 * u64::from(tick));
+            verdict = validator.check(pos, pos, false, WALKING, &config, now);
+        }
+        assert_eq!(verdict, MovementVerdict::Floating);
+
+        // Falling resets the counter.
+        let now = start + Duration::from_secs(10);
+        let below = Vector3::new(0.0, 99.0, 0.0);
+        let verdict = validator.check(pos, below, false, WALKING, &config, now);
+        assert_eq!(verdict, MovementVerdict::Valid);
+    }
+}
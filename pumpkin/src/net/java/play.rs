@@ -5,8 +5,8 @@ use rsa::signature::Verifier;
 use sha1::Sha1;
 use std::num::NonZeroU8;
 use std::sync::Arc;
-use std::sync::atomic::{AtomicU32, Ordering};
-use std::time::{SystemTime, UNIX_EPOCH};
+use std::sync::atomic::{AtomicI64, AtomicU32, Ordering};
+use std::time::{Instant, SystemTime, UNIX_EPOCH};
 use thiserror::Error;
 use tracing::{Level, debug, error, info, trace, warn};
 
@@ -16,11 +16,14 @@ use crate::block::{self, BlockIsReplacing};
 use crate::entity::EntityBase;
 use crate::entity::equipment_break_status;
 use crate::entity::player::statistics::{CustomStatistic, StatisticCategory};
-use crate::entity::player::{ChatMode, ChatSession, Player};
+use crate::entity::player::{
+    ChatMode, ChatSession, MAX_CACHED_SIGNATURES, MAX_PREVIOUS_MESSAGES, Player,
+};
 use crate::error::PumpkinError;
 use crate::log_at_level;
 use crate::net::PlayerConfig;
 use crate::net::java::JavaClient;
+use crate::net::java::movement::{MovementContext, MovementVerdict};
 use crate::plugin::block::block_place::BlockPlaceEvent;
 use crate::plugin::player::changed_main_hand::PlayerChangedMainHandEvent;
 use crate::plugin::player::fish::{PlayerFishEvent, PlayerFishState};
@@ -44,14 +47,14 @@ use pumpkin_data::block_properties::{
     BlockProperties, CommandBlockLikeProperties, WaterLikeProperties,
 };
 use pumpkin_data::data_component_impl::{
-    BlocksAttacksImpl, ConsumableImpl, EquipmentSlot, EquippableImpl, FoodImpl,
+    BlocksAttacksImpl, CanPlaceOnImpl, ConsumableImpl, EquipmentSlot, EquippableImpl, FoodImpl,
 };
+use pumpkin_data::effect::StatusEffect;
 use pumpkin_data::entity::EntityType;
 use pumpkin_data::item::Item;
 use pumpkin_data::item_stack::ItemStack;
 use pumpkin_data::sound::{Sound, SoundCategory};
 use pumpkin_data::{Block, BlockDirection, BlockState, translation};
-use pumpkin_inventory::InventoryError;
 use pumpkin_inventory::merchant::merchant_screen_handler::MerchantScreenHandler;
 use pumpkin_inventory::player::player_inventory::PlayerInventory;
 use pumpkin_inventory::screen_handler::{InventoryPlayer, ScreenHandler};
@@ -69,8 +72,8 @@ use pumpkin_protocol::java::server::play::{
     SChatMessage, SChunkBatch, SClientCommand, SClientInformationPlay, SCloseContainer,
     SCommandSuggestion, SConfirmTeleport, SCookieResponse as SPCookieResponse, SInteract,
     SJigsawGenerate, SKeepAlive, SMoveVehicle, SPaddleBoat, SPickItemFromBlock, SPlaceRecipe,
-    SPlayPingRequest, SPlayerAbilities, SPlayerAction, SPlayerCommand, SPlayerInput,
-    SPlayerPosition, SPlayerPositionRotation, SPlayerRotation, SPlayerSession,
+    SPlayPingRequest, SPlayResourcePack, SPlayerAbilities, SPlayerAction, SPlayerCommand,
+    SPlayerInput, SPlayerPosition, SPlayerPositionRotation, SPlayerRotation, SPlayerSession,
     SRecipeBookChangeSettings, SRecipeBookSeenRecipe, SSelectTrade, SSetCommandBlock,
     SSetCreativeSlot, SSetHeldItem, SSetJigsawBlock, SSetPlayerGround, SSwingArm, SUpdateSign,
     SUseItem, SUseItemOn, Status,
@@ -87,6 +90,8 @@ use tokio::sync::Mutex;
 /// In secure chat mode, Player will be kicked if they send a chat message with a timestamp that is older than this (in ms)
 /// Vanilla: 2 minutes
 const CHAT_MESSAGE_MAX_AGE: i64 = 1000 * 60 * 2;
+/// How far in the future (in ms) a chat message timestamp may be, to allow for clock skew
+const CHAT_MESSAGE_MAX_CLOCK_SKEW: i64 = 1000 * 5;
 
 #[derive(Debug, Error)]
 pub enum BlockPlacingError {
@@ -147,6 +152,48 @@ pub enum ChatError {
     InvalidPublicKey,
 }
 
+/// Runs the edition independent checks on the text of a chat message.
+pub fn validate_chat_text(message: &str) -> Result<(), ChatError> {
+    // Check for oversized messages
+    // If we're able to find the 257th UTF-16 character, the message is too big.
+    if message.encode_utf16().nth(256).is_some() {
+        return Err(ChatError::OversizedMessage);
+    }
+    // Check for illegal characters
+    if message.chars().any(|c| c == '§' || c < ' ' || c == '\x7F') {
+        return Err(ChatError::IllegalCharacters);
+    }
+    Ok(())
+}
+
+/// Checks that the last seen messages a client acknowledges could have been sent by the server.
+///
+/// `message_count` is the number of messages acknowledged since the previous update, and
+/// `acknowledged` the bitset over the last [`MAX_PREVIOUS_MESSAGES`] messages.
+pub fn validate_last_seen(message_count: i32, acknowledged: &[u8]) -> Result<(), ChatError> {
+    if !(0..=i32::from(MAX_CACHED_SIGNATURES)).contains(&message_count) {
+        return Err(ChatError::ChatValidationFailed);
+    }
+    let tracked_bits = usize::from(MAX_PREVIOUS_MESSAGES);
+    let has_untracked_bits = acknowledged.iter().enumerate().any(|(index, byte)| {
+        (0..8).any(|bit| byte & (1 << bit) != 0 && index * 8 + bit >= tracked_bits)
+    });
+    if has_untracked_bits {
+        return Err(ChatError::ChatValidationFailed);
+    }
+    Ok(())
+}
+
+/// Records `timestamp` as the latest signed chat timestamp, rejecting it unless it is strictly
+/// newer than the previous one, which also stops replays.
+pub fn advance_chat_timestamp(last_timestamp: &AtomicI64, timestamp: i64) -> Result<(), ChatError> {
+    let previous = last_timestamp.fetch_max(timestamp, Ordering::Relaxed);
+    if timestamp <= previous {
+        return Err(ChatError::OutOfOrderChat);
+    }
+    Ok(())
+}
+
 impl PumpkinError for ChatError {
     fn is_kick(&self) -> bool {
         true
@@ -232,10 +279,12 @@ impl JavaClient {
             if id == &confirm_teleport.teleport_id {
                 // We should set the position now to what we requested in the teleport packet.
                 // This may fix issues when the client sends the position while being teleported.
+                // Relative teleports also store their absolute target, so they snap here too.
                 player.get_entity().set_pos(*position);
 
                 *awaiting_teleport = None;
                 drop(awaiting_teleport);
+                self.movement_validator.lock().await.reset();
             } else {
                 drop(awaiting_teleport);
                 self.kick(TextComponent::text("Wrong teleport id")).await;
@@ -345,6 +394,17 @@ impl JavaClient {
             Self::clamp_vertical(position.y),
             Self::clamp_horizontal(position.z),
         );
+        if !self
+            .validate_move(
+                player,
+                server,
+                position,
+                packet.collision & FLAG_ON_GROUND != 0,
+            )
+            .await
+        {
+            return;
+        }
 
         send_cancellable! {{
             server;
@@ -382,7 +442,6 @@ impl JavaClient {
                 }
                 let world = &player.world();
 
-                // TODO: Warn when player moves to quickly
                 if !Self::sync_position(player, world, pos, last_pos, entity.yaw.load(), entity.pitch.load(), packet.collision & FLAG_ON_GROUND != 0) {
                     // Send the new position to all other players.
                     world.broadcast_packet_except_editioned_sync(
@@ -481,6 +540,17 @@ impl JavaClient {
             Self::clamp_vertical(position.y),
             Self::clamp_horizontal(position.z),
         );
+        if !self
+            .validate_move(
+                player,
+                server,
+                position,
+                packet.collision & FLAG_ON_GROUND != 0,
+            )
+            .await
+        {
+            return;
+        }
 
         send_cancellable! {{
             server;
@@ -525,7 +595,6 @@ impl JavaClient {
                 // let head_yaw = (entity.head_yaw * 256.0 / 360.0).floor();
                 let world = entity.world.load_full();
 
-                // TODO: Warn when player moves to quickly
                 if !Self::
                     sync_position(player, &world, pos, last_pos, yaw, pitch, (packet.collision & FLAG_ON_GROUND) != 0)
                 {
@@ -598,6 +667,98 @@ impl JavaClient {
         }}
     }
 
+    /// Gathers what currently loosens the movement checks for the player.
+    async fn movement_context(player: &Player) -> MovementContext {
+        let entity = player.get_entity();
+        let gamemode = player.gamemode.load();
+        let living = &player.living_entity;
+        let speed_multiplier = living
+            .get_effect(&StatusEffect::SPEED)
+            .await
+            .map_or(1.0, |effect| {
+                0.2f64.mul_add(f64::from(effect.amplifier) + 1.0, 1.0)
+            });
+
+        // Ladders, vines, water, cobwebs and the like let players stay in mid-air
+        let in_block = {
+            let world = entity.world.load();
+            let feet = entity.block_pos.load();
+            !world.get_block(&feet).is_air() || !world.get_block(&feet.up()).is_air()
+        };
+
+        MovementContext {
+            exempt: matches!(gamemode, GameMode::Creative | GameMode::Spectator)
+                || entity.has_vehicle().await
+                || living.dead.load(Ordering::Relaxed),
+            may_float: player.abilities.lock().await.allow_flying
+                || in_block
+                || living.has_effect(&StatusEffect::LEVITATION).await
+                || living.has_effect(&StatusEffect::SLOW_FALLING).await,
+            fall_flying: entity.is_fall_flying(),
+            speed_multiplier,
+        }
+    }
+
+    /// Checks a move reported by the client, teleporting the player back if it is impossible.
+    ///
+    /// Returns whether the move should be applied.
+    async fn validate_move(
+        &self,
+        player: &Arc<Player>,
+        server: &Server,
+        to: Vector3<f64>,
+        on_ground: bool,
+    ) -> bool {
+        let config = &server.advanced_config.movement;
+        if !config.enabled {
+            return true;
+        }
+
+        let context = Self::movement_context(player).await;
+        let from = player.get_entity().pos.load();
+        let mut validator = self.movement_validator.lock().await;
+        let verdict = validator.check(from, to, on_ground, context, config, Instant::now());
+        let violations = validator.violations();
+        drop(validator);
+
+        match verdict {
+            MovementVerdict::Valid => true,
+            MovementVerdict::TooFast => {
+                warn!(
+                    "{} moved too quickly! {:.2},{:.2},{:.2}",
+                    player.gameprofile.name,
+                    to.x - from.x,
+                    to.y - from.y,
+                    to.z - from.z
+                );
+                if config.max_violations > 0 && violations >= config.max_violations {
+                    self.kick(TextComponent::translate_cross(
+                        translation::java::MULTIPLAYER_DISCONNECT_INVALID_PLAYER_MOVEMENT,
+                        translation::java::MULTIPLAYER_DISCONNECT_INVALID_PLAYER_MOVEMENT,
+                        [],
+                    ))
+                    .await;
+                } else {
+                    self.force_tp(player, from).await;
+                }
+                false
+            }
+            MovementVerdict::Floating => {
+                warn!(
+                    "{} was kicked for floating too long!",
+                    player.gameprofile.name
+                );
+                self.kick(TextComponent::translate_cross(
+                    translation::java::MULTIPLAYER_DISCONNECT_FLYING,
+                    translation::java::MULTIPLAYER_DISCONNECT_FLYING,
+                    [],
+                ))
+                .await;
+                false
+            }
+        }
+    }
+
     pub async fn force_tp(&self, player: &Arc<Player>, position: Vector3<f64>) {
         let teleport_id = player.teleport_id_count.fetch_add(1, Ordering::Relaxed) + 1;
         *player.awaiting_teleport.lock().await = Some((teleport_id.into(), position));
@@ -931,7 +1092,10 @@ impl JavaClient {
                         server;
                         PlayerToggleSprintEvent::new(player.clone(), true);
                         'after: {
-                            player.get_entity().set_sprinting(event.is_sprinting).await;
+                            player
+                                .living_entity
+                                .set_sprinting(event.is_sprinting)
+                                .await;
                         }
                     }}
                 }
@@ -942,7 +1106,10 @@ impl JavaClient {
                         server;
                         PlayerToggleSprintEvent::new(player.clone(), false);
                         'after: {
-                            player.get_entity().set_sprinting(event.is_sprinting).await;
+                            player
+                                .living_entity
+                                .set_sprinting(event.is_sprinting)
+                                .await;
                         }
                     }}
                 }
@@ -953,21 +1120,19 @@ impl JavaClient {
                 debug!("todo");
             }
             Action::StartFlyingElytra => {
-                let fall_flying = entity.check_fall_flying();
-                if entity.is_fall_flying() != fall_flying {
-                    entity.set_fall_flying(fall_flying).await;
+                if !player.try_start_fall_flying().await && entity.is_fall_flying() {
+                    entity.set_fall_flying(false).await;
                 }
             }
             // <= 1.21.5
             Action::StartSneaking | Action::StopSneaking => {
-                self.handle_player_input(
-                    player,
-                    SPlayerInput {
-                        input: SPlayerInput::SNEAK,
-                    },
-                    server,
-                )
-                .await;
+                let input = if matches!(command.action, Action::StartSneaking) {
+                    SPlayerInput::SNEAK
+                } else {
+                    0
+                };
+                self.handle_player_input(player, SPlayerInput { input }, server)
+                    .await;
             }
         }
     }
@@ -985,25 +1150,16 @@ impl JavaClient {
                 PlayerToggleSneakEvent::new(player.clone(), sneak);
                 'after: {
                     player.get_entity().set_sneaking(event.is_sneaking).await;
+                    // Apply the crouching pose right away so the bounding box and eye height
+                    // match before the next tick.
+                    player.update_player_pose().await;
                     if event.is_sneaking {
-                        let vehicle = player.get_entity().vehicle.lock().await.clone();
-                        if let Some(vehicle) = vehicle {
-                            vehicle
-                                .get_entity()
-                                .remove_passenger(player.entity_id())
-                                .await;
-                        }
+                        player.stop_riding().await;
                     }
                 }
             }}
         } else if sneak {
-            let vehicle = player.get_entity().vehicle.lock().await.clone();
-            if let Some(vehicle) = vehicle {
-                vehicle
-                    .get_entity()
-                    .remove_passenger(player.entity_id())
-                    .await;
-            }
+            player.stop_riding().await;
         }
     }
 
@@ -1261,7 +1417,7 @@ impl JavaClient {
 
     pub async fn handle_swing_arm(&self, player: &Arc<Player>, swing_arm: SSwingArm) {
         player.update_last_action_time();
-        let Ok(hand) = Hand::try_from(swing_arm.hand.0) else {
+        let Ok(hand) = Hand::from_interaction_hand(swing_arm.hand.0) else {
             self.kick(TextComponent::text("Invalid hand")).await;
             return;
         };
@@ -1281,15 +1437,26 @@ impl JavaClient {
             )
             .await;
 
-        let event = if let Some((hit_pos, _hit_dir)) = hit_result {
+        let event = if let Some((hit_pos, hit_dir)) = hit_result {
             PlayerInteractEvent::new(
                 player,
                 InteractAction::LeftClickBlock,
                 player.world().get_block(&hit_pos),
                 Some(hit_pos),
+                hand,
+                Some(hit_dir),
+                None,
             )
         } else {
-            PlayerInteractEvent::new(player, InteractAction::LeftClickAir, &Block::AIR, None)
+            PlayerInteractEvent::new(
+                player,
+                InteractAction::LeftClickAir,
+                &Block::AIR,
+                None,
+                hand,
+                None,
+                None,
+            )
         };
 
         let server = player.world().server.upgrade().unwrap();
@@ -1377,19 +1544,7 @@ impl JavaClient {
         player: &Arc<Player>,
         chat_message: &SChatMessage,
     ) -> Result<(), ChatError> {
-        // Check for oversized messages
-        // If we're able to find the 257th UTF-16 character, the message is too big.
-        if chat_message.message.encode_utf16().nth(256).is_some() {
-            return Err(ChatError::OversizedMessage);
-        }
-        // Check for illegal characters
-        if chat_message
-            .message
-            .chars()
-            .any(|c| c == '§' || c < ' ' || c == '\x7F')
-        {
-            return Err(ChatError::IllegalCharacters);
-        }
+        validate_chat_text(&chat_message.message)?;
         // These checks are only run in secure chat mode
         if server.basic_config.allow_chat_reports {
             // Check for unsigned chat
@@ -1407,16 +1562,20 @@ impl JavaClient {
                 .as_millis() as i64;
 
             // Verify message timestamp
-            if chat_message.timestamp > now || chat_message.timestamp < (now - CHAT_MESSAGE_MAX_AGE)
+            if chat_message.timestamp > now + CHAT_MESSAGE_MAX_CLOCK_SKEW
+                || chat_message.timestamp < (now - CHAT_MESSAGE_MAX_AGE)
             {
                 return Err(ChatError::OutOfOrderChat);
             }
+            advance_chat_timestamp(&self.last_chat_timestamp, chat_message.timestamp)?;
 
             // Verify session expiry
             if player.chat_session.lock().await.expires_at < now {
                 return Err(ChatError::ExpiredPublicKey);
             }
 
+            validate_last_seen(chat_message.message_count.0, &chat_message.acknowledged)?;
+
             // Validate previous signature checksum (new in 1.21.5)
             // The client can bypass this check by sending 0
             if chat_message.checksum != 0 {
@@ -1607,24 +1766,12 @@ impl JavaClient {
         player.update_last_action_time();
         match client_status.action_id.0 {
             0 => {
-                // Perform respawn
-                if player.living_entity.health.load() > 0.0 {
+                // Perform respawn, either after dying or after the end credits
+                if player.respawn_after_credits().await || player.living_entity.health.load() > 0.0
+                {
                     return;
                 }
-                player.world().clone().respawn_player(player, false).await;
-
-                {
-                    let screen_handler = player.current_screen_handler.lock().await;
-                    let mut screen_handler = screen_handler.lock().await;
-                    screen_handler.sync_state().await;
-                };
-
-                // Restore abilities based on gamemode after respawn
-                {
-                    let mut abilities = player.abilities.lock().await;
-                    abilities.set_for_gamemode(player.gamemode.load());
-                };
-                player.send_abilities_update().await;
+                player.respawn().await;
             }
             1 => {
                 // Request stats
@@ -1712,6 +1859,14 @@ impl JavaClient {
             self.kick(TextComponent::text("Invalid action type")).await;
             return;
         };
+        // Attacks don't send a hand, they always use the main hand
+        let Ok(hand) = interact
+            .hand
+            .map_or(Ok(Hand::Right), |hand| Hand::from_interaction_hand(hand.0))
+        else {
+            self.kick(TextComponent::text("Invalid hand")).await;
+            return;
+        };
 
         // Resolve the target entity for the event
         let world = player_entity.world.load_full();
@@ -1766,14 +1921,27 @@ impl JavaClient {
                             player.attack(event.target).await;
                         }
                         ActionType::Interact | ActionType::InteractAt => {
-                            let held = player.inventory.held_item();
+                            let held = player.inventory.get_stack_in_hand(hand).await;
                             let mut stack = held.lock().await;
+                            let before = stack.clone();
                             if !event.target.interact(player, &mut stack).await {
                                 server
                                     .item_registry
                                     .use_on_entity(&mut stack, player, event.target)
                                     .await;
                             }
+                            let after = stack.clone();
+                            drop(stack);
+
+                            let slot_index = if hand == Hand::Right {
+                                player.inventory.get_selected_slot() as usize
+                            } else {
+                                PlayerInventory::OFF_HAND_SLOT
+                            };
+                            Self::announce_used_up_item(player, slot_index, &before, &after).await;
+                            if !after.are_equal(&before) {
+                                player.sync_hand_slot(slot_index, after).await;
+                            }
                         }
                     }
                 }
@@ -1842,11 +2010,13 @@ impl JavaClient {
                             .await;
                     }
 
+                    let restricted = player.is_block_break_restricted(block).await;
                     let inventory = player.inventory();
                     let held = inventory.held_item();
-                    if !server
-                        .item_registry
-                        .can_mine(held.lock().await.item, player)
+                    if restricted
+                        || !server
+                            .item_registry
+                            .can_mine(held.lock().await.item, player)
                     {
                         self.enqueue_packet(&CBlockUpdate::new(
                             position,
@@ -1887,11 +2057,16 @@ impl JavaClient {
                         // Instant break
                         if speed >= 1.0 {
                             let broken_state = world.get_block_state(&position);
+                            let block_drop = player.can_harvest(broken_state, block).await;
                             let new_state = world
                                 .break_block(
                                     &position,
                                     Some(player.clone()),
-                                    BlockFlags::NOTIFY_NEIGHBORS,
+                                    if block_drop {
+                                        BlockFlags::NOTIFY_NEIGHBORS
+                                    } else {
+                                        BlockFlags::SKIP_DROPS | BlockFlags::NOTIFY_NEIGHBORS
+                                    },
                                 )
                                 .await;
                             if new_state.is_some() {
@@ -1963,6 +2138,15 @@ impl JavaClient {
                     world.set_block_breaking(entity, location, -1).await;
 
                     let (block, state) = world.get_block_and_state(&location);
+                    if player.is_block_break_restricted(block).await {
+                        self.enqueue_packet(&CBlockUpdate::new(
+                            location,
+                            VarInt(i32::from(state.id)),
+                        ))
+                        .await;
+                        self.update_sequence(player, player_action.sequence.0);
+                        return;
+                    }
                     let block_drop = player.gamemode.load() != GameMode::Creative
                         && player.can_harvest(state, block).await;
 
@@ -2042,14 +2226,18 @@ impl JavaClient {
         }
     }
 
+    /// Records a block action sequence number to be acknowledged at the end of the tick.
+    ///
+    /// Only the highest sequence is kept, since acknowledging it confirms every earlier
+    /// prediction as well, even if the packets were handled out of order.
     pub fn update_sequence(&self, player: &Player, sequence: i32) {
         if sequence < 0 {
             error!("Expected packet sequence >= 0");
+            return;
         }
-        player.packet_sequence.store(
-            player.packet_sequence.load(Ordering::Relaxed).max(sequence),
-            Ordering::Relaxed,
-        );
+        player
+            .packet_sequence
+            .fetch_max(sequence, Ordering::Relaxed);
     }
 
     async fn sync_block_state_to_client(&self, world: &World, position: BlockPos) {
@@ -2096,6 +2284,20 @@ impl JavaClient {
             .await;
     }
 
+    pub async fn handle_play_resource_pack_response(&self, packet: SPlayResourcePack) {
+        let mut resource_packs = self.resource_packs.lock().await;
+        if !resource_packs.contains(&packet.uuid) {
+            warn!(
+                "Client {} returned a response for a resource pack we did not push!",
+                self.id
+            );
+            return;
+        }
+        if self.resource_pack_result(packet.uuid, packet.response_result()) == Some(false) {
+            resource_packs.retain(|uuid| *uuid != packet.uuid);
+        }
+    }
+
     #[allow(clippy::too_many_lines)]
     pub async fn handle_use_item_on(
         &self,
@@ -2123,7 +2325,7 @@ impl JavaClient {
             return Err(BlockPlacingError::InvalidBlockFace);
         };
 
-        let Ok(hand) = Hand::try_from(use_item_on.hand.0) else {
+        let Ok(hand) = Hand::from_interaction_hand(use_item_on.hand.0) else {
             return Err(BlockPlacingError::InvalidHand);
         };
 
@@ -2136,7 +2338,7 @@ impl JavaClient {
         let held_item_empty = held_item.lock().await.is_empty();
         let off_hand_item_empty = off_hand_item.lock().await.is_empty();
 
-        let item = if matches!(hand, Hand::Left) {
+        let item = if hand == Hand::Right {
             held_item
         } else {
             off_hand_item
@@ -2149,6 +2351,15 @@ impl JavaClient {
 
         let entity = &player.get_entity();
         let world = entity.world.load_full();
+
+        // Each dimension has its own vertical bounds, which clients may not respect
+        if !world.is_in_height_limit(position.0.y) {
+            if position.0.y > world.get_top_y() {
+                Self::send_build_too_high(player, &world).await;
+            }
+            return Err(BlockPlacingError::BlockOutOfWorld);
+        }
+
         let block = world.get_block(&position);
 
         let event = PlayerInteractEvent::new(
@@ -2156,17 +2367,17 @@ impl JavaClient {
             InteractAction::RightClickBlock,
             block,
             Some(position),
+            hand,
+            Some(face),
+            Some(cursor_pos),
         );
 
         send_cancellable! {{
             server;
             event;
             'cancelled: {
-                self.enqueue_packet(&CBlockUpdate::new(
-                    position,
-                    VarInt(block.id as i32),
-                ))
-                .await;
+                self.resync_cancelled_interaction(player, hand, Some((position, face)))
+                    .await;
                 return Ok(());
             }
         }}
@@ -2197,7 +2408,7 @@ impl JavaClient {
             }
         }
 
-        let slot_index = if matches!(hand, Hand::Left) {
+        let slot_index = if hand == Hand::Right {
             inventory.get_selected_slot() as usize
         } else {
             PlayerInventory::OFF_HAND_SLOT
@@ -2211,6 +2422,18 @@ impl JavaClient {
             return Ok(());
         }
 
+        // Adventure mode players may only use items on the blocks listed in `can_place_on`
+        if player.gamemode.load() == GameMode::Adventure
+            && !stack
+                .get_data_component::<CanPlaceOnImpl>()
+                .is_some_and(|can_place_on| can_place_on.matches(block))
+        {
+            drop(stack);
+            self.resync_cancelled_interaction(player, hand, Some((position, face)))
+                .await;
+            return Ok(());
+        }
+
         let before = stack.clone();
 
         server
@@ -2222,18 +2445,17 @@ impl JavaClient {
 
         // Check if the item is a block, because not every item can be placed :D
         let item_id = stack.item.id;
+        let mut placement_rejected = false;
         if let Some(block) = Block::from_item_id(item_id) {
             should_try_decrement = self
                 .run_is_block_place(player, block, server, use_item_on, position, face)
                 .await?;
+            placement_rejected = !should_try_decrement;
         }
 
-        if should_try_decrement {
-            // TODO: Config
-            // Decrease block count
-            if player.gamemode.load() != GameMode::Creative {
-                stack.decrement(1);
-            }
+        // Decrease block count
+        if should_try_decrement && !player.has_infinite_materials() {
+            stack.decrement(1);
         }
 
         let after = stack.clone();
@@ -2241,24 +2463,60 @@ impl JavaClient {
 
         // Broadcast the break entity status before the slot sync; the client
         // needs the old item texture in the slot for break particles.
-        if !before.is_empty() && after.is_empty() {
-            let slot = if slot_index == player.inventory.get_selected_slot() as usize {
-                &EquipmentSlot::MAIN_HAND
-            } else {
-                &EquipmentSlot::OFF_HAND
-            };
-            player
-                .world()
-                .send_entity_status(player.get_entity(), equipment_break_status(slot));
-        }
+        Self::announce_used_up_item(player, slot_index, &before, &after).await;
 
-        if !after.are_equal(&before) {
+        if placement_rejected {
+            // The client already predicted the placement, so undo it
+            self.resync_cancelled_interaction(player, hand, Some((position, face)))
+                .await;
+        } else if !after.are_equal(&before) {
             player.sync_hand_slot(slot_index, after).await;
         }
 
         Ok(())
     }
 
+    /// Plays the break effect if using the item in `slot_index` used it up. A damageable item
+    /// can only be used up by wearing it out, so it also counts as broken.
+    async fn announce_used_up_item(
+        player: &Player,
+        slot_index: usize,
+        before: &ItemStack,
+        after: &ItemStack,
+    ) {
+        if before.is_empty() || !after.is_empty() {
+            return;
+        }
+        let slot = if slot_index == player.inventory.get_selected_slot() as usize {
+            &EquipmentSlot::MAIN_HAND
+        } else {
+            &EquipmentSlot::OFF_HAND
+        };
+        if before.is_damageable() {
+            player
+                .increment_stat(StatisticCategory::Broken, before.item.id as i32, 1)
+                .await;
+        }
+        player
+            .world()
+            .send_entity_status(player.get_entity(), equipment_break_status(slot));
+    }
+
+    /// Tells the player they can't build above the world's max build height.
+    async fn send_build_too_high(player: &Player, world: &World) {
+        player
+            .send_system_message_raw(
+                &TextComponent::translate_cross(
+                    translation::java::BUILD_TOOHIGH,
+                    translation::bedrock::BUILD_TOOHIGH,
+                    vec![TextComponent::text(world.get_top_y().to_string())],
+                )
+                .color_named(NamedColor::Red),
+                true,
+            )
+            .await;
+    }
+
     #[expect(clippy::too_many_arguments)]
     async fn call_use_item_on(
         &self,
@@ -2351,17 +2609,13 @@ impl JavaClient {
         player.update_last_action_time();
 
         let inventory = player.inventory();
-        let Ok(hand) = Hand::try_from(use_item.hand.0) else {
+        let Ok(hand) = Hand::from_interaction_hand(use_item.hand.0) else {
             self.kick(TextComponent::text("InvalidHand")).await;
             return;
         };
         self.update_sequence(player, use_item.sequence.0);
 
-        let item_in_hand = if hand == Hand::Left {
-            inventory.held_item()
-        } else {
-            inventory.off_hand_item().await
-        };
+        let item_in_hand = inventory.get_stack_in_hand(hand).await;
 
         let (item_id, _item) = {
             let guard = item_in_hand.lock().await;
@@ -2386,15 +2640,26 @@ impl JavaClient {
             )
             .await;
 
-        let event = if let Some((hit_pos, _hit_dir)) = hit_result {
+        let event = if let Some((hit_pos, hit_dir)) = hit_result {
             PlayerInteractEvent::new(
                 player,
                 InteractAction::RightClickBlock,
                 player.world().get_block(&hit_pos),
                 Some(hit_pos),
+                hand,
+                Some(hit_dir),
+                None,
             )
         } else {
-            PlayerInteractEvent::new(player, InteractAction::RightClickAir, &Block::AIR, None)
+            PlayerInteractEvent::new(
+                player,
+                InteractAction::RightClickAir,
+                &Block::AIR,
+                None,
+                hand,
+                None,
+                None,
+            )
         };
         self.prepare_hand_item_for_use(player, hand, &item_in_hand)
             .await;
@@ -2415,11 +2680,50 @@ impl JavaClient {
             server;
             event;
             'after: {
-                server.item_registry.on_use(&stack_for_use, player).await;
+                server.item_registry.on_use(&stack_for_use, player, hand).await;
+            }
+            'cancelled: {
+                self.resync_cancelled_interaction(player, hand, None).await;
             }
         }}
     }
 
+    /// Undoes what the client predicted for a cancelled interaction: resends the clicked block
+    /// and the block against its `face` (where a placement would have gone), and the item in
+    /// `hand`.
+    async fn resync_cancelled_interaction(
+        &self,
+        player: &Player,
+        hand: Hand,
+        clicked: Option<(BlockPos, BlockDirection)>,
+    ) {
+        if let Some((position, face)) = clicked {
+            let world = player.world();
+            for pos in [position, position.offset(face.to_offset())] {
+                self.enqueue_packet(&CBlockUpdate::new(
+                    pos,
+                    VarInt(i32::from(world.get_block_state_id(&pos))),
+                ))
+                .await;
+            }
+        }
+
+        let inventory = player.inventory();
+        let (slot_index, item) = if hand == Hand::Right {
+            (
+                inventory.get_selected_slot() as usize,
+                inventory.held_item(),
+            )
+        } else {
+            (
+                PlayerInventory::OFF_HAND_SLOT,
+                inventory.off_hand_item().await,
+            )
+        };
+        let stack = item.lock().await.clone();
+        player.sync_hand_slot(slot_index, stack).await;
+    }
+
     async fn prepare_hand_item_for_use(
         &self,
         player: &Arc<Player>,
@@ -2429,14 +2733,14 @@ impl JavaClient {
         let inventory = player.inventory();
         let mut held = item_in_hand.lock().await;
 
-        if let Some(cooldown) = held.get_use_cooldown() {
-            let group = cooldown
-                .cooldown_group
-                .clone()
-                .unwrap_or_else(|| held.item.registry_key.to_string());
-            if player.is_on_cooldown(&group).await {
-                return;
-            }
+        // Items without a `use_cooldown` component, like the enchanted golden apple, can still be
+        // put on cooldown by the server
+        let group = held
+            .get_use_cooldown()
+            .and_then(|cooldown| cooldown.cooldown_group.clone())
+            .unwrap_or_else(|| held.item.registry_key.to_string());
+        if player.is_on_cooldown(&group).await {
+            return;
         }
 
         if held.get_data_component::<ConsumableImpl>().is_some()
@@ -2538,13 +2842,14 @@ impl JavaClient {
         player.living_entity.send_equipment_changes(equipment);
     }
 
-    pub async fn handle_set_creative_slot(
-        &self,
-        player: &Player,
-        packet: SSetCreativeSlot,
-    ) -> Result<(), InventoryError> {
+    pub async fn handle_set_creative_slot(&self, player: &Player, packet: SSetCreativeSlot) {
         if player.gamemode.load() != GameMode::Creative {
-            return Err(InventoryError::PermissionError);
+            // Clients can still send this right after leaving creative mode
+            warn!(
+                "{} tried to set a creative slot while not in creative mode",
+                player.gameprofile.name
+            );
+            return;
         }
         let is_negative = packet.slot < 0;
         let valid_slot = packet.slot >= 1 && packet.slot as usize <= 45;
@@ -2600,8 +2905,12 @@ impl JavaClient {
         } else if is_negative && is_legal {
             // Item drop
             player.drop_item(item_stack).await;
+        } else {
+            warn!(
+                "{} sent an invalid creative slot {} or item stack",
+                player.gameprofile.name, packet.slot
+            );
         }
-        Ok(())
     }
 
     pub async fn handle_chunk_batch(&self, player: &Player, packet: SChunkBatch) {
@@ -2726,11 +3035,9 @@ impl JavaClient {
     ) -> Result<bool, BlockPlacingError> {
         let entity = &player.get_entity();
 
-        match player.gamemode.load() {
-            GameMode::Spectator | GameMode::Adventure => {
-                return Err(BlockPlacingError::InvalidGamemode);
-            }
-            _ => {}
+        // Adventure mode placement is checked against the item's `can_place_on` beforehand
+        if player.gamemode.load() == GameMode::Spectator {
+            return Err(BlockPlacingError::InvalidGamemode);
         }
 
         let clicked_block_pos = BlockPos(location.0);
@@ -2743,17 +3050,7 @@ impl JavaClient {
 
         // Check the world's max build height
         if location.0.y + face.to_offset().y > world.get_top_y() {
-            player
-                .send_system_message_raw(
-                    &TextComponent::translate_cross(
-                        translation::java::BUILD_TOOHIGH,
-                        translation::bedrock::BUILD_TOOHIGH,
-                        vec![TextComponent::text((world.get_top_y()).to_string())],
-                    )
-                    .color_named(NamedColor::Red),
-                    true,
-                )
-                .await;
+            Self::send_build_too_high(player, &world).await;
             return Err(BlockPlacingError::BlockOutOfWorld);
         }
 
@@ -2869,7 +3166,7 @@ impl JavaClient {
         let event =
             BlockPlaceEvent::new(player.clone(), block, clicked_block, final_block_pos, true);
         let event = server.plugin_manager.fire::<BlockPlaceEvent>(event).await;
-        if event.cancelled {
+        if event.cancelled || !event.can_build {
             return Ok(false);
         }
 
@@ -2910,3 +3207,26 @@ impl JavaClient {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn chat_timestamps_must_strictly_increase() {
+        let last_timestamp = AtomicI64::new(i64::MIN);
+        assert!(advance_chat_timestamp(&last_timestamp, 1_000).is_ok());
+        assert!(advance_chat_timestamp(&last_timestamp, 1_001).is_ok());
+        assert!(advance_chat_timestamp(&last_timestamp, 1_000).is_err());
+    }
+
+    #[test]
+    fn repeated_chat_timestamp_is_rejected() {
+        let last_timestamp = AtomicI64::new(i64::MIN);
+        assert!(advance_chat_timestamp(&last_timestamp, 1_000).is_ok());
+        assert!(matches!(
+            advance_chat_timestamp(&last_timestamp, 1_000),
+            Err(ChatError::OutOfOrderChat)
+        ));
+    }
+}
@@ -0,0 +1,178 @@
+use std::time::Instant;
+
+use pumpkin_config::networking::rate_limit::{PacketRateLimitConfig, RateLimit};
+use pumpkin_protocol::java::server::play::{
+    SAttack, SChatCommand, SChatMessage, SClickSlot, SCommandSuggestion, SContainerButtonClick,
+    SInteract, SMoveVehicle, SPaddleBoat, SPickItemFromBlock, SPlaceRecipe, SPlayerAction,
+    SPlayerInput, SPlayerPosition, SPlayerPositionRotation, SPlayerRotation, SSetCreativeSlot,
+    SSetHeldItem, SSetPlayerGround, SSwingArm, SUseItem, SUseItemOn,
+};
+use pumpkin_protocol::packet::MultiVersionJavaPacket;
+use pumpkin_util::version::JavaMinecraftVersion;
+
+/// The groups inbound play packets are rate limited in.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum PacketCategory {
+    Chat,
+    CommandSuggestion,
+    Inventory,
+    Movement,
+    Interaction,
+    Other,
+}
+
+impl PacketCategory {
+    const COUNT: usize = 6;
+
+    /// Classifies a serverbound play packet by its id.
+    #[must_use]
+    pub fn from_packet_id(id: i32, version: JavaMinecraftVersion) -> Self {
+        let is = |packet_id: i32| id == packet_id;
+        if is(SChatMessage::to_id(version)) || is(SChatCommand::to_id(version)) {
+            Self::Chat
+        } else if is(SCommandSuggestion::to_id(version)) {
+            Self::CommandSuggestion
+        } else if is(SClickSlot::to_id(version))
+            || is(SSetCreativeSlot::to_id(version))
+            || is(SSetHeldItem::to_id(version))
+            || is(SPickItemFromBlock::to_id(version))
+            || is(SContainerButtonClick::to_id(version))
+            || is(SPlaceRecipe::to_id(version))
+        {
+            Self::Inventory
+        } else if is(SPlayerPosition::to_id(version))
+            || is(SPlayerPositionRotation::to_id(version))
+            || is(SPlayerRotation::to_id(version))
+            || is(SSetPlayerGround::to_id(version))
+            || is(SPlayerInput::to_id(version))
+            || is(SMoveVehicle::to_id(version))
+            || is(SPaddleBoat::to_id(version))
+        {
+            Self::Movement
+        } else if is(SUseItemOn::to_id(version))
+            || is(SUseItem::to_id(version))
+            || is(SPlayerAction::to_id(version))
+            || is(SSwingArm::to_id(version))
+            || is(SInteract::to_id(version))
+            || is(SAttack::to_id(version))
+        {
+            Self::Interaction
+        } else {
+            Self::Other
+        }
+    }
+
+    const fn limit(self, config: &PacketRateLimitConfig) -> RateLimit {
+        match self {
+            Self::Chat => config.chat,
+            Self::CommandSuggestion => config.command_suggestion,
+            Self::Inventory => config.inventory,
+            Self::Movement => config.movement,
+            Self::Interaction => config.interaction,
+            Self::Other => config.other,
+        }
+    }
+
+    /// Whether exceeding this category's limit may kick the client.
+    ///
+    /// Command suggestions are only dropped, as the client requests them on every keystroke
+    /// while a command is typed.
+    #[must_use]
+    pub const fn can_kick(self) -> bool {
+        !matches!(self, Self::CommandSuggestion)
+    }
+}
+
+/// A token bucket which starts full and refills continuously.
+struct TokenBucket {
+    tokens: f64,
+    last_refill: Instant,
+}
+
+impl TokenBucket {
+    fn new(limit: RateLimit, now: Instant) -> Self {
+        Self {
+            tokens: f64::from(limit.burst),
+            last_refill: now,
+        }
+    }
+
+    /// Takes one token, returning `false` if the bucket is empty.
+    fn try_take(&mut self, limit: RateLimit, now: Instant) -> bool {
+        let elapsed = now
+            .saturating_duration_since(self.last_refill)
+            .as_secs_f64();
+        self.tokens = elapsed
+            .mul_add(limit.per_second, self.tokens)
+            .min(f64::from(limit.burst));
+        self.last_refill = now;
+
+        if self.tokens >= 1.0 {
+            self.tokens -= 1.0;
+            true
+        } else {
+            false
+        }
+    }
+}
+
+/// Per-connection token buckets, one per [`PacketCategory`].
+#[derive(Default)]
+pub struct PacketRateLimiter {
+    buckets: [Option<TokenBucket>; PacketCategory::COUNT],
+}
+
+impl PacketRateLimiter {
+    /// Records a packet of the given category and returns whether it is within the limit.
+    pub fn try_acquire(
+        &mut self,
+        category: PacketCategory,
+        config: &PacketRateLimitConfig,
+        now: Instant,
+    ) -> bool {
+        let limit = category.limit(config);
+        self.buckets[category as usize]
+            .get_or_insert_with(|| TokenBucket::new(limit, now))
+            .try_take(limit, now)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    #[test]
+    fn bucket_allows_burst_then_refills() {
+        let config = PacketRateLimitConfig::default();
+        let limit = config.chat;
+        let mut limiter = PacketRateLimiter::default();
+        let start = Instant::now();
+
+        for _ in 0..limit.burst {
+            assert!(limiter.try_acquire(PacketCategory::Chat, &config, start));
+        }
+        assert!(!limiter.try_acquire(PacketCategory::Chat, &config, start));
+        // Other categories have their own bucket.
+        assert!(limiter.try_acquire(PacketCategory::Movement, &config, start));
+
+        let refilled = start + Duration::from_secs_f64(1.0 / limit.per_second);
+        assert!(limiter.try_acquire(PacketCategory::Chat, &config, refilled));
+        assert!(!limiter.try_acquire(PacketCategory::Chat, &config, refilled));
+    }
+
+    #[test]
+    fn typing_a_command_does_not_use_the_chat_limit() {
+        let config = PacketRateLimitConfig::default();
+        let mut limiter = PacketRateLimiter::default();
+        let start = Instant::now();
+
+        // One suggestion request per keystroke of a long command
+        for _ in 0..40 {
+            assert!(limiter.try_acquire(PacketCategory::CommandSuggestion, &config, start));
+        }
+        assert!(limiter.try_acquire(PacketCategory::Chat, &config, start));
+        assert!(!PacketCategory::CommandSuggestion.can_kick());
+    }
+}
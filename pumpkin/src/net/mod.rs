@@ -30,6 +30,7 @@ pub mod authentication;
 pub mod bedrock;
 pub mod java;
 pub mod lan_broadcast;
+pub mod profile_resolver;
 mod proxy;
 pub mod query;
 pub mod rcon;
@@ -317,11 +318,7 @@ pub async fn can_not_join(
         let whitelist = server.data.whitelist_config.read().await;
 
         if ops.get_entry(&profile.id).is_none() && !whitelist.is_whitelisted(profile) {
-            return Some(TextComponent::translate_cross(
-                translation::java::MULTIPLAYER_DISCONNECT_NOT_WHITELISTED,
-                translation::java::MULTIPLAYER_DISCONNECT_NOT_WHITELISTED,
-                &[],
-            ));
+            return Some(server.whitelist_message());
         }
     }
 
@@ -0,0 +1,137 @@
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use arc_swap::ArcSwap;
+use tracing::warn;
+use uuid::Uuid;
+
+use super::authentication::lookup_profile_by_name;
+use super::{GameProfile, offline_uuid};
+use crate::server::Server;
+
+/// Mojang allows this many profile lookups per [`LOOKUP_WINDOW`] from one address.
+const MAX_LOOKUPS_PER_WINDOW: usize = 600;
+const LOOKUP_WINDOW: Duration = Duration::from_mins(10);
+/// How long to stop asking Mojang after a lookup failed, e.g. because the API is unreachable.
+const FAILURE_BACKOFF: Duration = Duration::from_mins(1);
+
+/// Resolves player names to game profiles for commands and lists which accept offline players,
+/// such as the whitelist and ban list.
+///
+/// Results are kept in the user cache (`usercache.json`) until they expire. In online mode,
+/// unknown names are looked up through the Mojang API within its rate limit, while offline mode
+/// servers use the offline UUID of the name.
+#[derive(Default)]
+pub struct ProfileResolver {
+    /// When the lookups in the current rate limit window were made, oldest first.
+    recent_lookups: Mutex<VecDeque<Instant>>,
+    /// Lookups are skipped until this time after one failed.
+    backoff_until: Mutex<Option<Instant>>,
+}
+
+impl ProfileResolver {
+    /// Returns the profile of the player called `name`, or `None` if no such player exists or
+    /// Mojang can't be asked right now.
+    pub async fn resolve_by_name(&self, server: &Server, name: &str) -> Option<GameProfile> {
+        if let Some(player) = server.get_player_by_name(name) {
+            return Some(player.gameprofile.clone());
+        }
+
+        let cached_entry = server.data.user_cache.write().await.get_by_name(name);
+        if let Some(entry) = cached_entry {
+            return Some(profile_from_uuid_name(entry.uuid, entry.name));
+        }
+
+        let (uuid, name) = if server.basic_config.online_mode {
+            self.lookup(server, name).await?
+        } else {
+            (offline_uuid(name).ok()?, name.to_string())
+        };
+        server
+            .data
+            .user_cache
+            .write()
+            .await
+            .upsert(uuid, name.clone());
+        Some(profile_from_uuid_name(uuid, name))
+    }
+
+    async fn lookup(&self, server: &Server, name: &str) -> Option<(Uuid, String)> {
+        if !self.try_start_lookup() {
+            warn!("Not looking up the profile of {name}, Mojang's rate limit was reached");
+            return None;
+        }
+
+        let auth_config = server.advanced_config.networking.authentication.clone();
+        let lookup_name = name.to_string();
+        let result =
+            tokio::task::spawn_blocking(move || lookup_profile_by_name(&lookup_name, &auth_config))
+                .await;
+        match result {
+            Ok(Ok(profile)) => profile,
+            Ok(Err(error)) => {
+                warn!("Failed to look up the profile of {name}: {error}");
+                *self.backoff_until.lock().unwrap() = Some(Instant::now() + FAILURE_BACKOFF);
+                None
+            }
+            Err(_) => None,
+        }
+    }
+
+    /// Records a lookup if it stays within Mojang's rate limit and no backoff is active.
+    fn try_start_lookup(&self) -> bool {
+        let now = Instant::now();
+        if self
+            .backoff_until
+            .lock()
+            .unwrap()
+            .is_some_and(|backoff_until| now < backoff_until)
+        {
+            return false;
+        }
+
+        let mut recent_lookups = self.recent_lookups.lock().unwrap();
+        while recent_lookups
+            .front()
+            .is_some_and(|lookup| now.duration_since(*lookup) >= LOOKUP_WINDOW)
+        {
+            recent_lookups.pop_front();
+        }
+        if recent_lookups.len() >= MAX_LOOKUPS_PER_WINDOW {
+            return false;
+        }
+        recent_lookups.push_back(now);
+        true
+    }
+}
+
+fn profile_from_uuid_name(uuid: Uuid, name: String) -> GameProfile {
+    GameProfile {
+        id: uuid,
+        name,
+        properties: ArcSwap::new(Arc::new(vec![])),
+        profile_actions: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lookups_stop_at_the_rate_limit() {
+        let resolver = ProfileResolver::default();
+        for _ in 0..MAX_LOOKUPS_PER_WINDOW {
+            assert!(resolver.try_start_lookup());
+        }
+        assert!(!resolver.try_start_lookup());
+    }
+
+    #[test]
+    fn lookups_stop_during_backoff() {
+        let resolver = ProfileResolver::default();
+        *resolver.backoff_until.lock().unwrap() = Some(Instant::now() + FAILURE_BACKOFF);
+        assert!(!resolver.try_start_lookup());
+    }
+}
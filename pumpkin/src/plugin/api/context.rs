@@ -23,7 +23,10 @@ use crate::{
 
 use tracing_subscriber::{fmt, layer::SubscriberExt, util::SubscriberInitExt};
 
-use super::{EventPriority, Payload};
+use super::{
+    EventPriority, Payload,
+    events::player::player_custom_payload::{PlayerCustomPayloadEvent, PluginChannelHandler},
+};
 
 /// The `Context` struct represents the context of a plugin, containing metadata,
 /// a server reference, and event handlers.
@@ -294,6 +297,25 @@ impl Context {
         handlers_vec.push(Box::new(typed_handler));
     }
 
+    /// Registers a handler for plugin messages that players send on `channel`
+    /// (e.g. `bungeecord:main`). Messages on other channels never reach the handler.
+    ///
+    /// # Arguments
+    /// - `channel`: The channel identifier to listen on.
+    /// - `handler`: A reference to the event handler.
+    /// - `priority`: The priority of the event handler.
+    pub async fn register_plugin_channel<C: Into<String>, H>(
+        &self,
+        channel: C,
+        handler: Arc<H>,
+        priority: EventPriority,
+    ) where
+        H: EventHandler<PlayerCustomPayloadEvent> + 'static,
+    {
+        let handler = Arc::new(PluginChannelHandler::new(channel.into(), handler));
+        self.register_event(handler, priority, false).await;
+    }
+
     /// Registers a custom plugin loader that can load additional plugin types.
     ///
     /// This method allows plugins to extend the server with support for loading
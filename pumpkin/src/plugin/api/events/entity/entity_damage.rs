@@ -0,0 +1,59 @@
+use std::sync::Arc;
+
+use pumpkin_data::damage::DamageType;
+use pumpkin_macros::{Event, cancellable};
+
+use crate::entity::EntityBase;
+
+use super::EntityEvent;
+
+/// An event that occurs before a living entity takes damage.
+///
+/// It is not fired for damage the entity is invulnerable to or while it is dead. If the event
+/// is cancelled, no damage is applied. Changing `amount` changes the damage before fire
+/// immunity, Resistance, shields, the hurt cooldown and absorption are applied.
+#[cancellable]
+#[derive(Event, Clone)]
+pub struct EntityDamageEvent {
+    /// The entity being damaged.
+    pub entity: Arc<dyn EntityBase>,
+
+    /// The amount of damage about to be dealt.
+    pub amount: f32,
+
+    /// The type of damage being dealt.
+    pub damage_type: DamageType,
+
+    /// The entity that directly dealt the damage, e.g. an arrow.
+    pub source: Option<Arc<dyn EntityBase>>,
+
+    /// The entity responsible for the damage, e.g. the player who shot the arrow.
+    pub cause: Option<Arc<dyn EntityBase>>,
+}
+
+impl EntityDamageEvent {
+    /// Creates a new `EntityDamageEvent`.
+    #[must_use]
+    pub const fn new(
+        entity: Arc<dyn EntityBase>,
+        amount: f32,
+        damage_type: DamageType,
+        source: Option<Arc<dyn EntityBase>>,
+        cause: Option<Arc<dyn EntityBase>>,
+    ) -> Self {
+        Self {
+            entity,
+            amount,
+            damage_type,
+            source,
+            cause,
+            cancelled: false,
+        }
+    }
+}
+
+impl EntityEvent for EntityDamageEvent {
+    fn get_entity(&self) -> &Arc<dyn EntityBase> {
+        &self.entity
+    }
+}
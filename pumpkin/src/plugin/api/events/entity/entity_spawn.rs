@@ -0,0 +1,67 @@
+use std::sync::Arc;
+
+use pumpkin_data::entity::EntityType;
+use pumpkin_macros::{Event, cancellable};
+use pumpkin_util::math::vector3::Vector3;
+
+use crate::entity::EntityBase;
+
+use super::EntityEvent;
+
+/// Why an entity is being spawned into a world.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EntitySpawnReason {
+    /// Spawned by the natural mob spawner or by the weather.
+    Natural,
+    /// Dropped items and experience orbs.
+    Drop,
+    /// Spawned by a command such as `/summon`.
+    Command,
+    /// Spawned by anything else, e.g. a plugin.
+    Custom,
+}
+
+/// An event that occurs before an entity is added to a world.
+///
+/// If the event is cancelled, the entity is discarded and never added.
+/// Changing `position` moves the entity before it is spawned.
+#[cancellable]
+#[derive(Event, Clone)]
+pub struct EntitySpawnEvent {
+    /// The entity about to be spawned.
+    pub entity: Arc<dyn EntityBase>,
+
+    /// The type of the entity.
+    pub entity_type: &'static EntityType,
+
+    /// The position the entity will be spawned at.
+    pub position: Vector3<f64>,
+
+    /// Why the entity is being spawned.
+    pub reason: EntitySpawnReason,
+}
+
+impl EntitySpawnEvent {
+    /// Creates a new `EntitySpawnEvent`.
+    #[must_use]
+    pub const fn new(
+        entity: Arc<dyn EntityBase>,
+        entity_type: &'static EntityType,
+        position: Vector3<f64>,
+        reason: EntitySpawnReason,
+    ) -> Self {
+        Self {
+            entity,
+            entity_type,
+            position,
+            reason,
+            cancelled: false,
+        }
+    }
+}
+
+impl EntityEvent for EntitySpawnEvent {
+    fn get_entity(&self) -> &Arc<dyn EntityBase> {
+        &self.entity
+    }
+}
@@ -7,6 +7,7 @@ pub mod fish;
 pub mod inventory_close;
 pub mod inventory_interact;
 pub mod item_held;
+pub mod player_camera_change;
 pub mod player_change_world;
 pub mod player_chat;
 pub mod player_command_send;
@@ -0,0 +1,43 @@
+use pumpkin_macros::{Event, cancellable};
+use std::sync::Arc;
+
+use crate::entity::player::Player;
+
+use super::PlayerEvent;
+
+/// An event that occurs when a player's camera is moved to another entity or reset.
+///
+/// If the event is cancelled, the player's camera is left unchanged.
+#[cancellable]
+#[derive(Event, Clone)]
+pub struct PlayerCameraChangeEvent {
+    /// The player whose camera is changing.
+    pub player: Arc<Player>,
+
+    /// The entity ID the camera will follow, or `None` to return it to the player.
+    pub target_id: Option<i32>,
+}
+
+impl PlayerCameraChangeEvent {
+    /// Creates a new instance of `PlayerCameraChangeEvent`.
+    ///
+    /// # Arguments
+    /// - `player`: A reference to the player whose camera is changing.
+    /// - `target_id`: The entity ID of the new camera, or `None` to reset it.
+    ///
+    /// # Returns
+    /// A new instance of `PlayerCameraChangeEvent`.
+    pub const fn new(player: Arc<Player>, target_id: Option<i32>) -> Self {
+        Self {
+            player,
+            target_id,
+            cancelled: false,
+        }
+    }
+}
+
+impl PlayerEvent for PlayerCameraChangeEvent {
+    fn get_player(&self) -> &Arc<Player> {
+        &self.player
+    }
+}
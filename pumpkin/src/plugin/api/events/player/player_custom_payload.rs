@@ -2,7 +2,11 @@ use bytes::Bytes;
 use pumpkin_macros::Event;
 use std::sync::Arc;
 
-use crate::entity::player::Player;
+use crate::{
+    entity::player::Player,
+    plugin::{BoxFuture, EventHandler},
+    server::Server,
+};
 
 use super::PlayerEvent;
 
@@ -33,3 +37,32 @@ impl PlayerEvent for PlayerCustomPayloadEvent {
         &self.player
     }
 }
+
+/// Forwards [`PlayerCustomPayloadEvent`]s to `handler` only when they arrive on `channel`.
+/// Created by [`Context::register_plugin_channel`](crate::plugin::api::context::Context::register_plugin_channel).
+pub(crate) struct PluginChannelHandler<H> {
+    channel: String,
+    handler: Arc<H>,
+}
+
+impl<H> PluginChannelHandler<H> {
+    pub(crate) const fn new(channel: String, handler: Arc<H>) -> Self {
+        Self { channel, handler }
+    }
+}
+
+impl<H: EventHandler<PlayerCustomPayloadEvent>> EventHandler<PlayerCustomPayloadEvent>
+    for PluginChannelHandler<H>
+{
+    fn handle<'a>(
+        &'a self,
+        server: &'a Arc<Server>,
+        event: &'a PlayerCustomPayloadEvent,
+    ) -> BoxFuture<'a, ()> {
+        Box::pin(async move {
+            if event.channel == self.channel {
+                self.handler.handle(server, event).await;
+            }
+        })
+    }
+}
@@ -1,17 +1,19 @@
 use std::sync::Arc;
 
 use crate::entity::player::Player;
-use pumpkin_data::Block;
+use pumpkin_data::{Block, BlockDirection};
 use pumpkin_macros::{Event, cancellable};
-use pumpkin_util::math::position::BlockPos;
+use pumpkin_util::Hand;
+use pumpkin_util::math::{position::BlockPos, vector3::Vector3};
 
 use super::PlayerEvent;
 
 /// Event that is triggered when a player interacts with a block or air.
 ///
-/// This event includes information about the player, the action performed,
-/// the item in the player's hand, the block interacted with, and the position clicked (if any).
-/// It can be cancelled to prevent the default interaction behavior.
+/// This event includes information about the player, the action performed, the hand used,
+/// the block interacted with, and the position, face and cursor clicked (if any).
+/// It can be cancelled to prevent the default interaction behavior; the client's view of the
+/// block and the held item is then resynced.
 #[cancellable]
 #[derive(Event, Clone)]
 pub struct PlayerInteractEvent {
@@ -26,6 +28,16 @@ pub struct PlayerInteractEvent {
 
     /// The block that was interacted with.
     pub block: &'static Block,
+
+    /// The hand the player interacted with.
+    pub hand: Hand,
+
+    /// The face of the clicked block, if any.
+    pub face: Option<BlockDirection>,
+
+    /// Where on `face` the player clicked, relative to the block's origin. Only known for
+    /// right-clicks on a block.
+    pub cursor_pos: Option<Vector3<f32>>,
 }
 
 impl PlayerInteractEvent {
@@ -37,6 +49,9 @@ impl PlayerInteractEvent {
     /// - `action`: The type of interaction performed.
     /// - `block`: The block that was interacted with.
     /// - `clicked_pos`: The optional position of the block that was clicked.
+    /// - `hand`: The hand the player interacted with.
+    /// - `face`: The optional face of the block that was clicked.
+    /// - `cursor_pos`: The optional point on `face` that was clicked.
     ///
     /// # Returns
     ///
@@ -46,12 +61,18 @@ impl PlayerInteractEvent {
         action: InteractAction,
         block: &'static Block,
         clicked_pos: Option<BlockPos>,
+        hand: Hand,
+        face: Option<BlockDirection>,
+        cursor_pos: Option<Vector3<f32>>,
     ) -> Self {
         Self {
             player: Arc::clone(player),
             action,
             block,
             clicked_pos,
+            hand,
+            face,
+            cursor_pos,
             cancelled: false,
         }
     }
@@ -1,4 +1,5 @@
 pub mod chunk_load;
 pub mod chunk_save;
 pub mod chunk_send;
+pub mod portal_create;
 pub mod spawn_change;
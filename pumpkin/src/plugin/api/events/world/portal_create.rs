@@ -0,0 +1,62 @@
+use crate::world::World;
+use pumpkin_data::block_properties::HorizontalAxis;
+use pumpkin_macros::{Event, cancellable};
+use pumpkin_util::math::position::BlockPos;
+use std::sync::Arc;
+
+/// Why a nether portal is being created.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PortalCreateReason {
+    /// An obsidian frame was lit with fire, e.g. by flint and steel.
+    Fire,
+    /// A destination portal is being built for an entity travelling through another portal.
+    NetherPair,
+}
+
+/// An event that occurs when a nether portal is about to be created.
+///
+/// If the event is cancelled, no portal blocks are placed.
+#[cancellable]
+#[derive(Event, Clone)]
+pub struct PortalCreateEvent {
+    /// The world in which the portal is created.
+    pub world: Arc<World>,
+
+    /// The lower corner of the portal interior.
+    pub lower_corner: BlockPos,
+
+    /// The horizontal axis the portal spans.
+    pub axis: HorizontalAxis,
+
+    /// The width of the portal interior in blocks.
+    pub width: u32,
+
+    /// The height of the portal interior in blocks.
+    pub height: u32,
+
+    /// Why the portal is being created.
+    pub reason: PortalCreateReason,
+}
+
+impl PortalCreateEvent {
+    /// Creates a new `PortalCreateEvent`.
+    #[must_use]
+    pub const fn new(
+        world: Arc<World>,
+        lower_corner: BlockPos,
+        axis: HorizontalAxis,
+        width: u32,
+        height: u32,
+        reason: PortalCreateReason,
+    ) -> Self {
+        Self {
+            world,
+            lower_corner,
+            axis,
+            width,
+            height,
+            reason,
+            cancelled: false,
+        }
+    }
+}
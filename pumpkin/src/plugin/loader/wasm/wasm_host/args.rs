@@ -54,7 +54,7 @@ impl OwnedArg {
             Arg::Players(v) => Self::Players(v.clone()),
             Arg::GameProfiles(v) => Self::GameProfiles(v.clone()),
             Arg::BlockPos(p) => Self::BlockPos(*p),
-            Arg::Pos3D(v) => Self::Pos3D(*v),
+            Arg::Pos3D(v, _) => Self::Pos3D(*v),
             Arg::Pos2D(v) => Self::Pos2D(*v),
             Arg::Rotation(a, b, c, d) => Self::Rotation(*a, *b, *c, *d),
             Arg::GameMode(g) => Self::GameMode(*g),
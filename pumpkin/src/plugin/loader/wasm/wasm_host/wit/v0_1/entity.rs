@@ -222,7 +222,12 @@ impl HostEntity for PluginHostState {
         sprinting: bool,
     ) -> wasmtime::Result<()> {
         let entity = entity_from_resource(self, &entity)?;
-        entity.get_entity().set_sprinting(sprinting).await;
+        // Living entities also need their sprint speed boost updated
+        if let Some(living) = entity.get_living_entity() {
+            living.set_sprinting(sprinting).await;
+        } else {
+            entity.get_entity().set_sprinting(sprinting).await;
+        }
         Ok(())
     }
 
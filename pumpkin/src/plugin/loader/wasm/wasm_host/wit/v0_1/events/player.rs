@@ -674,11 +674,16 @@ impl ToFromWasmEvent for PlayerInteractEvent {
 
     fn from_wasm_event(event: Event, state: &mut PluginHostState) -> Self {
         match event {
+            // The WIT interface doesn't carry the hand, face or cursor, so they can't be
+            // read back; the server only uses the cancellation state of this event.
             Event::PlayerInteractEvent(data) => Self {
                 player: consume_player(state, &data.player),
                 action: from_wasm_interact_action(data.action),
                 clicked_pos: data.clicked_pos.map(from_wasm_block_position),
                 block: from_wasm_block_name(&data.block),
+                hand: pumpkin_util::Hand::Right,
+                face: None,
+                cursor_pos: None,
                 cancelled: data.cancelled,
             },
             _ => panic!("unexpected event type"),
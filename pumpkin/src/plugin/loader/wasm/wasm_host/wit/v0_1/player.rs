@@ -896,7 +896,9 @@ impl pumpkin::plugin::player::HostPlayer for PluginHostState {
         let header = text_component_from_resource(self, &header);
         let footer = text_component_from_resource(self, &footer);
         let player = player_from_resource(self, &player)?;
-        player.set_tab_list_header_footer(header, footer).await;
+        player
+            .set_tab_list_header_footer(Some(header), Some(footer))
+            .await;
         Ok(())
     }
 
@@ -937,7 +939,9 @@ impl pumpkin::plugin::player::HostPlayer for PluginHostState {
     ) -> wasmtime::Result<()> {
         let component = text_component_from_resource(self, &text);
         let player = player_from_resource(self, &player)?;
-        player.show_title(&component, &TitleMode::Title).await;
+        player
+            .show_title(&component, &TitleMode::Title, false)
+            .await;
         Ok(())
     }
 
@@ -948,7 +952,9 @@ impl pumpkin::plugin::player::HostPlayer for PluginHostState {
     ) -> wasmtime::Result<()> {
         let component = text_component_from_resource(self, &text);
         let player = player_from_resource(self, &player)?;
-        player.show_title(&component, &TitleMode::SubTitle).await;
+        player
+            .show_title(&component, &TitleMode::SubTitle, false)
+            .await;
         Ok(())
     }
 
@@ -959,7 +965,9 @@ impl pumpkin::plugin::player::HostPlayer for PluginHostState {
     ) -> wasmtime::Result<()> {
         let component = text_component_from_resource(self, &text);
         let player = player_from_resource(self, &player)?;
-        player.show_title(&component, &TitleMode::ActionBar).await;
+        player
+            .show_title(&component, &TitleMode::ActionBar, false)
+            .await;
         Ok(())
     }
 
@@ -971,7 +979,7 @@ impl pumpkin::plugin::player::HostPlayer for PluginHostState {
         fade_out: i32,
     ) -> wasmtime::Result<()> {
         let player = player_from_resource(self, &player)?;
-        player.send_title_animation(fade_in, stay, fade_out).await;
+        player.set_title_times(fade_in, stay, fade_out).await;
         Ok(())
     }
 
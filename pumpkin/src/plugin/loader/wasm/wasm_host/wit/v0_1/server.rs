@@ -248,7 +248,7 @@ impl pumpkin::plugin::server::HostServer for PluginHostState {
             .as_ref()
             .ok_or_else(|| wasmtime::Error::msg("Server not available"))?;
         server
-            .broadcast_tab_list_header_footer(&header, &footer)
+            .broadcast_tab_list_header_footer(Some(&header), Some(&footer))
             .await;
         Ok(())
     }
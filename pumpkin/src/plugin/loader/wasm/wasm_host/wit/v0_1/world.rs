@@ -25,7 +25,7 @@ use crate::plugin::loader::wasm::wasm_host::{
     },
     wit::v0_1::pumpkin::{self, plugin::world::World},
 };
-use crate::world::explosion::Explosion;
+use crate::world::explosion::ExplosionBlockInteraction;
 
 pub(crate) const fn to_wasm_block_direction(dir: InternalBlockDirection) -> WitBlockDirection {
     match dir {
@@ -504,16 +504,19 @@ impl pumpkin::plugin::world::HostWorld for PluginHostState {
         world: Resource<World>,
         pos: pumpkin::plugin::common::Position,
         power: f32,
-        _create_fire: bool,
+        create_fire: bool,
         _interaction: pumpkin::plugin::world::ExplosionInteraction,
     ) -> wasmtime::Result<()> {
         let world_ref = self.get_world_res(&world)?;
-        // Currently Explosion only supports power and position in this codebase
-        let explosion = Explosion::new(
-            power,
-            pumpkin_util::math::vector3::Vector3::new(pos.0, pos.1, pos.2),
-        );
-        explosion.explode(&world_ref.provider).await;
+        world_ref
+            .provider
+            .create_explosion(
+                pumpkin_util::math::vector3::Vector3::new(pos.0, pos.1, pos.2),
+                power,
+                create_fire,
+                ExplosionBlockInteraction::DestroyWithDecay,
+            )
+            .await;
         Ok(())
     }
 
@@ -886,7 +889,7 @@ impl pumpkin::plugin::world::HostWorldBorder for PluginHostState {
 
     async fn get_diameter(&mut self, border: Resource<WitWorldBorder>) -> wasmtime::Result<f64> {
         let border_res = self.get_world_border_res(&border)?;
-        Ok(border_res.provider.worldborder.lock().await.new_diameter)
+        Ok(border_res.provider.worldborder.lock().await.diameter)
     }
 
     async fn set_diameter(
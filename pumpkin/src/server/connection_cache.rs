@@ -14,6 +14,8 @@ use uuid::Uuid;
 
 const DEFAULT_ICON: &[u8] = include_bytes!("../../../assets/default_icon.png");
 const MAX_SAMPLE_PLAYERS: usize = 12;
+/// The icon picked up from the working directory when no `favicon_path` is configured, as in vanilla.
+const SERVER_ICON_PATH: &str = "server-icon.png";
 
 fn load_icon_from_file<P: AsRef<Path>>(path: P) -> Result<String, Box<dyn error::Error>> {
     let buf = fs::read(path)?;
@@ -28,6 +30,12 @@ fn load_icon_from_file<P: AsRef<Path>>(path: P) -> Result<String, Box<dyn error:
     Ok(load_icon_from_bytes(&buf))
 }
 
+/// Replaces the `%online%` and `%max%` placeholders in the MOTD with the current player counts.
+fn fill_motd_placeholders(motd: &str, online: u32, max: u32) -> String {
+    motd.replace("%online%", &online.to_string())
+        .replace("%max%", &max.to_string())
+}
+
 fn load_icon_from_bytes(png_data: &[u8]) -> String {
     assert!(!png_data.is_empty(), "PNG data is empty");
     let mut result = "data:image/png;base64,".to_owned();
@@ -90,6 +98,11 @@ impl CachedStatus {
             version.protocol = client_protocol as u32;
         }
 
+        if let Some(players) = &response.players {
+            response.description =
+                fill_motd_placeholders(&response.description, players.online, players.max);
+        }
+
         let json = serde_json::to_string(&response).expect("Failed to serialize status response");
 
         CStatusResponse::new(json)
@@ -139,9 +152,18 @@ impl CachedStatus {
         let favicon = if config.use_favicon {
             config.favicon_path.as_ref().map_or_else(
                 || {
-                    debug!("Loading default icon");
+                    if Path::new(SERVER_ICON_PATH).is_file() {
+                        debug!("Loading server icon from '{SERVER_ICON_PATH}'");
+                        match load_icon_from_file(SERVER_ICON_PATH) {
+                            Ok(icon) => return Some(icon),
+                            Err(e) => warn!(
+                                "Failed to load favicon from '{SERVER_ICON_PATH}': {e}; using default."
+                            ),
+                        }
+                    } else {
+                        debug!("Loading default icon");
+                    }
 
-                    // Attempt to load default icon
                     Some(load_icon_from_bytes(DEFAULT_ICON))
                 },
                 |icon_path| {
@@ -203,3 +225,20 @@ impl Default for CachedStatus {
         Self::new(&BasicConfiguration::default())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn motd_placeholders_use_player_counts() {
+        assert_eq!(
+            fill_motd_placeholders("%online%/%max% playing, %online% online", 3, 20),
+            "3/20 playing, 3 online"
+        );
+        assert_eq!(
+            fill_motd_placeholders("A Pumpkin server", 3, 20),
+            "A Pumpkin server"
+        );
+    }
+}
@@ -1,11 +1,15 @@
 use crate::block::registry::BlockRegistry;
 use crate::command::commands::default_dispatcher;
 use crate::command::commands::defaultgamemode::DefaultGamemode;
-use crate::data::VanillaData;
+use crate::data::banlist_serializer::{BannedIpEntry, BannedPlayerEntry};
+use crate::data::custom_dimension::{CustomDimension, CustomDimensionType, load_custom_dimensions};
 use crate::data::player_server::ServerPlayerData;
+use crate::data::whitelist::WhitelistConfig;
+use crate::data::{LoadJSONConfiguration, SaveJSONConfiguration, VanillaData};
 use crate::entity::{EntityBase, NBTStorage};
 use crate::item::registry::ItemRegistry;
-use crate::net::authentication::fetch_mojang_public_keys;
+use crate::net::authentication::{AuthError, fetch_mojang_public_keys};
+use crate::net::profile_resolver::ProfileResolver;
 use crate::net::{ClientPlatform, DisconnectReason, EncryptionError, GameProfile, PlayerConfig};
 use crate::plugin::PluginManager;
 use crate::plugin::player::player_login::PlayerLoginEvent;
@@ -20,9 +24,11 @@ use crate::{
 use arc_swap::ArcSwap;
 use connection_cache::{CachedBranding, CachedStatus};
 use key_store::KeyStore;
+use pumpkin_config::whitelist::WhitelistEntry;
 use pumpkin_config::{AdvancedConfiguration, BasicConfiguration};
 use pumpkin_data::dimension::Dimension;
 use pumpkin_data::entity::EntityType;
+use pumpkin_data::translation;
 use pumpkin_util::permission::{PermissionManager, PermissionRegistry};
 use pumpkin_util::text::color::NamedColor;
 use pumpkin_world::dimension::into_level;
@@ -30,9 +36,13 @@ use pumpkin_world::world::WorldPortalExt;
 use tracing::{debug, error, info, warn};
 
 use crate::command::CommandSender;
+use crate::command::audit::CommandAuditLog;
 use pumpkin_macros::send_cancellable;
+use pumpkin_protocol::codec::var_int::VarInt;
 use pumpkin_protocol::java::client::login::CEncryptionRequest;
-use pumpkin_protocol::java::client::play::{CChangeDifficulty, CTabList};
+use pumpkin_protocol::java::client::play::{
+    CChangeDifficulty, CPlayerInfoUpdate, PlayerAction, PlayerInfoFlags,
+};
 use pumpkin_protocol::{ClientPacket, java::client::config::CPluginMessage};
 use pumpkin_util::Difficulty;
 use pumpkin_util::text::TextComponent;
@@ -46,11 +56,13 @@ use std::collections::HashSet;
 use std::fs;
 use std::net::IpAddr;
 use std::sync::Arc;
-use std::sync::atomic::{AtomicBool, AtomicI32, AtomicI64, AtomicU32};
+use std::sync::atomic::{AtomicBool, AtomicI32, AtomicI64, AtomicU32, AtomicUsize};
 use std::{future::Future, sync::atomic::Ordering, time::Duration};
+use time::OffsetDateTime;
 use tokio::sync::{Mutex, OnceCell, RwLock};
 use tokio::task::{JoinHandle, JoinSet};
 use tokio_util::task::TaskTracker;
+use uuid::Uuid;
 
 mod connection_cache;
 mod key_store;
@@ -66,7 +78,10 @@ use crate::command::args::entities::{
     EntityFilter, EntityFilterSort, EntitySelectorType, TargetSelector, ValueCondition,
 };
 use crate::data::advancement_data::AdvancementManager;
-use crate::server::scheduler::TaskScheduler;
+use crate::server::scheduler::{TaskHandle, TaskScheduler};
+
+/// How often the player list latency is refreshed, in ticks (vanilla uses 30 seconds).
+const LATENCY_UPDATE_INTERVAL: i32 = 600;
 
 /// Represents a Minecraft server instance.
 pub struct Server {
@@ -74,6 +89,8 @@ pub struct Server {
     pub advanced_config: AdvancedConfiguration,
 
     pub data: VanillaData,
+    /// Resolves player names to profiles, e.g. for the whitelist and ban commands.
+    pub profile_resolver: ProfileResolver,
 
     /// Plugin manager
     pub plugin_manager: Arc<PluginManager>,
@@ -93,6 +110,8 @@ pub struct Server {
     branding: CachedBranding,
     /// Saves and dispatches commands to appropriate handlers.
     pub command_dispatcher: RwLock<CommandDispatcher>,
+    /// Records the commands run by privileged senders.
+    pub command_audit_log: CommandAuditLog,
     /// Block behaviour.
     pub block_registry: Arc<BlockRegistry>,
     /// Item behaviour.
@@ -101,6 +120,10 @@ pub struct Server {
     pub worlds: ArcSwap<Vec<Arc<World>>>,
     /// All the dimensions that exist on the server.
     pub dimensions: Vec<Dimension>,
+    /// The dimension types defined by the world's data packs, sorted by their registry id.
+    pub custom_dimension_types: Vec<CustomDimensionType>,
+    /// The dimensions defined by the world's data packs, which are also part of `dimensions`.
+    pub custom_dimensions: Vec<CustomDimension>,
     /// Assigns unique IDs to containers.
     container_id: AtomicU32,
     pub recipe_manager: Arc<recipe::RecipeManager>,
@@ -133,9 +156,13 @@ pub struct Server {
     pub server_guid: u64,
     /// Player idle timeout in minutes (0 = disabled)
     pub player_idle_timeout: AtomicI32,
+    /// Players which got a slot but haven't been added to a world yet
+    pending_logins: AtomicUsize,
     /// Manages scheduled tasks (e.g. from plugins)
     pub task_scheduler: Arc<TaskScheduler>,
     tasks: TaskTracker,
+    /// Set once the worlds and `level.dat` have been saved on shutdown.
+    shutdown_complete: OnceCell<()>,
 
     // world stuff which maybe should be put into a struct
     pub level_info: Arc<ArcSwap<LevelData>>,
@@ -150,6 +177,11 @@ impl Server {
         advanced_config: AdvancedConfiguration,
         vanilla_data: VanillaData,
     ) -> Arc<Self> {
+        let networking = &advanced_config.networking;
+        pumpkin_protocol::ser::set_read_limits(
+            networking.max_string_length,
+            networking.max_list_length,
+        );
         let permission_registry = Arc::new(RwLock::new(PermissionRegistry::new()));
         // First register the default commands. After that, plugins can put in their own.
         let command_dispatcher =
@@ -221,6 +253,9 @@ impl Server {
             }
         });
 
+        let data_pack_dimensions = load_custom_dimensions(&world_path);
+        let custom_dimension_types = data_pack_dimensions.types;
+        let custom_dimensions = data_pack_dimensions.dimensions;
         let dimensions = {
             let mut dimensions = vec![Dimension::OVERWORLD];
             if basic_config.allow_nether {
@@ -229,6 +264,11 @@ impl Server {
             if basic_config.allow_end {
                 dimensions.push(Dimension::THE_END);
             }
+            dimensions.extend(
+                custom_dimensions
+                    .iter()
+                    .map(|custom_dimension| custom_dimension.dimension.clone()),
+            );
             dimensions
         };
         info!(
@@ -239,10 +279,12 @@ impl Server {
                 .collect::<Vec<_>>()
         );
 
+        let player_idle_timeout = AtomicI32::new(basic_config.player_idle_timeout as i32);
         let server = Self {
             basic_config,
             advanced_config,
             data: vanilla_data,
+            profile_resolver: ProfileResolver::default(),
             plugin_manager: Arc::new(PluginManager::new()),
             permission_manager: Arc::new(RwLock::new(PermissionManager::new(
                 permission_registry.clone(),
@@ -253,7 +295,10 @@ impl Server {
             map_id: level_info.load().map_id.into(),
             worlds: ArcSwap::from_pointee(vec![]),
             dimensions,
+            custom_dimension_types,
+            custom_dimensions,
             command_dispatcher,
+            command_audit_log: CommandAuditLog::default(),
             block_registry: block_registry.clone(),
             item_registry: super::item::items::default_registry(),
             key_store: OnceCell::new(),
@@ -271,9 +316,11 @@ impl Server {
             aggregated_tick_times_nanos: AtomicI64::new(0),
             tick_count: AtomicI32::new(0),
             tasks: TaskTracker::new(),
+            shutdown_complete: OnceCell::new(),
             task_scheduler: Arc::new(TaskScheduler::new()),
             server_guid: rand::random(),
-            player_idle_timeout: AtomicI32::new(0),
+            player_idle_timeout,
+            pending_logins: AtomicUsize::new(0),
             mojang_public_keys: ArcSwap::from_pointee(Vec::new()),
             world_info_writer: Arc::new(AnvilLevelInfo),
             level_info,
@@ -368,6 +415,25 @@ impl Server {
         self.tasks.spawn(task)
     }
 
+    /// Returns the loaded world of the dimension with the given resource location, if any.
+    pub fn get_world_by_name(&self, name: &str) -> Option<Arc<World>> {
+        self.worlds
+            .load()
+            .iter()
+            .find(|w| w.dimension.minecraft_name == name)
+            .cloned()
+    }
+
+    /// Returns the vanilla or data pack dimension with the given resource location, if any.
+    pub fn get_dimension_by_name(&self, name: &str) -> Option<Dimension> {
+        Dimension::from_name(name).cloned().or_else(|| {
+            self.custom_dimensions
+                .iter()
+                .find(|custom| custom.dimension.minecraft_name == name)
+                .map(|custom| custom.dimension.clone())
+        })
+    }
+
     pub fn get_world_from_dimension(&self, dimension: &Dimension) -> Arc<World> {
         self.worlds
             .load()
@@ -386,10 +452,9 @@ impl Server {
     pub async fn create_world(self: &Arc<Self>, name: String, dimension: Dimension) -> Arc<World> {
         {
             let worlds = self.worlds.load();
-            if let Some(world) = worlds
-                .iter()
-                .find(|w| w.get_world_name() == name && w.dimension == dimension)
-            {
+            if let Some(world) = worlds.iter().find(|w| {
+                w.get_world_name() == name && w.dimension.minecraft_name == dimension.minecraft_name
+            }) {
                 return world.clone();
             }
         }
@@ -460,42 +525,40 @@ impl Server {
     ) -> Option<(Arc<Player>, Arc<World>)> {
         let gamemode = self.defaultgamemode.lock().await.gamemode;
 
-        let (world, nbt) =
-            if let Ok(Some(data)) = self.player_data_storage.load_data(&profile.id).await {
-                if let Some(dimension_key) = data.get_string("Dimension") {
-                    if let Some(dimension) = Dimension::from_name(dimension_key) {
-                        let world = self.get_world_from_dimension(dimension);
-                        (world, Some(data))
-                    } else {
-                        warn!("Invalid dimension key in player data: {dimension_key}");
-                        let default_world = self
-                            .worlds
-                            .load()
-                            .first()
-                            .expect("Default world should exist")
-                            .clone();
-                        (default_world, Some(data))
+        let default_world = self
+            .worlds
+            .load()
+            .first()
+            .expect("Default world should exist")
+            .clone();
+
+        // Players rejoin the world they logged out in, which is stored as its resource location
+        let (world, nbt, lost_saved_world) = match self
+            .player_data_storage
+            .load_data(&profile.id)
+            .await
+        {
+            Ok(Some(data)) => {
+                let saved_world = data.get_string("Dimension").map(|dimension_key| {
+                    let world = self.get_world_by_name(dimension_key);
+                    if world.is_none() {
+                        warn!(
+                            "Player {} was saved in {dimension_key}, which is not loaded; moving them to the default world",
+                            profile.name
+                        );
                     }
-                } else {
-                    // Player data exists but doesn't have a "Dimension" key.
-                    let default_world = self
-                        .worlds
-                        .load()
-                        .first()
-                        .expect("Default world should exist")
-                        .clone();
-                    (default_world, Some(data))
-                }
-            } else {
-                // No player data found or an error occurred, default to the Overworld.
-                let default_world = self
-                    .worlds
-                    .load()
-                    .first()
-                    .expect("Default world should exist")
-                    .clone();
-                (default_world, None)
-            };
+                    world
+                });
+                let lost_saved_world = matches!(saved_world, Some(None));
+                (
+                    saved_world.flatten().unwrap_or(default_world),
+                    Some(data),
+                    lost_saved_world,
+                )
+            }
+            // No player data found or an error occurred, default to the Overworld.
+            _ => (default_world, None, false),
+        };
 
         let mut player = Player::new(
             client,
@@ -508,6 +571,17 @@ impl Server {
 
         if let Some(mut nbt_data) = nbt {
             player.read_nbt(&mut nbt_data).await;
+            if lost_saved_world {
+                // The saved position belongs to another dimension, so spawn at the world spawn
+                player.has_played_before.store(false, Ordering::Relaxed);
+            }
+            // Like vanilla's `force-gamemode`, returning players are reset to the default
+            if self.basic_config.force_gamemode && player.gamemode.load() != gamemode {
+                player
+                    .previous_gamemode
+                    .store(Some(player.gamemode.swap(gamemode)));
+                player.abilities.lock().await.set_for_gamemode(gamemode);
+            }
         }
 
         // Wrap in Arc after data is loaded
@@ -563,26 +637,264 @@ impl Server {
         self.listing.lock().await.remove_player(player);
     }
 
-    pub async fn shutdown(&self) {
-        self.tasks.close();
-        debug!("Awaiting tasks for server");
-        self.tasks.wait().await;
-        debug!("Done awaiting tasks for server");
+    /// Adds a player to the whitelist, or updates their stored name if they already are on it.
+    ///
+    /// Returns `false` if the player was already whitelisted.
+    pub async fn whitelist_add(&self, uuid: Uuid, name: String) -> bool {
+        let mut whitelist = self.data.whitelist_config.write().await;
+        let added = if let Some(entry) = whitelist
+            .whitelist
+            .iter_mut()
+            .find(|entry| entry.uuid == uuid)
+        {
+            entry.name = name;
+            false
+        } else {
+            whitelist.whitelist.push(WhitelistEntry::new(uuid, name));
+            true
+        };
+        whitelist.save();
+        added
+    }
 
-        info!("Starting worlds");
-        for world in self.worlds.load().iter() {
-            world.shutdown().await;
+    /// Adds a player to the whitelist by name, resolving their UUID through Mojang in online
+    /// mode or deriving the offline UUID otherwise.
+    ///
+    /// Returns `false` if the player was already whitelisted.
+    pub async fn whitelist_add_by_name(&self, name: &str) -> Result<bool, AuthError> {
+        let profile = self
+            .profile_resolver
+            .resolve_by_name(self, name)
+            .await
+            .ok_or(AuthError::UnverifiedUsername)?;
+        Ok(self.whitelist_add(profile.id, profile.name).await)
+    }
+
+    /// Removes a player from the whitelist, kicking them if the whitelist is enforced.
+    ///
+    /// Returns `false` if the player wasn't whitelisted.
+    pub async fn whitelist_remove(&self, uuid: &Uuid) -> bool {
+        let mut whitelist = self.data.whitelist_config.write().await;
+        let Some(index) = whitelist
+            .whitelist
+            .iter()
+            .position(|entry| entry.uuid == *uuid)
+        else {
+            return false;
+        };
+        whitelist.whitelist.remove(index);
+        whitelist.save();
+        drop(whitelist);
+
+        self.kick_non_whitelisted_players().await;
+        true
+    }
+
+    /// Reloads the whitelist from disk, kicking players who are no longer on it if the
+    /// whitelist is enforced.
+    pub async fn whitelist_reload(&self) {
+        *self.data.whitelist_config.write().await = WhitelistConfig::load();
+        self.kick_non_whitelisted_players().await;
+    }
+
+    /// The message non-whitelisted players are disconnected with.
+    #[must_use]
+    pub fn whitelist_message(&self) -> TextComponent {
+        if self.basic_config.whitelist_message.is_empty() {
+            TextComponent::translate_cross(
+                translation::java::MULTIPLAYER_DISCONNECT_NOT_WHITELISTED,
+                translation::java::MULTIPLAYER_DISCONNECT_NOT_WHITELISTED,
+                [],
+            )
+        } else {
+            TextComponent::text(self.basic_config.whitelist_message.clone())
         }
-        let level_data = self.level_info.load();
-        // then lets save the world info
+    }
 
-        if let Err(err) = self
-            .world_info_writer
-            .write_world_info(&level_data, &self.basic_config.get_world_path())
-        {
-            error!("Failed to save level.dat: {err}");
+    /// Kicks every online player who isn't whitelisted, if the whitelist is on and enforced.
+    pub async fn kick_non_whitelisted_players(&self) {
+        if !self.basic_config.enforce_whitelist || !self.white_list.load(Ordering::Relaxed) {
+            return;
+        }
+        let ops = self.data.operator_config.read().await;
+        let whitelist = self.data.whitelist_config.read().await;
+        for player in self.get_all_players() {
+            if whitelist.is_whitelisted(&player.gameprofile)
+                || ops.get_entry(&player.gameprofile.id).is_some()
+            {
+                continue;
+            }
+            player
+                .kick(DisconnectReason::Kicked, self.whitelist_message())
+                .await;
+        }
+    }
+
+    /// Bans a player and kicks them if they are online. An `expires` of `None` bans them forever.
+    ///
+    /// Returns `false` if the player was already banned.
+    pub async fn ban_player(
+        &self,
+        profile: &GameProfile,
+        source: String,
+        reason: String,
+        expires: Option<OffsetDateTime>,
+    ) -> bool {
+        let mut banned_players = self.data.banned_player_list.write().await;
+        if banned_players.get_entry(profile).is_some() {
+            if let Some(entry) = banned_players
+                .banned_players
+                .iter_mut()
+                .find(|entry| entry.uuid == profile.id && entry.name != profile.name)
+            {
+                entry.name.clone_from(&profile.name);
+                banned_players.save();
+            }
+            return false;
+        }
+
+        banned_players
+            .banned_players
+            .push(BannedPlayerEntry::new(profile, source, expires, reason));
+        banned_players.save();
+        drop(banned_players);
+
+        if let Some(player) = self.get_player_by_uuid(profile.id) {
+            player
+                .kick(
+                    DisconnectReason::Kicked,
+                    TextComponent::translate_cross(
+                        translation::java::MULTIPLAYER_DISCONNECT_BANNED,
+                        translation::bedrock::DISCONNECTIONSCREEN_TITLE_BANNEDBYHOST,
+                        [],
+                    ),
+                )
+                .await;
+        }
+        true
+    }
+
+    /// Bans an IP address and kicks every player connected from it. An `expires` of `None` bans
+    /// it forever.
+    ///
+    /// Returns `false` if the address was already banned.
+    pub async fn ban_ip(
+        &self,
+        ip: IpAddr,
+        source: String,
+        reason: String,
+        expires: Option<OffsetDateTime>,
+    ) -> bool {
+        let mut banned_ips = self.data.banned_ip_list.write().await;
+        if banned_ips.get_entry(&ip).is_some() {
+            return false;
+        }
+
+        banned_ips
+            .banned_ips
+            .push(BannedIpEntry::new(ip, source, expires, reason));
+        banned_ips.save();
+        drop(banned_ips);
+
+        for player in self.get_players_by_ip(ip).await {
+            player
+                .kick(
+                    DisconnectReason::Kicked,
+                    TextComponent::translate_cross(
+                        translation::java::MULTIPLAYER_DISCONNECT_IP_BANNED,
+                        translation::java::MULTIPLAYER_DISCONNECT_IP_BANNED,
+                        [],
+                    ),
+                )
+                .await;
+        }
+        true
+    }
+
+    /// Lifts a player's ban. Returns `false` if the player wasn't banned.
+    pub async fn pardon(&self, uuid: &Uuid) -> bool {
+        let mut banned_players = self.data.banned_player_list.write().await;
+        let Some(index) = banned_players
+            .banned_players
+            .iter()
+            .position(|entry| entry.uuid == *uuid)
+        else {
+            return false;
+        };
+        banned_players.banned_players.remove(index);
+        banned_players.save();
+        true
+    }
+
+    /// Lifts an IP ban. Returns `false` if the address wasn't banned.
+    pub async fn pardon_ip(&self, ip: &IpAddr) -> bool {
+        let mut banned_ips = self.data.banned_ip_list.write().await;
+        let Some(index) = banned_ips
+            .banned_ips
+            .iter()
+            .position(|entry| entry.ip == *ip)
+        else {
+            return false;
+        };
+        banned_ips.banned_ips.remove(index);
+        banned_ips.save();
+        true
+    }
+
+    /// Gracefully shuts the server down.
+    ///
+    /// Stops accepting new connections, kicks every online player with `message` after saving
+    /// their data, then saves all worlds with their dirty chunks and `level.dat`. Once this
+    /// returns, the process can exit without losing data. Calling it again, or concurrently,
+    /// waits for the first shutdown to finish instead of saving twice.
+    ///
+    /// This waits for all tasks spawned with [`Self::spawn_task`], so it must not be awaited
+    /// from within one of them; use [`crate::stop_server`] there instead.
+    pub async fn shutdown(&self, message: TextComponent) {
+        crate::stop_server();
+        self.disconnect_all_players(message).await;
+
+        self.shutdown_complete
+            .get_or_init(|| async {
+                self.tasks.close();
+                debug!("Awaiting tasks for server");
+                self.tasks.wait().await;
+                debug!("Done awaiting tasks for server");
+
+                info!("Starting worlds");
+                for world in self.worlds.load().iter() {
+                    world.shutdown().await;
+                }
+                let level_data = self.level_info.load();
+                // then lets save the world info
+
+                if let Err(err) = self
+                    .world_info_writer
+                    .write_world_info(&level_data, &self.basic_config.get_world_path())
+                {
+                    error!("Failed to save level.dat: {err}");
+                }
+                info!("Completed worlds");
+            })
+            .await;
+    }
+
+    /// Saves the data of every online player, then kicks them all with `message`.
+    pub async fn disconnect_all_players(&self, message: TextComponent) {
+        let players = self.get_all_players();
+        if players.is_empty() {
+            return;
+        }
+
+        if let Err(e) = self.player_data_storage.save_all_players(self).await {
+            error!("Error saving all players during shutdown: {e}");
+        }
+
+        for player in players {
+            player
+                .kick(DisconnectReason::Shutdown, message.clone())
+                .await;
         }
-        info!("Completed worlds");
     }
 
     /// Broadcasts a packet to all players in all worlds.
@@ -598,18 +910,15 @@ impl Server {
         }
     }
 
+    /// Sets the player list header and footer for every player on the server.
+    /// See [`Player::set_tab_list_header_footer`].
     pub async fn broadcast_tab_list_header_footer(
         &self,
-        header: &TextComponent,
-        footer: &TextComponent,
+        header: Option<&TextComponent>,
+        footer: Option<&TextComponent>,
     ) {
-        let packet = CTabList::new(header, footer);
         for world in self.worlds.load().iter() {
-            for player in world.players.load().iter() {
-                *player.tab_list_header.lock().await = header.clone();
-                *player.tab_list_footer.lock().await = footer.clone();
-                player.client.enqueue_packet(&packet).await;
-            }
+            world.broadcast_tab_list_header_footer(header, footer).await;
         }
     }
 
@@ -634,6 +943,13 @@ impl Server {
         }}
     }
 
+    /// Sends a system chat message (or action bar text if `overlay` is set) to every player in every world.
+    pub async fn broadcast_system_message(&self, message: &TextComponent, overlay: bool) {
+        for world in self.worlds.load().iter() {
+            world.broadcast_system_message(message, overlay).await;
+        }
+    }
+
     /// Gets the current difficulty of the server.
     pub fn get_difficulty(&self) -> Difficulty {
         self.level_info.load().difficulty
@@ -766,6 +1082,38 @@ impl Server {
         None
     }
 
+    /// Reserves a player slot for a joining player, returning `false` if the server is full.
+    ///
+    /// Logins which are still in progress count towards `max_players`, so simultaneous joins
+    /// can't overfill the server. Operators with `bypasses_player_limit` always get a slot.
+    /// Every reserved slot must be given back with [`Server::release_player_slot`] once the
+    /// player was added to a world (or failed to be).
+    pub async fn try_reserve_player_slot(&self, profile: &GameProfile) -> bool {
+        let pending = self.pending_logins.fetch_add(1, Ordering::SeqCst);
+        // If `max_players` is set to zero, then there is no max player count enforced.
+        let max_players = self.basic_config.max_players as usize;
+        if max_players == 0 || !self.has_n_players(max_players.saturating_sub(pending)) {
+            return true;
+        }
+
+        let bypasses_limit = self
+            .data
+            .operator_config
+            .read()
+            .await
+            .get_entry(&profile.id)
+            .is_some_and(|op| op.bypasses_player_limit);
+        if !bypasses_limit {
+            self.pending_logins.fetch_sub(1, Ordering::SeqCst);
+        }
+        bypasses_limit
+    }
+
+    /// Gives back a slot reserved with [`Server::try_reserve_player_slot`].
+    pub fn release_player_slot(&self) {
+        self.pending_logins.fetch_sub(1, Ordering::SeqCst);
+    }
+
     /// Counts the total number of players across all worlds.
     ///
     /// This function iterates through each world and sums up the number of players currently connected to that world.
@@ -851,6 +1199,120 @@ impl Server {
         } else {
             self.tick_players_and_network().await;
         }
+
+        let tick_count = self.tick_count.load(Ordering::Relaxed);
+        if tick_count % LATENCY_UPDATE_INTERVAL == 0 {
+            self.broadcast_player_latency();
+        }
+
+        let autosave_ticks = self.advanced_config.world.autosave_ticks;
+        if autosave_ticks > 0 && tick_count > 0 && tick_count as u64 % autosave_ticks == 0 {
+            self.autosave();
+        }
+    }
+
+    /// Saves all online players and `level.dat` on a separate task so the tick loop is not blocked.
+    /// Dirty chunks are written by each world's chunk system on the same interval.
+    fn autosave(self: &Arc<Self>) {
+        let server = self.clone();
+        self.spawn_task(async move {
+            let players = match server.player_data_storage.save_all_players(&server).await {
+                Ok(players) => players,
+                Err(e) => {
+                    error!("Failed to autosave player data: {e}");
+                    0
+                }
+            };
+
+            server.save_level_info().await;
+
+            info!("Autosaved {players} players and level.dat");
+        });
+    }
+
+    /// Writes the current level data to `level.dat` on a blocking thread.
+    pub async fn save_level_info(&self) {
+        let level_data = self.level_info.load_full();
+        let writer = self.world_info_writer.clone();
+        let world_path = self.basic_config.get_world_path();
+        match tokio::task::spawn_blocking(move || writer.write_world_info(&level_data, &world_path))
+            .await
+        {
+            Ok(Ok(())) => {}
+            Ok(Err(err)) => error!("Failed to save level.dat: {err}"),
+            Err(err) => error!("Saving level.dat panicked: {err}"),
+        }
+    }
+
+    /// Refreshes the ping column of the player list with every player's measured latency.
+    fn broadcast_player_latency(&self) {
+        let players = self.get_all_players();
+        if players.is_empty() {
+            return;
+        }
+
+        let actions: Vec<_> = players
+            .iter()
+            .map(|player| {
+                let latency = player.ping() as i32;
+                player.tab_list_latency.store(latency, Ordering::Relaxed);
+                [PlayerAction::UpdateLatency(VarInt(latency))]
+            })
+            .collect();
+        let entries: Vec<_> = players
+            .iter()
+            .zip(&actions)
+            .map(
+                |(player, actions)| pumpkin_protocol::java::client::play::Player {
+                    uuid: player.gameprofile.id,
+                    actions,
+                },
+            )
+            .collect();
+
+        self.broadcast_packet_all(&CPlayerInfoUpdate::new(
+            PlayerInfoFlags::UPDATE_LATENCY.bits(),
+            &entries,
+        ));
+    }
+
+    /// Runs `task` once, `ticks` game ticks from now.
+    ///
+    /// The returned future is awaited on the tick loop before worlds are ticked, so the task
+    /// sees a consistent world state; long-running work should be spawned onto its own Tokio
+    /// task instead. Scheduled tasks are paused while the game is frozen with `/tick freeze`.
+    pub async fn schedule_delayed<F, Fut>(&self, ticks: u64, task: F) -> TaskHandle
+    where
+        F: FnOnce(Arc<Self>) -> Fut + Send + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        let current_tick = self.tick_count.load(Ordering::Relaxed) as u64;
+        self.task_scheduler
+            .schedule_native_delayed(
+                Box::new(move |server| Box::pin(task(server))),
+                ticks,
+                current_tick,
+            )
+            .await
+    }
+
+    /// Runs `task` every `period_ticks` game ticks, starting `period_ticks` ticks from now,
+    /// until the returned handle is cancelled.
+    ///
+    /// Runs on the tick loop like [`Server::schedule_delayed`].
+    pub async fn schedule_repeating<F, Fut>(&self, period_ticks: u64, mut task: F) -> TaskHandle
+    where
+        F: FnMut(Arc<Self>) -> Fut + Send + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        let current_tick = self.tick_count.load(Ordering::Relaxed) as u64;
+        self.task_scheduler
+            .schedule_native_repeating(
+                Box::new(move |server| Box::pin(task(server))),
+                period_ticks,
+                current_tick,
+            )
+            .await
     }
 
     /// Ticks essential server functions that must run even when the game is frozen.
@@ -1145,3 +1607,22 @@ impl Server {
         }
     }
 }
+
+/// Starts an offline server whose worlds are stored in `dir`.
+#[cfg(test)]
+pub(crate) async fn test_server(dir: &std::path::Path) -> Arc<Server> {
+    let basic_config = BasicConfiguration {
+        default_level_name: dir.join("world").to_string_lossy().into_owned(),
+        online_mode: false,
+        allow_chat_reports: false,
+        ..Default::default()
+    };
+    let vanilla_data = VanillaData {
+        banned_ip_list: RwLock::default(),
+        banned_player_list: RwLock::default(),
+        operator_config: RwLock::default(),
+        user_cache: RwLock::default(),
+        whitelist_config: RwLock::default(),
+    };
+    Server::new(basic_config, AdvancedConfiguration::default(), vanilla_data).await
+}
@@ -2,20 +2,60 @@ use crate::plugin::loader::wasm::wasm_host::WasmPlugin;
 use crate::server::Server;
 use std::cmp::Ordering;
 use std::collections::{BinaryHeap, HashSet};
-use std::sync::Arc;
+use std::pin::Pin;
 use std::sync::atomic::Ordering as AtomicOrdering;
+use std::sync::{Arc, Weak};
 use tokio::sync::Mutex;
 
 pub type TaskId = u32;
 
+pub type TaskFuture = Pin<Box<dyn Future<Output = ()> + Send>>;
+
+/// What a scheduled task runs when it comes due.
+pub enum TaskHandler {
+    /// A handler exported by a WASM plugin, run on its own Tokio task.
+    Wasm {
+        plugin: Arc<WasmPlugin>,
+        handler_id: u32,
+    },
+    /// A native one-shot task, awaited on the tick loop.
+    Once(Box<dyn FnOnce(Arc<Server>) -> TaskFuture + Send>),
+    /// A native repeating task, awaited on the tick loop every period.
+    Repeating(Box<dyn FnMut(Arc<Server>) -> TaskFuture + Send>),
+}
+
 pub struct ScheduledTask {
     pub id: TaskId,
-    pub plugin: Arc<WasmPlugin>,
-    pub handler_id: u32,
+    pub handler: TaskHandler,
     pub next_tick: u64,
     pub period: Option<u64>,
 }
 
+/// A handle to a task scheduled with [`Server::schedule_delayed`] or
+/// [`Server::schedule_repeating`].
+///
+/// Dropping the handle does not cancel the task.
+#[derive(Clone)]
+pub struct TaskHandle {
+    id: TaskId,
+    scheduler: Weak<TaskScheduler>,
+}
+
+impl TaskHandle {
+    #[must_use]
+    pub const fn id(&self) -> TaskId {
+        self.id
+    }
+
+    /// Cancels the task. A repeating task will not run again, even if it is cancelled from
+    /// inside its own body.
+    pub async fn cancel(&self) {
+        if let Some(scheduler) = self.scheduler.upgrade() {
+            scheduler.cancel_task(self.id).await;
+        }
+    }
+}
+
 impl PartialEq for ScheduledTask {
     fn eq(&self, other: &Self) -> bool {
         self.next_tick == other.next_tick
@@ -39,6 +79,9 @@ impl Ord for ScheduledTask {
 
 pub struct TaskScheduler {
     tasks: Mutex<BinaryHeap<ScheduledTask>>,
+    /// Tasks taken off the heap this tick that have not finished yet.
+    running_tasks: Mutex<HashSet<TaskId>>,
+    /// Running tasks that were cancelled, so they neither start nor repeat.
     cancelled_tasks: Mutex<HashSet<TaskId>>,
     next_task_id: std::sync::atomic::AtomicU32,
 }
@@ -54,11 +97,24 @@ impl TaskScheduler {
     pub fn new() -> Self {
         Self {
             tasks: Mutex::new(BinaryHeap::new()),
+            running_tasks: Mutex::new(HashSet::new()),
             cancelled_tasks: Mutex::new(HashSet::new()),
             next_task_id: std::sync::atomic::AtomicU32::new(0),
         }
     }
 
+    async fn schedule(&self, handler: TaskHandler, next_tick: u64, period: Option<u64>) -> TaskId {
+        let id = self.next_task_id.fetch_add(1, AtomicOrdering::SeqCst);
+        let task = ScheduledTask {
+            id,
+            handler,
+            next_tick,
+            period,
+        };
+        self.tasks.lock().await.push(task);
+        id
+    }
+
     pub async fn schedule_delayed_task(
         &self,
         plugin: Arc<WasmPlugin>,
@@ -66,16 +122,8 @@ impl TaskScheduler {
         delay: u64,
         current_tick: u64,
     ) -> TaskId {
-        let id = self.next_task_id.fetch_add(1, AtomicOrdering::SeqCst);
-        let task = ScheduledTask {
-            id,
-            plugin,
-            handler_id,
-            next_tick: current_tick + delay,
-            period: None,
-        };
-        self.tasks.lock().await.push(task);
-        id
+        let handler = TaskHandler::Wasm { plugin, handler_id };
+        self.schedule(handler, current_tick + delay, None).await
     }
 
     pub async fn schedule_repeating_task(
@@ -86,30 +134,70 @@ impl TaskScheduler {
         period: u64,
         current_tick: u64,
     ) -> TaskId {
-        let id = self.next_task_id.fetch_add(1, AtomicOrdering::SeqCst);
-        let task = ScheduledTask {
+        let handler = TaskHandler::Wasm { plugin, handler_id };
+        self.schedule(handler, current_tick + delay, Some(period))
+            .await
+    }
+
+    /// Schedules a native task that runs once, `delay` ticks after `current_tick`.
+    pub async fn schedule_native_delayed(
+        self: &Arc<Self>,
+        task: Box<dyn FnOnce(Arc<Server>) -> TaskFuture + Send>,
+        delay: u64,
+        current_tick: u64,
+    ) -> TaskHandle {
+        let id = self
+            .schedule(TaskHandler::Once(task), current_tick + delay, None)
+            .await;
+        TaskHandle {
             id,
-            plugin,
-            handler_id,
-            next_tick: current_tick + delay,
-            period: Some(period),
-        };
-        self.tasks.lock().await.push(task);
-        id
+            scheduler: Arc::downgrade(self),
+        }
+    }
+
+    /// Schedules a native task that runs every `period` ticks, starting `period` ticks after
+    /// `current_tick`. A period of zero is treated as one.
+    pub async fn schedule_native_repeating(
+        self: &Arc<Self>,
+        task: Box<dyn FnMut(Arc<Server>) -> TaskFuture + Send>,
+        period: u64,
+        current_tick: u64,
+    ) -> TaskHandle {
+        let period = period.max(1);
+        let id = self
+            .schedule(
+                TaskHandler::Repeating(task),
+                current_tick + period,
+                Some(period),
+            )
+            .await;
+        TaskHandle {
+            id,
+            scheduler: Arc::downgrade(self),
+        }
     }
 
+    /// Cancels a task. Tasks that already finished are left alone.
     pub async fn cancel_task(&self, id: TaskId) {
-        self.cancelled_tasks.lock().await.insert(id);
+        let mut tasks = self.tasks.lock().await;
+        let scheduled = tasks.len();
+        tasks.retain(|task| task.id != id);
+        if tasks.len() == scheduled && self.running_tasks.lock().await.contains(&id) {
+            self.cancelled_tasks.lock().await.insert(id);
+        }
     }
 
     pub async fn cancel_all_tasks(&self, plugin: &Arc<WasmPlugin>) {
-        let tasks = self.tasks.lock().await;
-        let mut cancelled = self.cancelled_tasks.lock().await;
-        for task in tasks.iter() {
-            if Arc::ptr_eq(&task.plugin, plugin) {
-                cancelled.insert(task.id);
-            }
-        }
+        self.tasks.lock().await.retain(|task| match &task.handler {
+            TaskHandler::Wasm { plugin: owner, .. } => !Arc::ptr_eq(owner, plugin),
+            TaskHandler::Once(_) | TaskHandler::Repeating(_) => true,
+        });
+    }
+
+    /// Marks a running task as finished, returning whether it was cancelled while it ran.
+    async fn finish(&self, id: TaskId) -> bool {
+        self.running_tasks.lock().await.remove(&id);
+        self.cancelled_tasks.lock().await.remove(&id)
     }
 
     pub async fn tick(&self, server: &Arc<Server>) {
@@ -118,7 +206,7 @@ impl TaskScheduler {
 
         {
             let mut tasks = self.tasks.lock().await;
-            let mut cancelled = self.cancelled_tasks.lock().await;
+            let mut running = self.running_tasks.lock().await;
 
             while let Some(task) = tasks.peek() {
                 if task.next_tick > current_tick {
@@ -126,38 +214,182 @@ impl TaskScheduler {
                 }
 
                 let task = tasks.pop().unwrap();
-                if cancelled.remove(&task.id) {
-                    continue;
-                }
-
+                running.insert(task.id);
                 tasks_to_run.push(task);
             }
         }
 
-        for mut task in tasks_to_run {
-            // Run the task
-            let plugin = task.plugin.clone();
-            let handler_id = task.handler_id;
-            let server_clone = server.clone();
-
-            tokio::spawn(async move {
-                let mut store = plugin.store.lock().await;
-                match plugin.plugin_instance {
-                    crate::plugin::loader::wasm::wasm_host::PluginInstance::V0_1(ref instance) => {
-                        if let Ok(server_res) = store.data_mut().add_server(server_clone) {
-                            let _ = instance
-                                .call_handle_task(&mut *store, handler_id, server_res)
-                                .await;
+        for task in tasks_to_run {
+            let ScheduledTask {
+                id,
+                handler,
+                period,
+                ..
+            } = task;
+
+            // An earlier task this tick may have cancelled this one
+            if self.cancelled_tasks.lock().await.contains(&id) {
+                self.finish(id).await;
+                continue;
+            }
+
+            let handler = match handler {
+                TaskHandler::Wasm { plugin, handler_id } => {
+                    let plugin_clone = plugin.clone();
+                    let server_clone = server.clone();
+
+                    tokio::spawn(async move {
+                        let plugin = plugin_clone;
+                        let mut store = plugin.store.lock().await;
+                        match plugin.plugin_instance {
+                            crate::plugin::loader::wasm::wasm_host::PluginInstance::V0_1(
+                                ref instance,
+                            ) => {
+                                if let Ok(server_res) = store.data_mut().add_server(server_clone) {
+                                    let _ = instance
+                                        .call_handle_task(&mut *store, handler_id, server_res)
+                                        .await;
+                                }
+                            }
                         }
-                    }
+                    });
+                    TaskHandler::Wasm { plugin, handler_id }
+                }
+                // Native tasks are awaited here so they run in tick order with the rest of
+                // the world logic.
+                TaskHandler::Once(run) => {
+                    run(server.clone()).await;
+                    self.finish(id).await;
+                    continue;
+                }
+                TaskHandler::Repeating(mut run) => {
+                    run(server.clone()).await;
+                    TaskHandler::Repeating(run)
                 }
-            });
+            };
 
             // If repeating, schedule next run
-            if let Some(period) = task.period {
-                task.next_tick = current_tick + period;
-                self.tasks.lock().await.push(task);
+            let mut tasks = self.tasks.lock().await;
+            if !self.finish(id).await
+                && let Some(period) = period
+            {
+                tasks.push(ScheduledTask {
+                    id,
+                    handler,
+                    next_tick: current_tick + period,
+                    period: Some(period),
+                });
             }
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::AtomicU32;
+    use std::sync::{Arc, OnceLock};
+
+    use super::*;
+    use crate::server::test_server;
+
+    /// Advances the server to `tick` and runs the tasks that are due.
+    async fn tick_to(server: &Arc<Server>, tick: i32) {
+        server.tick_count.store(tick, AtomicOrdering::Relaxed);
+        server.task_scheduler.tick(server).await;
+    }
+
+    async fn is_idle(scheduler: &TaskScheduler) -> bool {
+        scheduler.tasks.lock().await.is_empty()
+            && scheduler.running_tasks.lock().await.is_empty()
+            && scheduler.cancelled_tasks.lock().await.is_empty()
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn delayed_tasks_run_once() {
+        let dir = tempfile::tempdir().unwrap();
+        let server = test_server(dir.path()).await;
+        let runs = Arc::new(AtomicU32::new(0));
+
+        let counter = runs.clone();
+        let handle = server
+            .schedule_delayed(2, move |_| async move {
+                counter.fetch_add(1, AtomicOrdering::Relaxed);
+            })
+            .await;
+
+        tick_to(&server, 1).await;
+        assert_eq!(runs.load(AtomicOrdering::Relaxed), 0);
+        tick_to(&server, 2).await;
+        tick_to(&server, 3).await;
+        assert_eq!(runs.load(AtomicOrdering::Relaxed), 1);
+
+        // Cancelling a finished task leaves nothing behind
+        handle.cancel().await;
+        assert!(is_idle(&server.task_scheduler).await);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn repeating_tasks_run_every_period() {
+        let dir = tempfile::tempdir().unwrap();
+        let server = test_server(dir.path()).await;
+        let runs = Arc::new(AtomicU32::new(0));
+
+        let counter = runs.clone();
+        let handle = server
+            .schedule_repeating(2, move |_| {
+                let counter = counter.clone();
+                async move {
+                    counter.fetch_add(1, AtomicOrdering::Relaxed);
+                }
+            })
+            .await;
+
+        for tick in 1..=6 {
+            tick_to(&server, tick).await;
+        }
+        assert_eq!(runs.load(AtomicOrdering::Relaxed), 3);
+
+        handle.cancel().await;
+        tick_to(&server, 8).await;
+        assert_eq!(runs.load(AtomicOrdering::Relaxed), 3);
+        assert!(is_idle(&server.task_scheduler).await);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn cancelled_tasks_stop_running() {
+        let dir = tempfile::tempdir().unwrap();
+        let server = test_server(dir.path()).await;
+        let runs = Arc::new(AtomicU32::new(0));
+
+        let counter = runs.clone();
+        let pending = server
+            .schedule_delayed(1, move |_| async move {
+                counter.fetch_add(1, AtomicOrdering::Relaxed);
+            })
+            .await;
+        pending.cancel().await;
+
+        // A repeating task that cancels itself on its second run
+        let own_handle = Arc::new(OnceLock::<TaskHandle>::new());
+        let counter = runs.clone();
+        let handle_slot = own_handle.clone();
+        let handle = server
+            .schedule_repeating(1, move |_| {
+                let counter = counter.clone();
+                let handle_slot = handle_slot.clone();
+                async move {
+                    if counter.fetch_add(1, AtomicOrdering::Relaxed) == 1 {
+                        handle_slot.get().unwrap().cancel().await;
+                    }
+                }
+            })
+            .await;
+        assert!(own_handle.set(handle).is_ok());
+
+        for tick in 1..=5 {
+            tick_to(&server, tick).await;
+        }
+        assert_eq!(runs.load(AtomicOrdering::Relaxed), 2);
+        assert!(is_idle(&server.task_scheduler).await);
+    }
+}
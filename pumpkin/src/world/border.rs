@@ -1,3 +1,5 @@
+use std::time::{Duration, Instant};
+
 use pumpkin_protocol::java::client::play::{
     CInitializeWorldBorder, CSetBorderCenter, CSetBorderLerpSize, CSetBorderSize,
     CSetBorderWarningDelay, CSetBorderWarningDistance,
@@ -10,9 +12,15 @@ use super::World;
 pub struct Worldborder {
     pub center_x: f64,
     pub center_z: f64,
+    /// The diameter the current resize started from.
     pub old_diameter: f64,
+    /// The diameter the border is resizing towards, or its size when it is not resizing.
     pub new_diameter: f64,
+    /// The diameter at the last tick, interpolated between `old_diameter` and `new_diameter`.
+    pub diameter: f64,
+    /// The duration of the current resize in milliseconds.
     pub speed: i64,
+    lerp_start: Option<Instant>,
     pub portal_teleport_boundary: i32,
     pub warning_blocks: i32,
     pub warning_time: i32,
@@ -35,7 +43,9 @@ impl Worldborder {
             center_z: z,
             old_diameter: diameter,
             new_diameter: diameter,
+            diameter,
             speed,
+            lerp_start: None,
             portal_teleport_boundary: 29_999_984,
             warning_blocks,
             warning_time,
@@ -49,9 +59,9 @@ impl Worldborder {
             .enqueue_packet(&CInitializeWorldBorder::new(
                 self.center_x,
                 self.center_z,
-                self.old_diameter,
+                self.diameter,
                 self.new_diameter,
-                self.speed.into(),
+                self.remaining_time().into(),
                 self.portal_teleport_boundary.into(),
                 self.warning_blocks.into(),
                 self.warning_time.into(),
@@ -67,25 +77,68 @@ impl Worldborder {
     }
 
     pub fn set_diameter(&mut self, world: &World, diameter: f64, speed: Option<i64>) {
-        self.old_diameter = self.new_diameter;
-        self.new_diameter = diameter;
-
         match speed {
-            Some(speed) => {
-                world.broadcast_packet_all(&CSetBorderLerpSize::new(
-                    self.old_diameter,
-                    self.new_diameter,
-                    speed.into(),
-                ));
-            }
-            None => {
-                world.broadcast_packet_all(&CSetBorderSize::new(self.new_diameter));
+            Some(speed) if speed > 0 => self.set_size(world, diameter, speed),
+            _ => {
+                self.old_diameter = diameter;
+                self.new_diameter = diameter;
+                self.diameter = diameter;
+                self.speed = 0;
+                self.lerp_start = None;
+
+                world.broadcast_packet_all(&CSetBorderSize::new(diameter));
             }
         }
     }
 
+    /// Smoothly resizes the border from its current size to `target` over `duration_ms`
+    /// milliseconds. Clients animate the resize themselves; the server interpolates it in [`Self::tick`].
+    pub fn set_size(&mut self, world: &World, target: f64, duration_ms: i64) {
+        self.old_diameter = self.diameter;
+        self.new_diameter = target;
+        self.speed = duration_ms;
+        self.lerp_start = Some(Instant::now());
+
+        world.broadcast_packet_all(&CSetBorderLerpSize::new(
+            self.old_diameter,
+            self.new_diameter,
+            duration_ms.into(),
+        ));
+    }
+
+    /// Updates the interpolated diameter, snapping to the target once the resize is done.
+    pub fn tick(&mut self) {
+        let Some(start) = self.lerp_start else {
+            return;
+        };
+        let duration = Duration::from_millis(self.speed.max(0) as u64);
+        let elapsed = start.elapsed();
+
+        if elapsed >= duration {
+            self.old_diameter = self.new_diameter;
+            self.diameter = self.new_diameter;
+            self.speed = 0;
+            self.lerp_start = None;
+        } else {
+            let progress = elapsed.as_secs_f64() / duration.as_secs_f64();
+            self.diameter =
+                (self.new_diameter - self.old_diameter).mul_add(progress, self.old_diameter);
+        }
+    }
+
+    /// Returns how many milliseconds are left in the current resize.
+    #[must_use]
+    pub fn remaining_time(&self) -> i64 {
+        self.lerp_start.map_or(0, |start| {
+            let elapsed = i64::try_from(start.elapsed().as_millis()).unwrap_or(i64::MAX);
+            (self.speed - elapsed).max(0)
+        })
+    }
+
+    /// Resizes the border by `offset` relative to its current, possibly mid-resize, diameter.
     pub fn add_diameter(&mut self, world: &World, offset: f64, speed: Option<i64>) {
-        self.set_diameter(world, self.new_diameter + offset, speed);
+        self.tick();
+        self.set_diameter(world, self.diameter + offset, speed);
     }
 
     pub fn set_warning_delay(&mut self, world: &World, delay: i32) {
@@ -102,7 +155,7 @@ impl Worldborder {
 
     #[must_use]
     pub fn contains(&self, x: f64, z: f64) -> bool {
-        let half = self.new_diameter / 2.0;
+        let half = self.diameter / 2.0;
         let min_x = self.center_x - half;
         let max_x = self.center_x + half;
         let min_z = self.center_z - half;
@@ -110,6 +163,16 @@ impl Worldborder {
         x >= min_x && x < max_x && z >= min_z && z < max_z
     }
 
+    /// Returns the distance from the given position to the closest edge of the border. This is
+    /// negative when the position is outside of it.
+    #[must_use]
+    pub fn distance_inside(&self, x: f64, z: f64) -> f64 {
+        let half = self.diameter / 2.0;
+        let to_x = half - (x - self.center_x).abs();
+        let to_z = half - (z - self.center_z).abs();
+        to_x.min(to_z)
+    }
+
     #[must_use]
     pub fn contains_block(&self, x: i32, z: i32) -> bool {
         self.contains(f64::from(x), f64::from(z))
@@ -118,7 +181,7 @@ impl Worldborder {
 
     #[must_use]
     pub fn clamp_block(&self, x: i32, z: i32) -> (i32, i32) {
-        let half = self.new_diameter / 2.0;
+        let half = self.diameter / 2.0;
         let min_x = (self.center_x - half).floor() as i32;
         let max_x = (self.center_x + half).floor() as i32 - 1;
         let min_z = (self.center_z - half).floor() as i32;
@@ -1,5 +1,8 @@
 use pumpkin_util::math::vector2::Vector2;
-use std::{num::NonZeroU8, sync::Arc};
+use std::{
+    num::NonZeroU8,
+    sync::{Arc, atomic::Ordering},
+};
 
 use pumpkin_protocol::{
     bedrock::client::network_chunk_publisher_update::CNetworkChunkPublisherUpdate,
@@ -20,6 +23,18 @@ pub fn get_view_distance(player: &Player) -> NonZeroU8 {
     )
 }
 
+/// Returns the radius of chunks ticked around `player`: the player's own simulation distance
+/// when set, otherwise the server's, never exceeding the player's view distance.
+pub fn get_simulation_distance(player: &Player) -> NonZeroU8 {
+    let server = player.world().server.upgrade().unwrap();
+    let requested = NonZeroU8::new(player.simulation_distance.load(Ordering::Relaxed))
+        .unwrap_or(server.basic_config.simulation_distance);
+    requested
+        .min(server.basic_config.simulation_distance)
+        .min(get_view_distance(player))
+        .max(NonZeroU8::new(2).unwrap())
+}
+
 // Checks if the target chunk is within the view distance
 // of the center chunk. Uses Chebyshev distance.
 #[must_use]
@@ -114,6 +129,7 @@ pub async fn update_position(player: &Arc<Player>) {
         .await;
 
     if !chunks_to_clean.is_empty() {
+        world.save_entities_in_chunks(&chunks_to_clean).await;
         world.level.clean_entity_chunks(&chunks_to_clean);
         world.remove_entities_in_chunks(&chunks_to_clean);
     }
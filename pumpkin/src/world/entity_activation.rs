@@ -0,0 +1,98 @@
+use pumpkin_config::EntityActivationConfig;
+use pumpkin_data::entity::{EntityType, MobCategory};
+use pumpkin_util::math::vector3::Vector3;
+
+use super::entity_tracker::is_in_tracking_range;
+
+/// Returns the range in blocks within which a player keeps entities of the given type active,
+/// or `None` if they are always active.
+#[must_use]
+pub fn activation_range(config: &EntityActivationConfig, entity_type: &EntityType) -> Option<f64> {
+    let range = if entity_type == &EntityType::ITEM || entity_type == &EntityType::EXPERIENCE_ORB {
+        config.misc
+    } else if entity_type == &EntityType::PLAYER || entity_type.category == &MobCategory::MISC {
+        return None;
+    } else if entity_type.category == &MobCategory::MONSTER {
+        config.monsters
+    } else {
+        config.animals
+    };
+    Some(f64::from(range))
+}
+
+/// Returns whether an entity at `entity_pos` should run its full tick, because it is within the
+/// activation range of one of the players.
+#[must_use]
+pub fn is_active(
+    config: &EntityActivationConfig,
+    entity_type: &EntityType,
+    entity_pos: Vector3<f64>,
+    mut player_positions: impl Iterator<Item = Vector3<f64>>,
+) -> bool {
+    if !config.enabled {
+        return true;
+    }
+    let Some(range) = activation_range(config, entity_type) else {
+        return true;
+    };
+    player_positions.any(|player_pos| is_in_tracking_range(player_pos, entity_pos, range))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn distant_mobs_are_inactive() {
+        let config = EntityActivationConfig {
+            enabled: true,
+            ..Default::default()
+        };
+        let players = [Vector3::new(0.0, 64.0, 0.0)];
+        let near = Vector3::new(20.0, 0.0, -20.0);
+        let far = Vector3::new(40.0, 64.0, 0.0);
+
+        assert!(is_active(
+            &config,
+            &EntityType::ZOMBIE,
+            near,
+            players.into_iter()
+        ));
+        assert!(!is_active(
+            &config,
+            &EntityType::ZOMBIE,
+            far,
+            players.into_iter()
+        ));
+        assert!(!is_active(
+            &config,
+            &EntityType::ITEM,
+            near,
+            players.into_iter()
+        ));
+        assert!(is_active(
+            &config,
+            &EntityType::ARROW,
+            far,
+            players.into_iter()
+        ));
+        assert!(is_active(
+            &config,
+            &EntityType::PLAYER,
+            far,
+            players.into_iter()
+        ));
+    }
+
+    #[test]
+    fn everything_is_active_when_disabled() {
+        let config = EntityActivationConfig::default();
+        let far = Vector3::new(1000.0, 64.0, 0.0);
+        assert!(is_active(
+            &config,
+            &EntityType::ZOMBIE,
+            far,
+            std::iter::empty()
+        ));
+    }
+}
@@ -0,0 +1,166 @@
+use dashmap::DashMap;
+use pumpkin_config::EntityTrackingConfig;
+use pumpkin_data::entity::{EntityType, MobCategory};
+use pumpkin_util::math::vector3::Vector3;
+use rustc_hash::FxHashSet;
+
+/// Remembers which players' clients currently know about which entities.
+///
+/// Non-player entities are only sent to players within the tracking range of their type, and
+/// only those players receive their movement and metadata updates.
+#[derive(Default)]
+pub struct EntityTracker {
+    /// The entity IDs of the players tracking each entity, keyed by the entity's ID.
+    trackers: DashMap<i32, FxHashSet<i32>>,
+}
+
+impl EntityTracker {
+    /// Returns whether the player tracks the entity, or `None` if the entity isn't tracked yet.
+    pub fn is_tracked_by(&self, entity_id: i32, player_id: i32) -> Option<bool> {
+        self.trackers
+            .get(&entity_id)
+            .map(|players| players.contains(&player_id))
+    }
+
+    /// Brings the players tracking an entity up to date.
+    ///
+    /// `in_range` tells whether a player should track the entity. Returns the players that
+    /// started and stopped tracking it, in that order.
+    pub fn update<'a, T>(
+        &self,
+        entity_id: i32,
+        players: impl IntoIterator<Item = (i32, &'a T)>,
+        mut in_range: impl FnMut(&T) -> bool,
+    ) -> (Vec<&'a T>, Vec<&'a T>)
+    where
+        T: 'a,
+    {
+        let mut trackers = self.trackers.entry(entity_id).or_default();
+        let mut online = FxHashSet::default();
+        let mut added = Vec::new();
+        let mut removed = Vec::new();
+
+        for (player_id, player) in players {
+            online.insert(player_id);
+            if in_range(player) {
+                if trackers.insert(player_id) {
+                    added.push(player);
+                }
+            } else if trackers.remove(&player_id) {
+                removed.push(player);
+            }
+        }
+        trackers.retain(|player_id| online.contains(player_id));
+
+        (added, removed)
+    }
+
+    /// Forgets entities which are no longer in the world.
+    pub fn retain_entities(&self, mut alive: impl FnMut(i32) -> bool) {
+        self.trackers.retain(|entity_id, _| alive(*entity_id));
+    }
+
+    /// Forgets an entity which was removed from the world.
+    pub fn remove_entity(&self, entity_id: i32) {
+        self.trackers.remove(&entity_id);
+    }
+
+    /// Forgets everything the player tracks, e.g. because their client cleared its entities when
+    /// respawning.
+    pub fn remove_player(&self, player_id: i32) {
+        for mut players in self.trackers.iter_mut() {
+            players.remove(&player_id);
+        }
+    }
+}
+
+/// Returns the range in blocks within which entities of the given type are sent to players.
+#[must_use]
+pub fn tracking_range(config: &EntityTrackingConfig, entity_type: &EntityType) -> f64 {
+    const PROJECTILES: [&EntityType; 16] = [
+        &EntityType::ARROW,
+        &EntityType::SPECTRAL_ARROW,
+        &EntityType::TRIDENT,
+        &EntityType::SNOWBALL,
+        &EntityType::EGG,
+        &EntityType::ENDER_PEARL,
+        &EntityType::SPLASH_POTION,
+        &EntityType::LINGERING_POTION,
+        &EntityType::EXPERIENCE_BOTTLE,
+        &EntityType::FIREWORK_ROCKET,
+        &EntityType::SMALL_FIREBALL,
+        &EntityType::FIREBALL,
+        &EntityType::WITHER_SKULL,
+        &EntityType::LLAMA_SPIT,
+        &EntityType::WIND_CHARGE,
+        &EntityType::FISHING_BOBBER,
+    ];
+
+    let range = if entity_type == &EntityType::ITEM || entity_type == &EntityType::EXPERIENCE_ORB {
+        config.misc
+    } else if PROJECTILES.contains(&entity_type) {
+        config.projectiles
+    } else if entity_type.category == &MobCategory::MONSTER {
+        config.monsters
+    } else if entity_type.category == &MobCategory::MISC {
+        config.other
+    } else {
+        config.animals
+    };
+    f64::from(range)
+}
+
+/// Returns whether an entity is within `range` blocks of a player, using vanilla's square
+/// tracking area.
+#[must_use]
+pub fn is_in_tracking_range(
+    player_pos: Vector3<f64>,
+    entity_pos: Vector3<f64>,
+    range: f64,
+) -> bool {
+    (player_pos.x - entity_pos.x).abs() <= range && (player_pos.z - entity_pos.z).abs() <= range
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tracking_range_is_square_and_ignores_height() {
+        let player = Vector3::new(0.0, 64.0, 0.0);
+        assert!(is_in_tracking_range(
+            player,
+            Vector3::new(64.0, 300.0, -64.0),
+            64.0
+        ));
+        assert!(!is_in_tracking_range(
+            player,
+            Vector3::new(64.5, 64.0, 0.0),
+            64.0
+        ));
+    }
+
+    #[test]
+    fn update_reports_players_entering_and_leaving() {
+        let tracker = EntityTracker::default();
+        let in_range = |distance: &u32| *distance < 100;
+
+        let (added, removed) = tracker.update(7, [(1, &10), (2, &500)], in_range);
+        assert_eq!(added, [&10]);
+        assert!(removed.is_empty());
+        assert_eq!(tracker.is_tracked_by(7, 1), Some(true));
+        assert_eq!(tracker.is_tracked_by(7, 2), Some(false));
+        assert_eq!(tracker.is_tracked_by(8, 1), None);
+
+        let (added, removed) = tracker.update(7, [(1, &200)], in_range);
+        assert!(added.is_empty());
+        assert_eq!(removed, [&200]);
+
+        tracker.update(7, [(1, &10)], in_range);
+        tracker.remove_player(1);
+        assert_eq!(tracker.is_tracked_by(7, 1), Some(false));
+
+        tracker.retain_entities(|_| false);
+        assert_eq!(tracker.is_tracked_by(7, 1), None);
+    }
+}
@@ -1,7 +1,11 @@
 use std::sync::Arc;
 
-use pumpkin_data::{Block, BlockState, damage::DamageType, entity::EntityType};
+use pumpkin_data::{
+    Block, BlockDirection, BlockState, attributes::Attributes, damage::DamageType,
+    entity::EntityType,
+};
 use pumpkin_util::math::{boundingbox::BoundingBox, position::BlockPos, vector3::Vector3};
+use rand::RngExt;
 use rustc_hash::FxHashMap;
 
 use crate::{
@@ -12,15 +16,49 @@ use crate::{
 
 use super::{BlockFlags, World};
 
+/// How an explosion affects the blocks it reaches.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ExplosionBlockInteraction {
+    /// Blocks are left intact.
+    Keep,
+    /// Blocks are destroyed and always drop their loot.
+    Destroy,
+    /// Blocks are destroyed, each dropping its loot with a chance of `1 / power`.
+    DestroyWithDecay,
+}
+
 pub struct Explosion {
     power: f32,
     pos: Vector3<f64>,
+    fire: bool,
+    block_interaction: ExplosionBlockInteraction,
 }
 
 impl Explosion {
     #[must_use]
     pub const fn new(power: f32, pos: Vector3<f64>) -> Self {
-        Self { power, pos }
+        Self {
+            power,
+            pos,
+            fire: false,
+            block_interaction: ExplosionBlockInteraction::DestroyWithDecay,
+        }
+    }
+
+    /// Sets whether the explosion randomly sets fire to the blocks it destroyed.
+    #[must_use]
+    pub const fn with_fire(mut self, fire: bool) -> Self {
+        self.fire = fire;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_block_interaction(
+        mut self,
+        block_interaction: ExplosionBlockInteraction,
+    ) -> Self {
+        self.block_interaction = block_interaction;
+        self
     }
 
     fn get_blocks_to_destroy(
@@ -129,9 +167,15 @@ impl Explosion {
                 * self.power as f64
                 + 1.0) as f32;
 
-            // TODO: damage type
-            entity
-                .damage(entity_base.as_ref(), damage, DamageType::EXPLOSION)
+            entity_base
+                .damage_with_context(
+                    entity_base.as_ref(),
+                    damage,
+                    DamageType::EXPLOSION,
+                    Some(self.pos),
+                    None,
+                    None,
+                )
                 .await;
 
             // Calculate and apply knockback
@@ -141,8 +185,9 @@ impl Explosion {
                 entity.get_eye_pos()
             };
             let direction = (dir_pos - self.pos).normalize();
-            // TODO
-            let knockback_resistance = 0.0;
+            let knockback_resistance = entity_base.get_living_entity().map_or(0.0, |living| {
+                living.get_attribute_value(&Attributes::EXPLOSION_KNOCKBACK_RESISTANCE)
+            });
 
             let knockback_multiplier = (1.0 - distance) * exposure * (1.0 - knockback_resistance);
             let knockback = direction * knockback_multiplier;
@@ -209,9 +254,38 @@ impl Explosion {
         visible_points as f32 / total_points as f32
     }
 
+    /// Sets fire to a third of the given positions, where there is ground to burn on.
+    async fn place_fire(&self, world: &World, positions: impl Iterator<Item = &BlockPos>) {
+        let mut fire_positions = Vec::new();
+        {
+            let mut rng = rand::rng();
+            for pos in positions {
+                if rng.random_range(0..3) == 0 {
+                    fire_positions.push(*pos);
+                }
+            }
+        }
+
+        for pos in fire_positions {
+            if world.get_block_state(&pos).is_air()
+                && world
+                    .get_block_state(&pos.down())
+                    .is_side_solid(BlockDirection::Up)
+            {
+                world
+                    .set_block_state(&pos, Block::FIRE.default_state.id, BlockFlags::NOTIFY_ALL)
+                    .await;
+            }
+        }
+    }
+
     /// Returns the removed block count
     pub async fn explode(&self, world: &Arc<World>) -> u32 {
-        let blocks = self.get_blocks_to_destroy(world);
+        let blocks = if self.block_interaction == ExplosionBlockInteraction::Keep {
+            FxHashMap::default()
+        } else {
+            self.get_blocks_to_destroy(world)
+        };
         self.damage_entities(world).await;
         for (pos, (block, state)) in &blocks {
             world.set_block_state(pos, 0, BlockFlags::NOTIFY_ALL).await;
@@ -222,7 +296,9 @@ impl Explosion {
             if pumpkin_block.is_none_or(|s| s.should_drop_items_on_explosion()) {
                 let params = LootContextParameters {
                     block_state: Some(state),
-                    explosion_radius: Some(self.power),
+                    explosion_radius: (self.block_interaction
+                        == ExplosionBlockInteraction::DestroyWithDecay)
+                        .then_some(self.power),
                     position: Some(pumpkin_util::math::vector3::Vector3::new(
                         pos.0.x as f64,
                         pos.0.y as f64,
@@ -243,7 +319,9 @@ impl Explosion {
                     .await;
             }
         }
-        // TODO: fire
+        if self.fire {
+            self.place_fire(world, blocks.keys()).await;
+        }
         blocks.len() as u32
     }
 }
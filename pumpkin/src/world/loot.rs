@@ -1,7 +1,8 @@
 use pumpkin_data::damage::DamageType;
+use pumpkin_data::enchantment::Enchantment;
 use pumpkin_data::entity::EntityType;
 use pumpkin_data::item_stack::ItemStack;
-use pumpkin_data::tag;
+use pumpkin_data::tag::{self, Taggable};
 use pumpkin_data::{Block, BlockState, item::Item};
 use pumpkin_util::{
     loot_table::{
@@ -24,6 +25,32 @@ pub struct LootContextParameters {
     pub position: Option<pumpkin_util::math::vector3::Vector3<f64>>,
     pub world_time: u64,
     pub damage_type: Option<DamageType>,
+    /// The item used to break the block, if any.
+    pub tool: Option<&'static Item>,
+    pub silk_touch: bool,
+    pub fortune_level: i32,
+}
+
+impl LootContextParameters {
+    /// Fills in the tool context (item, Silk Touch and Fortune) from the stack used to break a block.
+    #[must_use]
+    pub fn with_tool(mut self, tool: &ItemStack) -> Self {
+        if tool.is_empty() {
+            return self;
+        }
+        self.tool = Some(tool.item);
+        self.silk_touch = tool.get_enchantment_level(&Enchantment::SILK_TOUCH) > 0;
+        self.fortune_level = tool.get_enchantment_level(&Enchantment::FORTUNE);
+        self
+    }
+
+    fn enchantment_level(&self, enchantment: &str) -> i32 {
+        match enchantment {
+            "minecraft:fortune" => self.fortune_level,
+            "minecraft:silk_touch" => i32::from(self.silk_touch),
+            _ => 0,
+        }
+    }
 }
 
 pub trait LootTableExt {
@@ -102,10 +129,8 @@ fn apply_bonus(
     stacks: &mut [ItemStack],
     formula: &str,
     parameters: Option<&LootFunctionBonusParameter>,
+    enchantment_level: i32,
 ) {
-    // We currently don't have tool/enchantment data in LootContextParameters.
-    // Assuming enchantment level is 0 for now.
-    let enchantment_level = 0;
     if enchantment_level > 0 {
         for stack in stacks {
             match formula {
@@ -196,11 +221,16 @@ impl LootFunctionExt for LootFunction {
                 }
             }
             LootFunctionTypes::ApplyBonus {
-                enchantment: _,
+                enchantment,
                 formula,
                 parameters,
             } => {
-                apply_bonus(stacks, formula, parameters.as_ref());
+                apply_bonus(
+                    stacks,
+                    formula,
+                    parameters.as_ref(),
+                    params.enchantment_level(enchantment),
+                );
             }
             LootFunctionTypes::CopyComponents {
                 source: _,
@@ -348,6 +378,13 @@ fn compare_entity_type(expected_type: &str, actual: &EntityType) -> bool {
     expected == actual
 }
 
+fn tool_matches(tool: &Item, key: &str) -> bool {
+    if key.starts_with('#') {
+        return tool.is_tagged_with(key).unwrap_or(false);
+    }
+    key.strip_prefix("minecraft:").unwrap_or(key) == tool.registry_key
+}
+
 fn check_block_state_property(state: &BlockState, properties: &[(&str, &str)]) -> bool {
     let block_actual_properties = match Block::properties(Block::from_state_id(state.id), state.id)
     {
@@ -437,26 +474,29 @@ impl LootConditionExt for LootCondition {
             Self::AnyOf(terms) => terms.iter().any(|cond| cond.is_fulfilled(params)),
             Self::AllOf(terms) => terms.iter().all(|cond| cond.is_fulfilled(params)),
             Self::RandomChanceWithEnchantedBonus {
-                enchantment: _,
+                enchantment,
                 chances,
             } => chances.as_ref().is_some_and(|chances| {
-                // Missing tool context in params, assume enchantment level 0
-                let level = 0;
+                let level = params.enchantment_level(enchantment) as usize;
                 let chance = chances.get(level).unwrap_or(chances.last().unwrap_or(&0.0));
                 rand::rng().random::<f32>() < *chance
             }),
             Self::TableBonus {
-                enchantment: _,
+                enchantment,
                 chances,
             } => {
-                // Missing tool context in params, assume enchantment level 0
-                let level = 0;
+                let level = params.enchantment_level(enchantment) as usize;
                 let chance = chances.get(level).unwrap_or(chances.last().unwrap_or(&0.0));
                 rand::rng().random::<f32>() < *chance
             }
+            // Codegen only keeps the item list; every block loot table without one checks for
+            // Silk Touch instead.
+            Self::MatchTool { items: None } => params.silk_touch,
+            Self::MatchTool { items: Some(items) } => params
+                .tool
+                .is_some_and(|tool| items.iter().any(|key| tool_matches(tool, key))),
             Self::EntityScores { .. }
             | Self::WeatherCheck { .. }
-            | Self::MatchTool { .. }
             | Self::Reference { .. }
             | Self::EnchantmentActiveCheck { .. }
             | Self::LocationCheck { .. } => {
@@ -659,3 +699,44 @@ fn shuffle_and_split_items(
         result.swap(i, j);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn drops(block: &Block, tool: &ItemStack) -> Vec<&'static str> {
+        block
+            .loot_table
+            .as_ref()
+            .unwrap()
+            .get_loot(
+                LootContextParameters {
+                    block_state: Some(block.default_state),
+                    ..Default::default()
+                }
+                .with_tool(tool),
+            )
+            .iter()
+            .map(|stack| stack.item.registry_key)
+            .collect()
+    }
+
+    #[test]
+    fn silk_touch_keeps_the_block() {
+        let pickaxe = ItemStack::new(1, &Item::DIAMOND_PICKAXE);
+        assert_eq!(drops(&Block::STONE, &pickaxe), ["cobblestone"]);
+
+        let mut silk_touch = pickaxe.clone();
+        silk_touch.add_enchantment(&Enchantment::SILK_TOUCH, 1);
+        assert_eq!(drops(&Block::STONE, &silk_touch), ["stone"]);
+    }
+
+    #[test]
+    fn shears_match_by_item() {
+        assert!(tool_matches(&Item::SHEARS, "minecraft:shears"));
+        assert!(!tool_matches(&Item::DIAMOND_SWORD, "minecraft:shears"));
+
+        let shears = ItemStack::new(1, &Item::SHEARS);
+        assert_eq!(drops(&Block::SHORT_GRASS, &shears), ["short_grass"]);
+    }
+}
@@ -28,7 +28,10 @@ pub mod time;
 
 use crate::block::RandomTickArgs;
 use crate::world::chunker::is_within_view_distance;
-use crate::world::{chunker::get_view_distance, loot::LootContextParameters};
+use crate::world::{
+    chunker::{get_simulation_distance, get_view_distance},
+    loot::LootContextParameters,
+};
 use crate::{block::BlockEvent, entity::item::ItemEntity};
 use crate::{
     block::{
@@ -37,11 +40,14 @@ use crate::{
         {OnNeighborUpdateArgs, OnScheduledTickArgs},
     },
     command::client_suggestions,
-    entity::{Entity, EntityBase, player::Player, r#type::from_type},
+    entity::{
+        Entity, EntityBase, lightning_bolt::LightningBoltEntity, player::Player, r#type::from_type,
+    },
     error::PumpkinError,
     net::{ClientPlatform, java::JavaClient},
     plugin::{
         block::block_break::BlockBreakEvent,
+        entity::entity_spawn::{EntitySpawnEvent, EntitySpawnReason},
         player::{player_join::PlayerJoinEvent, player_leave::PlayerLeaveEvent},
     },
     server::Server,
@@ -49,12 +55,11 @@ use crate::{
 use arc_swap::ArcSwap;
 use border::Worldborder;
 use bytes::BufMut;
-use explosion::Explosion;
+use explosion::{Explosion, ExplosionBlockInteraction};
 use pumpkin_config::BasicConfiguration;
 use pumpkin_data::block_properties::is_air;
 use pumpkin_data::block_rotation::{Mirror, Rotation};
 use pumpkin_data::chunk_gen_settings::GenerationSettings;
-use pumpkin_data::data_component_impl::EquipmentSlot;
 use pumpkin_data::dimension::Dimension;
 use pumpkin_data::entity::MobCategory;
 use pumpkin_data::fluid::{Falling, FluidProperties, FluidState};
@@ -146,11 +151,16 @@ pub mod bossbar;
 pub mod custom_bossbar;
 pub mod dragon_fight;
 pub mod end_podium;
+pub mod entity_activation;
+pub mod entity_tracker;
 pub mod natural_spawner;
 pub mod scoreboard;
 pub mod weather;
 
+use crate::world::entity_activation;
+use crate::world::entity_tracker::{EntityTracker, is_in_tracking_range, tracking_range};
 use crate::world::natural_spawner::{SpawnState, spawn_for_chunk};
+use pumpkin_config::EntityTrackingConfig;
 use pumpkin_config::lighting::LightingEngineConfig;
 use pumpkin_data::effect::StatusEffect;
 use pumpkin_world::chunk::ChunkHeightmapType::{self, MotionBlocking};
@@ -195,6 +205,8 @@ pub struct World {
     /// A map of active entities within the world, keyed by their unique UUID.
     /// This does not include players.
     pub entities: ArcSwap<Vec<Arc<dyn EntityBase>>>,
+    /// Which players' clients know about which entities.
+    pub entity_tracker: EntityTracker,
     /// The world's scoreboard, used for tracking scores, objectives, and display information.
     pub scoreboard: Mutex<Scoreboard>,
     /// The world's worldborder, defining the playable area and controlling its expansion or contraction.
@@ -280,25 +292,28 @@ impl World {
     ) -> Self {
         // TODO
         let generation_settings = GenerationSettings::from_dimension(&dimension);
+        let min_y = dimension.min_y;
 
         // Load portal POI from disk (PoiStorage::new automatically loads from disk if files exist)
         let portal_poi = portal::PortalPoiStorage::new(&level.level_folder.root_folder);
         let dragon_fight = (dimension.minecraft_name == Dimension::THE_END.minecraft_name)
             .then(|| Mutex::new(dragon_fight::DragonFight::new()));
+        let weather = Weather::from_level_data(&level_info.load());
         Self {
             uuid: Uuid::new_v4(),
             level,
             level_info,
             players: ArcSwap::new(Arc::new(Vec::new())),
             entities: ArcSwap::new(Arc::new(Vec::new())),
+            entity_tracker: EntityTracker::default(),
             scoreboard: Mutex::new(Scoreboard::default()),
             worldborder: Mutex::new(Worldborder::new(0.0, 0.0, 5.999_996_8E7, 0, 5, 300)),
             level_time: Mutex::new(LevelTime::new()),
             dimension,
-            weather: Mutex::new(Weather::new()),
+            weather: Mutex::new(weather),
             block_registry,
             sea_level: generation_settings.sea_level,
-            min_y: i32::from(generation_settings.shape.min_y),
+            min_y,
             synced_block_event_queue: Mutex::new(Vec::new()),
             unsent_block_changes: Mutex::new(HashMap::new()),
             portal_poi: Mutex::new(portal_poi),
@@ -314,9 +329,9 @@ impl World {
         let mut active_chunks = FxHashSet::default();
         for player in self.players.load().iter() {
             let center = player.get_entity().chunk_pos.load();
-            // TODO: gamerule for view distance/ticking distance
-            for dx in -8..=8 {
-                for dy in -8..=8 {
+            let simulation_distance = i32::from(get_simulation_distance(player).get());
+            for dx in -simulation_distance..=simulation_distance {
+                for dy in -simulation_distance..=simulation_distance {
                     active_chunks.insert(center.add_raw(dx, dy));
                 }
             }
@@ -357,9 +372,7 @@ impl World {
     }
 
     pub async fn shutdown(&self) {
-        for entity in self.entities.load().iter() {
-            self.save_entity(entity).await;
-        }
+        self.save_entities().await;
 
         // Save portal POI to disk
         let save_result = self.portal_poi.lock().await.save_all();
@@ -367,10 +380,30 @@ impl World {
             error!("Failed to save portal POI: {e}");
         }
 
+        self.save_weather(&self.weather.lock().await);
+
         self.level.shutdown().await;
     }
 
-    async fn save_entity(&self, entity: &Arc<dyn EntityBase>) {
+    /// Writes the current state of every entity into its entity chunk, so it is saved with it.
+    pub async fn save_entities(&self) {
+        for entity in self.entities.load().iter() {
+            Self::save_entity(&self.level, entity).await;
+        }
+    }
+
+    /// Writes the current state of the entities in the given chunks into their entity chunks,
+    /// before those chunks are unloaded.
+    pub async fn save_entities_in_chunks(&self, chunks: &[Vector2<i32>]) {
+        let chunks_set: FxHashSet<_> = chunks.iter().copied().collect();
+        for entity in self.entities.load().iter() {
+            if chunks_set.contains(&entity.get_entity().chunk_pos.load()) {
+                Self::save_entity(&self.level, entity).await;
+            }
+        }
+    }
+
+    async fn save_entity(level: &Level, entity: &Arc<dyn EntityBase>) {
         // First lets see if the entity was saved on an other chunk, and if the current chunk does not match we remove it
         // Otherwise we just update the nbt data
         let base_entity = entity.get_entity();
@@ -381,7 +414,7 @@ impl World {
         if let Some(old_chunk) = base_entity.first_loaded_chunk_position.load() {
             let old_chunk = old_chunk.to_vec2_i32();
 
-            let chunk = self.level.get_entity_chunk(old_chunk).await;
+            let chunk = level.get_entity_chunk(old_chunk).await;
             chunk.mark_dirty(true);
             let mut data = chunk.data.lock().await;
             if old_chunk == current_chunk_coordinate {
@@ -393,7 +426,7 @@ impl World {
             data.remove(&uuid);
         }
         // We did not continue, so lets save data in a new chunk
-        let chunk = self.level.get_entity_chunk(current_chunk_coordinate).await;
+        let chunk = level.get_entity_chunk(current_chunk_coordinate).await;
         let mut data = chunk.data.lock().await;
         data.insert(uuid, nbt);
         chunk.mark_dirty(true);
@@ -418,23 +451,20 @@ impl World {
 
     /// Sends an entity status update to all players tracking the specified entity.
     pub fn send_entity_status(&self, entity: &Entity, status: EntityStatus) {
-        let chunk_pos = entity.chunk_pos.load();
-        self.broadcast_to_chunk(
-            chunk_pos,
+        self.broadcast_to_entity_viewers(
+            entity,
             &CEntityStatus::new(entity.entity_id, status as i8),
         );
     }
 
     pub fn send_remove_mob_effect(&self, entity: &Entity, effect_type: &'static StatusEffect) {
-        let chunk_pos = entity.chunk_pos.load();
-        self.broadcast_to_chunk(
-            chunk_pos,
+        self.broadcast_to_entity_viewers(
+            entity,
             &CRemoveMobEffect::new(entity.entity_id.into(), VarInt(i32::from(effect_type.id))),
         );
     }
 
     pub fn send_add_mob_effect(&self, entity: &Entity, effect: &pumpkin_data::potion::Effect) {
-        // TODO: only nearby
         let mut flags: i8 = 0;
         if effect.ambient {
             flags |= 0x01;
@@ -446,13 +476,16 @@ impl World {
             flags |= 0x04;
         }
 
-        self.broadcast_packet_all(&CUpdateMobEffect::new(
-            VarInt(entity.entity_id),
-            VarInt(i32::from(effect.effect_type.id)),
-            VarInt(i32::from(effect.amplifier)),
-            VarInt(effect.duration),
-            flags,
-        ));
+        self.broadcast_to_entity_viewers(
+            entity,
+            &CUpdateMobEffect::new(
+                VarInt(entity.entity_id),
+                VarInt(i32::from(effect.effect_type.id)),
+                VarInt(i32::from(effect.amplifier)),
+                VarInt(effect.duration),
+                flags,
+            ),
+        );
     }
 
     pub fn set_difficulty(&self, difficulty: Difficulty) {
@@ -462,6 +495,34 @@ impl World {
         self.level_info.store(Arc::new(new_info));
     }
 
+    /// Moves the world spawn into this world, persists it to `level.dat` and tells every player
+    /// on the server about it.
+    ///
+    /// Players without a respawn point also respawn here.
+    pub async fn set_spawn_point(&self, pos: BlockPos, yaw: f32, pitch: f32) {
+        self.level_info.rcu(|level_info| {
+            let mut new_info = (**level_info).clone();
+            new_info.spawn_x = pos.0.x;
+            new_info.spawn_y = pos.0.y;
+            new_info.spawn_z = pos.0.z;
+            new_info.spawn_yaw = yaw;
+            new_info.spawn_pitch = pitch;
+            new_info.spawn_dimension = self.dimension.minecraft_name.to_owned();
+            new_info
+        });
+
+        let Some(server) = self.server.upgrade() else {
+            return;
+        };
+        server.save_level_info().await;
+
+        let packet =
+            CPlayerSpawnPosition::new(pos, yaw, pitch, self.dimension.minecraft_name.to_owned());
+        for player in server.get_all_players() {
+            player.client.enqueue_packet(&packet).await;
+        }
+    }
+
     pub async fn add_synced_block_event(&self, pos: BlockPos, r#type: u8, data: u8) {
         let mut queue = self.synced_block_event_queue.lock().await;
         queue.push(BlockEvent { pos, r#type, data });
@@ -537,6 +598,20 @@ impl World {
         }
     }
 
+    /// Sets the player list header and footer for every player in this world.
+    /// See [`Player::set_tab_list_header_footer`].
+    pub async fn broadcast_tab_list_header_footer(
+        &self,
+        header: Option<&TextComponent>,
+        footer: Option<&TextComponent>,
+    ) {
+        for player in self.players.load().iter() {
+            player
+                .set_tab_list_header_footer(header.cloned(), footer.cloned())
+                .await;
+        }
+    }
+
     /// Broadcasts a packet to all connected players within the world.
     /// Please avoid this as we want to replace it with `broadcast_editioned`
     ///
@@ -645,6 +720,16 @@ impl World {
         };
 
         for recipient in self.players.load().iter() {
+            // Bedrock clients don't take part in secure chat
+            if let ClientPlatform::Bedrock(be_client) = &recipient.client {
+                be_client
+                    .enqueue_packet(&SText::new(
+                        chat_message.message.clone(),
+                        sender.gameprofile.name.clone(),
+                    ))
+                    .await;
+                continue;
+            }
             let messages_received: i32 = recipient.chat_session.lock().await.messages_received;
             let packet = &CPlayerChatMessage::new(
                 VarInt(messages_received),
@@ -751,6 +836,7 @@ impl World {
         Self::broadcast_java_grouped(packet, recipients_by_version);
     }
 
+    /// Spawns particles for every player within 32 blocks of `position`.
     pub fn spawn_particle<P: SerializeParticleData + Copy>(
         &self,
         position: Vector3<f64>,
@@ -759,18 +845,48 @@ impl World {
         particle_count: i32,
         particle: P,
     ) {
-        let id = VarInt(particle.id());
-        let mut data = [0u8; 512];
-        let size = particle.to_bytes(&mut data).unwrap();
+        self.spawn_particle_with_options(
+            position,
+            offset,
+            max_speed,
+            particle_count,
+            particle,
+            false,
+        );
+    }
 
+    /// Spawns particles for every player in range of `position`.
+    ///
+    /// `long_distance` particles reach players within 512 instead of 32 blocks, and are also
+    /// rendered by clients from further away, like `/particle ... force` does.
+    pub fn spawn_particle_with_options<P: SerializeParticleData + Copy>(
+        &self,
+        position: Vector3<f64>,
+        offset: Vector3<f32>,
+        max_speed: f32,
+        particle_count: i32,
+        particle: P,
+        long_distance: bool,
+    ) {
+        let range = if long_distance { 512.0 } else { 32.0 };
         for player in self.players.load().iter() {
+            if player
+                .living_entity
+                .entity
+                .pos
+                .load()
+                .squared_distance_to_vec(&position)
+                > range * range
+            {
+                continue;
+            }
             player.spawn_particle(
                 position,
                 offset,
                 max_speed,
                 particle_count,
-                id,
-                &data[..size],
+                particle,
+                long_distance,
             );
         }
     }
@@ -876,6 +992,40 @@ impl World {
         Self::broadcast_java_grouped(&packet, recipients_by_version);
     }
 
+    /// Plays a sound for every player close enough to hear it, skipping the players in `except`.
+    ///
+    /// Like vanilla, a sound carries `16 * volume` blocks (but at least 16), checked against each
+    /// recipient's own position.
+    pub fn play_sound_broadcast(
+        &self,
+        sound: Sound,
+        category: SoundCategory,
+        position: &Vector3<f64>,
+        volume: f32,
+        pitch: f32,
+        except: Option<&[Uuid]>,
+    ) {
+        let seed = rand::rng().random::<f64>();
+        let packet = CSoundEffect::new(
+            IdOr::Id(sound as u16),
+            category,
+            position,
+            volume,
+            pitch,
+            seed,
+        );
+
+        let range = 16.0 * f64::from(volume.max(1.0));
+        let players = self.players.load();
+        let recipients = players.iter().filter(|p| {
+            !except.is_some_and(|except| except.contains(&p.gameprofile.id))
+                && p.position().squared_distance_to_vec(position) <= range * range
+        });
+
+        let recipients_by_version = Self::collect_java_recipients_by_version(recipients);
+        Self::broadcast_java_grouped(&packet, recipients_by_version);
+    }
+
     pub fn play_block_sound(&self, sound: Sound, category: SoundCategory, position: BlockPos) {
         let new_vec = Vector3::new(
             f64::from(position.0.x) + 0.5,
@@ -909,6 +1059,7 @@ impl World {
         self.flush_synced_block_events().await;
         self.update_active_chunks();
         self.tick_environment().await;
+        self.worldborder.lock().await.tick();
 
         let chunk_start = tokio::time::Instant::now();
         self.tick_chunks().await;
@@ -934,16 +1085,39 @@ impl World {
         let player_elapsed = player_start.elapsed();
 
         let entity_start = tokio::time::Instant::now();
-        let entities_to_tick = self.entities.load().clone();
+        let entities_to_tick: Vec<Arc<dyn EntityBase>> = {
+            // Entities outside every player's simulation distance are frozen, like vanilla.
+            let active_chunks = self.active_chunks.load();
+            self.entities
+                .load()
+                .iter()
+                .filter(|e| active_chunks.contains(&e.get_entity().chunk_pos.load()))
+                .cloned()
+                .collect()
+        };
         let entity_count = entities_to_tick.len();
 
+        let activation_config = &server.advanced_config.entity_activation;
         let mut entity_tasks = tokio::task::JoinSet::new();
-        for entity in entities_to_tick.iter() {
+        for entity in &entities_to_tick {
+            let base_entity = entity.get_entity();
+            let active = entity_activation::is_active(
+                activation_config,
+                base_entity.entity_type,
+                base_entity.pos.load(),
+                players.iter().map(|player| player.get_entity().pos.load()),
+            );
             let entity_clone = entity.clone();
             let server_clone = server.clone();
             let players_clone = players.clone();
             entity_tasks.spawn(async move {
                 entity_clone.get_entity().age.fetch_add(1, Relaxed);
+                if !active {
+                    entity_clone
+                        .inactive_tick(&entity_clone, &server_clone)
+                        .await;
+                    return;
+                }
                 entity_clone.tick(&entity_clone, &server_clone).await;
 
                 let entity_inner = entity_clone.get_entity();
@@ -974,6 +1148,13 @@ impl World {
                 error!("Entity tick panicked: {:?}", e);
             }
         }
+        let tracking = &server.advanced_config.entity_tracking;
+        if tracking.enabled {
+            let world_age = self.level_time.lock().await.world_age;
+            if world_age % i64::from(tracking.update_interval.max(1)) == 0 {
+                self.update_entity_tracking(tracking).await;
+            }
+        }
         let entity_elapsed = entity_start.elapsed();
 
         let block_entity_start = tokio::time::Instant::now();
@@ -1103,7 +1284,7 @@ impl World {
     }
 
     async fn tick_environment(&self) {
-        let (world_age, is_night, time_of_day) = {
+        let (world_age, is_night, time_of_day, advance_weather, autosave) = {
             let mut level_time = self.level_time.lock().await;
             let (advance_time, advance_weather) = {
                 let lock = self.level_info.load();
@@ -1131,21 +1312,33 @@ impl World {
                     }
                 }
             }
-            if self.level.autosave_ticks > 0 {
-                let autosave = self.level.autosave_ticks as i64;
-                if autosave > 0 && level_time.world_age % autosave == 0 {
-                    self.level.should_save.store(true, Relaxed);
-                    self.level.level_channel.notify();
-                }
-            }
+            let autosave = self.level.autosave_ticks > 0
+                && level_time.world_age % self.level.autosave_ticks as i64 == 0;
             (
                 level_time.world_age,
                 level_time.is_night(),
                 level_time.time_of_day,
+                advance_weather,
+                autosave,
             )
         };
 
+        if autosave {
+            // Entities only write their state into their chunk when asked to. Serializing all of
+            // them takes a while in big worlds, so it happens on a level task instead of the tick
+            let entities = self.entities.load_full();
+            let level = self.level.clone();
+            self.level.spawn_task(async move {
+                for entity in entities.iter() {
+                    Self::save_entity(&level, entity).await;
+                }
+                level.should_save.store(true, Relaxed);
+                level.level_channel.notify();
+            });
+        }
+
         let mut weather = self.weather.lock().await;
+        weather.weather_cycle_enabled = advance_weather;
         weather.tick_weather(self);
 
         if self.should_skip_night() && is_night {
@@ -1647,12 +1840,8 @@ impl World {
                     );
                     self.spawn_entity(Arc::new(entity)).await;
                 }
-                let entity = Entity::new(
-                    self.clone(),
-                    random_pos.to_f64().add_raw(0.5, 0., 0.5),
-                    &EntityType::LIGHTNING_BOLT,
-                );
-                self.spawn_entity(Arc::new(entity)).await;
+                self.strike_lightning(random_pos.to_block_pos(), false)
+                    .await;
             }
         }
 
@@ -1669,7 +1858,8 @@ impl World {
             is_thundering,
         );
         for entity in entities {
-            self.spawn_entity(entity).await;
+            self.spawn_entity_checked(entity, EntitySpawnReason::Natural)
+                .await;
         }
     }
 
@@ -1687,6 +1877,29 @@ impl World {
         level_time.send_time(self).await;
     }
 
+    /// Sets the weather of this world and broadcasts it to its players.
+    ///
+    /// `duration_ticks` is how long the weather lasts before the weather cycle
+    /// changes it again; `None` picks a random vanilla duration.
+    pub async fn set_weather(&self, rain: bool, thunder: bool, duration_ticks: Option<i32>) {
+        let mut weather = self.weather.lock().await;
+        weather.set_weather(self, rain, thunder, duration_ticks);
+        self.save_weather(&weather);
+    }
+
+    /// Copies the weather into the shared level info, which is written to `level.dat`.
+    /// `level.dat` only holds a single weather state, so only the overworld's is kept.
+    fn save_weather(&self, weather: &Weather) {
+        if self.dimension.minecraft_name != Dimension::OVERWORLD.minecraft_name {
+            return;
+        }
+        self.level_info.rcu(|level_info| {
+            let mut new_level_info = (**level_info).clone();
+            weather.write_level_data(&mut new_level_info);
+            new_level_info
+        });
+    }
+
     pub async fn is_raining(&self) -> bool {
         self.weather.lock().await.raining
     }
@@ -1696,6 +1909,7 @@ impl World {
         if weather.raining != raining {
             let thunder = weather.thundering;
             weather.set_weather_parameters(self, 0, 0, raining, thunder);
+            self.save_weather(&weather);
         }
     }
 
@@ -1708,6 +1922,7 @@ impl World {
         if weather.thundering != thundering {
             let raining = weather.raining;
             weather.set_weather_parameters(self, 0, 0, raining, thundering);
+            self.save_weather(&weather);
         }
     }
 
@@ -2245,6 +2460,12 @@ impl World {
         }
 
         // 3. Trigger Join Event and Broadcast Join Message
+        self.announce_player_join(player, server).await;
+    }
+
+    /// Fires the [`PlayerJoinEvent`] and broadcasts its join message, unless a plugin cancelled
+    /// it.
+    async fn announce_player_join(&self, player: &Arc<Player>, server: &Server) {
         let msg_comp = TextComponent::translate_cross(
             translation::java::MULTIPLAYER_PLAYER_JOINED,
             translation::bedrock::MULTIPLAYER_PLAYER_JOINED,
@@ -2258,6 +2479,7 @@ impl World {
         if !event.cancelled {
             self.broadcast_system_message(&event.join_message, false)
                 .await;
+            // TODO: Switch to structured logging, e.g. info!(player = %name, "connected")
             info!("{}", event.join_message.to_pretty_console());
         }
     }
@@ -2286,6 +2508,15 @@ impl World {
         let Some(client) = player.client.java() else {
             return;
         };
+        let (reduced_debug_info, enabled_respawn_screen, limited_crafting) = {
+            let game_rules = &self.level_info.load().game_rules;
+            (
+                base_config.reduced_debug_info || game_rules.reduced_debug_info,
+                !game_rules.immediate_respawn,
+                game_rules.limited_crafting,
+            )
+        };
+        let death_location = player.last_death_location.lock().await.clone();
         // Send the login packet for our new player
         client
             .send_packet_now(&CLogin::new(
@@ -2294,10 +2525,10 @@ impl World {
                 dimensions,
                 base_config.max_players.try_into().unwrap(),
                 base_config.view_distance.get().into(), //  TODO: view distance
-                base_config.simulation_distance.get().into(), // TODO: sim view dinstance
-                false,
-                true,
-                false,
+                get_simulation_distance(player).get().into(),
+                reduced_debug_info,
+                enabled_respawn_screen,
+                limited_crafting,
                 self.dimension.clone(),
                 biome::hash_seed(self.level.seed.0), // seed
                 gamemode as u8,
@@ -2307,7 +2538,7 @@ impl World {
                     .map_or(-1, |gamemode| gamemode as i8),
                 false,
                 false,
-                None,
+                death_location,
                 VarInt(player.get_entity().portal_cooldown.load(Ordering::Relaxed) as i32),
                 self.sea_level.into(),
                 // This should stay true even when reports are disabled.
@@ -2409,7 +2640,7 @@ impl World {
             .await;
 
         // If the player has a custom tab_list_name, send an update for it
-        if let Some(tab_list_name) = player.get_tab_list_name().await {
+        if let Some(tab_list_name) = player.get_listed_name().await {
             let actions = [PlayerAction::UpdateDisplayName(Some(&tab_list_name))];
             let java_player = [pumpkin_protocol::java::client::play::Player {
                 uuid: gameprofile.id,
@@ -2437,7 +2668,7 @@ impl World {
             let mut current_player_data = Vec::new();
             for (properties, player) in &data_to_process {
                 let chat_session = player.chat_session.lock().await;
-                let tab_list_name = player.get_tab_list_name().await;
+                let tab_list_name = player.get_listed_name().await;
 
                 let mut player_actions = vec![
                     PlayerAction::AddPlayer {
@@ -2742,27 +2973,11 @@ impl World {
             };
 
             {
-                let mut equipment_list = Vec::new();
-
-                equipment_list.push((
-                    EquipmentSlot::MAIN_HAND.discriminant(),
-                    existing_player.inventory.held_item().lock().await.clone(),
-                ));
-
-                for (slot, item_arc_mutex) in &existing_player
-                    .inventory
-                    .entity_equipment
-                    .lock()
+                let equipment: Vec<(i8, ItemStackSerializer)> = existing_player
+                    .visible_equipment()
                     .await
-                    .equipment
-                {
-                    let item_stack = item_arc_mutex.lock().await.clone();
-                    equipment_list.push((slot.discriminant(), item_stack));
-                }
-
-                let equipment: Vec<(i8, ItemStackSerializer)> = equipment_list
-                    .iter()
-                    .map(|(slot, stack)| (*slot, ItemStackSerializer::from(stack.clone())))
+                    .into_iter()
+                    .map(|(slot, stack)| (slot.discriminant(), ItemStackSerializer::from(stack)))
                     .collect();
 
                 client
@@ -2791,11 +3006,12 @@ impl World {
             .await;
 
         self.worldborder.lock().await.init_client(client).await;
+        self.scoreboard.lock().await.send_teams(player).await;
 
         // Sends initial time
         player.send_time(self).await;
 
-        let (spawn_block_pos, yaw, pitch) = {
+        let (spawn_block_pos, yaw, pitch, spawn_dimension) = {
             let level_info_lock = self.level_info.load();
             (
                 BlockPos::new(
@@ -2805,6 +3021,7 @@ impl World {
                 ),
                 level_info_lock.spawn_yaw,
                 level_info_lock.spawn_pitch,
+                level_info_lock.spawn_dimension.clone(),
             )
         };
 
@@ -2813,7 +3030,7 @@ impl World {
                 spawn_block_pos,
                 yaw,
                 pitch,
-                self.dimension.minecraft_name.to_owned(),
+                spawn_dimension,
             ))
             .await;
 
@@ -2866,40 +3083,16 @@ impl World {
                 .await;
         }
 
-        let msg_comp = TextComponent::translate_cross(
-            translation::java::MULTIPLAYER_PLAYER_JOINED,
-            translation::bedrock::MULTIPLAYER_PLAYER_JOINED,
-            [TextComponent::text(player.gameprofile.name.clone())],
-        )
-        .color_named(NamedColor::Yellow);
-        let event = PlayerJoinEvent::new(player.clone(), msg_comp);
-
-        let event = server.plugin_manager.fire(event).await;
-
-        if !event.cancelled {
-            self.broadcast_system_message(&event.join_message, false)
-                .await;
-            // TODO: Switch to structured logging, e.g. info!(player = %name, "connected")
-            info!("{}", event.join_message.to_pretty_console());
-        }
+        self.announce_player_join(player, server).await;
     }
 
-    async fn send_player_equipment(&self, from: &Player) {
-        let mut equipment_list = Vec::new();
-
-        equipment_list.push((
-            EquipmentSlot::MAIN_HAND.discriminant(),
-            from.inventory.held_item().lock().await.clone(),
-        ));
-
-        for (slot, item_arc_mutex) in &from.inventory.entity_equipment.lock().await.equipment {
-            let item_stack = item_arc_mutex.lock().await.clone();
-            equipment_list.push((slot.discriminant(), item_stack));
-        }
-
-        let equipment: Vec<(i8, ItemStackSerializer)> = equipment_list
-            .iter()
-            .map(|(slot, stack)| (*slot, ItemStackSerializer::from(stack.clone())))
+    /// Broadcasts the full visible equipment of `from` to the players around them.
+    pub async fn send_player_equipment(&self, from: &Player) {
+        let equipment: Vec<(i8, ItemStackSerializer)> = from
+            .visible_equipment()
+            .await
+            .into_iter()
+            .map(|(slot, stack)| (slot.discriminant(), ItemStackSerializer::from(stack)))
             .collect();
         let chunk_pos = from.get_entity().chunk_pos.load();
         self.broadcast_to_chunk_except(
@@ -2950,11 +3143,33 @@ impl World {
         chunker::update_position(player).await;
         // Update commands
 
-        player.set_health(20.0).await;
+        player
+            .set_health(player.living_entity.get_max_health())
+            .await;
     }
 
     pub async fn explode(self: &Arc<Self>, position: Vector3<f64>, power: f32) {
-        let explosion = Explosion::new(power, position);
+        self.create_explosion(
+            position,
+            power,
+            false,
+            ExplosionBlockInteraction::DestroyWithDecay,
+        )
+        .await;
+    }
+
+    /// Creates an explosion which destroys blocks according to `block_interaction`, damages and
+    /// knocks back nearby entities, optionally sets fire, and shows it to nearby players.
+    pub async fn create_explosion(
+        self: &Arc<Self>,
+        position: Vector3<f64>,
+        power: f32,
+        fire: bool,
+        block_interaction: ExplosionBlockInteraction,
+    ) {
+        let explosion = Explosion::new(power, position)
+            .with_fire(fire)
+            .with_block_interaction(block_interaction);
         let block_count = explosion.explode(self).await;
         let particle = if power < 2.0 {
             particle::Explosion.id()
@@ -2986,15 +3201,10 @@ impl World {
 
     #[allow(clippy::too_many_lines)]
     pub async fn respawn_player(self: &Arc<Self>, player: &Arc<Player>, alive: bool) {
-        let last_pos = player.get_entity().last_pos.load();
-        let death_dimension = ResourceLocation::from(player.world().dimension.minecraft_name);
-        let death_location = BlockPos(Vector3::new(
-            last_pos.x.round() as i32,
-            last_pos.y.round() as i32,
-            last_pos.z.round() as i32,
-        ));
+        let death_location = player.last_death_location.lock().await.clone();
 
-        let data_kept = u8::from(alive);
+        // Keep both attributes and metadata when the player did not die
+        let data_kept = if alive { 0x03 } else { 0 };
 
         // Copy spawn info from level_info to avoid holding lock across await
         let (spawn_x, spawn_z, spawn_yaw, spawn_pitch, keep_inventory) = {
@@ -3044,7 +3254,7 @@ impl World {
             };
 
         // Get target world (may be different from current world for cross-dimension respawn)
-        let target_world = if respawn_dimension == self.dimension {
+        let target_world = if respawn_dimension.minecraft_name == self.dimension.minecraft_name {
             None
         } else {
             // Cross-dimension respawn: get target world from server
@@ -3057,7 +3267,7 @@ impl World {
                     let worlds = server.worlds.load();
                     worlds
                         .iter()
-                        .find(|w| w.dimension == respawn_dimension)
+                        .find(|w| w.dimension.minecraft_name == respawn_dimension.minecraft_name)
                         .cloned()
                 },
             )
@@ -3110,6 +3320,10 @@ impl World {
             (self.as_ref(), position)
         };
 
+        // The client forgets every entity on respawn, so they have to be tracked anew.
+        self.entity_tracker.remove_player(player.entity_id());
+        target_world.entity_tracker.remove_player(player.entity_id());
+
         // Send respawn packet with target dimension (using send_packet_now to ensure proper order)
         player
             .client
@@ -3118,10 +3332,13 @@ impl World {
                 ResourceLocation::from(target_world.dimension.minecraft_name),
                 biome::hash_seed(target_world.level.seed.0),
                 player.gamemode.load() as u8,
-                player.gamemode.load() as i8,
+                player
+                    .previous_gamemode
+                    .load()
+                    .map_or(-1, |gamemode| gamemode as i8),
                 false,
                 false,
-                Some((death_dimension, death_location)),
+                death_location,
                 VarInt(player.get_entity().portal_cooldown.load(Ordering::Relaxed) as i32),
                 target_world.sea_level.into(),
                 data_kept,
@@ -3146,15 +3363,18 @@ impl World {
             ))
             .await;
 
-        player.living_entity.reset_state().await;
-
         player.send_permission_lvl_update();
 
-        player.hunger_manager.restart();
+        // A player returning from the credits is still alive and keeps everything
+        if !alive {
+            player.living_entity.reset_state().await;
+
+            player.hunger_manager.restart();
 
-        if !keep_inventory {
-            player.set_experience(0, 0.0, 0).await;
-            player.inventory.clear().await;
+            if !keep_inventory {
+                player.set_experience(0, 0.0, 0).await;
+                player.inventory.clear().await;
+            }
         }
 
         // Set entity position BEFORE loading chunks, so chunks load at the right location
@@ -3186,6 +3406,8 @@ impl World {
 
         // Send teleport packet after at least the center chunk was delivered
         player.request_teleport(position, yaw, pitch).await;
+
+        target_world.send_player_equipment(player).await;
     }
 
     /// Returns true if enough players are sleeping and we should skip the night.
@@ -3244,6 +3466,11 @@ impl World {
         let mut entity_receiver = self.level.receive_entity_chunks(chunks);
         let level = self.level.clone();
         let world = self.clone();
+        // With entity tracking, entities are spawned for the player once they come into range.
+        let tracking_enabled = self
+            .server
+            .upgrade()
+            .is_some_and(|server| server.advanced_config.entity_tracking.enabled);
         player.clone().spawn_task(async move {
             'main: loop {
                 let recv_result = tokio::select! {
@@ -3340,12 +3567,13 @@ impl World {
                     // Pos is zero since it will read from nbt
                     let entity = from_type(entity_type, Vector3::new(0.0, 0.0, 0.0), &world, *uuid);
                     entity.read_nbt_non_mut(entity_nbt).await;
-                    let base_entity = entity.get_entity();
-                    player
-                        .client
-                        .enqueue_packet(&base_entity.create_spawn_packet())
-                        .await;
-                    entity.init_data_tracker().await;
+                    if !tracking_enabled {
+                        player
+                            .client
+                            .enqueue_packet(&entity.get_entity().create_spawn_packet())
+                            .await;
+                        entity.init_data_tracker().await;
+                    }
 
                     if first_load {
                         entities_to_add.push(entity);
@@ -3407,13 +3635,14 @@ impl World {
 
         entities_guard
             .iter()
-            .map(|e| e.clone() as Arc<dyn EntityBase>)
+            .filter(|entity| entity.get_entity().bounding_box.load().intersects(aabb))
+            .cloned()
             .chain(
                 players_guard
                     .iter()
-                    .map(|p| p.clone() as Arc<dyn EntityBase>),
+                    .filter(|player| player.get_entity().bounding_box.load().intersects(aabb))
+                    .map(|player| player.clone() as Arc<dyn EntityBase>),
             )
-            .filter(|entity| entity.get_entity().bounding_box.load().intersects(aabb))
             .collect()
     }
 
@@ -3720,6 +3949,7 @@ impl World {
             }
             new_list
         });
+        self.entity_tracker.remove_player(player.entity_id());
         if let Some(ref player) = removed_player {
             let uuid = player.gameprofile.id;
             let entity_id = player.entity_id();
@@ -3768,9 +3998,8 @@ impl World {
                     .await;
 
                 if !event.cancelled {
-                    for player in self.players.load().iter() {
-                        player.send_system_message(&event.leave_message).await;
-                    }
+                    self.broadcast_system_message(&event.leave_message, false)
+                        .await;
                     info!("{}", event.leave_message.to_pretty_console());
                 }
             }
@@ -3790,13 +4019,72 @@ impl World {
         });
     }
 
+    /// Strikes lightning at the bottom center of `pos`.
+    ///
+    /// The bolt is always shown to clients. Unless `effect_only` is set it also lights
+    /// fire around the strike and hits nearby entities, which take lightning damage and
+    /// may transform (for example creepers become charged).
+    pub async fn strike_lightning(
+        self: &Arc<Self>,
+        pos: BlockPos,
+        effect_only: bool,
+    ) -> Arc<LightningBoltEntity> {
+        let entity = Entity::new(
+            self.clone(),
+            pos.0.to_f64().add_raw(0.5, 0.0, 0.5),
+            &EntityType::LIGHTNING_BOLT,
+        );
+        let bolt = Arc::new(LightningBoltEntity::new(entity, effect_only));
+        self.spawn_entity(bolt.clone()).await;
+        bolt
+    }
+
     pub async fn spawn_entity(&self, entity: Arc<dyn EntityBase>) {
         self.broadcast_entity_spawn(&entity);
         entity.init_data_tracker().await;
         self.add_entity_silent(entity).await;
     }
 
+    /// Spawns `entity` after firing a cancellable [`EntitySpawnEvent`].
+    ///
+    /// Returns `false` if a plugin cancelled the spawn, in which case the entity is
+    /// dropped without ever being added to the world.
+    pub async fn spawn_entity_checked(
+        &self,
+        entity: Arc<dyn EntityBase>,
+        reason: EntitySpawnReason,
+    ) -> bool {
+        if let Some(server) = self.server.upgrade() {
+            let base_entity = entity.get_entity();
+            let position = base_entity.pos.load();
+            let event = server
+                .plugin_manager
+                .fire(EntitySpawnEvent::new(
+                    entity.clone(),
+                    base_entity.entity_type,
+                    position,
+                    reason,
+                ))
+                .await;
+            if event.cancelled {
+                return false;
+            }
+            if event.position != position {
+                base_entity.set_pos(event.position);
+            }
+        }
+        self.spawn_entity(entity).await;
+        true
+    }
+
     pub fn broadcast_entity_spawn(&self, entity: &Arc<dyn EntityBase>) {
+        if let Some(server) = self.server.upgrade()
+            && server.advanced_config.entity_tracking.enabled
+        {
+            self.update_entity_trackers(entity, &server.advanced_config.entity_tracking);
+            return;
+        }
+
         let base_entity = entity.get_entity();
         let chunk_pos = base_entity.chunk_pos.load();
 
@@ -3841,11 +4129,11 @@ impl World {
             new_entities
         });
 
-        let chunk_pos = base_entity.chunk_pos.load();
-        self.broadcast_to_chunk(
-            chunk_pos,
+        self.broadcast_to_entity_viewers(
+            base_entity,
             &CRemoveEntities::new(&[base_entity.entity_id.into()]),
         );
+        self.entity_tracker.remove_entity(base_entity.entity_id);
 
         self.remove_entity_data(base_entity).await;
     }
@@ -3880,13 +4168,39 @@ impl World {
     }
 
     pub async fn set_block_breaking(&self, from: &Entity, location: BlockPos, progress: i32) {
-        let chunk_pos = location.chunk_position(); // pumpkin's BlockPos already has this method
-        let je_packet = CSetBlockDestroyStage::new(from.entity_id.into(), location, progress as i8);
+        let (je_packet, be_packet) =
+            Self::block_break_packets(from.entity_id, location, progress as i8);
+
+        self.broadcast_to_chunk_except_editioned(
+            location.chunk_position(),
+            &[from.entity_uuid],
+            &je_packet,
+            &be_packet,
+        )
+        .await;
+    }
+
+    /// Shows a block-breaking crack overlay at `pos` to every player that can see it.
+    ///
+    /// `stage` ranges from 0 to 9, and -1 (or any value outside that range) clears the
+    /// overlay. Clients track one overlay per `source_id`, so plugins should use an id from
+    /// [`Entity::reserve_ids`] to avoid clashing with a player's own breaking progress.
+    pub fn set_block_break_progress(&self, source_id: i32, pos: BlockPos, stage: i8) {
+        let (je_packet, be_packet) = Self::block_break_packets(source_id, pos, stage);
+        self.broadcast_to_chunk_editioned_sync(pos.chunk_position(), &je_packet, &be_packet);
+    }
+
+    fn block_break_packets(
+        source_id: i32,
+        location: BlockPos,
+        progress: i8,
+    ) -> (CSetBlockDestroyStage, CLevelEvent) {
+        let je_packet = CSetBlockDestroyStage::new(source_id.into(), location, progress);
 
         let (event_id, data) = match progress {
-            -1 => (LevelEvent::BlockStopBreak, 0),
             0 => (LevelEvent::BlockStartBreak, 0),
-            _ => (LevelEvent::BlockUpdateBreak, progress),
+            1..=9 => (LevelEvent::BlockUpdateBreak, i32::from(progress)),
+            _ => (LevelEvent::BlockStopBreak, 0),
         };
 
         let be_packet = CLevelEvent {
@@ -3899,13 +4213,7 @@ impl World {
             data: VarInt(data),
         };
 
-        self.broadcast_to_chunk_except_editioned(
-            chunk_pos,
-            &[from.entity_uuid],
-            &je_packet,
-            &be_packet,
-        )
-        .await;
+        (je_packet, be_packet)
     }
 
     /// Sets a block and returns the old block id
@@ -4085,15 +4393,11 @@ impl World {
     pub fn get_biome(&self, position: &BlockPos) -> &'static Biome {
         let chunk_pos = position.chunk_position();
         if let Some(chunk) = self.level.loaded_chunks.get(&chunk_pos) {
-            let id = chunk
-                .section
-                .get_rough_biome_absolute_y(
-                    (position.0.x & 15) as usize,
-                    position.0.y,
-                    (position.0.z & 15) as usize,
-                )
-                .unwrap_or(0);
-            Biome::from_id(id).unwrap_or(&Biome::PLAINS)
+            chunk.biome_at(
+                (position.0.x & 15) as usize,
+                position.0.y,
+                (position.0.z & 15) as usize,
+            )
         } else {
             &Biome::PLAINS
         }
@@ -4199,7 +4503,7 @@ impl World {
                     false,
                 );
                 let chunk_pos = position.chunk_position();
-                match cause {
+                match &cause {
                     Some(player) => {
                         self.broadcast_to_chunk_except(
                             chunk_pos,
@@ -4211,6 +4515,10 @@ impl World {
                 }
             }
             if !flags.contains(BlockFlags::SKIP_DROPS) {
+                let tool = match &cause {
+                    Some(player) => player.inventory.held_item().lock().await.clone(),
+                    None => ItemStack::EMPTY.clone(),
+                };
                 let params = LootContextParameters {
                     block_state: Some(BlockState::from_id(broken_state_id)),
                     luck,
@@ -4221,7 +4529,8 @@ impl World {
                     )),
                     world_time: self.level_info.load().day_time as u64,
                     ..Default::default()
-                };
+                }
+                .with_tool(&tool);
                 block::drop_loot(self, broken_block, position, true, params).await;
             }
             return Some(new_state_id);
@@ -4252,7 +4561,8 @@ impl World {
 
         let entity = Entity::new(self.clone(), spawn_pos, &EntityType::ITEM);
         let item_entity = Arc::new(ItemEntity::new(entity, stack));
-        self.spawn_entity(item_entity).await;
+        self.spawn_entity_checked(item_entity, EntitySpawnReason::Drop)
+            .await;
     }
 
     /* ItemScatterer.java */
@@ -4298,7 +4608,8 @@ impl World {
 
             let entity = Entity::new(self.clone(), Vector3::new(x, y, z), &EntityType::ITEM);
             let entity = Arc::new(ItemEntity::new_with_velocity(entity, item, velocity, 10));
-            self.spawn_entity(entity).await;
+            self.spawn_entity_checked(entity, EntitySpawnReason::Drop)
+                .await;
         }
     }
     /* End ItemScatterer.java */
@@ -4936,6 +5247,111 @@ impl World {
         Self::broadcast_java_grouped(je_packet, recipients_by_version);
     }
 
+    /// Returns whether the player's client knows about the entity.
+    ///
+    /// Entities handled by the [`EntityTracker`] are known to the players tracking them; all
+    /// others, such as players, to every player who has their chunk loaded.
+    pub fn is_entity_visible_to(&self, entity: &Entity, player: &Player) -> bool {
+        self.entity_tracker
+            .is_tracked_by(entity.entity_id, player.entity_id())
+            .unwrap_or_else(|| {
+                let center = player.get_entity().chunk_pos.load();
+                let view_distance = get_view_distance(player).get() as i32;
+                is_within_view_distance(entity.chunk_pos.load(), center, view_distance)
+            })
+    }
+
+    /// Broadcasts a packet about an entity to all players who can see it.
+    pub fn broadcast_to_entity_viewers<P: ClientPacket>(&self, entity: &Entity, packet: &P) {
+        let players = self.players.load();
+        let recipients = players
+            .iter()
+            .filter(|p| self.is_entity_visible_to(entity, p));
+
+        let recipients_by_version = Self::collect_java_recipients_by_version(recipients);
+        Self::broadcast_java_grouped(packet, recipients_by_version);
+    }
+
+    pub fn broadcast_to_entity_viewers_editioned_sync<J: ClientPacket, B: BClientPacket>(
+        &self,
+        entity: &Entity,
+        je_packet: &J,
+        be_packet: &B,
+    ) {
+        let players = self.players.load();
+        let mut java_recipients = Vec::new();
+
+        let recipients = players
+            .iter()
+            .filter(|p| self.is_entity_visible_to(entity, p));
+
+        for p in recipients {
+            match &p.client {
+                ClientPlatform::Java(_) => java_recipients.push(p),
+                ClientPlatform::Bedrock(be_client) => be_client.try_enqueue_packet(be_packet),
+            }
+        }
+
+        let recipients_by_version =
+            Self::collect_java_recipients_by_version(java_recipients.into_iter());
+        Self::broadcast_java_grouped(je_packet, recipients_by_version);
+    }
+
+    /// Spawns the entity for players who came into its tracking range and removes it for players
+    /// who left it. Returns whether any player started tracking the entity.
+    fn update_entity_trackers(
+        &self,
+        entity: &Arc<dyn EntityBase>,
+        config: &EntityTrackingConfig,
+    ) -> bool {
+        let base_entity = entity.get_entity();
+        let range = tracking_range(config, base_entity.entity_type);
+        let entity_pos = base_entity.pos.load();
+        let entity_chunk = base_entity.chunk_pos.load();
+
+        let players = self.players.load();
+        let (added, removed) = self.entity_tracker.update(
+            base_entity.entity_id,
+            players.iter().map(|p| (p.entity_id(), p)),
+            |player| {
+                let center = player.get_entity().chunk_pos.load();
+                let view_distance = get_view_distance(player).get() as i32;
+                is_within_view_distance(entity_chunk, center, view_distance)
+                    && is_in_tracking_range(player.get_entity().pos.load(), entity_pos, range)
+            },
+        );
+
+        for player in &added {
+            player.client.try_enqueue_spawn_packet(entity);
+        }
+        for player in removed {
+            player.client.try_enqueue_packet_editioned(
+                &CRemoveEntities::new(&[base_entity.entity_id.into()]),
+                &CRemoveActor::new(VarLong(i64::from(base_entity.entity_id))),
+            );
+        }
+
+        !added.is_empty()
+    }
+
+    /// Brings the players tracking each entity in the world up to date.
+    async fn update_entity_tracking(&self, config: &EntityTrackingConfig) {
+        let entities = self.entities.load_full();
+        let alive: FxHashSet<i32> = entities
+            .iter()
+            .map(|entity| entity.get_entity().entity_id)
+            .collect();
+        self.entity_tracker
+            .retain_entities(|entity_id| alive.contains(&entity_id));
+
+        for entity in entities.iter() {
+            if self.update_entity_trackers(entity, config) {
+                // Players who just started tracking the entity need its metadata too.
+                entity.init_data_tracker().await;
+            }
+        }
+    }
+
     /// Broadcasts a packet to chunk watchers, excluding specific players.
     pub fn broadcast_to_chunk_except<P: ClientPacket>(
         &self,
@@ -10,7 +10,10 @@ use pumpkin_data::{
 use pumpkin_util::math::{boundingbox::EntityDimensions, position::BlockPos, vector3::Vector3};
 use pumpkin_world::{chunk::ChunkHeightmapType, world::BlockFlags};
 
-use crate::world::World;
+use crate::{
+    plugin::api::events::world::portal_create::{PortalCreateEvent, PortalCreateReason},
+    world::World,
+};
 
 const SEARCH_RADIUS_NETHER: i32 = 128;
 const SEARCH_RADIUS_OVERWORLD: i32 = 128;
@@ -261,6 +264,62 @@ impl NetherPortal {
         self.height
     }
 
+    /// Fires a [`PortalCreateEvent`] for a portal about to be placed in `world`.
+    /// Returns `false` if a plugin cancelled it.
+    async fn fire_create_event(
+        world: &Arc<World>,
+        lower_corner: BlockPos,
+        axis: HorizontalAxis,
+        width: u32,
+        height: u32,
+        reason: PortalCreateReason,
+    ) -> bool {
+        let Some(server) = world.server.upgrade() else {
+            return true;
+        };
+        let event = server
+            .plugin_manager
+            .fire(PortalCreateEvent::new(
+                world.clone(),
+                lower_corner,
+                axis,
+                width,
+                height,
+                reason,
+            ))
+            .await;
+        !event.cancelled
+    }
+
+    /// Lights this portal frame after firing a [`PortalCreateEvent`].
+    ///
+    /// Returns `false` without placing any blocks if the Nether is disabled,
+    /// the frame is already lit or a plugin cancelled the event.
+    pub async fn light(&self, world: &Arc<World>) -> bool {
+        if self.was_already_valid()
+            || world
+                .server
+                .upgrade()
+                .is_some_and(|server| !server.basic_config.allow_nether)
+        {
+            return false;
+        }
+        if !Self::fire_create_event(
+            world,
+            self.lower_conor,
+            self.axis,
+            self.width,
+            self.height,
+            PortalCreateReason::Fire,
+        )
+        .await
+        {
+            return false;
+        }
+        self.create(world).await;
+        true
+    }
+
     pub async fn create(&self, world: &Arc<World>) {
         let mut props = NetherPortalLikeProperties::default(&Block::NETHER_PORTAL);
         props.axis = self.axis;
@@ -725,12 +784,28 @@ impl NetherPortal {
         true
     }
 
+    /// Builds a 2x3 destination portal with its obsidian frame at `lower_corner`.
+    ///
+    /// Returns `false` without touching the world if a plugin cancelled the [`PortalCreateEvent`].
     pub async fn build_portal_frame(
         world: &Arc<World>,
         lower_corner: BlockPos,
         axis: HorizontalAxis,
         is_fallback: bool,
-    ) {
+    ) -> bool {
+        if !Self::fire_create_event(
+            world,
+            lower_corner,
+            axis,
+            2,
+            3,
+            PortalCreateReason::NetherPair,
+        )
+        .await
+        {
+            return false;
+        }
+
         let direction = if axis == HorizontalAxis::X {
             BlockDirection::East
         } else {
@@ -804,5 +879,6 @@ impl NetherPortal {
                 poi_storage.add_portal(pos);
             }
         }
+        true
     }
 }
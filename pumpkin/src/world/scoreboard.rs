@@ -17,6 +17,7 @@ use pumpkin_util::text::{TextComponent, color::NamedColor};
 use tracing::warn;
 
 use super::World;
+use crate::entity::player::Player;
 
 #[derive(Default)]
 pub struct Scoreboard {
@@ -158,6 +159,39 @@ impl Scoreboard {
         }
     }
 
+    /// Sends every team to a player who just joined the world.
+    pub async fn send_teams(&self, player: &Player) {
+        for team in self.teams.values() {
+            player
+                .client
+                .enqueue_packet(&CSetPlayerTeam {
+                    team_name: team.name.clone(),
+                    method: TeamMethod::Create,
+                    parameters: Some(team.parameters()),
+                    players: team.players.clone().into(),
+                })
+                .await;
+        }
+        player.team_color.store(
+            self.teams
+                .values()
+                .find(|team| team.players.contains(&player.gameprofile.name))
+                .map(|team| team.color),
+        );
+    }
+
+    /// Updates the name color of an online player who joined or left a team.
+    fn set_team_color(world: &World, player: &str, color: Option<NamedColor>) {
+        if let Some(player) = world.get_player_by_name(player) {
+            player.team_color.store(color);
+        }
+    }
+
+    #[must_use]
+    pub fn has_team(&self, name: &str) -> bool {
+        self.teams.contains_key(name)
+    }
+
     pub fn add_team(&mut self, world: &World, team: Team) {
         if self.teams.contains_key(&team.name) {
             warn!(
@@ -167,23 +201,16 @@ impl Scoreboard {
             return;
         }
 
-        let parameters = TeamParameters {
-            display_name: &team.display_name,
-            options: team.options,
-            nametag_visibility: team.nametag_visibility.to_str(),
-            collision_rule: team.collision_rule.to_str(),
-            color: team.color as i32,
-            player_prefix: &team.player_prefix,
-            player_suffix: &team.player_suffix,
-        };
-
         world.broadcast_packet_all(&CSetPlayerTeam {
             team_name: team.name.clone(),
             method: TeamMethod::Create,
-            parameters: Some(parameters),
+            parameters: Some(team.parameters()),
             players: team.players.clone().into(),
         });
 
+        for player in &team.players {
+            Self::set_team_color(world, player, Some(team.color));
+        }
         self.teams.insert(team.name.clone(), team);
     }
 
@@ -193,23 +220,16 @@ impl Scoreboard {
             return;
         }
 
-        let parameters = TeamParameters {
-            display_name: &team.display_name,
-            options: team.options,
-            nametag_visibility: team.nametag_visibility.to_str(),
-            collision_rule: team.collision_rule.to_str(),
-            color: team.color as i32,
-            player_prefix: &team.player_prefix,
-            player_suffix: &team.player_suffix,
-        };
-
         world.broadcast_packet_all(&CSetPlayerTeam {
             team_name: team.name.clone(),
             method: TeamMethod::Update,
-            parameters: Some(parameters),
+            parameters: Some(team.parameters()),
             players: Box::new([]),
         });
 
+        for player in &team.players {
+            Self::set_team_color(world, player, Some(team.color));
+        }
         self.teams.insert(team.name.clone(), team);
     }
 
@@ -226,7 +246,11 @@ impl Scoreboard {
             players: Box::new([]),
         });
 
-        self.teams.remove(name);
+        if let Some(team) = self.teams.remove(name) {
+            for player in &team.players {
+                Self::set_team_color(world, player, None);
+            }
+        }
     }
 
     pub fn add_player_to_team(&mut self, world: &World, team_name: &str, player: String) {
@@ -249,6 +273,7 @@ impl Scoreboard {
             players: vec![player.clone()].into(),
         });
 
+        Self::set_team_color(world, &player, Some(team.color));
         team.players.push(player);
     }
 
@@ -272,6 +297,7 @@ impl Scoreboard {
             players: vec![player.to_string()].into(),
         });
 
+        Self::set_team_color(world, player, None);
         team.players.retain(|p| p != player);
     }
 }
@@ -376,3 +402,17 @@ pub struct Team {
     pub player_suffix: TextComponent,
     pub players: Vec<String>,
 }
+
+impl Team {
+    fn parameters(&self) -> TeamParameters<'_> {
+        TeamParameters {
+            display_name: &self.display_name,
+            options: self.options,
+            nametag_visibility: self.nametag_visibility.to_str(),
+            collision_rule: self.collision_rule.to_str(),
+            color: self.color as i32,
+            player_prefix: &self.player_prefix,
+            player_suffix: &self.player_suffix,
+        }
+    }
+}
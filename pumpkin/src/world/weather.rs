@@ -1,5 +1,6 @@
 use super::World;
 use pumpkin_protocol::java::client::play::{CGameEvent, GameEvent};
+use pumpkin_world::world_info::LevelData;
 use rand::RngExt;
 
 // Weather timing constants
@@ -52,6 +53,53 @@ impl Weather {
         }
     }
 
+    /// Restores the weather saved in `level.dat`.
+    #[must_use]
+    pub fn from_level_data(data: &LevelData) -> Self {
+        let rain_level = if data.raining { 1.0 } else { 0.0 };
+        let thunder_level = if data.thundering { 1.0 } else { 0.0 };
+        Self {
+            clear_weather_time: data.clear_weather_time,
+            raining: data.raining,
+            rain_time: data.rain_time,
+            thundering: data.thundering,
+            thunder_time: data.thunder_time,
+            rain_level,
+            old_rain_level: rain_level,
+            thunder_level,
+            old_thunder_level: thunder_level,
+            weather_cycle_enabled: data.game_rules.advance_weather,
+        }
+    }
+
+    /// Writes the current weather into `data` so it is saved with `level.dat`.
+    pub const fn write_level_data(&self, data: &mut LevelData) {
+        data.clear_weather_time = self.clear_weather_time;
+        data.raining = self.raining;
+        data.rain_time = self.rain_time;
+        data.thundering = self.thundering;
+        data.thunder_time = self.thunder_time;
+    }
+
+    /// Switches to clear weather, rain or a thunderstorm for `duration` ticks.
+    /// Without a duration a random one is picked, just like `/weather` does.
+    pub fn set_weather(&mut self, world: &World, rain: bool, thunder: bool, duration: Option<i32>) {
+        if thunder {
+            let duration = duration.unwrap_or_else(|| {
+                rand::rng().random_range(THUNDER_DURATION_MIN..=THUNDER_DURATION_MAX)
+            });
+            self.set_weather_parameters(world, 0, duration, true, true);
+        } else if rain {
+            let duration = duration
+                .unwrap_or_else(|| rand::rng().random_range(RAIN_DURATION_MIN..=RAIN_DURATION_MAX));
+            self.set_weather_parameters(world, 0, duration, true, false);
+        } else {
+            let duration = duration
+                .unwrap_or_else(|| rand::rng().random_range(RAIN_DELAY_MIN..=RAIN_DELAY_MAX));
+            self.set_weather_parameters(world, duration, 0, false, false);
+        }
+    }
+
     pub fn set_weather_parameters(
         &mut self,
         world: &World,
@@ -68,6 +116,10 @@ impl Weather {
         self.raining = raining;
         self.thundering = thundering;
 
+        Self::broadcast_rain_change(world, was_raining, raining);
+    }
+
+    fn broadcast_rain_change(world: &World, was_raining: bool, raining: bool) {
         if was_raining != raining {
             if was_raining {
                 world.broadcast_packet_all(&CGameEvent::new(GameEvent::EndRaining, 0.0));
@@ -78,8 +130,10 @@ impl Weather {
     }
 
     pub fn tick_weather(&mut self, world: &World) {
-        if !self.weather_cycle_enabled {
+        if self.weather_cycle_enabled {
+            let was_raining = self.raining;
             self.advance_weather_cycle();
+            Self::broadcast_rain_change(world, was_raining, self.raining);
         }
 
         // Update visual transitions